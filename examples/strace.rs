@@ -0,0 +1,169 @@
+// Copyright 2014 The syscall.rs Project Developers. See the
+// COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A (very) small `strace`: runs a command and prints the syscalls it makes to stderr.
+//!
+//! Usage: `cargo run --example strace -- COMMAND [ARGS...]`
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod imp {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    use scall::ptrace::{self, SyscallStop};
+    use scall::{sig, syscall, Errno, Sysno};
+
+    fn wait(pid: i32) -> i32 {
+        let mut status = 0;
+        unsafe {
+            syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+        }
+        status
+    }
+
+    fn print_entry(nr: usize, args: &[u64; 6]) {
+        let sysno = Sysno::new(nr);
+        let nargs = sysno.and_then(Sysno::arg_count).unwrap_or(6);
+
+        match sysno {
+            Some(sysno) => eprint!("{}(", sysno),
+            None => eprint!("syscall_{}(", nr),
+        }
+        for (i, arg) in args[..nargs].iter().enumerate() {
+            if i > 0 {
+                eprint!(", ");
+            }
+            eprint!("{:#x}", arg);
+        }
+        eprint!(")");
+    }
+
+    fn print_exit(res: Result<usize, i32>) {
+        match res {
+            Ok(ret) => eprintln!(" = {}", ret as isize),
+            Err(eno) => eprintln!(" = -1 {}", Errno::new(eno)),
+        }
+    }
+
+    pub fn main() {
+        let args: Vec<CString> = std::env::args_os()
+            .skip(1)
+            .map(|arg| CString::new(arg.as_bytes()).unwrap())
+            .collect();
+        if args.is_empty() {
+            eprintln!("Usage: strace COMMAND [ARGS...]");
+            std::process::exit(2);
+        }
+        let env: Vec<CString> = std::env::vars_os()
+            .map(|(key, val)| {
+                let mut var = key.as_bytes().to_vec();
+                var.push(b'=');
+                var.extend_from_slice(val.as_bytes());
+                CString::new(var).unwrap()
+            })
+            .collect();
+
+        let mut argv: Vec<*const u8> = args.iter().map(|arg| arg.as_ptr().cast()).collect();
+        argv.push(core::ptr::null());
+        let mut envp: Vec<*const u8> = env.iter().map(|var| var.as_ptr().cast()).collect();
+        envp.push(core::ptr::null());
+
+        // Everything the child needs is allocated beforehand; it only makes raw syscalls
+        let mut stack = vec![0u8; 65536];
+        let pid = unsafe {
+            scall::clone_with_stack(
+                sig::SIGCHLD as u64,
+                stack.as_mut_ptr().add(stack.len()),
+                0,
+                core::ptr::null_mut(),
+                core::ptr::null_mut(),
+                || {
+                    if ptrace::traceme().is_err() {
+                        return 127;
+                    }
+                    let pid = scall::syscall_nofail!(GETPID);
+                    scall::syscall_nofail!(KILL, pid, sig::SIGSTOP);
+                    scall::syscall_nofail!(EXECVE, argv[0], argv.as_ptr(), envp.as_ptr());
+                    127
+                },
+            )
+            .unwrap() as i32
+        };
+
+        // Wait for the SIGSTOP
+        wait(pid);
+        ptrace::set_options(
+            pid,
+            // PTRACE_O_TRACEEXEC replaces the SIGTRAP sent after execve() with an event stop
+            ptrace::PTRACE_O_TRACESYSGOOD | ptrace::PTRACE_O_TRACEEXEC | ptrace::PTRACE_O_EXITKILL,
+        )
+        .unwrap();
+
+        // Only used if PTRACE_GET_SYSCALL_INFO isn't supported
+        let mut in_syscall = false;
+        let mut sig = 0;
+
+        let status = loop {
+            ptrace::cont_syscall(pid, sig).unwrap();
+            sig = 0;
+
+            let status = wait(pid);
+            if status & 0xff != 0x7f {
+                // The child exited or was killed
+                break status;
+            }
+
+            let stopsig = (status >> 8) & 0xff;
+            if status >> 16 != 0 {
+                // A PTRACE_EVENT_* stop
+                continue;
+            } else if stopsig != sig::SIGTRAP | 0x80 {
+                // Pass the signal on
+                sig = stopsig;
+                continue;
+            }
+
+            match ptrace::get_syscall_info(pid) {
+                Ok(info) => match info.stop {
+                    SyscallStop::Entry { nr, args } => print_entry(nr as usize, &args),
+                    SyscallStop::Exit(res) => print_exit(res),
+                    _ => (),
+                },
+
+                Err(_) => {
+                    let regs = ptrace::get_regs(pid).unwrap();
+                    if in_syscall {
+                        print_exit(regs.result());
+                    } else {
+                        print_entry(regs.nr(), &regs.args().map(|arg| arg as u64));
+                    }
+                    in_syscall = !in_syscall;
+                }
+            }
+        };
+
+        // The last syscall (exit_group()) never returns
+        eprintln!(" = ?");
+        if status & 0x7f == 0 {
+            eprintln!("+++ exited with {} +++", (status >> 8) & 0xff);
+        } else {
+            eprintln!("+++ killed by signal {} +++", status & 0x7f);
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn main() {
+    imp::main();
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn main() {
+    eprintln!("This example only works on Linux");
+}
@@ -0,0 +1,6 @@
+//! AArch64 Linux.
+
+#[path = "../platform/linux-aarch64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-aarch64/nr.rs"]
+pub mod nr;
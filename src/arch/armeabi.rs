@@ -0,0 +1,6 @@
+//! 32-bit ARM (EABI) Linux.
+
+#[path = "../platform/linux-armeabi/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-armeabi/nr.rs"]
+pub mod nr;
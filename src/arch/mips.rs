@@ -0,0 +1,6 @@
+//! 32-bit MIPS (O32 ABI) Linux.
+
+#[path = "../platform/linux-mips/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-mips/nr.rs"]
+pub mod nr;
@@ -0,0 +1,6 @@
+//! 64-bit MIPS (N64 ABI) Linux.
+
+#[path = "../platform/linux-mips64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-mips64/nr.rs"]
+pub mod nr;
@@ -0,0 +1,108 @@
+//! The system call and error number tables of every supported Linux architecture.
+//!
+//! The top-level [`nr`] and [`eno`] modules (and [`Sysno`]/[`Errno`]) always describe the
+//! native ABI of the current target. Some programs also need to handle other ABIs: for example, an
+//! x86-64 program that traces (or filters syscalls from) a 32-bit x86 process will see the
+//! syscall numbers from `linux-x86`. This module makes all of the tables available at the same
+//! time, regardless of the current target.
+//!
+//! Each submodule has an `nr` module with the syscall number constants and a `Sysno` enum, and an
+//! `eno` module with the error number constants and functions to look up their names. These are
+//! independent of the native tables (even for the current target's architecture), so their
+//! `Sysno`s can't be passed to [`syscall!`].
+//!
+//! ```
+//! use scall::arch::{x86, x86_64};
+//!
+//! assert_eq!(x86::nr::OPEN, 5);
+//! assert_eq!(x86_64::nr::OPEN, 2);
+//! assert_eq!(x86::nr::Sysno::new(5).map(|sysno| sysno.name()), Some("open"));
+//!
+//! // Error numbers differ between architectures too
+//! assert_eq!(scall::arch::mips::eno::name(89), Some("ENOSYS"));
+//! assert_eq!(scall::arch::mips::eno::from_name("ENOSYS"), Some(89));
+//! ```
+//!
+//! [`nr`]: ../nr/index.html
+//! [`eno`]: ../eno/index.html
+//! [`Sysno`]: ../nr/enum.Sysno.html
+//! [`Errno`]: ../struct.Errno.html
+//! [`syscall!`]: ../macro.syscall.html
+
+// The current target's tables are also included by `platform`, but the copies here are
+// deliberately independent of them
+#![allow(clippy::duplicate_mod)]
+
+// These shadow the macros of the same names in the crate root, so that the generated modules
+// included by the submodules don't define `Errno` constants or implement `SyscallNumber`.
+
+macro_rules! errno_table {
+    ($($name:ident $desc:literal,)* $(@alias $alias:ident = $target:ident,)*) => {
+        static ERRNO_TABLE: &[(&str, i32, &str)] = &[$((stringify!($name), $name, $desc),)*];
+        static ERRNO_ALIASES: &[(&str, i32)] = &[$((stringify!($alias), $alias),)*];
+
+        /// Get the name of the given error number (for example, `"ENOENT"`), or `None` if it is
+        /// not recognized.
+        ///
+        /// If several names refer to the same error number (like `EWOULDBLOCK` and `EAGAIN`), the
+        /// "primary" name is returned.
+        pub fn name(eno: i32) -> Option<&'static str> {
+            ERRNO_TABLE
+                .iter()
+                .find(|&&(_, e, _)| e == eno)
+                .map(|&(name, _, _)| name)
+        }
+
+        /// Get a short description of the given error number (for example, `"No such file or
+        /// directory"`), or `None` if it is not recognized.
+        pub fn description(eno: i32) -> Option<&'static str> {
+            ERRNO_TABLE
+                .iter()
+                .find(|&&(_, e, _)| e == eno)
+                .map(|&(_, _, desc)| desc)
+        }
+
+        /// Look up an error number by name (for example, `"ENOENT"`).
+        ///
+        /// Aliases (like `EWOULDBLOCK`) are also recognized.
+        pub fn from_name(name: &str) -> Option<i32> {
+            ERRNO_TABLE
+                .iter()
+                .map(|&(name, eno, _)| (name, eno))
+                .chain(ERRNO_ALIASES.iter().copied())
+                .find(|&(n, _)| n == name)
+                .map(|(_, eno)| eno)
+        }
+    };
+}
+
+macro_rules! sysno_table {
+    ($($entries:tt)*) => {
+        sysno_enum! {
+            /// A system call number for this architecture.
+            ///
+            /// Unlike the raw `usize` constants in this module, a `Sysno` knows its own name, which
+            /// makes it possible to map syscall numbers back to names (and vice versa) at runtime.
+            ///
+            /// This is not the same type as the native `Sysno`, even for the current target's
+            /// architecture, so it can't be passed to `syscall!`; use [`into_raw()`] to get the
+            /// raw number.
+            ///
+            /// [`into_raw()`]: #method.into_raw
+            ;
+            $($entries)*
+        }
+    };
+}
+
+pub mod aarch64;
+pub mod armeabi;
+pub mod mips;
+pub mod mips64;
+pub mod powerpc;
+pub mod powerpc64;
+pub mod riscv64;
+pub mod sparc64;
+pub mod x32;
+pub mod x86;
+pub mod x86_64;
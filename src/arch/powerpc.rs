@@ -0,0 +1,6 @@
+//! 32-bit PowerPC Linux.
+
+#[path = "../platform/linux-powerpc/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-powerpc/nr.rs"]
+pub mod nr;
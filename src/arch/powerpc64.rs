@@ -0,0 +1,6 @@
+//! 64-bit PowerPC Linux.
+
+#[path = "../platform/linux-powerpc64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-powerpc64/nr.rs"]
+pub mod nr;
@@ -0,0 +1,6 @@
+//! 64-bit RISC-V Linux.
+
+#[path = "../platform/linux-riscv64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-riscv64/nr.rs"]
+pub mod nr;
@@ -0,0 +1,6 @@
+//! 64-bit SPARC Linux.
+
+#[path = "../platform/linux-sparc64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-sparc64/nr.rs"]
+pub mod nr;
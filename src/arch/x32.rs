@@ -0,0 +1,6 @@
+//! x32 Linux (the ILP32 ABI on x86-64).
+
+#[path = "../platform/linux-x32/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-x32/nr.rs"]
+pub mod nr;
@@ -0,0 +1,6 @@
+//! 32-bit x86 Linux.
+
+#[path = "../platform/linux-x86/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-x86/nr.rs"]
+pub mod nr;
@@ -0,0 +1,6 @@
+//! x86-64 Linux.
+
+#[path = "../platform/linux-x86_64/eno.rs"]
+pub mod eno;
+#[path = "../platform/linux-x86_64/nr.rs"]
+pub mod nr;
@@ -0,0 +1,184 @@
+/// A type that can be passed as a system call argument.
+///
+/// The macros (like [`syscall!`]) convert each of their arguments to a `usize` with
+/// [`into_arg()`]. This is implemented for:
+///
+/// - Integers. Signed integers (including file descriptors, which are plain `i32`s) are
+///   sign-extended, and unsigned integers are zero-extended, matching what C does when passing an
+///   `int` or `unsigned int` to `syscall(2)`. 64-bit integers can only be passed directly on
+///   64-bit platforms; see [`syscall_args64!`] for 32-bit platforms.
+/// - `bool` (`0` or `1`).
+/// - Raw pointers.
+/// - References (`&T` and `&mut T`), which are passed as pointers. Slices are passed as a pointer
+///   to their first element (the length must be passed separately).
+/// - `Option<&T>` and `Option<&mut T>`, which are passed as `NULL` if they are `None`.
+/// - With the `std` feature, `BorrowedFd<'_>`, which is passed as its raw file descriptor
+///   (`RawFd` itself is just an `i32`). Note that a reference to a file descriptor type (like
+///   `&OwnedFd` or `&File`) is still a reference, and is passed as a pointer; use `as_fd()` to
+///   borrow the file descriptor instead.
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall;
+/// let mut fds = [0i32; 2];
+/// unsafe {
+///     assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+///     assert_eq!(syscall!(WRITE, fds[1], &b"abc"[..], 3), Ok(3));
+///
+///     let mut buf = [0u8; 3];
+///     assert_eq!(syscall!(READ, fds[0], &mut buf[..], buf.len()), Ok(3));
+///     assert_eq!(&buf, b"abc");
+/// #   scall::syscall_nofail!(CLOSE, fds[0]);
+/// #   scall::syscall_nofail!(CLOSE, fds[1]);
+/// }
+/// ```
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`syscall_args64!`]: ./macro.syscall_args64.html
+/// [`into_arg()`]: #tymethod.into_arg
+pub trait SyscallArg {
+    /// Convert this value to a raw system call argument.
+    fn into_arg(self) -> usize;
+}
+
+macro_rules! impl_arg_signed {
+    ($($ty:ty)*) => {$(
+        impl SyscallArg for $ty {
+            #[inline(always)]
+            fn into_arg(self) -> usize {
+                self as isize as usize
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_arg_unsigned {
+    ($($ty:ty)*) => {$(
+        impl SyscallArg for $ty {
+            #[inline(always)]
+            fn into_arg(self) -> usize {
+                self as usize
+            }
+        }
+    )*};
+}
+
+impl_arg_signed! { i8 i16 i32 isize }
+impl_arg_unsigned! { u8 u16 u32 usize bool }
+
+#[cfg(target_pointer_width = "64")]
+impl_arg_signed! { i64 }
+#[cfg(target_pointer_width = "64")]
+impl_arg_unsigned! { u64 }
+
+impl<T> SyscallArg for *const T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+impl<T> SyscallArg for *mut T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+impl<T> SyscallArg for &T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as *const T as usize
+    }
+}
+
+impl<T> SyscallArg for &mut T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as *mut T as usize
+    }
+}
+
+impl<T> SyscallArg for &[T] {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self.as_ptr() as usize
+    }
+}
+
+impl<T> SyscallArg for &mut [T] {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self.as_mut_ptr() as usize
+    }
+}
+
+impl<T> SyscallArg for Option<&T> {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self.map_or(0, SyscallArg::into_arg)
+    }
+}
+
+impl<T> SyscallArg for Option<&mut T> {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self.map_or(0, SyscallArg::into_arg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl SyscallArg for std::os::unix::io::BorrowedFd<'_> {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        std::os::unix::io::AsRawFd::as_raw_fd(&self).into_arg()
+    }
+}
+
+/// A type that a successful system call result can be converted to.
+///
+/// This is used by [`syscall_into!`] to convert the raw `usize` returned by the kernel into
+/// something more useful. It is implemented for:
+///
+/// - `()`, for syscalls whose result is always `0` on success (like `close()`).
+/// - Integers. For example, `i32` is appropriate for syscalls that return file descriptors.
+/// - Raw pointers, for syscalls like `mmap()`.
+///
+/// [`syscall_into!`]: ./macro.syscall_into.html
+pub trait SyscallRet {
+    /// Convert a raw system call result to this type.
+    fn from_ret(ret: usize) -> Self;
+}
+
+impl SyscallRet for () {
+    #[inline(always)]
+    fn from_ret(_ret: usize) -> Self {}
+}
+
+macro_rules! impl_ret_int {
+    ($($ty:ty)*) => {$(
+        impl SyscallRet for $ty {
+            #[inline(always)]
+            fn from_ret(ret: usize) -> Self {
+                ret as $ty
+            }
+        }
+    )*};
+}
+
+impl_ret_int! { i32 u32 isize usize }
+
+impl<T> SyscallRet for *const T {
+    #[inline(always)]
+    fn from_ret(ret: usize) -> Self {
+        ret as *const T
+    }
+}
+
+impl<T> SyscallRet for *mut T {
+    #[inline(always)]
+    fn from_ret(ret: usize) -> Self {
+        ret as *mut T
+    }
+}
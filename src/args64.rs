@@ -27,7 +27,29 @@
 /// ```
 ///
 /// On 32-bit systems, the `offset` argument will be split and/or aligned appropriately; on 64-bit
-/// systems it will be passed directly.
+/// systems (and with the x32 ABI, which has 64-bit registers) it will be passed directly.
+///
+/// On 64-bit systems (and x32), the number of arguments is checked just like [`syscall!`] checks
+/// it, so calls like this one fail to compile:
+///
+#[cfg_attr(
+    all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "x86_64"
+    ),
+    doc = "```compile_fail"
+)]
+#[cfg_attr(
+    not(all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "x86_64"
+    )),
+    doc = "```ignore"
+)]
+/// # use scall::syscall_args64;
+/// // pread64() takes 4 arguments
+/// unsafe { syscall_args64!(PREAD64, 0, @u64 0) };
+/// ```
 ///
 /// # Important notes
 ///
@@ -88,19 +110,42 @@ macro_rules! _scall_internal_syscall_args64 {
     };
 }
 
+// The x32 ABI has 32-bit pointers, but 64-bit registers, so 64-bit values aren't split
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _scall_internal_syscall_args64 {
+    ($nr:ident, $($sarg1:expr,)* $(@u64 $larg2:expr,)+ $($sarg3:expr,)*) => {{
+        $crate::_scall_internal_check_nargs!($nr $(, $sarg1)* $(, $larg2)+ $(, $sarg3)*);
+        $crate::decode_raw_result($crate::platform::syscall_u64(
+            $crate::nr::$nr,
+            &[
+                $($crate::SyscallArg::into_arg($sarg1) as u64,)*
+                $($larg2 as u64,)+
+                $($crate::SyscallArg::into_arg($sarg3) as u64,)*
+            ],
+        ))
+    }};
+}
+
 // Some 32-bit architectures require that 64-bit values be split, but not necessarily aligned
 // This is the *little-endian* version
 #[cfg(all(
     target_pointer_width = "32",
-    not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")),
+    not(any(
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "powerpc",
+        target_arch = "x86_64",
+    )),
     target_endian = "little",
 ))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! _scall_internal_syscall_args64 {
     ($nr:ident, $($sarg1:expr,)* $(@u64 $larg2:expr,)+ $($sarg3:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1,)* $($larg2 as u32, $larg2 as u64 >> 32,)+ $($sarg3,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1,)* $($larg2 as u32, ($larg2 as u64 >> 32) as u32,)+ $($sarg3,)*
         )
     };
 }
@@ -116,8 +161,8 @@ macro_rules! _scall_internal_syscall_args64 {
 #[macro_export]
 macro_rules! _scall_internal_syscall_args64 {
     ($nr:ident, $($sarg1:expr,)* $(@u64 $larg2:expr,)+ $($sarg3:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1,)* $($larg2 as u64 >> 32, $larg2 as u32,)+ $($sarg3,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1,)* $(($larg2 as u64 >> 32) as u32, $larg2 as u32,)+ $($sarg3,)*
         )
     };
 }
@@ -133,14 +178,14 @@ macro_rules! _scall_internal_syscall_args64 {
 #[macro_export]
 macro_rules! _scall_internal_syscall_args64 {
     ($nr:ident, $($sarg1:expr, $sarg2:expr,)* $(@u64 $larg3:expr,)+ $($sarg4:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1, $sarg2,)* $($larg3 as u32, $larg3 as u64 >> 32,)+ $($sarg4,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1, $sarg2,)* $($larg3 as u32, ($larg3 as u64 >> 32) as u32,)+ $($sarg4,)*
         )
     };
 
     ($nr:ident, $($sarg1:expr,)* $(@u64 $larg2:expr,)+ $($sarg3:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1,)* 0, $($larg2 as u32, $larg2 as u64 >> 32,)+ $($sarg3,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1,)* 0, $($larg2 as u32, ($larg2 as u64 >> 32) as u32,)+ $($sarg3,)*
         )
     };
 }
@@ -156,14 +201,14 @@ macro_rules! _scall_internal_syscall_args64 {
 #[macro_export]
 macro_rules! _scall_internal_syscall_args64 {
     ($nr:ident, $($sarg1:expr, $sarg2:expr,)* $(@u64 $larg3:expr,)+ $($sarg4:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1, $sarg2,)* $($larg3 as u64 >> 32, $larg3 as u32,)+ $($sarg4,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1, $sarg2,)* $(($larg3 as u64 >> 32) as u32, $larg3 as u32,)+ $($sarg4,)*
         )
     };
 
     ($nr:ident, $($sarg1:expr,)* $(@u64 $larg2:expr,)+ $($sarg3:expr,)*) => {
-        $crate::syscall!(
-            $nr, $($sarg1,)* 0, $($larg2 as u64 >> 32, $larg2 as u32,)+ $($sarg3,)*
+        $crate::syscall_unchecked!(
+            $nr, $($sarg1,)* 0, $(($larg2 as u64 >> 32) as u32, $larg2 as u32,)+ $($sarg3,)*
         )
     };
 }
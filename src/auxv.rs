@@ -0,0 +1,336 @@
+//! Access to the auxiliary vector (auxv).
+//!
+//! The kernel passes a list of key/value pairs to every new process just past the environment
+//! variables on the initial stack. It contains information like the page size ([`AT_PAGESZ`]),
+//! hardware capabilities ([`AT_HWCAP`]), and the location of the vDSO ([`AT_SYSINFO_EHDR`]).
+//!
+//! This module can read the auxiliary vector from two places:
+//!
+//! - From memory, given a pointer to it ([`AuxvIter::from_ptr()`]) or to the `envp` array that
+//!   precedes it ([`AuxvIter::from_envp()`]). This is what a custom `_start` would use.
+//! - From `/proc/self/auxv` ([`ProcAuxvIter::open()`]), which works anywhere `/proc` is mounted.
+//!
+//! [`getauxval()`] caches the auxiliary vector the first time it is called, so repeated lookups
+//! are cheap. By default, it reads `/proc/self/auxv`; programs that have access to the real
+//! auxiliary vector can call [`init()`] first to avoid that.
+//!
+//! # Example
+//!
+//! ```
+//! use scall::auxv;
+//!
+//! assert_eq!(auxv::getauxval(auxv::AT_PAGESZ), Some(auxv::page_size()));
+//!
+//! for (key, value) in auxv::ProcAuxvIter::open().unwrap() {
+//!     if key == auxv::AT_PAGESZ {
+//!         assert!(value.is_power_of_two());
+//!     }
+//! }
+//! ```
+//!
+//! [`AT_PAGESZ`]: ./constant.AT_PAGESZ.html
+//! [`AT_HWCAP`]: ./constant.AT_HWCAP.html
+//! [`AT_SYSINFO_EHDR`]: ./constant.AT_SYSINFO_EHDR.html
+//! [`AuxvIter::from_ptr()`]: ./struct.AuxvIter.html#method.from_ptr
+//! [`AuxvIter::from_envp()`]: ./struct.AuxvIter.html#method.from_envp
+//! [`ProcAuxvIter::open()`]: ./struct.ProcAuxvIter.html#method.open
+//! [`getauxval()`]: ./fn.getauxval.html
+//! [`init()`]: ./fn.init.html
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// End of the vector.
+pub const AT_NULL: usize = 0;
+/// Entry should be ignored.
+pub const AT_IGNORE: usize = 1;
+/// File descriptor of the program.
+pub const AT_EXECFD: usize = 2;
+/// Address of the program headers of the program.
+pub const AT_PHDR: usize = 3;
+/// Size of each program header.
+pub const AT_PHENT: usize = 4;
+/// Number of program headers.
+pub const AT_PHNUM: usize = 5;
+/// System page size.
+pub const AT_PAGESZ: usize = 6;
+/// Base address of the interpreter.
+pub const AT_BASE: usize = 7;
+/// Flags (unused).
+pub const AT_FLAGS: usize = 8;
+/// Entry point of the program.
+pub const AT_ENTRY: usize = 9;
+/// Program is not ELF.
+pub const AT_NOTELF: usize = 10;
+/// Real UID.
+pub const AT_UID: usize = 11;
+/// Effective UID.
+pub const AT_EUID: usize = 12;
+/// Real GID.
+pub const AT_GID: usize = 13;
+/// Effective GID.
+pub const AT_EGID: usize = 14;
+/// Address of a string identifying the CPU (for optimizations).
+pub const AT_PLATFORM: usize = 15;
+/// Architecture-specific hints about CPU capabilities.
+pub const AT_HWCAP: usize = 16;
+/// Frequency at which `times()` increments.
+pub const AT_CLKTCK: usize = 17;
+/// Nonzero if the program is running in "secure mode" (for example, it is setuid).
+pub const AT_SECURE: usize = 23;
+/// Address of a string identifying the real platform (may differ from `AT_PLATFORM`).
+pub const AT_BASE_PLATFORM: usize = 24;
+/// Address of 16 random bytes.
+pub const AT_RANDOM: usize = 25;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP2: usize = 26;
+/// Size of the rseq feature set supported by the kernel.
+pub const AT_RSEQ_FEATURE_SIZE: usize = 27;
+/// Required alignment of the rseq area.
+pub const AT_RSEQ_ALIGN: usize = 28;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP3: usize = 29;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP4: usize = 30;
+/// Address of the filename of the program.
+pub const AT_EXECFN: usize = 31;
+/// Address of the vDSO's entry point for making syscalls.
+#[cfg(target_arch = "x86")]
+pub const AT_SYSINFO: usize = 32;
+/// Address of the vDSO's ELF header.
+pub const AT_SYSINFO_EHDR: usize = 33;
+/// Minimal stack size required for signal delivery.
+pub const AT_MINSIGSTKSZ: usize = 51;
+
+/// An iterator over an auxiliary vector in memory.
+///
+/// This yields `(key, value)` pairs, and stops at the terminating [`AT_NULL`] entry.
+///
+/// [`AT_NULL`]: ./constant.AT_NULL.html
+#[derive(Clone, Debug)]
+pub struct AuxvIter {
+    ptr: *const usize,
+}
+
+impl AuxvIter {
+    /// Iterate over the auxiliary vector at `auxv`.
+    ///
+    /// # Safety
+    ///
+    /// `auxv` must point to a valid auxiliary vector, terminated by an `AT_NULL` entry, that
+    /// outlives the iterator.
+    #[inline]
+    pub unsafe fn from_ptr(auxv: *const usize) -> Self {
+        Self { ptr: auxv }
+    }
+
+    /// Iterate over the auxiliary vector that follows the given environment array.
+    ///
+    /// On process startup, the kernel places the auxiliary vector immediately after the `NULL`
+    /// that terminates `envp`.
+    ///
+    /// # Safety
+    ///
+    /// `envp` must be the `envp` array that the kernel passed to the program, and it must not have
+    /// been modified (for example, by `setenv()` moving the environment to the heap).
+    pub unsafe fn from_envp(envp: *const *const u8) -> Self {
+        let mut ptr = envp;
+        while !(*ptr).is_null() {
+            ptr = ptr.add(1);
+        }
+        Self::from_ptr(ptr.add(1) as *const usize)
+    }
+
+    /// Get a pointer to the current position in the auxiliary vector.
+    #[inline]
+    pub fn as_ptr(&self) -> *const usize {
+        self.ptr
+    }
+}
+
+impl Iterator for AuxvIter {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = unsafe { (*self.ptr, *self.ptr.add(1)) };
+        if key == AT_NULL {
+            None
+        } else {
+            self.ptr = self.ptr.wrapping_add(2);
+            Some((key, value))
+        }
+    }
+}
+
+/// An iterator over the current process's auxiliary vector, read from `/proc/self/auxv`.
+///
+/// This yields `(key, value)` pairs, and stops at the terminating [`AT_NULL`] entry (or if an
+/// error occurs while reading the file). It does not allocate; the file is read in small chunks.
+///
+/// [`AT_NULL`]: ./constant.AT_NULL.html
+#[derive(Debug)]
+pub struct ProcAuxvIter {
+    fd: i32,
+    buf: [usize; 32],
+    pos: usize,
+    len: usize,
+    done: bool,
+}
+
+impl ProcAuxvIter {
+    /// Open `/proc/self/auxv` for reading.
+    pub fn open() -> Result<Self, i32> {
+        let fd = unsafe {
+            crate::syscall!(
+                OPENAT,
+                crate::consts::AT_FDCWD,
+                b"/proc/self/auxv\0",
+                crate::consts::O_RDONLY | crate::consts::O_CLOEXEC,
+                0
+            )?
+        };
+
+        Ok(Self {
+            fd: fd as i32,
+            buf: [0; 32],
+            pos: 0,
+            len: 0,
+            done: false,
+        })
+    }
+
+    fn fill(&mut self) -> usize {
+        // Fill the buffer completely (or until EOF) so entries are never split across reads
+        let size = core::mem::size_of_val(&self.buf);
+        let mut nread = 0;
+        while nread < size {
+            match unsafe {
+                crate::syscall_retry!(
+                    READ,
+                    self.fd,
+                    (self.buf.as_mut_ptr() as *mut u8).wrapping_add(nread),
+                    size - nread
+                )
+            } {
+                Ok(0) | Err(_) => break,
+                Ok(n) => nread += n,
+            }
+        }
+
+        nread / core::mem::size_of::<usize>() / 2 * 2
+    }
+}
+
+impl Iterator for ProcAuxvIter {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        } else if self.pos == self.len {
+            self.pos = 0;
+            self.len = self.fill();
+        }
+
+        if self.pos == self.len || self.buf[self.pos] == AT_NULL {
+            self.done = true;
+            return None;
+        }
+
+        let item = (self.buf[self.pos], self.buf[self.pos + 1]);
+        self.pos += 2;
+        Some(item)
+    }
+}
+
+impl Drop for ProcAuxvIter {
+    fn drop(&mut self) {
+        unsafe {
+            crate::syscall_nofail!(CLOSE, self.fd);
+        }
+    }
+}
+
+// The cached auxiliary vector used by `getauxval()`. Real auxiliary vectors have fewer than 40
+// entries on every architecture.
+const CACHE_SIZE: usize = 64;
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicUsize = AtomicUsize::new(0);
+static CACHE: [AtomicUsize; CACHE_SIZE * 2] = [ZERO; CACHE_SIZE * 2];
+// The number of entries in `CACHE`, or `usize::MAX` if it has not been filled yet.
+static CACHE_LEN: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+fn fill_cache(iter: impl Iterator<Item = (usize, usize)>) -> usize {
+    let mut len = 0;
+    for (key, value) in iter.take(CACHE_SIZE) {
+        CACHE[len * 2].store(key, Ordering::Relaxed);
+        CACHE[len * 2 + 1].store(value, Ordering::Relaxed);
+        len += 1;
+    }
+    CACHE_LEN.store(len, Ordering::Release);
+    len
+}
+
+/// Initialize the cache used by [`getauxval()`] from the auxiliary vector at `auxv`.
+///
+/// This is optional; if it is not called, [`getauxval()`] reads `/proc/self/auxv` on first use.
+///
+/// # Safety
+///
+/// `auxv` must point to a valid auxiliary vector (see [`AuxvIter::from_ptr()`]).
+///
+/// [`getauxval()`]: ./fn.getauxval.html
+/// [`AuxvIter::from_ptr()`]: ./struct.AuxvIter.html#method.from_ptr
+pub unsafe fn init(auxv: *const usize) {
+    fill_cache(AuxvIter::from_ptr(auxv));
+}
+
+/// Look up the value of the given entry in the auxiliary vector of the current process.
+///
+/// Returns `None` if the entry is not present (or if the auxiliary vector could not be read).
+/// Unlike the C library's `getauxval()`, this can distinguish between a missing entry and one
+/// whose value is 0.
+///
+/// If [`init()`] was not called, the auxiliary vector is read from `/proc/self/auxv` the first
+/// time this is called. If that fails (for example, because `/proc` is not mounted), it is tried
+/// again on the next call.
+///
+/// [`init()`]: ./fn.init.html
+pub fn getauxval(key: usize) -> Option<usize> {
+    let mut len = CACHE_LEN.load(Ordering::Acquire);
+    if len == usize::MAX {
+        // Several threads may race to fill the cache, but they will all store the same values.
+        // Nothing is cached on failure, so that a later call (or `init()`) can fill it instead.
+        len = match ProcAuxvIter::open() {
+            Ok(iter) => fill_cache(iter),
+            Err(_) => return None,
+        };
+    }
+
+    (0..len)
+        .find(|&i| CACHE[i * 2].load(Ordering::Relaxed) == key)
+        .map(|i| CACHE[i * 2 + 1].load(Ordering::Relaxed))
+}
+
+/// Get the system page size (`AT_PAGESZ`).
+///
+/// If the auxiliary vector could not be read, this returns 4096.
+pub fn page_size() -> usize {
+    getauxval(AT_PAGESZ).unwrap_or(4096)
+}
+
+/// Check whether the program is running in "secure mode" (`AT_SECURE`), for example because it
+/// is setuid or setgid. Programs in secure mode should be careful about trusting their
+/// environment.
+///
+/// If the auxiliary vector could not be read, this returns `true` to be safe.
+pub fn secure() -> bool {
+    getauxval(AT_SECURE) != Some(0)
+}
+
+/// Get the 16 random bytes that the kernel provides to every process (`AT_RANDOM`).
+pub fn random_bytes() -> Option<&'static [u8; 16]> {
+    getauxval(AT_RANDOM)
+        .filter(|&addr| addr != 0)
+        .map(|addr| unsafe { &*(addr as *const [u8; 16]) })
+}
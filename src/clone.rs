@@ -0,0 +1,215 @@
+use core::mem::{align_of, size_of, ManuallyDrop};
+
+// Placed at the top of the child's stack. `raw_clone()` calls `func` in the child, passing it a
+// pointer to this structure, and exits with the value it returns.
+#[repr(C)]
+struct Start<F> {
+    func: unsafe extern "C" fn(*mut Start<F>) -> i32,
+    f: ManuallyDrop<F>,
+}
+
+unsafe extern "C" fn child_main<F: FnOnce() -> i32>(start: *mut Start<F>) -> i32 {
+    // There is nothing to unwind into, so if `f` panics, exit the thread instead
+    struct ExitOnUnwind;
+
+    impl Drop for ExitOnUnwind {
+        fn drop(&mut self) {
+            loop {
+                unsafe {
+                    crate::syscall_nofail!(EXIT, 101);
+                }
+            }
+        }
+    }
+
+    let guard = ExitOnUnwind;
+    let f = ManuallyDrop::take(&mut (*start).f);
+    let status = f();
+    core::mem::forget(guard);
+    status
+}
+
+// Write a `Start` to the top of the stack (aligned to 16 bytes, which satisfies every
+// architecture's ABI), and return a pointer to it.
+unsafe fn write_start<F: FnOnce() -> i32>(stack: *mut u8, f: F) -> *mut Start<F> {
+    let align = core::cmp::max(16, align_of::<Start<F>>());
+    let start = (stack as usize - size_of::<Start<F>>()) & !(align - 1);
+    let start = start as *mut Start<F>;
+
+    start.write(Start {
+        func: child_main::<F>,
+        f: ManuallyDrop::new(f),
+    });
+
+    start
+}
+
+// Clean up after `raw_clone()`. If the child shares our memory, it now owns `f`; otherwise (or if
+// the syscall failed), our copy has to be dropped.
+unsafe fn finish<F>(
+    res: crate::RawResult,
+    shared: bool,
+    start: *mut Start<F>,
+) -> Result<usize, i32> {
+    let res = crate::decode_raw_result(res);
+    if res.is_err() || !shared {
+        ManuallyDrop::drop(&mut (*start).f);
+    }
+    res
+}
+
+/// Create a new process or thread running on a new stack with `clone()`, and run `f` in it.
+///
+/// `stack` points to the *top* (i.e. the highest address) of the child's stack; a small amount
+/// of data is stored just below it before the child starts. In the child, `f` is called on the
+/// new stack, and the child exits (with `exit()`, not `exit_group()`) using the value it returns
+/// as the exit status. If `f` panics, the child exits with status 101. In the parent, this returns
+/// the child's thread ID.
+///
+/// `flags` holds the `CLONE_*` flags, combined with the signal to send to the parent when the
+/// child exits (usually `SIGCHLD`). Only the low 32 bits are used; the flags that don't fit
+/// require [`clone3_with_stack()`].
+///
+/// `tls`, `ptid`, and `ctid` are passed to `clone()` as the "TLS", "parent TID", and "child TID"
+/// arguments. They are only used if the corresponding flags (`CLONE_SETTLS`, `CLONE_PARENT_SETTID`,
+/// and `CLONE_CHILD_SETTID`/`CLONE_CHILD_CLEARTID`) are set in `flags`; otherwise they can be 0 or
+/// null. (The argument order of the raw syscall differs between architectures; this function
+/// takes care of that.) On 32-bit x86, `tls` is a pointer to a `struct user_desc`.
+///
+/// If `flags` includes `CLONE_VM`, the child takes ownership of `f` and is responsible for dropping
+/// it. Otherwise, the child gets its own copy of `f` (along with the rest of the parent's memory),
+/// and the parent's copy is dropped before this returns.
+///
+/// # Safety
+///
+/// - `stack` must point to the end of a writable region of memory that is large enough to serve as
+///   the child's stack, and (if `flags` includes `CLONE_VM`) it must remain valid until the child
+///   exits.
+/// - If `flags` includes `CLONE_VM`, anything that `f` borrows must remain valid until the child
+///   exits (`CLONE_VFORK` is an easy way to guarantee that), and `f` must be safe to run
+///   concurrently with the parent.
+/// - The child runs with the same thread-local storage as the parent, unless `CLONE_SETTLS` is
+///   used. In particular, the C library and the standard library will not work correctly in a
+///   child that shares the parent's memory, since they don't know about the new thread.
+/// - All the usual caveats of `clone()` apply; see clone(2).
+///
+/// # Example
+///
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use scall::{consts, sig};
+///
+/// let value = AtomicU32::new(0);
+/// let mut stack = [0u8; 16384];
+///
+/// let pid = unsafe {
+///     scall::clone_with_stack(
+///         consts::CLONE_VM | consts::CLONE_VFORK | sig::SIGCHLD as u64,
+///         stack.as_mut_ptr().add(stack.len()),
+///         0,
+///         core::ptr::null_mut(),
+///         core::ptr::null_mut(),
+///         || {
+///             value.store(1, Ordering::SeqCst);
+///             0
+///         },
+///     )
+///     .unwrap()
+/// };
+///
+/// // Because of CLONE_VFORK, the child has already exited
+/// assert_eq!(value.load(Ordering::SeqCst), 1);
+///
+/// let mut status = 0;
+/// unsafe {
+///     scall::syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+/// }
+/// assert_eq!(status, 0);
+/// ```
+///
+/// [`clone3_with_stack()`]: ./fn.clone3_with_stack.html
+pub unsafe fn clone_with_stack<F: FnOnce() -> i32>(
+    flags: u64,
+    stack: *mut u8,
+    tls: usize,
+    ptid: *mut i32,
+    ctid: *mut i32,
+    f: F,
+) -> Result<usize, i32> {
+    let start = write_start(stack, f);
+
+    // On SPARC, the stack pointer is offset by the stack bias (2047), and there must be room for a
+    // register save area (176 bytes) above it.
+    #[cfg(target_arch = "sparc64")]
+    let sp = start as usize - 176 - 2047;
+    #[cfg(not(target_arch = "sparc64"))]
+    let sp = start as usize;
+
+    #[cfg(target_arch = "x86_64")]
+    let res = crate::platform::raw_clone(
+        crate::nr::CLONE,
+        flags as usize,
+        sp,
+        ptid as usize,
+        ctid as usize,
+        tls,
+    );
+    #[cfg(not(target_arch = "x86_64"))]
+    let res = crate::platform::raw_clone(
+        crate::nr::CLONE,
+        flags as usize,
+        sp,
+        ptid as usize,
+        tls,
+        ctid as usize,
+    );
+
+    finish(res, flags & crate::consts::CLONE_VM != 0, start)
+}
+
+/// Create a new process or thread running on a new stack with `clone3()`, and run `f` in it.
+///
+/// This is the same as [`clone_with_stack()`], except that the arguments are specified with a
+/// [`clone_args`] structure. `args.stack` and `args.stack_size` describe the child's stack (they
+/// must both be nonzero, or this fails with `EINVAL`); the rest of the fields are passed through
+/// to the kernel unchanged.
+///
+/// `clone3()` was added in Linux 5.3; on older kernels, this fails with `ENOSYS`.
+///
+/// # Safety
+///
+/// See [`clone_with_stack()`]. The region described by `args.stack` and `args.stack_size` must
+/// satisfy the same requirements as the `stack` argument of [`clone_with_stack()`].
+///
+/// [`clone_with_stack()`]: ./fn.clone_with_stack.html
+/// [`clone_args`]: ./types/struct.clone_args.html
+#[cfg(not(target_arch = "sparc64"))]
+#[doc(cfg(not(target_arch = "sparc64")))]
+pub unsafe fn clone3_with_stack<F: FnOnce() -> i32>(
+    args: &crate::types::clone_args,
+    f: F,
+) -> Result<usize, i32> {
+    if args.stack == 0 || args.stack_size == 0 {
+        return Err(crate::eno::EINVAL);
+    }
+
+    let stack = args.stack as usize;
+    let start = write_start((stack + args.stack_size as usize) as *mut u8, f);
+
+    // The kernel starts the child with its stack pointer at `stack + stack_size`
+    let args = crate::types::clone_args {
+        stack_size: (start as usize - stack) as u64,
+        ..*args
+    };
+
+    let res = crate::platform::raw_clone(
+        crate::nr::CLONE3,
+        &args as *const _ as usize,
+        size_of::<crate::types::clone_args>(),
+        0,
+        0,
+        0,
+    );
+
+    finish(res, args.flags & crate::consts::CLONE_VM != 0, start)
+}
@@ -0,0 +1,277 @@
+//! Typed wrappers around the `epoll` syscalls.
+//!
+//! `struct epoll_event` is packed on x86-64 (so the 64-bit `data` field is not naturally aligned),
+//! but not on any other architecture. [`EpollEvent`] wraps the correct definition for the target,
+//! and the functions in this module take care of the other differences between architectures:
+//! `epoll_wait()` does not exist on AArch64 or RISC-V, so [`epoll_wait()`] uses `epoll_pwait()`
+//! there instead.
+//!
+//! # Example
+//!
+//! ```
+//! use scall::epoll::{self, EpollEvent, EPOLLIN, EPOLL_CLOEXEC, EPOLL_CTL_ADD};
+//! use scall::syscall;
+//!
+//! let epfd = epoll::epoll_create1(EPOLL_CLOEXEC).unwrap();
+//!
+//! let mut fds = [0i32; 2];
+//! unsafe {
+//!     syscall!(PIPE2, fds.as_mut_ptr(), 0).unwrap();
+//! }
+//!
+//! let event = EpollEvent::new(EPOLLIN, 42);
+//! epoll::epoll_ctl(epfd, EPOLL_CTL_ADD, fds[0], Some(&event)).unwrap();
+//!
+//! unsafe {
+//!     syscall!(WRITE, fds[1], b"x".as_ptr(), 1).unwrap();
+//! }
+//!
+//! let mut events = [EpollEvent::default(); 4];
+//! assert_eq!(epoll::epoll_wait(epfd, &mut events, -1), Ok(1));
+//! assert_eq!(events[0].events(), EPOLLIN);
+//! assert_eq!(events[0].data(), 42);
+//! #
+//! # unsafe {
+//! #     scall::syscall_nofail!(CLOSE, fds[0]);
+//! #     scall::syscall_nofail!(CLOSE, fds[1]);
+//! #     scall::syscall_nofail!(CLOSE, epfd);
+//! # }
+//! ```
+//!
+//! [`EpollEvent`]: ./struct.EpollEvent.html
+//! [`epoll_wait()`]: ./fn.epoll_wait.html
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::futex::Timespec64;
+use crate::{consts, types, SigSet};
+
+/// Set the close-on-exec flag on the new file descriptor.
+pub const EPOLL_CLOEXEC: i32 = consts::O_CLOEXEC;
+
+/// Add a file descriptor to the interest list.
+pub const EPOLL_CTL_ADD: i32 = 1;
+/// Remove a file descriptor from the interest list.
+pub const EPOLL_CTL_DEL: i32 = 2;
+/// Change the settings of a file descriptor in the interest list.
+pub const EPOLL_CTL_MOD: i32 = 3;
+
+/// The file descriptor is available for reading.
+pub const EPOLLIN: u32 = 0x1;
+/// There is an exceptional condition (for example, out-of-band data) on the file descriptor.
+pub const EPOLLPRI: u32 = 0x2;
+/// The file descriptor is available for writing.
+pub const EPOLLOUT: u32 = 0x4;
+/// An error occurred on the file descriptor (always reported).
+pub const EPOLLERR: u32 = 0x8;
+/// The file descriptor was hung up (always reported).
+pub const EPOLLHUP: u32 = 0x10;
+/// The file descriptor is invalid (only used by `poll()`).
+pub const EPOLLNVAL: u32 = 0x20;
+/// Normal data is available for reading (the same as `EPOLLIN`).
+pub const EPOLLRDNORM: u32 = 0x40;
+/// Priority data is available for reading.
+pub const EPOLLRDBAND: u32 = 0x80;
+/// Normal data can be written (the same as `EPOLLOUT`).
+pub const EPOLLWRNORM: u32 = 0x100;
+/// Priority data can be written.
+pub const EPOLLWRBAND: u32 = 0x200;
+/// Unused.
+pub const EPOLLMSG: u32 = 0x400;
+/// The peer closed the connection or shut down its writing half.
+pub const EPOLLRDHUP: u32 = 0x2000;
+/// Only wake up one of the `epoll` instances waiting on the file descriptor.
+pub const EPOLLEXCLUSIVE: u32 = 1 << 28;
+/// Keep the system from suspending while the event is pending (requires `CAP_BLOCK_SUSPEND`).
+pub const EPOLLWAKEUP: u32 = 1 << 29;
+/// Disable the file descriptor after one event is reported.
+pub const EPOLLONESHOT: u32 = 1 << 30;
+/// Edge-triggered notification.
+pub const EPOLLET: u32 = 1 << 31;
+
+/// An event for [`epoll_ctl()`] and [`epoll_wait()`] (`struct epoll_event`).
+///
+/// This has the same layout as [`types::epoll_event`], which is packed on x86-64. Since fields of
+/// a packed structure can't be borrowed, the fields are accessed through methods.
+///
+/// [`epoll_ctl()`]: ./fn.epoll_ctl.html
+/// [`epoll_wait()`]: ./fn.epoll_wait.html
+/// [`types::epoll_event`]: ../types/struct.epoll_event.html
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EpollEvent(types::epoll_event);
+
+impl EpollEvent {
+    /// Create a new `EpollEvent` with the given `EPOLL*` flags and user data.
+    #[inline]
+    pub const fn new(events: u32, data: u64) -> Self {
+        Self(types::epoll_event { events, data })
+    }
+
+    /// Get the `EPOLL*` flags.
+    #[inline]
+    pub const fn events(&self) -> u32 {
+        self.0.events
+    }
+
+    /// Get the user data.
+    #[inline]
+    pub const fn data(&self) -> u64 {
+        self.0.data
+    }
+
+    /// Set the `EPOLL*` flags.
+    #[inline]
+    pub fn set_events(&mut self, events: u32) {
+        self.0.events = events;
+    }
+
+    /// Set the user data.
+    #[inline]
+    pub fn set_data(&mut self, data: u64) {
+        self.0.data = data;
+    }
+}
+
+impl From<types::epoll_event> for EpollEvent {
+    #[inline]
+    fn from(event: types::epoll_event) -> Self {
+        Self(event)
+    }
+}
+
+impl From<EpollEvent> for types::epoll_event {
+    #[inline]
+    fn from(event: EpollEvent) -> Self {
+        event.0
+    }
+}
+
+impl fmt::Debug for EpollEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EpollEvent")
+            .field("events", &self.events())
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+/// Create a new `epoll` instance, and return its file descriptor.
+///
+/// `flags` may contain [`EPOLL_CLOEXEC`].
+///
+/// [`EPOLL_CLOEXEC`]: ./constant.EPOLL_CLOEXEC.html
+#[inline]
+pub fn epoll_create1(flags: i32) -> Result<i32, i32> {
+    unsafe { crate::syscall!(EPOLL_CREATE1, flags).map(|fd| fd as i32) }
+}
+
+/// Add, modify, or remove an entry in the interest list of the `epoll` instance `epfd`.
+///
+/// `op` is one of [`EPOLL_CTL_ADD`], [`EPOLL_CTL_MOD`], or [`EPOLL_CTL_DEL`]. `event` may only be
+/// `None` for `EPOLL_CTL_DEL`.
+///
+/// [`EPOLL_CTL_ADD`]: ./constant.EPOLL_CTL_ADD.html
+/// [`EPOLL_CTL_MOD`]: ./constant.EPOLL_CTL_MOD.html
+/// [`EPOLL_CTL_DEL`]: ./constant.EPOLL_CTL_DEL.html
+#[inline]
+pub fn epoll_ctl(epfd: i32, op: i32, fd: i32, event: Option<&EpollEvent>) -> Result<(), i32> {
+    unsafe {
+        crate::syscall!(EPOLL_CTL, epfd, op, fd, event)?;
+    }
+    Ok(())
+}
+
+// The kernel rejects `maxevents` values that don't fit in an `int` (or that are larger than
+// `INT_MAX / sizeof(struct epoll_event)`); there's no point in passing more than that anyway.
+#[inline]
+fn max_events(events: &[EpollEvent]) -> usize {
+    core::cmp::min(
+        events.len(),
+        i32::MAX as usize / core::mem::size_of::<EpollEvent>(),
+    )
+}
+
+/// Wait for events on the `epoll` instance `epfd`, and return the number of events that were
+/// stored in `events`.
+///
+/// `timeout` is in milliseconds; -1 means to wait indefinitely, and 0 means to return
+/// immediately.
+///
+/// On architectures where `epoll_wait()` doesn't exist (AArch64 and RISC-V), this uses
+/// `epoll_pwait()` instead.
+#[inline]
+pub fn epoll_wait(epfd: i32, events: &mut [EpollEvent], timeout: i32) -> Result<usize, i32> {
+    let maxevents = max_events(events);
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
+    unsafe {
+        crate::syscall!(EPOLL_WAIT, epfd, events.as_mut_ptr(), maxevents, timeout)
+    }
+
+    #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+    unsafe {
+        crate::syscall!(
+            EPOLL_PWAIT,
+            epfd,
+            events.as_mut_ptr(),
+            maxevents,
+            timeout,
+            0,
+            SigSet::SIZE
+        )
+    }
+}
+
+/// Like [`epoll_wait()`], but atomically replace the signal mask with `sigmask` (if it is not
+/// `None`) while waiting.
+///
+/// [`epoll_wait()`]: ./fn.epoll_wait.html
+#[inline]
+pub fn epoll_pwait(
+    epfd: i32,
+    events: &mut [EpollEvent],
+    timeout: i32,
+    sigmask: Option<&SigSet>,
+) -> Result<usize, i32> {
+    unsafe {
+        crate::syscall!(
+            EPOLL_PWAIT,
+            epfd,
+            events.as_mut_ptr(),
+            max_events(events),
+            timeout,
+            sigmask,
+            SigSet::SIZE
+        )
+    }
+}
+
+/// Like [`epoll_pwait()`], but with a timeout with nanosecond precision (`epoll_pwait2()`, Linux
+/// 5.11+).
+///
+/// A `timeout` of `None` means to wait indefinitely.
+///
+/// [`epoll_pwait()`]: ./fn.epoll_pwait.html
+#[inline]
+pub fn epoll_pwait2(
+    epfd: i32,
+    events: &mut [EpollEvent],
+    timeout: Option<Duration>,
+    sigmask: Option<&SigSet>,
+) -> Result<usize, i32> {
+    let ts = timeout.map(Timespec64::from);
+
+    unsafe {
+        crate::syscall!(
+            EPOLL_PWAIT2,
+            epfd,
+            events.as_mut_ptr(),
+            max_events(events),
+            ts.as_ref(),
+            sigmask,
+            SigSet::SIZE
+        )
+    }
+}
@@ -0,0 +1,316 @@
+//! Typed wrappers around the `futex()` and `futex_waitv()` syscalls.
+//!
+//! A futex is a 32-bit integer in memory that threads can wait on and wake each other up through.
+//! The kernel only gets involved when a thread actually needs to sleep; the "fast path" of a lock
+//! built on futexes is a single atomic operation in userspace. See futex(2) for details.
+//!
+//! The functions in this module take the futex word as an `&AtomicU32`, and they take care of
+//! the details of the raw syscall: the op codes, the timeout format (including using
+//! `futex_time64()` on 32-bit architectures, where `futex()` only accepts 32-bit timestamps), and
+//! the odd argument layout of `FUTEX_CMP_REQUEUE`.
+//!
+//! Most programs want the higher-level primitives in [`sync`] instead.
+//!
+//! # Example
+//!
+//! ```
+//! use core::sync::atomic::{AtomicU32, Ordering};
+//! use core::time::Duration;
+//! use scall::{eno, futex};
+//!
+//! let word = AtomicU32::new(0);
+//!
+//! // The value doesn't match, so this fails immediately
+//! assert_eq!(futex::futex_wait(&word, 1, None, futex::FUTEX_PRIVATE_FLAG), Err(eno::EAGAIN));
+//!
+//! // Nobody wakes us up, so this times out
+//! assert_eq!(
+//!     futex::futex_wait(&word, 0, Some(Duration::from_millis(1)), futex::FUTEX_PRIVATE_FLAG),
+//!     Err(eno::ETIMEDOUT),
+//! );
+//!
+//! word.store(1, Ordering::Release);
+//! assert_eq!(futex::futex_wake(&word, u32::MAX, futex::FUTEX_PRIVATE_FLAG), Ok(0));
+//! ```
+//!
+//! [`sync`]: ../sync/index.html
+
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicU32;
+use core::time::Duration;
+
+/// Wait until the futex is woken up, if it has the expected value.
+pub const FUTEX_WAIT: i32 = 0;
+/// Wake up waiters on the futex.
+pub const FUTEX_WAKE: i32 = 1;
+/// Wake up waiters on the futex, and move the rest to another futex.
+pub const FUTEX_REQUEUE: i32 = 3;
+/// Like `FUTEX_REQUEUE`, but only if the futex has the expected value.
+pub const FUTEX_CMP_REQUEUE: i32 = 4;
+/// Modify another futex and wake up waiters on one or both futexes.
+pub const FUTEX_WAKE_OP: i32 = 5;
+/// Acquire a priority-inheritance futex.
+pub const FUTEX_LOCK_PI: i32 = 6;
+/// Release a priority-inheritance futex.
+pub const FUTEX_UNLOCK_PI: i32 = 7;
+/// Try to acquire a priority-inheritance futex.
+pub const FUTEX_TRYLOCK_PI: i32 = 8;
+/// Like `FUTEX_WAIT`, but with a bitmask and an absolute timeout.
+pub const FUTEX_WAIT_BITSET: i32 = 9;
+/// Like `FUTEX_WAKE`, but only wake waiters whose bitmask matches.
+pub const FUTEX_WAKE_BITSET: i32 = 10;
+/// Wait on a futex, to be requeued to a priority-inheritance futex.
+pub const FUTEX_WAIT_REQUEUE_PI: i32 = 11;
+/// Requeue waiters to a priority-inheritance futex.
+pub const FUTEX_CMP_REQUEUE_PI: i32 = 12;
+/// Like `FUTEX_LOCK_PI`, but the timeout is measured against `CLOCK_MONOTONIC`.
+pub const FUTEX_LOCK_PI2: i32 = 13;
+
+/// Flag indicating that the futex is only used within the current process.
+///
+/// This allows the kernel to skip some work, so it should be passed whenever possible.
+pub const FUTEX_PRIVATE_FLAG: i32 = 128;
+/// Flag indicating that an absolute timeout should be measured against `CLOCK_REALTIME` instead
+/// of `CLOCK_MONOTONIC`.
+pub const FUTEX_CLOCK_REALTIME: i32 = 256;
+
+/// A bitmask for `FUTEX_WAIT_BITSET`/`FUTEX_WAKE_BITSET` that matches everything.
+pub const FUTEX_BITSET_MATCH_ANY: u32 = !0;
+
+/// `futex_waitv()` flag indicating that the futex is 8 bits.
+pub const FUTEX2_SIZE_U8: u32 = 0x00;
+/// `futex_waitv()` flag indicating that the futex is 16 bits.
+pub const FUTEX2_SIZE_U16: u32 = 0x01;
+/// `futex_waitv()` flag indicating that the futex is 32 bits (the only size currently supported).
+pub const FUTEX2_SIZE_U32: u32 = 0x02;
+/// `futex_waitv()` flag indicating that the futex is 64 bits.
+pub const FUTEX2_SIZE_U64: u32 = 0x03;
+/// `futex_waitv()` flag indicating that the futex is only used within the current process.
+pub const FUTEX2_PRIVATE: u32 = FUTEX_PRIVATE_FLAG as u32;
+
+/// The maximum number of futexes that can be passed to [`futex_waitv()`].
+///
+/// [`futex_waitv()`]: ./fn.futex_waitv.html
+pub const FUTEX_WAITV_MAX: usize = 128;
+
+// `struct __kernel_timespec`, which has a 64-bit `tv_sec` on every architecture. (Also used by
+// `epoll_pwait2()`.)
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub(crate) struct Timespec64 {
+    tv_sec: i64,
+    tv_nsec: i64,
+}
+
+impl From<Duration> for Timespec64 {
+    #[inline]
+    fn from(d: Duration) -> Self {
+        Self {
+            tv_sec: if d.as_secs() > i64::MAX as u64 {
+                i64::MAX
+            } else {
+                d.as_secs() as i64
+            },
+            tv_nsec: d.subsec_nanos() as i64,
+        }
+    }
+}
+
+// The raw `futex()` syscall, with a relative timeout (for the ops that take one). On 32-bit
+// architectures, `futex()` takes a 32-bit `struct timespec`, so `futex_time64()` is tried first
+// (falling back to `futex()` on kernels older than 5.1).
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+))]
+unsafe fn futex(
+    uaddr: &AtomicU32,
+    op: i32,
+    val: u32,
+    timeout: Option<Duration>,
+    uaddr2: Option<&AtomicU32>,
+    val3: u32,
+) -> Result<usize, i32> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return crate::syscall!(FUTEX, uaddr, op, val, 0, uaddr2, val3),
+    };
+
+    let ts = Timespec64::from(timeout);
+    match crate::syscall!(FUTEX_TIME64, uaddr, op, val, &ts, uaddr2, val3) {
+        Err(crate::eno::ENOSYS) => (),
+        res => return res,
+    }
+
+    let ts = crate::types::timespec {
+        tv_sec: if ts.tv_sec > isize::MAX as i64 {
+            isize::MAX
+        } else {
+            ts.tv_sec as isize
+        },
+        tv_nsec: ts.tv_nsec as isize,
+    };
+    crate::syscall!(FUTEX, uaddr, op, val, &ts, uaddr2, val3)
+}
+
+#[cfg(not(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+)))]
+unsafe fn futex(
+    uaddr: &AtomicU32,
+    op: i32,
+    val: u32,
+    timeout: Option<Duration>,
+    uaddr2: Option<&AtomicU32>,
+    val3: u32,
+) -> Result<usize, i32> {
+    let ts = timeout.map(Timespec64::from);
+    crate::syscall!(FUTEX, uaddr, op, val, ts.as_ref(), uaddr2, val3)
+}
+
+/// Wait on a futex (`FUTEX_WAIT`).
+///
+/// If `futex` contains `expected`, this sleeps until another thread calls [`futex_wake()`] on it
+/// or until the `timeout` (if any) elapses. `flags` may contain [`FUTEX_PRIVATE_FLAG`].
+///
+/// The comparison and going to sleep happen atomically with respect to `futex_wake()`, so a
+/// wakeup between the caller checking the value and calling this function cannot be lost.
+///
+/// # Errors
+///
+/// - `EAGAIN`: `futex` did not contain `expected`.
+/// - `ETIMEDOUT`: The timeout elapsed.
+/// - `EINTR`: The wait was interrupted by a signal handler.
+///
+/// Spurious wakeups are also possible; callers should always re-check the condition they are
+/// waiting for.
+///
+/// [`futex_wake()`]: ./fn.futex_wake.html
+/// [`FUTEX_PRIVATE_FLAG`]: ./constant.FUTEX_PRIVATE_FLAG.html
+#[inline]
+pub fn futex_wait(
+    futex: &AtomicU32,
+    expected: u32,
+    timeout: Option<Duration>,
+    flags: i32,
+) -> Result<(), i32> {
+    unsafe { self::futex(futex, FUTEX_WAIT | flags, expected, timeout, None, 0).map(drop) }
+}
+
+/// Wake up to `count` waiters on a futex (`FUTEX_WAKE`).
+///
+/// Pass `u32::MAX` to wake up all of the waiters. `flags` must match the flags the waiters
+/// passed to [`futex_wait()`].
+///
+/// Returns the number of waiters that were woken up.
+///
+/// [`futex_wait()`]: ./fn.futex_wait.html
+#[inline]
+pub fn futex_wake(futex: &AtomicU32, count: u32, flags: i32) -> Result<usize, i32> {
+    // The kernel treats the count as a signed integer
+    let count = core::cmp::min(count, i32::MAX as u32);
+    unsafe { self::futex(futex, FUTEX_WAKE | flags, count, None, None, 0) }
+}
+
+/// Wake up to `nwake` waiters on `futex`, and move up to `nrequeue` of the remaining waiters so
+/// they wait on `target` instead (`FUTEX_CMP_REQUEUE`).
+///
+/// This is useful to avoid a "thundering herd": for example, a condition variable can wake up a
+/// single waiter and requeue the rest onto the mutex, instead of waking up every waiter only for
+/// all but one of them to go back to sleep on the mutex.
+///
+/// The operation is only performed if `futex` contains `expected` (otherwise, this fails with
+/// `EAGAIN`). The unchecked `FUTEX_REQUEUE` operation is inherently racy, so it is not exposed.
+///
+/// Returns the total number of waiters that were woken up or requeued.
+#[inline]
+pub fn futex_requeue(
+    futex: &AtomicU32,
+    expected: u32,
+    nwake: u32,
+    target: &AtomicU32,
+    nrequeue: u32,
+    flags: i32,
+) -> Result<usize, i32> {
+    let nwake = core::cmp::min(nwake, i32::MAX as u32);
+    let nrequeue = core::cmp::min(nrequeue, i32::MAX as u32);
+
+    // The requeue count is passed in place of the timeout pointer
+    unsafe {
+        crate::syscall!(
+            FUTEX,
+            futex,
+            FUTEX_CMP_REQUEUE | flags,
+            nwake,
+            nrequeue as usize,
+            target,
+            expected
+        )
+    }
+}
+
+/// A futex to wait on with [`futex_waitv()`] (`struct futex_waitv`).
+///
+/// [`futex_waitv()`]: ./fn.futex_waitv.html
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct FutexWaitv<'a> {
+    val: u64,
+    uaddr: u64,
+    flags: u32,
+    __reserved: u32,
+    _marker: PhantomData<&'a AtomicU32>,
+}
+
+impl<'a> FutexWaitv<'a> {
+    /// Wait on `futex` as long as it contains `expected`.
+    ///
+    /// `flags` may contain [`FUTEX2_PRIVATE`]; [`FUTEX2_SIZE_U32`] is added automatically.
+    ///
+    /// [`FUTEX2_PRIVATE`]: ./constant.FUTEX2_PRIVATE.html
+    /// [`FUTEX2_SIZE_U32`]: ./constant.FUTEX2_SIZE_U32.html
+    #[inline]
+    pub fn new(futex: &'a AtomicU32, expected: u32, flags: u32) -> Self {
+        Self {
+            val: expected as u64,
+            uaddr: futex as *const AtomicU32 as usize as u64,
+            flags: flags | FUTEX2_SIZE_U32,
+            __reserved: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Wait on several futexes at once (`futex_waitv()`, Linux 5.16+).
+///
+/// This sleeps until any of the futexes is woken up with [`futex_wake()`] (as long as every
+/// futex contains the expected value to begin with), and returns the index of the futex that was
+/// woken up.
+///
+/// Unlike [`futex_wait()`], the timeout is absolute: `deadline` is measured against `clockid`
+/// (which must be `CLOCK_MONOTONIC` or `CLOCK_REALTIME`).
+///
+/// # Errors
+///
+/// - `EAGAIN`: One of the futexes did not contain the expected value.
+/// - `ETIMEDOUT`: The deadline passed.
+/// - `EINVAL`: `waiters` is empty or has more than [`FUTEX_WAITV_MAX`] elements.
+/// - `ENOSYS`: The kernel does not support `futex_waitv()`.
+///
+/// [`futex_wake()`]: ./fn.futex_wake.html
+/// [`futex_wait()`]: ./fn.futex_wait.html
+/// [`FUTEX_WAITV_MAX`]: ./constant.FUTEX_WAITV_MAX.html
+#[inline]
+pub fn futex_waitv(
+    waiters: &[FutexWaitv],
+    deadline: Option<Duration>,
+    clockid: i32,
+) -> Result<usize, i32> {
+    let ts = deadline.map(Timespec64::from);
+    unsafe { crate::syscall!(FUTEX_WAITV, waiters, waiters.len(), 0, ts.as_ref(), clockid) }
+}
@@ -0,0 +1,250 @@
+//! Formatted output to file descriptors without allocating.
+//!
+//! [`FdWriter`] is a small buffered writer over `write()` that implements `core::fmt::Write`, and
+//! [`fd_print!`], [`fd_println!`], and [`fd_eprintln!`] are `print!`-style macros built on top of
+//! it.
+//!
+//! Everything in this module is async-signal-safe: it does not allocate, take locks, or touch any
+//! global state. It can be used from signal handlers, in the child after `fork()` or `vfork()`,
+//! and in programs that don't link against a C library (see the `rt` feature).
+//!
+//! # Example
+//!
+//! ```
+//! use scall::{fd_eprintln, fd_println};
+//!
+//! fd_println!(1, "Hello, {}!", "world").unwrap();
+//! fd_eprintln!("pid: {}", unsafe { scall::syscall_nofail!(GETPID) });
+//! ```
+//!
+//! [`FdWriter`]: ./struct.FdWriter.html
+//! [`fd_print!`]: ../macro.fd_print.html
+//! [`fd_println!`]: ../macro.fd_println.html
+//! [`fd_eprintln!`]: ../macro.fd_eprintln.html
+
+use core::fmt;
+
+/// Write all of `buf` to `fd`, retrying on partial writes and `EINTR`.
+///
+/// If the `write()` syscall reports that it wrote 0 bytes, this fails with `EIO`.
+pub fn write_all(fd: i32, mut buf: &[u8]) -> Result<(), i32> {
+    while !buf.is_empty() {
+        match unsafe { crate::syscall_retry!(WRITE, fd, buf.as_ptr(), buf.len())? } {
+            0 => return Err(crate::eno::EIO),
+            n => buf = &buf[n..],
+        }
+    }
+
+    Ok(())
+}
+
+/// A buffered writer for a file descriptor that implements `core::fmt::Write`.
+///
+/// Data is collected in a fixed-size buffer ([`FdWriter::CAPACITY`] bytes, stored inline) and
+/// written out with [`write_all()`] when the buffer fills up, when [`flush()`] is called, or when
+/// the `FdWriter` is dropped. As a result, output that fits in the buffer is written with a single
+/// `write()` call, so it won't be interleaved with output from other threads or processes writing
+/// to the same pipe.
+///
+/// The `FdWriter` does not own the file descriptor; it will not be closed on drop.
+///
+/// # Example
+///
+/// ```
+/// use core::fmt::Write;
+/// use scall::io::FdWriter;
+///
+/// let mut w = FdWriter::new(1);
+/// for i in 0..3 {
+///     write!(w, "{} ", i).unwrap();
+/// }
+/// writeln!(w).unwrap();
+/// w.flush().unwrap();
+/// ```
+///
+/// [`FdWriter::CAPACITY`]: #associatedconstant.CAPACITY
+/// [`write_all()`]: ./fn.write_all.html
+/// [`flush()`]: #method.flush
+pub struct FdWriter {
+    fd: i32,
+    buf: [u8; Self::CAPACITY],
+    len: usize,
+    error: Option<i32>,
+}
+
+impl FdWriter {
+    /// The size of the internal buffer.
+    ///
+    /// This is kept small, since `FdWriter`s are often used on the (small) stacks of signal
+    /// handlers.
+    pub const CAPACITY: usize = 256;
+
+    /// Create a new `FdWriter` that writes to the given file descriptor.
+    #[inline]
+    pub const fn new(fd: i32) -> Self {
+        Self {
+            fd,
+            buf: [0; Self::CAPACITY],
+            len: 0,
+            error: None,
+        }
+    }
+
+    /// Get the file descriptor that this `FdWriter` writes to.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.fd
+    }
+
+    /// Get the data that is currently buffered.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Write all of the given data (possibly buffering some or all of it).
+    ///
+    /// If an error occurs, any buffered data is discarded.
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), i32> {
+        if data.len() > Self::CAPACITY - self.len {
+            self.flush()?;
+
+            if data.len() >= Self::CAPACITY {
+                // Too big to buffer; write it directly
+                return write_all(self.fd, data);
+            }
+        }
+
+        self.buf[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+        Ok(())
+    }
+
+    /// Write formatted data (as from `format_args!()`).
+    ///
+    /// This is the same as `core::fmt::Write::write_fmt()`, except that it returns the error
+    /// number if a `write()` syscall fails. (If a formatting trait implementation returns an
+    /// error, this fails with `EINVAL`.)
+    pub fn write_args(&mut self, args: fmt::Arguments) -> Result<(), i32> {
+        self.error = None;
+        match fmt::Write::write_fmt(self, args) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(self.error.take().unwrap_or(crate::eno::EINVAL)),
+        }
+    }
+
+    /// Write out any buffered data.
+    ///
+    /// If an error occurs, the buffered data is discarded.
+    pub fn flush(&mut self) -> Result<(), i32> {
+        let len = core::mem::replace(&mut self.len, 0);
+        write_all(self.fd, &self.buf[..len])
+    }
+}
+
+impl fmt::Write for FdWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes()).map_err(|eno| {
+            self.error = Some(eno);
+            fmt::Error
+        })
+    }
+}
+
+impl fmt::Debug for FdWriter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FdWriter")
+            .field("fd", &self.fd)
+            .field("buffered", &self.len)
+            .finish()
+    }
+}
+
+impl Drop for FdWriter {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+#[doc(hidden)]
+pub fn _scall_internal_fd_print(fd: i32, args: fmt::Arguments) -> Result<(), i32> {
+    let mut w = FdWriter::new(fd);
+    if let Err(eno) = w.write_args(args) {
+        // Don't let `Drop` write out a partial line
+        w.len = 0;
+        return Err(eno);
+    }
+    w.flush()
+}
+
+/// Print formatted text to the given file descriptor.
+///
+/// This takes a file descriptor followed by the same arguments as `print!()`, and returns a
+/// `Result<(), i32>`. The output is written with a single `write()` call unless it is longer than
+/// [`FdWriter::CAPACITY`].
+///
+/// This macro is async-signal-safe; see the [`io`] module.
+///
+/// # Example
+///
+/// ```
+/// # use scall::fd_print;
+/// fd_print!(1, "{} + {} = {}\n", 1, 2, 1 + 2).unwrap();
+/// ```
+///
+/// [`FdWriter::CAPACITY`]: ./io/struct.FdWriter.html#associatedconstant.CAPACITY
+/// [`io`]: ./io/index.html
+#[macro_export]
+macro_rules! fd_print {
+    ($fd:expr, $($arg:tt)+) => {
+        $crate::io::_scall_internal_fd_print($fd, format_args!($($arg)+))
+    };
+}
+
+/// Print formatted text, followed by a newline, to the given file descriptor.
+///
+/// See [`fd_print!`].
+///
+/// # Example
+///
+/// ```
+/// # use scall::fd_println;
+/// fd_println!(1).unwrap();
+/// fd_println!(1, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// ```
+///
+/// [`fd_print!`]: ./macro.fd_print.html
+#[macro_export]
+macro_rules! fd_println {
+    ($fd:expr $(,)?) => {
+        $crate::fd_print!($fd, "\n")
+    };
+
+    ($fd:expr, $fmt:literal $($arg:tt)*) => {
+        $crate::fd_print!($fd, concat!($fmt, "\n") $($arg)*)
+    };
+}
+
+/// Print formatted text, followed by a newline, to standard error.
+///
+/// This takes the same arguments as `eprintln!()`. Unlike [`fd_println!`], it does not return
+/// anything; errors are ignored, since there is usually nothing useful to do if writing to
+/// standard error fails.
+///
+/// This macro is async-signal-safe; see the [`io`] module.
+///
+/// # Example
+///
+/// ```
+/// # use scall::fd_eprintln;
+/// fd_eprintln!("something went wrong: {}", scall::Errno::EINVAL);
+/// ```
+///
+/// [`fd_println!`]: ./macro.fd_println.html
+/// [`io`]: ./io/index.html
+#[macro_export]
+macro_rules! fd_eprintln {
+    ($($arg:tt)*) => {{
+        let _ = $crate::fd_println!(2, $($arg)*);
+    }};
+}
@@ -0,0 +1,57 @@
+use std::io;
+
+use crate::Errno;
+
+/// Map an error number to the corresponding [`std::io::ErrorKind`].
+///
+/// This is the same mapping that `std` uses for [`std::io::Error::kind()`], so it gives the same
+/// result as `std::io::Error::from_raw_os_error(eno).kind()`.
+///
+/// # Example
+///
+/// ```
+/// # use scall::{eno, error_kind};
+/// use std::io::ErrorKind;
+///
+/// assert_eq!(error_kind(eno::ENOENT), ErrorKind::NotFound);
+/// assert_eq!(error_kind(eno::EWOULDBLOCK), ErrorKind::WouldBlock);
+/// ```
+///
+/// [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+/// [`std::io::Error::kind()`]: https://doc.rust-lang.org/std/io/struct.Error.html#method.kind
+#[doc(cfg(feature = "std"))]
+#[inline]
+pub fn error_kind(eno: i32) -> io::ErrorKind {
+    io::Error::from_raw_os_error(eno).kind()
+}
+
+#[doc(cfg(feature = "std"))]
+impl Errno {
+    /// Get the [`std::io::ErrorKind`] corresponding to this error number.
+    ///
+    /// See [`error_kind()`] for more information.
+    ///
+    /// [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+    /// [`error_kind()`]: ./fn.error_kind.html
+    #[inline]
+    pub fn kind(self) -> io::ErrorKind {
+        error_kind(self.into_raw())
+    }
+}
+
+#[doc(cfg(feature = "std"))]
+impl From<Errno> for io::Error {
+    #[inline]
+    fn from(eno: Errno) -> Self {
+        io::Error::from_raw_os_error(eno.into_raw())
+    }
+}
+
+#[doc(cfg(feature = "std"))]
+impl std::error::Error for Errno {}
+
+#[doc(hidden)]
+#[inline]
+pub fn _scall_internal_io_error(eno: i32) -> io::Error {
+    io::Error::from_raw_os_error(eno)
+}
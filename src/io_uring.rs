@@ -0,0 +1,1145 @@
+//! Asynchronous I/O with `io_uring` (Linux 5.1+).
+//!
+//! An `io_uring` instance consists of two ring buffers shared with the kernel: the submission queue
+//! (SQ), where userspace places requests ([`Sqe`]s), and the completion queue (CQ), where the
+//! kernel posts their results ([`Cqe`]s). [`IoUring`] sets up an instance, maps the rings (and
+//! the array of SQEs) into memory, and takes care of the memory ordering on the shared head and
+//! tail indices. The `Sqe` constructors build requests for the most common operations; see
+//! io_uring_enter(2) for the full list and their semantics.
+//!
+//! `io_uring` may be disabled by the system administrator (see the `kernel.io_uring_disabled`
+//! sysctl) or blocked by a seccomp filter, in which case [`IoUring::new()`] fails with `EPERM` or
+//! `ENOSYS`.
+//!
+//! # Example
+//!
+//! ```
+//! use scall::io_uring::{IoUring, Sqe};
+//! use scall::eno;
+//!
+//! let mut ring = match IoUring::new(8) {
+//!     Ok(ring) => ring,
+//!     // io_uring is disabled or unavailable
+//!     Err(eno::ENOSYS) | Err(eno::EPERM) => return,
+//!     Err(e) => panic!("io_uring_setup() failed: {}", e),
+//! };
+//!
+//! let msg = b"hello\n";
+//! unsafe {
+//!     ring.push(&Sqe::write(1, msg.as_ptr(), msg.len() as u32, u64::MAX).user_data(42))
+//!         .unwrap();
+//! }
+//! ring.submit_and_wait(1).unwrap();
+//!
+//! let cqe = ring.pop().unwrap();
+//! assert_eq!(cqe.user_data, 42);
+//! assert_eq!(cqe.result(), Ok(msg.len()));
+//! ```
+//!
+//! [`Sqe`]: ./struct.Sqe.html
+//! [`Cqe`]: ./struct.Cqe.html
+//! [`IoUring`]: ./struct.IoUring.html
+//! [`IoUring::new()`]: ./struct.IoUring.html#method.new
+
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::sync::atomic::{fence, AtomicU32, Ordering};
+use core::time::Duration;
+
+use crate::{consts, eno, types};
+
+/// Poll for completions instead of using interrupts (only for files opened with `O_DIRECT`).
+pub const IORING_SETUP_IOPOLL: u32 = 1 << 0;
+/// Start a kernel thread that polls the submission queue.
+pub const IORING_SETUP_SQPOLL: u32 = 1 << 1;
+/// Bind the `SQPOLL` thread to the CPU in `sq_thread_cpu`.
+pub const IORING_SETUP_SQ_AFF: u32 = 1 << 2;
+/// Use the number of CQ entries in `cq_entries`.
+pub const IORING_SETUP_CQSIZE: u32 = 1 << 3;
+/// Clamp the number of SQ/CQ entries instead of failing with `EINVAL`.
+pub const IORING_SETUP_CLAMP: u32 = 1 << 4;
+/// Share the async worker pool of the ring in `wq_fd`.
+pub const IORING_SETUP_ATTACH_WQ: u32 = 1 << 5;
+/// Start the ring disabled (enable it with `IORING_REGISTER_ENABLE_RINGS`).
+pub const IORING_SETUP_R_DISABLED: u32 = 1 << 6;
+/// Continue submitting requests after one of them fails.
+pub const IORING_SETUP_SUBMIT_ALL: u32 = 1 << 7;
+/// Only run deferred completion work when the task enters the kernel, instead of interrupting it.
+pub const IORING_SETUP_COOP_TASKRUN: u32 = 1 << 8;
+/// Set `IORING_SQ_TASKRUN` when there is deferred completion work (with `COOP_TASKRUN`).
+pub const IORING_SETUP_TASKRUN_FLAG: u32 = 1 << 9;
+/// Use 128-byte SQEs (not supported by [`IoUring`]).
+///
+/// [`IoUring`]: ./struct.IoUring.html
+pub const IORING_SETUP_SQE128: u32 = 1 << 10;
+/// Use 32-byte CQEs (not supported by [`IoUring`]).
+///
+/// [`IoUring`]: ./struct.IoUring.html
+pub const IORING_SETUP_CQE32: u32 = 1 << 11;
+/// Only one task (the one that created the ring) will submit requests.
+pub const IORING_SETUP_SINGLE_ISSUER: u32 = 1 << 12;
+/// Defer completion work until `IORING_ENTER_GETEVENTS` (requires `IORING_SETUP_SINGLE_ISSUER`).
+pub const IORING_SETUP_DEFER_TASKRUN: u32 = 1 << 13;
+/// Use memory provided by the application for the rings (not supported by [`IoUring`]).
+///
+/// [`IoUring`]: ./struct.IoUring.html
+pub const IORING_SETUP_NO_MMAP: u32 = 1 << 14;
+/// Return a registered ring index instead of a file descriptor (requires `IORING_SETUP_NO_MMAP`).
+pub const IORING_SETUP_REGISTERED_FD_ONLY: u32 = 1 << 15;
+/// Don't use the SQ index array; SQEs are consumed in order.
+pub const IORING_SETUP_NO_SQARRAY: u32 = 1 << 16;
+
+/// `mmap()` offset of the submission queue ring.
+pub const IORING_OFF_SQ_RING: u64 = 0;
+/// `mmap()` offset of the completion queue ring.
+pub const IORING_OFF_CQ_RING: u64 = 0x800_0000;
+/// `mmap()` offset of the array of SQEs.
+pub const IORING_OFF_SQES: u64 = 0x1000_0000;
+
+/// Wait for `min_complete` completions before returning.
+pub const IORING_ENTER_GETEVENTS: u32 = 1 << 0;
+/// Wake up the `SQPOLL` thread.
+pub const IORING_ENTER_SQ_WAKEUP: u32 = 1 << 1;
+/// Wait until there is free space in the submission queue (with `SQPOLL`).
+pub const IORING_ENTER_SQ_WAIT: u32 = 1 << 2;
+/// The argument is a `struct io_uring_getevents_arg` instead of a signal mask.
+pub const IORING_ENTER_EXT_ARG: u32 = 1 << 3;
+/// The file descriptor is a registered ring index.
+pub const IORING_ENTER_REGISTERED_RING: u32 = 1 << 4;
+
+/// The `SQPOLL` thread has gone to sleep and needs to be woken up with `IORING_ENTER_SQ_WAKEUP`.
+pub const IORING_SQ_NEED_WAKEUP: u32 = 1 << 0;
+/// The completion queue has overflowed.
+pub const IORING_SQ_CQ_OVERFLOW: u32 = 1 << 1;
+/// There is deferred completion work; enter the kernel to run it.
+pub const IORING_SQ_TASKRUN: u32 = 1 << 2;
+
+/// The SQ and CQ rings can be mapped with a single `mmap()` (Linux 5.4+).
+pub const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+/// Completions are never dropped when the completion queue overflows (Linux 5.5+).
+pub const IORING_FEAT_NODROP: u32 = 1 << 1;
+/// The data for a request only has to be stable until it is submitted (Linux 5.5+).
+pub const IORING_FEAT_SUBMIT_STABLE: u32 = 1 << 2;
+/// An offset of `-1` reads or writes at the current file position (Linux 5.6+).
+pub const IORING_FEAT_RW_CUR_POS: u32 = 1 << 3;
+/// Requests use the credentials of the submitting task (Linux 5.6+).
+pub const IORING_FEAT_CUR_PERSONALITY: u32 = 1 << 4;
+/// Requests on files that aren't ready are retried with internal polling (Linux 5.7+).
+pub const IORING_FEAT_FAST_POLL: u32 = 1 << 5;
+/// `IORING_OP_POLL_ADD` accepts all 32 bits of the event mask (Linux 5.9+).
+pub const IORING_FEAT_POLL_32BITS: u32 = 1 << 6;
+/// `SQPOLL` works with files that aren't registered (Linux 5.11+).
+pub const IORING_FEAT_SQPOLL_NONFIXED: u32 = 1 << 7;
+/// `IORING_ENTER_EXT_ARG` is supported (Linux 5.11+).
+pub const IORING_FEAT_EXT_ARG: u32 = 1 << 8;
+/// Async workers are threads of the submitting process (Linux 5.12+).
+pub const IORING_FEAT_NATIVE_WORKERS: u32 = 1 << 9;
+/// Registered resources can be tagged (Linux 5.13+).
+pub const IORING_FEAT_RSRC_TAGS: u32 = 1 << 10;
+/// `IOSQE_CQE_SKIP_SUCCESS` is supported (Linux 5.17+).
+pub const IORING_FEAT_CQE_SKIP: u32 = 1 << 11;
+/// Files for linked requests are resolved when the request starts (Linux 5.17+).
+pub const IORING_FEAT_LINKED_FILE: u32 = 1 << 12;
+/// Registration can use a registered ring index (Linux 6.3+).
+pub const IORING_FEAT_REG_REG_RING: u32 = 1 << 13;
+
+/// `fd` is an index into the registered files.
+pub const IOSQE_FIXED_FILE: u8 = 1 << 0;
+/// Wait for all the previous requests to complete before starting this one.
+pub const IOSQE_IO_DRAIN: u8 = 1 << 1;
+/// Don't start the next request until this one completes (successfully).
+pub const IOSQE_IO_LINK: u8 = 1 << 2;
+/// Like `IOSQE_IO_LINK`, but the chain isn't broken if this request fails.
+pub const IOSQE_IO_HARDLINK: u8 = 1 << 3;
+/// Always execute this request asynchronously.
+pub const IOSQE_ASYNC: u8 = 1 << 4;
+/// Pick a buffer from the group in `buf_index` instead of using `addr`.
+pub const IOSQE_BUFFER_SELECT: u8 = 1 << 5;
+/// Don't post a CQE if this request succeeds.
+pub const IOSQE_CQE_SKIP_SUCCESS: u8 = 1 << 6;
+
+/// The upper 16 bits of `flags` hold the ID of the selected buffer.
+pub const IORING_CQE_F_BUFFER: u32 = 1 << 0;
+/// More CQEs will be posted for this request.
+pub const IORING_CQE_F_MORE: u32 = 1 << 1;
+/// The socket still has data to read.
+pub const IORING_CQE_F_SOCK_NONEMPTY: u32 = 1 << 2;
+/// This is a zero-copy send notification.
+pub const IORING_CQE_F_NOTIF: u32 = 1 << 3;
+
+/// Do nothing.
+pub const IORING_OP_NOP: u8 = 0;
+/// Like `readv()`.
+pub const IORING_OP_READV: u8 = 1;
+/// Like `writev()`.
+pub const IORING_OP_WRITEV: u8 = 2;
+/// Like `fsync()`.
+pub const IORING_OP_FSYNC: u8 = 3;
+/// `read()` into a registered buffer.
+pub const IORING_OP_READ_FIXED: u8 = 4;
+/// `write()` from a registered buffer.
+pub const IORING_OP_WRITE_FIXED: u8 = 5;
+/// Wait for events on a file descriptor, like `poll()`.
+pub const IORING_OP_POLL_ADD: u8 = 6;
+/// Cancel an `IORING_OP_POLL_ADD` request.
+pub const IORING_OP_POLL_REMOVE: u8 = 7;
+/// Like `sync_file_range()`.
+pub const IORING_OP_SYNC_FILE_RANGE: u8 = 8;
+/// Like `sendmsg()`.
+pub const IORING_OP_SENDMSG: u8 = 9;
+/// Like `recvmsg()`.
+pub const IORING_OP_RECVMSG: u8 = 10;
+/// Complete after a timeout or a number of completions.
+pub const IORING_OP_TIMEOUT: u8 = 11;
+/// Cancel or update an `IORING_OP_TIMEOUT` request.
+pub const IORING_OP_TIMEOUT_REMOVE: u8 = 12;
+/// Like `accept4()`.
+pub const IORING_OP_ACCEPT: u8 = 13;
+/// Cancel another request.
+pub const IORING_OP_ASYNC_CANCEL: u8 = 14;
+/// Time out the previous linked request.
+pub const IORING_OP_LINK_TIMEOUT: u8 = 15;
+/// Like `connect()`.
+pub const IORING_OP_CONNECT: u8 = 16;
+/// Like `fallocate()`.
+pub const IORING_OP_FALLOCATE: u8 = 17;
+/// Like `openat()`.
+pub const IORING_OP_OPENAT: u8 = 18;
+/// Like `close()`.
+pub const IORING_OP_CLOSE: u8 = 19;
+/// Update some of the registered files.
+pub const IORING_OP_FILES_UPDATE: u8 = 20;
+/// Like `statx()`.
+pub const IORING_OP_STATX: u8 = 21;
+/// Like `read()`.
+pub const IORING_OP_READ: u8 = 22;
+/// Like `write()`.
+pub const IORING_OP_WRITE: u8 = 23;
+/// Like `posix_fadvise()`.
+pub const IORING_OP_FADVISE: u8 = 24;
+/// Like `madvise()`.
+pub const IORING_OP_MADVISE: u8 = 25;
+/// Like `send()`.
+pub const IORING_OP_SEND: u8 = 26;
+/// Like `recv()`.
+pub const IORING_OP_RECV: u8 = 27;
+/// Like `openat2()`.
+pub const IORING_OP_OPENAT2: u8 = 28;
+/// Like `epoll_ctl()`.
+pub const IORING_OP_EPOLL_CTL: u8 = 29;
+/// Like `splice()`.
+pub const IORING_OP_SPLICE: u8 = 30;
+/// Add buffers to a buffer group.
+pub const IORING_OP_PROVIDE_BUFFERS: u8 = 31;
+/// Remove buffers from a buffer group.
+pub const IORING_OP_REMOVE_BUFFERS: u8 = 32;
+/// Like `tee()`.
+pub const IORING_OP_TEE: u8 = 33;
+/// Like `shutdown()`.
+pub const IORING_OP_SHUTDOWN: u8 = 34;
+/// Like `renameat2()`.
+pub const IORING_OP_RENAMEAT: u8 = 35;
+/// Like `unlinkat()`.
+pub const IORING_OP_UNLINKAT: u8 = 36;
+/// Like `mkdirat()`.
+pub const IORING_OP_MKDIRAT: u8 = 37;
+/// Like `symlinkat()`.
+pub const IORING_OP_SYMLINKAT: u8 = 38;
+/// Like `linkat()`.
+pub const IORING_OP_LINKAT: u8 = 39;
+/// Post a completion to another ring.
+pub const IORING_OP_MSG_RING: u8 = 40;
+/// Like `fsetxattr()`.
+pub const IORING_OP_FSETXATTR: u8 = 41;
+/// Like `setxattr()`.
+pub const IORING_OP_SETXATTR: u8 = 42;
+/// Like `fgetxattr()`.
+pub const IORING_OP_FGETXATTR: u8 = 43;
+/// Like `getxattr()`.
+pub const IORING_OP_GETXATTR: u8 = 44;
+/// Like `socket()`.
+pub const IORING_OP_SOCKET: u8 = 45;
+/// A command specific to the file (passed through to the driver).
+pub const IORING_OP_URING_CMD: u8 = 46;
+/// Zero-copy `send()`.
+pub const IORING_OP_SEND_ZC: u8 = 47;
+/// Zero-copy `sendmsg()`.
+pub const IORING_OP_SENDMSG_ZC: u8 = 48;
+/// `read()` repeatedly into selected buffers.
+pub const IORING_OP_READ_MULTISHOT: u8 = 49;
+/// Like `waitid()`.
+pub const IORING_OP_WAITID: u8 = 50;
+/// Like `futex_wait()`.
+pub const IORING_OP_FUTEX_WAIT: u8 = 51;
+/// Like `futex_wake()`.
+pub const IORING_OP_FUTEX_WAKE: u8 = 52;
+/// Like `futex_waitv()`.
+pub const IORING_OP_FUTEX_WAITV: u8 = 53;
+/// Install a registered file as a regular file descriptor.
+pub const IORING_OP_FIXED_FD_INSTALL: u8 = 54;
+/// Like `ftruncate()`.
+pub const IORING_OP_FTRUNCATE: u8 = 55;
+/// Like `bind()`.
+pub const IORING_OP_BIND: u8 = 56;
+/// Like `listen()`.
+pub const IORING_OP_LISTEN: u8 = 57;
+
+/// Flag for `IORING_OP_FSYNC`: only sync the data, like `fdatasync()`.
+pub const IORING_FSYNC_DATASYNC: u32 = 1 << 0;
+/// Flag for `IORING_OP_TIMEOUT`: the timeout is an absolute time.
+pub const IORING_TIMEOUT_ABS: u32 = 1 << 0;
+
+/// Register fixed buffers.
+pub const IORING_REGISTER_BUFFERS: u32 = 0;
+/// Unregister the fixed buffers.
+pub const IORING_UNREGISTER_BUFFERS: u32 = 1;
+/// Register fixed files.
+pub const IORING_REGISTER_FILES: u32 = 2;
+/// Unregister the fixed files.
+pub const IORING_UNREGISTER_FILES: u32 = 3;
+/// Register an eventfd that is notified of completions.
+pub const IORING_REGISTER_EVENTFD: u32 = 4;
+/// Unregister the eventfd.
+pub const IORING_UNREGISTER_EVENTFD: u32 = 5;
+/// Update some of the registered files.
+pub const IORING_REGISTER_FILES_UPDATE: u32 = 6;
+/// Like `IORING_REGISTER_EVENTFD`, but only notify for requests that completed asynchronously.
+pub const IORING_REGISTER_EVENTFD_ASYNC: u32 = 7;
+/// Query which opcodes are supported.
+pub const IORING_REGISTER_PROBE: u32 = 8;
+/// Register the current credentials and return a personality ID.
+pub const IORING_REGISTER_PERSONALITY: u32 = 9;
+/// Unregister a personality ID.
+pub const IORING_UNREGISTER_PERSONALITY: u32 = 10;
+/// Restrict the operations allowed on a disabled ring.
+pub const IORING_REGISTER_RESTRICTIONS: u32 = 11;
+/// Enable a ring created with `IORING_SETUP_R_DISABLED`.
+pub const IORING_REGISTER_ENABLE_RINGS: u32 = 12;
+
+/// The offsets of the fields of the submission queue ring (`struct io_sqring_offsets`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct SqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+/// The offsets of the fields of the completion queue ring (`struct io_cqring_offsets`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct CqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+/// The parameters passed to, and filled in by, `io_uring_setup()` (`struct io_uring_params`).
+///
+/// Before calling [`IoUring::with_params()`], only `flags` (and the fields that the flags refer
+/// to, such as `sq_thread_idle` for `IORING_SETUP_SQPOLL`) should be set; the kernel fills in the
+/// rest.
+///
+/// [`IoUring::with_params()`]: ./struct.IoUring.html#method.with_params
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Params {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    /// The `IORING_SETUP_*` flags.
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    /// How long the `SQPOLL` thread waits for new submissions before going to sleep, in
+    /// milliseconds.
+    pub sq_thread_idle: u32,
+    /// The `IORING_FEAT_*` flags supported by the kernel.
+    pub features: u32,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: SqringOffsets,
+    pub cq_off: CqringOffsets,
+}
+
+/// A timeout for `IORING_OP_TIMEOUT` (`struct __kernel_timespec`).
+///
+/// Unlike `struct timespec`, this has a 64-bit `tv_sec` on every architecture.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+impl From<Duration> for Timespec {
+    #[inline]
+    fn from(d: Duration) -> Self {
+        Self {
+            tv_sec: if d.as_secs() > i64::MAX as u64 {
+                i64::MAX
+            } else {
+                d.as_secs() as i64
+            },
+            tv_nsec: d.subsec_nanos() as i64,
+        }
+    }
+}
+
+/// A submission queue entry (`struct io_uring_sqe`).
+///
+/// Several of the fields are unions in the kernel's definition; they are named after their most
+/// common use here. The constructors fill in the fields for the corresponding operation, and
+/// [`user_data()`] and [`flags()`] can be chained onto them:
+///
+/// ```
+/// use scall::io_uring::{Sqe, IOSQE_IO_LINK};
+///
+/// let sqe = Sqe::nop().user_data(1).flags(IOSQE_IO_LINK);
+/// assert_eq!(sqe.user_data, 1);
+/// assert_eq!(sqe.flags, IOSQE_IO_LINK);
+/// ```
+///
+/// Building an `Sqe` is safe, but the pointers it contains must be valid when it is pushed onto
+/// the submission queue, and they must remain valid until the request completes.
+///
+/// [`user_data()`]: #method.user_data
+/// [`flags()`]: #method.flags
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Sqe {
+    /// The `IORING_OP_*` operation.
+    pub opcode: u8,
+    /// The `IOSQE_*` flags.
+    pub flags: u8,
+    pub ioprio: u16,
+    pub fd: i32,
+    /// The file offset (`addr2` for some operations).
+    pub off: u64,
+    /// The buffer address (`splice_off_in` for some operations).
+    pub addr: u64,
+    pub len: u32,
+    /// The operation-specific flags (`rw_flags`, `fsync_flags`, `poll32_events`, etc.).
+    pub op_flags: u32,
+    /// An arbitrary value that is copied to the CQE.
+    pub user_data: u64,
+    /// `buf_index` or `buf_group`.
+    pub buf_index: u16,
+    pub personality: u16,
+    /// `file_index` or `splice_fd_in`.
+    pub file_index: u32,
+    pub addr3: u64,
+    pub __pad2: [u64; 1],
+}
+
+impl Sqe {
+    /// Create an `Sqe` for the given operation, with all the other fields set to 0.
+    #[inline]
+    pub const fn new(opcode: u8) -> Self {
+        Self {
+            opcode,
+            flags: 0,
+            ioprio: 0,
+            fd: 0,
+            off: 0,
+            addr: 0,
+            len: 0,
+            op_flags: 0,
+            user_data: 0,
+            buf_index: 0,
+            personality: 0,
+            file_index: 0,
+            addr3: 0,
+            __pad2: [0],
+        }
+    }
+
+    #[inline]
+    fn rw(opcode: u8, fd: i32, addr: usize, len: u32, off: u64) -> Self {
+        Self {
+            fd,
+            off,
+            addr: addr as u64,
+            len,
+            ..Self::new(opcode)
+        }
+    }
+
+    /// Set the `user_data` field, which is copied to the corresponding CQE.
+    #[inline]
+    pub const fn user_data(mut self, user_data: u64) -> Self {
+        self.user_data = user_data;
+        self
+    }
+
+    /// Set the `IOSQE_*` flags.
+    #[inline]
+    pub const fn flags(mut self, flags: u8) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Do nothing (`IORING_OP_NOP`).
+    #[inline]
+    pub const fn nop() -> Self {
+        Self::new(IORING_OP_NOP)
+    }
+
+    /// Read up to `len` bytes from `fd` at `offset` into `buf` (`IORING_OP_READ`, Linux 5.6+).
+    ///
+    /// An `offset` of `u64::MAX` means the current file position.
+    #[inline]
+    pub fn read(fd: i32, buf: *mut u8, len: u32, offset: u64) -> Self {
+        Self::rw(IORING_OP_READ, fd, buf as usize, len, offset)
+    }
+
+    /// Write `len` bytes from `buf` to `fd` at `offset` (`IORING_OP_WRITE`, Linux 5.6+).
+    ///
+    /// An `offset` of `u64::MAX` means the current file position.
+    #[inline]
+    pub fn write(fd: i32, buf: *const u8, len: u32, offset: u64) -> Self {
+        Self::rw(IORING_OP_WRITE, fd, buf as usize, len, offset)
+    }
+
+    /// Like `preadv()` (`IORING_OP_READV`).
+    #[inline]
+    pub fn readv(fd: i32, iov: *const types::iovec, iovcnt: u32, offset: u64) -> Self {
+        Self::rw(IORING_OP_READV, fd, iov as usize, iovcnt, offset)
+    }
+
+    /// Like `pwritev()` (`IORING_OP_WRITEV`).
+    #[inline]
+    pub fn writev(fd: i32, iov: *const types::iovec, iovcnt: u32, offset: u64) -> Self {
+        Self::rw(IORING_OP_WRITEV, fd, iov as usize, iovcnt, offset)
+    }
+
+    /// Like `fsync()`, or `fdatasync()` if `flags` contains [`IORING_FSYNC_DATASYNC`]
+    /// (`IORING_OP_FSYNC`).
+    ///
+    /// [`IORING_FSYNC_DATASYNC`]: ./constant.IORING_FSYNC_DATASYNC.html
+    #[inline]
+    pub fn fsync(fd: i32, flags: u32) -> Self {
+        Self {
+            fd,
+            op_flags: flags,
+            ..Self::new(IORING_OP_FSYNC)
+        }
+    }
+
+    /// Wait until `fd` is ready for one of the `POLL*` `events` (`IORING_OP_POLL_ADD`).
+    ///
+    /// The result is the mask of events that occurred.
+    #[inline]
+    pub fn poll_add(fd: i32, events: u32) -> Self {
+        // The kernel reads `poll32_events` with its 16-bit halves swapped on big-endian
+        // architectures (for compatibility with the original 16-bit `poll_events` field)
+        #[cfg(target_endian = "big")]
+        let events = events.rotate_left(16);
+
+        Self {
+            fd,
+            op_flags: events,
+            ..Self::new(IORING_OP_POLL_ADD)
+        }
+    }
+
+    /// Cancel the `IORING_OP_POLL_ADD` request with the given `user_data`
+    /// (`IORING_OP_POLL_REMOVE`).
+    #[inline]
+    pub fn poll_remove(user_data: u64) -> Self {
+        Self {
+            fd: -1,
+            addr: user_data,
+            ..Self::new(IORING_OP_POLL_REMOVE)
+        }
+    }
+
+    /// Complete after the timeout in `ts` expires, or after `count` other requests have completed
+    /// (if `count` is nonzero), whichever happens first (`IORING_OP_TIMEOUT`).
+    ///
+    /// If the timeout expires, the result is `ETIME`. `flags` may contain
+    /// [`IORING_TIMEOUT_ABS`].
+    ///
+    /// [`IORING_TIMEOUT_ABS`]: ./constant.IORING_TIMEOUT_ABS.html
+    #[inline]
+    pub fn timeout(ts: *const Timespec, count: u32, flags: u32) -> Self {
+        Self {
+            fd: -1,
+            off: count as u64,
+            addr: ts as usize as u64,
+            len: 1,
+            op_flags: flags,
+            ..Self::new(IORING_OP_TIMEOUT)
+        }
+    }
+
+    /// Cancel the `IORING_OP_TIMEOUT` request with the given `user_data`
+    /// (`IORING_OP_TIMEOUT_REMOVE`).
+    #[inline]
+    pub fn timeout_remove(user_data: u64, flags: u32) -> Self {
+        Self {
+            fd: -1,
+            addr: user_data,
+            op_flags: flags,
+            ..Self::new(IORING_OP_TIMEOUT_REMOVE)
+        }
+    }
+
+    /// Attempt to cancel the request with the given `user_data` (`IORING_OP_ASYNC_CANCEL`).
+    #[inline]
+    pub fn async_cancel(user_data: u64, flags: u32) -> Self {
+        Self {
+            fd: -1,
+            addr: user_data,
+            op_flags: flags,
+            ..Self::new(IORING_OP_ASYNC_CANCEL)
+        }
+    }
+
+    /// Like `openat()` (`IORING_OP_OPENAT`, Linux 5.6+). `path` must be NUL-terminated.
+    #[inline]
+    pub fn openat(dirfd: i32, path: *const u8, flags: i32, mode: u32) -> Self {
+        Self {
+            fd: dirfd,
+            addr: path as usize as u64,
+            len: mode,
+            op_flags: flags as u32,
+            ..Self::new(IORING_OP_OPENAT)
+        }
+    }
+
+    /// Like `close()` (`IORING_OP_CLOSE`, Linux 5.6+).
+    #[inline]
+    pub fn close(fd: i32) -> Self {
+        Self {
+            fd,
+            ..Self::new(IORING_OP_CLOSE)
+        }
+    }
+
+    /// Like `accept4()` (`IORING_OP_ACCEPT`, Linux 5.5+).
+    #[inline]
+    pub fn accept(fd: i32, addr: *mut u8, addrlen: *mut u32, flags: i32) -> Self {
+        Self {
+            fd,
+            off: addrlen as usize as u64,
+            addr: addr as usize as u64,
+            op_flags: flags as u32,
+            ..Self::new(IORING_OP_ACCEPT)
+        }
+    }
+
+    /// Like `connect()` (`IORING_OP_CONNECT`, Linux 5.5+).
+    #[inline]
+    pub fn connect(fd: i32, addr: *const u8, addrlen: u32) -> Self {
+        Self {
+            fd,
+            off: addrlen as u64,
+            addr: addr as usize as u64,
+            ..Self::new(IORING_OP_CONNECT)
+        }
+    }
+
+    /// Like `send()` (`IORING_OP_SEND`, Linux 5.6+).
+    #[inline]
+    pub fn send(fd: i32, buf: *const u8, len: u32, flags: i32) -> Self {
+        Self {
+            op_flags: flags as u32,
+            ..Self::rw(IORING_OP_SEND, fd, buf as usize, len, 0)
+        }
+    }
+
+    /// Like `recv()` (`IORING_OP_RECV`, Linux 5.6+).
+    #[inline]
+    pub fn recv(fd: i32, buf: *mut u8, len: u32, flags: i32) -> Self {
+        Self {
+            op_flags: flags as u32,
+            ..Self::rw(IORING_OP_RECV, fd, buf as usize, len, 0)
+        }
+    }
+
+    /// Like `sendmsg()` (`IORING_OP_SENDMSG`).
+    #[inline]
+    pub fn sendmsg(fd: i32, msg: *const types::msghdr, flags: i32) -> Self {
+        Self {
+            op_flags: flags as u32,
+            ..Self::rw(IORING_OP_SENDMSG, fd, msg as usize, 1, 0)
+        }
+    }
+
+    /// Like `recvmsg()` (`IORING_OP_RECVMSG`).
+    #[inline]
+    pub fn recvmsg(fd: i32, msg: *mut types::msghdr, flags: i32) -> Self {
+        Self {
+            op_flags: flags as u32,
+            ..Self::rw(IORING_OP_RECVMSG, fd, msg as usize, 1, 0)
+        }
+    }
+}
+
+/// A completion queue entry (`struct io_uring_cqe`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Cqe {
+    /// The `user_data` of the corresponding SQE.
+    pub user_data: u64,
+    /// The result of the operation: a negative error number on failure.
+    pub res: i32,
+    /// The `IORING_CQE_F_*` flags.
+    pub flags: u32,
+}
+
+impl Cqe {
+    /// Decode `res` the same way as the result of a syscall.
+    #[inline]
+    pub fn result(&self) -> Result<usize, i32> {
+        if self.res < 0 {
+            Err(-self.res)
+        } else {
+            Ok(self.res as usize)
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+))]
+unsafe fn mmap_shared(fd: i32, len: usize, offset: u64) -> Result<usize, i32> {
+    // `mmap2()` takes the offset in units of 4096 bytes (regardless of the page size)
+    crate::syscall!(
+        MMAP2,
+        0,
+        len,
+        consts::PROT_READ | consts::PROT_WRITE,
+        consts::MAP_SHARED | consts::MAP_POPULATE,
+        fd,
+        (offset / 4096) as usize
+    )
+}
+
+#[cfg(not(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+)))]
+unsafe fn mmap_shared(fd: i32, len: usize, offset: u64) -> Result<usize, i32> {
+    crate::syscall!(
+        MMAP,
+        0,
+        len,
+        consts::PROT_READ | consts::PROT_WRITE,
+        consts::MAP_SHARED | consts::MAP_POPULATE,
+        fd,
+        offset as usize
+    )
+}
+
+// A region mapped with `mmap()`; unmapped on drop
+struct Mapping {
+    addr: usize,
+    len: usize,
+}
+
+impl Mapping {
+    #[inline]
+    unsafe fn new(fd: i32, len: usize, offset: u64) -> Result<Self, i32> {
+        let addr = mmap_shared(fd, len, offset)?;
+        Ok(Self { addr, len })
+    }
+
+    #[inline]
+    fn ptr<T>(&self, offset: u32) -> *mut T {
+        (self.addr + offset as usize) as *mut T
+    }
+}
+
+impl Drop for Mapping {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            crate::syscall!(MUNMAP, self.addr, self.len).ok();
+        }
+    }
+}
+
+// Closes the ring's file descriptor on drop. (Declared after the mappings in `IoUring`, so the
+// rings are unmapped first.)
+struct OwnedFd(i32);
+
+impl Drop for OwnedFd {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            crate::syscall!(CLOSE, self.0).ok();
+        }
+    }
+}
+
+/// An `io_uring` instance, with its submission and completion queues mapped into memory.
+///
+/// Requests are added to the submission queue with [`push()`] and handed to the kernel with
+/// [`submit()`] or [`submit_and_wait()`]; their results are retrieved with [`pop()`]. The ring is
+/// unmapped and closed when the `IoUring` is dropped; any requests that are still in flight are
+/// canceled, but (as with `close()`) this does not wait for them.
+///
+/// If the ring was set up with `IORING_SETUP_SQPOLL`, a kernel thread picks up new requests on its
+/// own; [`submit()`] only makes a syscall if that thread has gone to sleep and needs to be woken
+/// up.
+///
+/// Only the default 64-byte SQEs and 16-byte CQEs are supported.
+///
+/// [`push()`]: #method.push
+/// [`submit()`]: #method.submit
+/// [`submit_and_wait()`]: #method.submit_and_wait
+/// [`pop()`]: #method.pop
+pub struct IoUring {
+    params: Params,
+
+    sq_head: *const AtomicU32,
+    sq_tail: *const AtomicU32,
+    sq_flags: *const AtomicU32,
+    sq_mask: u32,
+    sqes: *mut Sqe,
+    // The number of SQEs that have been pushed, but not yet passed to `io_uring_enter()`
+    sq_pending: u32,
+
+    cq_head: *const AtomicU32,
+    cq_tail: *const AtomicU32,
+    cq_mask: u32,
+    cqes: *const Cqe,
+
+    _sqe_map: Mapping,
+    _cq_map: Option<Mapping>,
+    _sq_map: Mapping,
+    fd: OwnedFd,
+}
+
+// The pointers all refer to the mappings owned by the `IoUring`, and all the methods that modify
+// the rings take `&mut self`.
+unsafe impl Send for IoUring {}
+unsafe impl Sync for IoUring {}
+
+impl IoUring {
+    /// Set up an `io_uring` instance with room for (at least) `entries` SQEs, using the default
+    /// parameters.
+    ///
+    /// The completion queue is twice as large as the submission queue.
+    #[inline]
+    pub fn new(entries: u32) -> Result<Self, i32> {
+        Self::with_params(entries, Params::default())
+    }
+
+    /// Set up an `io_uring` instance with room for (at least) `entries` SQEs, using the given
+    /// parameters.
+    ///
+    /// See [`Params`] for which fields should be set. The parameters filled in by the kernel can
+    /// be retrieved afterward with [`params()`].
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors returned by `io_uring_setup()` and `mmap()`, this fails with
+    /// `EINVAL` if `params.flags` contains `IORING_SETUP_SQE128`, `IORING_SETUP_CQE32`, or
+    /// `IORING_SETUP_NO_MMAP`.
+    ///
+    /// [`Params`]: ./struct.Params.html
+    /// [`params()`]: #method.params
+    pub fn with_params(entries: u32, mut params: Params) -> Result<Self, i32> {
+        if params.flags & (IORING_SETUP_SQE128 | IORING_SETUP_CQE32 | IORING_SETUP_NO_MMAP) != 0 {
+            return Err(eno::EINVAL);
+        }
+
+        let fd = OwnedFd(unsafe { crate::syscall!(IO_URING_SETUP, entries, &mut params)? } as i32);
+
+        let sq_off = params.sq_off;
+        let cq_off = params.cq_off;
+
+        let mut sq_len = sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+        let mut cq_len = cq_off.cqes as usize + params.cq_entries as usize * size_of::<Cqe>();
+
+        // With `IORING_FEAT_SINGLE_MMAP`, both rings live in the same region, which has to be
+        // mapped with the larger of the two sizes
+        let single_mmap = params.features & IORING_FEAT_SINGLE_MMAP != 0;
+        if single_mmap {
+            sq_len = core::cmp::max(sq_len, cq_len);
+            cq_len = sq_len;
+        }
+
+        let sq_map = unsafe { Mapping::new(fd.0, sq_len, IORING_OFF_SQ_RING)? };
+        let cq_map = if single_mmap {
+            None
+        } else {
+            Some(unsafe { Mapping::new(fd.0, cq_len, IORING_OFF_CQ_RING)? })
+        };
+        let sqe_map = unsafe {
+            Mapping::new(
+                fd.0,
+                params.sq_entries as usize * size_of::<Sqe>(),
+                IORING_OFF_SQES,
+            )?
+        };
+
+        let cq_ring = cq_map.as_ref().unwrap_or(&sq_map);
+
+        let ring = unsafe {
+            Self {
+                sq_head: sq_map.ptr(sq_off.head),
+                sq_tail: sq_map.ptr(sq_off.tail),
+                sq_flags: sq_map.ptr(sq_off.flags),
+                sq_mask: *sq_map.ptr::<u32>(sq_off.ring_mask),
+                sqes: sqe_map.ptr(0),
+                sq_pending: 0,
+
+                cq_head: cq_ring.ptr(cq_off.head),
+                cq_tail: cq_ring.ptr(cq_off.tail),
+                cq_mask: *cq_ring.ptr::<u32>(cq_off.ring_mask),
+                cqes: cq_ring.ptr(cq_off.cqes),
+
+                params,
+                _sqe_map: sqe_map,
+                _cq_map: cq_map,
+                _sq_map: sq_map,
+                fd,
+            }
+        };
+
+        // The SQ array maps ring slots to SQE indices. We always fill SQEs in ring order, so it
+        // can be set up once as the identity mapping.
+        if params.flags & IORING_SETUP_NO_SQARRAY == 0 {
+            let array = ring._sq_map.ptr::<u32>(sq_off.array);
+            for i in 0..params.sq_entries {
+                unsafe {
+                    array.add(i as usize).write(i);
+                }
+            }
+        }
+
+        Ok(ring)
+    }
+
+    /// Get the ring's file descriptor.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.fd.0
+    }
+
+    /// Get the parameters filled in by `io_uring_setup()`.
+    #[inline]
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Get the `IORING_FEAT_*` flags supported by the kernel.
+    #[inline]
+    pub fn features(&self) -> u32 {
+        self.params.features
+    }
+
+    /// Get the number of entries in the submission queue.
+    #[inline]
+    pub fn sq_entries(&self) -> u32 {
+        self.params.sq_entries
+    }
+
+    /// Get the number of entries in the completion queue.
+    #[inline]
+    pub fn cq_entries(&self) -> u32 {
+        self.params.cq_entries
+    }
+
+    /// Get the number of SQEs that have been pushed, but not yet consumed by the kernel.
+    #[inline]
+    pub fn sq_len(&self) -> u32 {
+        unsafe {
+            let tail = (*self.sq_tail).load(Ordering::Relaxed);
+            tail.wrapping_sub((*self.sq_head).load(Ordering::Acquire))
+        }
+    }
+
+    /// Check whether the submission queue is full.
+    #[inline]
+    pub fn is_sq_full(&self) -> bool {
+        self.sq_len() >= self.params.sq_entries
+    }
+
+    /// Get the number of CQEs that are ready to be popped.
+    #[inline]
+    pub fn cq_len(&self) -> u32 {
+        unsafe {
+            let head = (*self.cq_head).load(Ordering::Relaxed);
+            (*self.cq_tail).load(Ordering::Acquire).wrapping_sub(head)
+        }
+    }
+
+    /// Add an SQE to the submission queue.
+    ///
+    /// The request is not passed to the kernel until [`submit()`] is called (unless the ring uses
+    /// `IORING_SETUP_SQPOLL`, in which case the kernel may pick it up at any time).
+    ///
+    /// # Errors
+    ///
+    /// - `EBUSY`: The submission queue is full.
+    ///
+    /// # Safety
+    ///
+    /// Any buffers or other structures that `sqe` points to must be valid for the operation it
+    /// describes, and they must remain valid until the request completes (or, for structures that
+    /// are only read when the request is submitted, such as an `iovec` array, until it has been
+    /// submitted).
+    ///
+    /// [`submit()`]: #method.submit
+    pub unsafe fn push(&mut self, sqe: &Sqe) -> Result<(), i32> {
+        if self.is_sq_full() {
+            return Err(eno::EBUSY);
+        }
+
+        // We are the only writer of the tail, so it can be loaded with relaxed ordering
+        let tail = (*self.sq_tail).load(Ordering::Relaxed);
+        self.sqes.add((tail & self.sq_mask) as usize).write(*sqe);
+
+        // Make the SQE visible to the kernel before the new tail
+        (*self.sq_tail).store(tail.wrapping_add(1), Ordering::Release);
+        self.sq_pending += 1;
+
+        Ok(())
+    }
+
+    /// Submit the pushed SQEs to the kernel, and return the number that were submitted.
+    #[inline]
+    pub fn submit(&mut self) -> Result<usize, i32> {
+        self.submit_and_wait(0)
+    }
+
+    /// Submit the pushed SQEs to the kernel, and wait until at least `want` CQEs are ready.
+    ///
+    /// This returns the number of SQEs that were submitted.
+    ///
+    /// # Errors
+    ///
+    /// The errors returned by `io_uring_enter()`; in particular, `EINTR` if a signal was caught
+    /// while waiting, and `EBUSY` or `EAGAIN` if the completion queue is full. The SQEs that were
+    /// not submitted remain in the submission queue.
+    pub fn submit_and_wait(&mut self, want: u32) -> Result<usize, i32> {
+        let mut flags = 0;
+        if want > 0 {
+            flags |= IORING_ENTER_GETEVENTS;
+        }
+
+        if self.params.flags & IORING_SETUP_SQPOLL != 0 {
+            // The kernel thread consumes the SQEs on its own. It sets `IORING_SQ_NEED_WAKEUP`
+            // before going to sleep, and the full barrier makes sure that either it sees the new
+            // tail before doing so, or we see the flag.
+            fence(Ordering::SeqCst);
+            if unsafe { (*self.sq_flags).load(Ordering::Relaxed) } & IORING_SQ_NEED_WAKEUP != 0 {
+                flags |= IORING_ENTER_SQ_WAKEUP;
+            }
+
+            let submitted = self.sq_pending;
+            if flags != 0 {
+                self.enter(submitted, want, flags)?;
+            }
+            self.sq_pending = 0;
+
+            return Ok(submitted as usize);
+        }
+
+        if self.sq_pending == 0 && flags == 0 {
+            return Ok(0);
+        }
+
+        let submitted = self.enter(self.sq_pending, want, flags)?;
+        self.sq_pending -= submitted as u32;
+
+        Ok(submitted)
+    }
+
+    #[inline]
+    fn enter(&self, to_submit: u32, min_complete: u32, flags: u32) -> Result<usize, i32> {
+        unsafe {
+            crate::syscall!(
+                IO_URING_ENTER,
+                self.fd.0,
+                to_submit,
+                min_complete,
+                flags,
+                0,
+                0
+            )
+        }
+    }
+
+    /// Remove the next CQE from the completion queue, if one is ready.
+    pub fn pop(&mut self) -> Option<Cqe> {
+        unsafe {
+            // We are the only writer of the head; the acquire load of the tail makes sure that
+            // the CQE has been written
+            let head = (*self.cq_head).load(Ordering::Relaxed);
+            if head == (*self.cq_tail).load(Ordering::Acquire) {
+                return None;
+            }
+
+            let cqe = self.cqes.add((head & self.cq_mask) as usize).read();
+
+            // Make sure we're done reading the CQE before the kernel can reuse the slot
+            (*self.cq_head).store(head.wrapping_add(1), Ordering::Release);
+
+            Some(cqe)
+        }
+    }
+
+    /// Wait for the next CQE, and remove it from the completion queue.
+    ///
+    /// Any pushed SQEs are submitted first. If the wait is interrupted by a signal, this fails
+    /// with `EINTR`.
+    pub fn wait(&mut self) -> Result<Cqe, i32> {
+        loop {
+            if let Some(cqe) = self.pop() {
+                return Ok(cqe);
+            }
+            self.submit_and_wait(1)?;
+        }
+    }
+
+    /// Register or unregister resources with `io_uring_register()`.
+    ///
+    /// `opcode` is one of the `IORING_REGISTER_*` or `IORING_UNREGISTER_*` constants; `arg` and
+    /// `nr_args` depend on the opcode.
+    ///
+    /// # Safety
+    ///
+    /// `arg` must be valid for the given opcode. Registered buffers must remain valid until they
+    /// are unregistered (or the ring is dropped).
+    #[inline]
+    pub unsafe fn register(
+        &self,
+        opcode: u32,
+        arg: *const c_void,
+        nr_args: u32,
+    ) -> Result<usize, i32> {
+        crate::syscall!(IO_URING_REGISTER, self.fd.0, opcode, arg, nr_args)
+    }
+}
+
+impl core::fmt::Debug for IoUring {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IoUring")
+            .field("fd", &self.fd.0)
+            .field("params", &self.params)
+            .field("sq_len", &self.sq_len())
+            .field("cq_len", &self.cq_len())
+            .finish()
+    }
+}
@@ -0,0 +1,211 @@
+//! Encoding and decoding of `ioctl()` request numbers.
+//!
+//! These are equivalent to the C `_IO()`, `_IOR()`, `_IOW()`, and `_IOWR()` macros, and they
+//! take into account the differences in the encoding between architectures (on Linux, mips,
+//! powerpc, and sparc use a 3-bit direction field and a 13-bit size field instead of 2 and 14
+//! bits, and the direction values are different). They are `const fn`s, so they can be used to
+//! define constants:
+//!
+//! ```
+//! use scall::ioctl;
+//!
+//! // FS_IOC_GETFLAGS from <linux/fs.h>
+//! const FS_IOC_GETFLAGS: u32 = ioctl::ior(b'f', 1, core::mem::size_of::<isize>());
+//!
+//! assert_eq!(ioctl::ioc_dir(FS_IOC_GETFLAGS), ioctl::IOC_READ);
+//! assert_eq!(ioctl::ioc_type(FS_IOC_GETFLAGS), b'f');
+//! assert_eq!(ioctl::ioc_nr(FS_IOC_GETFLAGS), 1);
+//! assert_eq!(ioctl::ioc_size(FS_IOC_GETFLAGS), core::mem::size_of::<isize>());
+//! ```
+//!
+//! The resulting request numbers can be passed directly to [`syscall!`]:
+//!
+//! ```
+//! # use scall::{consts, ioctl, syscall, syscall_nofail};
+//! // RNDGETENTCNT (see random(4))
+//! # #[cfg(any(target_os = "linux", target_os = "android"))]
+//! const RNDGETENTCNT: u32 = ioctl::ior(b'R', 0x00, core::mem::size_of::<i32>());
+//!
+//! # #[cfg(any(target_os = "linux", target_os = "android"))]
+//! unsafe {
+//!     let fd = syscall!(OPENAT, consts::AT_FDCWD, b"/dev/urandom\0", consts::O_RDONLY, 0)
+//!         .unwrap();
+//!     let mut count = 0i32;
+//!     syscall!(IOCTL, fd, RNDGETENTCNT, &mut count).unwrap();
+//!     syscall_nofail!(CLOSE, fd);
+//! }
+//! ```
+//!
+//! Many older ioctls (for example, most of the terminal ioctls on Linux) predate this encoding
+//! and do not follow it; their numbers have to be defined by hand.
+//!
+//! [`syscall!`]: ../macro.syscall.html
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod imp {
+    #[cfg(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc64",
+    ))]
+    mod dir {
+        pub const SIZEBITS: u32 = 13;
+        pub const DIRBITS: u32 = 3;
+
+        pub const NONE: u32 = 1;
+        pub const READ: u32 = 2;
+        pub const WRITE: u32 = 4;
+    }
+
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc64",
+    )))]
+    mod dir {
+        pub const SIZEBITS: u32 = 14;
+        pub const DIRBITS: u32 = 2;
+
+        pub const NONE: u32 = 0;
+        pub const WRITE: u32 = 1;
+        pub const READ: u32 = 2;
+    }
+
+    pub use dir::{NONE, READ, WRITE};
+
+    pub const SIZEMASK: u32 = (1 << dir::SIZEBITS) - 1;
+    const SIZESHIFT: u32 = 16;
+    const DIRSHIFT: u32 = SIZESHIFT + dir::SIZEBITS;
+
+    #[inline]
+    pub const fn encode(dir: u32, size: u32) -> u32 {
+        (dir << DIRSHIFT) | (size << SIZESHIFT)
+    }
+
+    #[inline]
+    pub const fn dir(req: u32) -> u32 {
+        (req >> DIRSHIFT) & ((1 << dir::DIRBITS) - 1)
+    }
+
+    #[inline]
+    pub const fn size(req: u32) -> u32 {
+        (req >> SIZESHIFT) & SIZEMASK
+    }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "macos"))]
+mod imp {
+    // From <sys/ioccom.h>. Unlike on Linux, the "direction" values are already shifted into place.
+    pub const NONE: u32 = 0x2000_0000;
+    pub const READ: u32 = 0x4000_0000;
+    pub const WRITE: u32 = 0x8000_0000;
+
+    pub const SIZEMASK: u32 = 0x1fff;
+    const SIZESHIFT: u32 = 16;
+    const DIRMASK: u32 = NONE | READ | WRITE;
+
+    #[inline]
+    pub const fn encode(dir: u32, size: u32) -> u32 {
+        dir | (size << SIZESHIFT)
+    }
+
+    #[inline]
+    pub const fn dir(req: u32) -> u32 {
+        req & DIRMASK
+    }
+
+    #[inline]
+    pub const fn size(req: u32) -> u32 {
+        (req >> SIZESHIFT) & SIZEMASK
+    }
+}
+
+/// The "direction" of an ioctl with no argument (`_IOC_NONE` on Linux, `IOC_VOID` on BSDs).
+pub const IOC_NONE: u32 = imp::NONE;
+/// The "direction" of an ioctl that copies data out to userspace (`_IOC_READ` on Linux, `IOC_OUT`
+/// on BSDs).
+pub const IOC_READ: u32 = imp::READ;
+/// The "direction" of an ioctl that copies data in from userspace (`_IOC_WRITE` on Linux, `IOC_IN`
+/// on BSDs).
+pub const IOC_WRITE: u32 = imp::WRITE;
+
+/// The largest argument size that can be encoded in a request number.
+pub const IOC_SIZE_MAX: usize = imp::SIZEMASK as usize;
+
+/// Encode an ioctl request number (`_IOC()`).
+///
+/// `dir` should be [`IOC_NONE`], [`IOC_READ`], [`IOC_WRITE`], or `IOC_READ | IOC_WRITE`. `size`
+/// is the size of the argument that the ioctl reads and/or writes.
+///
+/// # Panics
+///
+/// Panics if `size` is larger than [`IOC_SIZE_MAX`]. When evaluated in a `const` context, this is
+/// a compile-time error instead.
+///
+/// [`IOC_NONE`]: ./constant.IOC_NONE.html
+/// [`IOC_READ`]: ./constant.IOC_READ.html
+/// [`IOC_WRITE`]: ./constant.IOC_WRITE.html
+/// [`IOC_SIZE_MAX`]: ./constant.IOC_SIZE_MAX.html
+#[inline]
+pub const fn ioc(dir: u32, ty: u8, nr: u8, size: usize) -> u32 {
+    // Fails at compile time in const contexts
+    assert!(size <= IOC_SIZE_MAX, "ioctl argument size too large");
+
+    imp::encode(dir, size as u32) | ((ty as u32) << 8) | nr as u32
+}
+
+/// Encode the request number of an ioctl that takes no argument (`_IO()`).
+#[inline]
+pub const fn io(ty: u8, nr: u8) -> u32 {
+    ioc(IOC_NONE, ty, nr, 0)
+}
+
+/// Encode the request number of an ioctl that writes `size` bytes out to userspace (`_IOR()`).
+///
+/// The name comes from the perspective of userspace, which "reads" the data.
+#[inline]
+pub const fn ior(ty: u8, nr: u8, size: usize) -> u32 {
+    ioc(IOC_READ, ty, nr, size)
+}
+
+/// Encode the request number of an ioctl that reads `size` bytes in from userspace (`_IOW()`).
+#[inline]
+pub const fn iow(ty: u8, nr: u8, size: usize) -> u32 {
+    ioc(IOC_WRITE, ty, nr, size)
+}
+
+/// Encode the request number of an ioctl that both reads and writes `size` bytes (`_IOWR()`).
+#[inline]
+pub const fn iowr(ty: u8, nr: u8, size: usize) -> u32 {
+    ioc(IOC_READ | IOC_WRITE, ty, nr, size)
+}
+
+/// Extract the direction from a request number (`_IOC_DIR()`).
+#[inline]
+pub const fn ioc_dir(req: u32) -> u32 {
+    imp::dir(req)
+}
+
+/// Extract the "type" (the first argument to [`ioc()`]) from a request number (`_IOC_TYPE()`).
+///
+/// [`ioc()`]: ./fn.ioc.html
+#[inline]
+pub const fn ioc_type(req: u32) -> u8 {
+    (req >> 8) as u8
+}
+
+/// Extract the number within the type from a request number (`_IOC_NR()`).
+#[inline]
+pub const fn ioc_nr(req: u32) -> u8 {
+    req as u8
+}
+
+/// Extract the argument size from a request number (`_IOC_SIZE()`).
+#[inline]
+pub const fn ioc_size(req: u32) -> usize {
+    imp::size(req) as usize
+}
@@ -19,6 +19,16 @@
 //! }
 //! ```
 //!
+//! # Cargo features
+//!
+//! - `std`: Integrate with `std::io::Error` (see [`syscall_io!`] and [`error_kind()`]), and
+//!   accept `BorrowedFd` as a syscall argument. This crate is `#![no_std]` if this feature is not
+//!   enabled.
+//! - `rt`: Provide a `_start` entry point for Linux programs that don't link against a C library
+//!   (see [`rt`]).
+//! - `arch`: Make the syscall and error number tables of every supported Linux architecture
+//!   available at the same time, for handling processes that use another ABI (see [`arch`]).
+//!
 //! *Note: This crate has several functions/macros, and looking through the documentation may seem
 //! a little confusing. However, for most purposes, you'll just want to use the [`syscall!`] macro
 //! (or sometimes [`syscall_nofail!`]). The other functions/macros are mostly present to support
@@ -27,16 +37,259 @@
 #![allow(clippy::missing_safety_doc)]
 #![feature(llvm_asm)]
 #![feature(asm)]
+#![feature(global_asm)]
 #![feature(doc_cfg)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use platform::*;
 
 mod macros;
 
+mod arg;
+pub use arg::*;
+
+#[cfg(feature = "std")]
+mod io_error;
+#[cfg(feature = "std")]
+pub use io_error::*;
+
+// Used by the generated `eno` modules to define the `Errno` constants and the tables backing
+// `Errno::name()`, `Errno::description()`, and `Errno::from_name()`.
+macro_rules! errno_table {
+    ($($name:ident $desc:literal,)* $(@alias $alias:ident = $target:ident,)*) => {
+        impl crate::Errno {
+            $(
+                #[doc = $desc]
+                pub const $name: Self = Self($name);
+            )*
+            $(
+                #[doc = concat!("Alias for [`Errno::", stringify!($target), "`](#associatedconstant.", stringify!($target), ").")]
+                pub const $alias: Self = Self($alias);
+            )*
+        }
+
+        pub(crate) static ERRNO_TABLE: &[(&str, i32, &str)] = &[$((stringify!($name), $name, $desc),)*];
+        pub(crate) static ERRNO_ALIASES: &[(&str, i32)] = &[$((stringify!($alias), $alias),)*];
+    };
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 mod args64;
 
+// `syscall_retry!` is available everywhere, but the helpers behind `syscall_retry_timeout!` are
+// only needed where it is
+mod retry;
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+pub use retry::*;
+
+mod sigset;
+pub use sigset::*;
+
+pub mod io;
+
+pub mod ioctl;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod auxv;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod vdso;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod futex;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod sync;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod clone;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use clone::*;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod thread;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod signal;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod io_uring;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod epoll;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod seccomp;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android")))]
+pub mod ptrace;
+
+#[cfg(all(feature = "rt", any(target_os = "linux", target_os = "android")))]
+#[doc(cfg(all(feature = "rt", any(target_os = "linux", target_os = "android"))))]
+pub mod rt;
+
+// Defines the `Sysno` enum (documented with the given attributes) and the methods that don't
+// depend on the table being the native one. Used by `sysno_table!` here and by the version of it
+// in the `arch` module.
+macro_rules! sysno_enum {
+    (@count _) => {
+        None
+    };
+
+    (@count $nargs:literal) => {
+        Some($nargs)
+    };
+
+    ($(#[$attr:meta])* ; $($name:ident $sname:literal $nargs:tt $($sig:literal)?,)* $(@alias $alias:ident = $target:ident,)*) => {
+        $(#[$attr])*
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(usize)]
+        #[non_exhaustive]
+        pub enum Sysno {
+            $(
+                #[doc = concat!("`", $sname, $("(", $sig, ")",)? "`")]
+                $name = $name,
+            )*
+        }
+
+        impl Sysno {
+            $(
+                #[doc = concat!("Alias for [`Sysno::", stringify!($target), "`](#variant.", stringify!($target), ").")]
+                pub const $alias: Self = Self::$target;
+            )*
+
+            const ALL: &'static [Self] = &[$(Self::$name,)*];
+            const ALIASES: &'static [(&'static str, Self)] = &[$((stringify!($alias), Self::$alias),)*];
+
+            /// Look up the system call with the given number, or return `None` if it is not
+            /// recognized.
+            pub fn new(nr: usize) -> Option<Self> {
+                match nr {
+                    $($name => Some(Self::$name),)*
+                    _ => None,
+                }
+            }
+
+            /// Get the raw system call number.
+            #[inline]
+            pub const fn into_raw(self) -> usize {
+                self as usize
+            }
+
+            /// Get the name of this system call (for example, `"openat"`).
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$name => $sname,)*
+                }
+            }
+
+            /// Get the number of arguments this system call takes, or `None` if it is not known.
+            ///
+            /// This is the number of registers used to pass the arguments, so on 32-bit
+            /// platforms it is unknown for syscalls that take 64-bit arguments.
+            pub fn arg_count(self) -> Option<usize> {
+                match self {
+                    $(Self::$name => sysno_enum!(@count $nargs),)*
+                }
+            }
+
+            /// Look up a system call by name (for example, `"openat"`).
+            ///
+            /// The comparison is case-insensitive, so the names of the constants in this module
+            /// (like `"OPENAT"`) are also accepted. Aliases (like `"SETUID32"` on architectures
+            /// where it is the same as `"SETUID"`) are recognized as well.
+            pub fn from_name(name: &str) -> Option<Self> {
+                Self::iter()
+                    .map(|sysno| (sysno.name(), sysno))
+                    .chain(Self::ALIASES.iter().copied())
+                    .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                    .map(|(_, sysno)| sysno)
+            }
+
+            /// Iterate over every system call defined on this platform.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        impl From<Sysno> for usize {
+            #[inline]
+            fn from(sysno: Sysno) -> Self {
+                sysno as usize
+            }
+        }
+
+        impl core::fmt::Display for Sysno {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    };
+}
+
+// Used by the generated `nr` modules to define the `Sysno` enum for that platform. Each syscall
+// is listed with the number of arguments it takes (or `_` if unknown) and, if known, its C
+// signature.
+macro_rules! sysno_table {
+    (@nargs $name:ident _) => {
+        pub struct $name;
+        impl<const N: usize> crate::TakesArgs<N> for $name {}
+    };
+
+    (@nargs $name:ident $nargs:literal) => {
+        pub struct $name;
+        impl crate::TakesArgs<$nargs> for $name {}
+    };
+
+    ($($name:ident $sname:literal $nargs:tt $($sig:literal)?,)* $(@alias $alias:ident = $target:ident,)*) => {
+        sysno_enum! {
+            /// A system call number on this platform.
+            ///
+            /// Unlike the raw `usize` constants in this module, a `Sysno` knows its own name, which
+            /// makes it possible to map syscall numbers back to names (and vice versa) at runtime.
+            ///
+            /// `Sysno`s can be passed directly to [`syscall!`] and friends, as well as to the
+            /// `syscallX()` functions (like [`syscall0()`]).
+            ///
+            /// [`syscall!`]: ../../macro.syscall.html
+            /// [`syscall0()`]: ../fn.syscall0.html
+            ;
+            $($name $sname $nargs $($sig)?,)*
+            $(@alias $alias = $target,)*
+        }
+
+        // Marker types used by `syscall!` and friends to check the number of arguments at
+        // compile time
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        pub mod _scall_internal_nargs {
+            $(sysno_table!(@nargs $name $nargs);)*
+            $(pub type $alias = $target;)*
+        }
+
+        impl crate::SyscallNumber for Sysno {
+            #[inline(always)]
+            fn into_nr(self) -> usize {
+                self as usize
+            }
+        }
+    };
+}
+
 #[cfg_attr(
     all(target_os = "linux", target_arch = "aarch64"),
     path = "platform/linux-aarch64/mod.rs"
@@ -74,9 +327,21 @@ mod args64;
     path = "platform/linux-x86/mod.rs"
 )]
 #[cfg_attr(
-    all(target_os = "linux", target_arch = "x86_64"),
+    all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "64"
+    ),
     path = "platform/linux-x86_64/mod.rs"
 )]
+#[cfg_attr(
+    all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "32"
+    ),
+    path = "platform/linux-x32/mod.rs"
+)]
 #[cfg_attr(
     all(target_os = "android", target_arch = "aarch64"),
     path = "platform/android-aarch64/mod.rs"
@@ -103,6 +368,41 @@ mod args64;
 )]
 pub mod platform;
 
+#[cfg(feature = "arch")]
+#[doc(cfg(feature = "arch"))]
+pub mod arch;
+
+pub use platform::nr::Sysno;
+
+/// A type that can be used as a system call number.
+///
+/// This is implemented for `usize` (the type of the constants in [`nr`]) and [`Sysno`], so either
+/// can be passed to the `syscallX()` functions (like [`syscall0()`]) and to the macros.
+///
+/// [`nr`]: ./platform/nr/index.html
+/// [`Sysno`]: ./platform/nr/enum.Sysno.html
+/// [`syscall0()`]: ./platform/fn.syscall0.html
+pub trait SyscallNumber: Copy {
+    /// Get the raw system call number.
+    fn into_nr(self) -> usize;
+}
+
+impl SyscallNumber for usize {
+    #[inline(always)]
+    fn into_nr(self) -> usize {
+        self
+    }
+}
+
+/// Implemented by the marker types in `nr::_scall_internal_nargs` for each number of arguments
+/// that the corresponding syscall accepts.
+#[doc(hidden)]
+pub trait TakesArgs<const N: usize> {}
+
+#[doc(hidden)]
+#[inline(always)]
+pub const fn _scall_internal_check_nargs<T: TakesArgs<N>, const N: usize>() {}
+
 /// The type returned by [`syscall_raw!`] on this platform.
 ///
 /// Can be "decoded" into a `Result<usize, i32>` with [`decode_raw_result()`].
@@ -140,3 +440,132 @@ pub fn decode_raw_result(res: RawResult) -> Result<usize, i32> {
     #[cfg(scall_error = "flag")]
     return if res.1 { Err(res.0 as i32) } else { Ok(res.0) };
 }
+
+/// A typed error number, as returned by [`syscall_errno!`].
+///
+/// This is a thin wrapper around the raw `i32` error numbers defined in [`eno`], which adds the
+/// ability to look up the name and description of an error (for example, `Errno::EOPNOTSUPP` on
+/// Linux has the name `"EOPNOTSUPP"` and the description `"Operation not supported on transport
+/// endpoint"`).
+///
+/// Constants are provided for every error number defined in [`eno`] on the current platform. Note
+/// that some error numbers are aliases for others (for example, `EWOULDBLOCK` and `EAGAIN` are the
+/// same on every supported platform); [`name()`] will always return the "primary" name.
+///
+/// # Example
+///
+/// ```
+/// # use scall::Errno;
+/// let err = Errno::new(scall::eno::ENOENT);
+/// assert_eq!(err, Errno::ENOENT);
+/// assert_eq!(err.name(), Some("ENOENT"));
+/// assert_eq!(Errno::from_name("ENOENT"), Some(err));
+///
+/// assert_eq!(Errno::EWOULDBLOCK, Errno::EAGAIN);
+/// assert_eq!(Errno::EWOULDBLOCK.name(), Some("EAGAIN"));
+/// ```
+///
+/// [`syscall_errno!`]: ./macro.syscall_errno.html
+/// [`eno`]: ./platform/eno/index.html
+/// [`name()`]: #method.name
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Errno(i32);
+
+impl Errno {
+    /// Wrap the given raw error number.
+    #[inline]
+    pub const fn new(eno: i32) -> Self {
+        Self(eno)
+    }
+
+    /// Get the raw error number.
+    #[inline]
+    pub const fn into_raw(self) -> i32 {
+        self.0
+    }
+
+    /// Get the name of this error number (for example, `"ENOENT"`), or `None` if it is not
+    /// recognized.
+    ///
+    /// If several names refer to the same error number (like `EWOULDBLOCK` and `EAGAIN`), the
+    /// "primary" name is returned.
+    pub fn name(self) -> Option<&'static str> {
+        eno::ERRNO_TABLE
+            .iter()
+            .find(|&&(_, eno, _)| eno == self.0)
+            .map(|&(name, _, _)| name)
+    }
+
+    /// Get a short description of this error number (for example, `"No such file or
+    /// directory"`), or `None` if it is not recognized.
+    ///
+    /// These descriptions are taken from the comments in the OS's `errno.h`, so they may differ
+    /// slightly from the messages returned by libc's `strerror()`.
+    pub fn description(self) -> Option<&'static str> {
+        eno::ERRNO_TABLE
+            .iter()
+            .find(|&&(_, eno, _)| eno == self.0)
+            .map(|&(_, _, desc)| desc)
+    }
+
+    /// Look up an error number by name (for example, `"ENOENT"`).
+    ///
+    /// Like [`Sysno::from_name()`], the comparison is case-insensitive, so `"enoent"` is also
+    /// accepted. Aliases (like `EWOULDBLOCK`) are recognized as well. Returns `None` if the name is
+    /// not defined on the current platform.
+    pub fn from_name(name: &str) -> Option<Self> {
+        eno::ERRNO_TABLE
+            .iter()
+            .map(|&(name, eno, _)| (name, eno))
+            .chain(eno::ERRNO_ALIASES.iter().copied())
+            .find(|&(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, eno)| Self(eno))
+    }
+}
+
+impl From<i32> for Errno {
+    #[inline]
+    fn from(eno: i32) -> Self {
+        Self(eno)
+    }
+}
+
+impl From<Errno> for i32 {
+    #[inline]
+    fn from(eno: Errno) -> Self {
+        eno.0
+    }
+}
+
+impl PartialEq<i32> for Errno {
+    #[inline]
+    fn eq(&self, other: &i32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Errno> for i32 {
+    #[inline]
+    fn eq(&self, other: &Errno) -> bool {
+        *self == other.0
+    }
+}
+
+impl core::fmt::Debug for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "Errno({})", self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match (self.name(), self.description()) {
+            (Some(name), Some(desc)) => write!(f, "{}: {}", name, desc),
+            _ => write!(f, "Unknown error {}", self.0),
+        }
+    }
+}
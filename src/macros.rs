@@ -43,70 +43,84 @@
 /// [`decode_raw_result()`]: ./fn.decode_raw_result.html
 #[macro_export]
 macro_rules! syscall_raw {
-    ($nr:ident) => {
-        $crate::syscall0($crate::nr::$nr)
+    (@call $nr:expr) => {
+        $crate::syscall0($nr)
     };
 
-    ($nr:ident, $a1:expr) => {
-        $crate::syscall1($crate::nr::$nr, $a1 as usize)
+    (@call $nr:expr, $a1:expr) => {
+        $crate::syscall1($nr, $crate::SyscallArg::into_arg($a1))
     };
 
-    ($nr:ident, $a1:expr, $a2:expr) => {
-        $crate::syscall2($crate::nr::$nr, $a1 as usize, $a2 as usize)
+    (@call $nr:expr, $a1:expr, $a2:expr) => {
+        $crate::syscall2(
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+        )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr) => {
-        $crate::syscall3($crate::nr::$nr, $a1 as usize, $a2 as usize, $a3 as usize)
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr) => {
+        $crate::syscall3(
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+        )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr) => {
         $crate::syscall4(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr) => {
         $crate::syscall5(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr) => {
         $crate::syscall6(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
-            $a6 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
+            $crate::SyscallArg::into_arg($a6),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr, $a7:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr, $a7:expr) => {
         $crate::syscall7(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
-            $a6 as usize,
-            $a7 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
+            $crate::SyscallArg::into_arg($a6),
+            $crate::SyscallArg::into_arg($a7),
         )
     };
 
-    ($nr:ident, $($args:expr,)*) => {
-        $crate::syscall_raw!($nr$(, $args)*)
+    ($nr:ident$(, $args:expr)* $(,)?) => {{
+        $crate::_scall_internal_check_nargs!($nr$(, $args)*);
+        $crate::syscall_raw!(@call $crate::nr::$nr$(, $args)*)
+    }};
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::syscall_raw!(@call $nr$(, $args)*)
     };
 }
 
@@ -147,70 +161,84 @@ macro_rules! syscall_raw {
 /// [`syscall!`]: ./macro.syscall.html
 #[macro_export]
 macro_rules! syscall_nofail {
-    ($nr:ident) => {
-        $crate::syscall0_nofail($crate::nr::$nr)
+    (@call $nr:expr) => {
+        $crate::syscall0_nofail($nr)
     };
 
-    ($nr:ident, $a1:expr) => {
-        $crate::syscall1_nofail($crate::nr::$nr, $a1 as usize)
+    (@call $nr:expr, $a1:expr) => {
+        $crate::syscall1_nofail($nr, $crate::SyscallArg::into_arg($a1))
     };
 
-    ($nr:ident, $a1:expr, $a2:expr) => {
-        $crate::syscall2_nofail($crate::nr::$nr, $a1 as usize, $a2 as usize)
+    (@call $nr:expr, $a1:expr, $a2:expr) => {
+        $crate::syscall2_nofail(
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+        )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr) => {
-        $crate::syscall3_nofail($crate::nr::$nr, $a1 as usize, $a2 as usize, $a3 as usize)
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr) => {
+        $crate::syscall3_nofail(
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+        )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr) => {
         $crate::syscall4_nofail(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr) => {
         $crate::syscall5_nofail(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr) => {
         $crate::syscall6_nofail(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
-            $a6 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
+            $crate::SyscallArg::into_arg($a6),
         )
     };
 
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr, $a7:expr) => {
+    (@call $nr:expr, $a1:expr, $a2:expr, $a3:expr, $a4:expr, $a5:expr, $a6:expr, $a7:expr) => {
         $crate::syscall7_nofail(
-            $crate::nr::$nr,
-            $a1 as usize,
-            $a2 as usize,
-            $a3 as usize,
-            $a4 as usize,
-            $a5 as usize,
-            $a6 as usize,
-            $a7 as usize,
+            $nr,
+            $crate::SyscallArg::into_arg($a1),
+            $crate::SyscallArg::into_arg($a2),
+            $crate::SyscallArg::into_arg($a3),
+            $crate::SyscallArg::into_arg($a4),
+            $crate::SyscallArg::into_arg($a5),
+            $crate::SyscallArg::into_arg($a6),
+            $crate::SyscallArg::into_arg($a7),
         )
     };
 
-    ($nr:ident, $($args:expr,)*) => {
-        $crate::syscall_nofail!($nr$(, $args)*)
+    ($nr:ident$(, $args:expr)* $(,)?) => {{
+        $crate::_scall_internal_check_nargs!($nr$(, $args)*);
+        $crate::syscall_nofail!(@call $crate::nr::$nr$(, $args)*)
+    }};
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::syscall_nofail!(@call $nr$(, $args)*)
     };
 }
 
@@ -219,9 +247,8 @@ macro_rules! syscall_nofail {
 /// This returns `Ok(retval)` on success, and `Err(errno)` on error. It's essentially equivalent to
 /// `decode_raw_result(syscall_raw!(...))`.
 ///
-/// Tip: If you're not in a `#![no_std]` crate, you can do something like `syscall!(SETRESUID, 0,
-/// 0, 0).map_err(std::io::Error::from_raw_os_error)` to get an `io::Result<usize>`, which is
-/// easier to work with.
+/// Tip: If you're not in a `#![no_std]` crate, you can enable the `std` feature and use
+/// [`syscall_io!`] to get an `io::Result<usize>`, which is easier to work with.
 ///
 /// # Example
 ///
@@ -231,13 +258,218 @@ macro_rules! syscall_nofail {
 /// assert_eq!(pid as u32, std::process::id());
 /// ```
 ///
+/// # Specifying the syscall
+///
+/// The first argument is usually the name of one of the constants in [`nr`] (like `GETPID`). It
+/// can also be an expression that evaluates to a [`Sysno`] (or a raw `usize`), which is useful if
+/// the syscall is only known at runtime:
+///
+/// ```
+/// # use scall::{syscall, Sysno};
+/// let sysno = Sysno::from_name("getpid").unwrap();
+/// let pid = unsafe { syscall!((sysno)).unwrap() };
+/// assert_eq!(pid as u32, std::process::id());
+///
+/// assert_eq!(unsafe { syscall!(Sysno::GETPID) }, Ok(pid));
+/// ```
+///
+/// Note that a bare identifier is always looked up in [`nr`], so variables must be wrapped in
+/// parentheses (as shown above).
+///
+/// This applies to [`syscall_raw!`] and [`syscall_nofail!`] as well.
+///
+/// # Arguments
+///
+/// Each argument is converted to a `usize` with [`SyscallArg`], so integers, pointers,
+/// references, slices, and `Option<&T>` (as a nullable pointer) can all be passed directly.
+///
+/// On Linux, the number of arguments is checked at compile time if the syscall is specified by
+/// name, so calls like this one fail to compile:
+///
+#[cfg_attr(
+    any(target_os = "linux", target_os = "android"),
+    doc = "```compile_fail"
+)]
+#[cfg_attr(
+    not(any(target_os = "linux", target_os = "android")),
+    doc = "```ignore"
+)]
+/// # use scall::syscall;
+/// // write() takes 3 arguments
+/// unsafe { syscall!(WRITE, 1, b"abc") };
+/// ```
+///
+/// Note that this is the number of arguments the *kernel* expects, which sometimes differs from
+/// the libc wrapper (for example, `open()` always takes a `mode` argument). Some syscalls (like
+/// those that take 64-bit arguments on 32-bit platforms) are not checked. Use
+/// [`syscall_unchecked!`] to bypass the check.
+///
 /// # Safety
 ///
 /// Making syscalls is *wildly* unsafe! Read the man pages carefully, and consider
 /// architecture-specific differences ([`syscall_args64!`] may help with this).
+///
+/// [`nr`]: ./platform/nr/index.html
+/// [`Sysno`]: ./platform/nr/enum.Sysno.html
+/// [`syscall_raw!`]: ./macro.syscall_raw.html
+/// [`syscall_nofail!`]: ./macro.syscall_nofail.html
+/// [`syscall_io!`]: ./macro.syscall_io.html
+/// [`SyscallArg`]: ./trait.SyscallArg.html
+/// [`syscall_unchecked!`]: ./macro.syscall_unchecked.html
 #[macro_export]
 macro_rules! syscall {
     ($nr:ident$(, $args:expr)* $(,)?) => {
         $crate::decode_raw_result($crate::syscall_raw!($nr$(, $args)*))
     };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::decode_raw_result($crate::syscall_raw!($nr$(, $args)*))
+    };
+}
+
+/// A version of [`syscall!`] that doesn't check the number of arguments.
+///
+/// [`syscall!`] normally rejects calls that pass the wrong number of arguments for the given
+/// syscall. This macro can be used for deliberately unusual invocations (for example, passing
+/// extra arguments that only some kernel versions look at).
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall_unchecked;
+/// let pid = unsafe { syscall_unchecked!(GETPID, 0, 0) }.unwrap();
+/// assert_eq!(pid as u32, std::process::id());
+/// ```
+///
+/// If the syscall is given as an expression (as described in "[Specifying the
+/// syscall](./macro.syscall.html#specifying-the-syscall)"), the number of arguments is never
+/// checked, so [`syscall_raw!`] and [`syscall_nofail!`] can be used without checks by wrapping
+/// the syscall number in parentheses (for example, `syscall_nofail!((nr::CLOSE), fd)`).
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`syscall_raw!`]: ./macro.syscall_raw.html
+/// [`syscall_nofail!`]: ./macro.syscall_nofail.html
+#[macro_export]
+macro_rules! syscall_unchecked {
+    ($nr:ident$(, $args:expr)* $(,)?) => {
+        $crate::decode_raw_result($crate::syscall_raw!(@call $crate::nr::$nr$(, $args)*))
+    };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::decode_raw_result($crate::syscall_raw!(@call $nr$(, $args)*))
+    };
+}
+
+/// Make a syscall, and return the result as a `Result<usize, Errno>`.
+///
+/// This is identical to [`syscall!`], except that errors are returned as an [`Errno`] instead of
+/// a raw `i32`. [`Errno`] implements `Display` and `Debug` in terms of the error's name and
+/// description, which makes it more useful for logging and error messages.
+///
+/// # Example
+///
+/// ```
+/// # use scall::{syscall_errno, Errno};
+/// let err = unsafe { syscall_errno!(CLOSE, -1i32) }.unwrap_err();
+/// assert_eq!(err, Errno::EBADF);
+/// assert_eq!(err.name(), Some("EBADF"));
+/// ```
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`Errno`]: ./struct.Errno.html
+#[macro_export]
+macro_rules! syscall_errno {
+    ($nr:ident$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map_err($crate::Errno::new)
+    };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map_err($crate::Errno::new)
+    };
+}
+
+/// Make a syscall, and convert the result to another type with [`SyscallRet`].
+///
+/// This is identical to [`syscall!`], except that successful results are converted to any type
+/// that implements [`SyscallRet`] (usually inferred from context). This avoids casting the raw
+/// `usize` to an `i32` (for file descriptors), a pointer (for `mmap()`), or discarding it entirely
+/// (for syscalls like `close()`).
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall_into;
+/// unsafe {
+///     let fd: i32 = syscall_into!(DUP, 0).unwrap();
+///     let () = syscall_into!(CLOSE, fd).unwrap();
+///
+///     assert_eq!(syscall_into!(CLOSE, fd), Err::<(), _>(scall::eno::EBADF));
+/// }
+/// ```
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`SyscallRet`]: ./trait.SyscallRet.html
+#[macro_export]
+macro_rules! syscall_into {
+    ($nr:ident$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map($crate::SyscallRet::from_ret)
+    };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map($crate::SyscallRet::from_ret)
+    };
+}
+
+/// Make a syscall, and return the result as an `io::Result<usize>`.
+///
+/// This is identical to [`syscall!`], except that errors are converted to `std::io::Error`s (with
+/// `std::io::Error::from_raw_os_error()`). It is only available if the `std` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall_io;
+/// let err = unsafe { syscall_io!(CLOSE, -1i32) }.unwrap_err();
+/// assert_eq!(err.raw_os_error(), Some(scall::eno::EBADF));
+/// ```
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+#[cfg(feature = "std")]
+#[doc(cfg(feature = "std"))]
+#[macro_export]
+macro_rules! syscall_io {
+    ($nr:ident$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map_err($crate::_scall_internal_io_error)
+    };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        $crate::syscall!($nr$(, $args)*).map_err($crate::_scall_internal_io_error)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _scall_internal_check_nargs {
+    ($nr:ident$(, $args:expr)*) => {
+        $crate::_scall_internal_check_nargs::<
+            $crate::nr::_scall_internal_nargs::$nr,
+            { <[&str]>::len(&[$(stringify!($args)),*]) },
+        >()
+    };
 }
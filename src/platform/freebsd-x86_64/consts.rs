@@ -0,0 +1,128 @@
+//! Flag constants for x86-64 FreeBSD.
+
+/* automatically generated by bsd_consts_from_src.py */
+
+pub const AT_EACCESS: i32 = 0x100;
+pub const AT_EMPTY_PATH: i32 = 0x4000;
+pub const AT_FDCWD: i32 = -100;
+pub const AT_REMOVEDIR: i32 = 0x800;
+pub const AT_RESOLVE_BENEATH: i32 = 0x2000;
+pub const AT_SYMLINK_FOLLOW: i32 = 0x400;
+pub const AT_SYMLINK_NOFOLLOW: i32 = 0x200;
+pub const CLOCK_MONOTONIC: i32 = 4;
+pub const CLOCK_MONOTONIC_FAST: i32 = 12;
+pub const CLOCK_MONOTONIC_PRECISE: i32 = 11;
+pub const CLOCK_PROCESS_CPUTIME_ID: i32 = 15;
+pub const CLOCK_PROF: i32 = 2;
+pub const CLOCK_REALTIME: i32 = 0;
+pub const CLOCK_REALTIME_FAST: i32 = 10;
+pub const CLOCK_REALTIME_PRECISE: i32 = 9;
+pub const CLOCK_SECOND: i32 = 13;
+pub const CLOCK_THREAD_CPUTIME_ID: i32 = 14;
+pub const CLOCK_UPTIME: i32 = 5;
+pub const CLOCK_UPTIME_FAST: i32 = 8;
+pub const CLOCK_UPTIME_PRECISE: i32 = 7;
+pub const CLOCK_VIRTUAL: i32 = 1;
+pub const FD_CLOEXEC: i32 = 1;
+pub const F_ADD_SEALS: i32 = 0x13;
+pub const F_DUP2FD: i32 = 10;
+pub const F_DUP2FD_CLOEXEC: i32 = 0x12;
+pub const F_DUPFD: i32 = 0;
+pub const F_DUPFD_CLOEXEC: i32 = 0x11;
+pub const F_GETFD: i32 = 1;
+pub const F_GETFL: i32 = 3;
+pub const F_GETLK: i32 = 11;
+pub const F_GETOWN: i32 = 5;
+pub const F_GET_SEALS: i32 = 0x14;
+pub const F_ISUNIONSTACK: i32 = 0x15;
+pub const F_RDAHEAD: i32 = 0x10;
+pub const F_RDLCK: i32 = 1;
+pub const F_READAHEAD: i32 = 15;
+pub const F_SEAL_GROW: i32 = 4;
+pub const F_SEAL_SEAL: i32 = 1;
+pub const F_SEAL_SHRINK: i32 = 2;
+pub const F_SEAL_WRITE: i32 = 8;
+pub const F_SETFD: i32 = 2;
+pub const F_SETFL: i32 = 4;
+pub const F_SETLK: i32 = 12;
+pub const F_SETLKW: i32 = 13;
+pub const F_SETLK_REMOTE: i32 = 14;
+pub const F_SETOWN: i32 = 6;
+pub const F_UNLCK: i32 = 2;
+pub const F_WRLCK: i32 = 3;
+pub const MADV_AUTOSYNC: i32 = 7;
+pub const MADV_CORE: i32 = 9;
+pub const MADV_DONTNEED: i32 = 4;
+pub const MADV_FREE: i32 = 5;
+pub const MADV_NOCORE: i32 = 8;
+pub const MADV_NORMAL: i32 = 0;
+pub const MADV_NOSYNC: i32 = 6;
+pub const MADV_PROTECT: i32 = 10;
+pub const MADV_RANDOM: i32 = 1;
+pub const MADV_SEQUENTIAL: i32 = 2;
+pub const MADV_WILLNEED: i32 = 3;
+pub const MAP_32BIT: i32 = 0x80000;
+pub const MAP_ALIGNED_SUPER: i32 = 0x1000000;
+pub const MAP_ANON: i32 = 0x1000;
+pub const MAP_ANONYMOUS: i32 = 0x1000;
+pub const MAP_EXCL: i32 = 0x4000;
+pub const MAP_FILE: i32 = 0;
+pub const MAP_FIXED: i32 = 0x10;
+pub const MAP_GUARD: i32 = 0x2000;
+pub const MAP_HASSEMAPHORE: i32 = 0x200;
+pub const MAP_NOCORE: i32 = 0x20000;
+pub const MAP_NOSYNC: i32 = 0x800;
+pub const MAP_PREFAULT_READ: i32 = 0x40000;
+pub const MAP_PRIVATE: i32 = 2;
+pub const MAP_SHARED: i32 = 1;
+pub const MAP_STACK: i32 = 0x400;
+pub const MCL_CURRENT: i32 = 1;
+pub const MCL_FUTURE: i32 = 2;
+pub const MS_ASYNC: i32 = 1;
+pub const MS_INVALIDATE: i32 = 2;
+pub const MS_SYNC: i32 = 0;
+pub const O_ACCMODE: i32 = 3;
+pub const O_APPEND: i32 = 8;
+pub const O_ASYNC: i32 = 0x40;
+pub const O_CLOEXEC: i32 = 0x100000;
+pub const O_CREAT: i32 = 0x200;
+pub const O_DIRECT: i32 = 0x10000;
+pub const O_DIRECTORY: i32 = 0x20000;
+pub const O_DSYNC: i32 = 0x1000000;
+pub const O_EMPTY_PATH: i32 = 0x2000000;
+pub const O_EXCL: i32 = 0x800;
+pub const O_EXEC: i32 = 0x40000;
+pub const O_EXLOCK: i32 = 0x20;
+pub const O_FSYNC: i32 = 0x80;
+pub const O_NDELAY: i32 = 4;
+pub const O_NOCTTY: i32 = 0x8000;
+pub const O_NOFOLLOW: i32 = 0x100;
+pub const O_NONBLOCK: i32 = 4;
+pub const O_PATH: i32 = 0x400000;
+pub const O_RDONLY: i32 = 0;
+pub const O_RDWR: i32 = 2;
+pub const O_RESOLVE_BENEATH: i32 = 0x800000;
+pub const O_SEARCH: i32 = 0x40000;
+pub const O_SHLOCK: i32 = 0x10;
+pub const O_SYNC: i32 = 0x80;
+pub const O_TRUNC: i32 = 0x400;
+pub const O_TTY_INIT: i32 = 0x80000;
+pub const O_VERIFY: i32 = 0x200000;
+pub const O_WRONLY: i32 = 1;
+pub const PROT_EXEC: i32 = 4;
+pub const PROT_NONE: i32 = 0;
+pub const PROT_READ: i32 = 1;
+pub const PROT_WRITE: i32 = 2;
+pub const SEEK_CUR: i32 = 1;
+pub const SEEK_DATA: i32 = 3;
+pub const SEEK_END: i32 = 2;
+pub const SEEK_HOLE: i32 = 4;
+pub const SEEK_SET: i32 = 0;
+pub const TIMER_ABSTIME: i32 = 1;
+pub const WCONTINUED: i32 = 4;
+pub const WEXITED: i32 = 0x10;
+pub const WNOHANG: i32 = 1;
+pub const WNOWAIT: i32 = 8;
+pub const WSTOPPED: i32 = 2;
+pub const WTRAPPED: i32 = 0x20;
+pub const WUNTRACED: i32 = 2;
@@ -9,11 +9,15 @@
 
 //! This library was built for x86-64 FreeBSD.
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
 
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> (usize, bool) {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -28,7 +32,8 @@ pub unsafe fn syscall0(n: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> (usize, bool) {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -44,7 +49,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> (usize, bool) {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -61,7 +67,13 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> (usize, bool) {
+pub unsafe fn syscall3(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -79,7 +91,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> (usize, boo
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> (usize, bool) {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -99,13 +118,14 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -126,7 +146,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -134,6 +154,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -154,7 +175,8 @@ pub unsafe fn syscall6(
 }
 
 #[inline(always)]
-pub unsafe fn syscall0_nofail(n: usize) -> usize {
+pub unsafe fn syscall0_nofail(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -166,7 +188,8 @@ pub unsafe fn syscall0_nofail(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -179,7 +202,8 @@ pub unsafe fn syscall1_nofail(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -193,7 +217,13 @@ pub unsafe fn syscall2_nofail(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -208,7 +238,14 @@ pub unsafe fn syscall3_nofail(n: usize, a1: usize, a2: usize, a3: usize) -> usiz
 }
 
 #[inline(always)]
-pub unsafe fn syscall4_nofail(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4_nofail(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -225,13 +262,14 @@ pub unsafe fn syscall4_nofail(n: usize, a1: usize, a2: usize, a3: usize, a4: usi
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -249,7 +287,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -257,6 +295,7 @@ pub unsafe fn syscall6_nofail(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
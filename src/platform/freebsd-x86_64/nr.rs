@@ -440,3 +440,444 @@ pub const RPCTLS_SYSCALL: usize = 576;
 pub const SPECIALFD: usize = 577;
 pub const AIO_WRITEV: usize = 578;
 pub const AIO_READV: usize = 579;
+
+sysno_table! {
+    SYSCALL "syscall" _,
+    EXIT "exit" _,
+    FORK "fork" _,
+    READ "read" _,
+    WRITE "write" _,
+    OPEN "open" _,
+    CLOSE "close" _,
+    WAIT4 "wait4" _,
+    LINK "link" _,
+    UNLINK "unlink" _,
+    CHDIR "chdir" _,
+    FCHDIR "fchdir" _,
+    FREEBSD11_MKNOD "freebsd11_mknod" _,
+    CHMOD "chmod" _,
+    CHOWN "chown" _,
+    BREAK "break" _,
+    FREEBSD4_GETFSSTAT "freebsd4_getfsstat" _,
+    GETPID "getpid" _,
+    MOUNT "mount" _,
+    UNMOUNT "unmount" _,
+    SETUID "setuid" _,
+    GETUID "getuid" _,
+    GETEUID "geteuid" _,
+    PTRACE "ptrace" _,
+    RECVMSG "recvmsg" _,
+    SENDMSG "sendmsg" _,
+    RECVFROM "recvfrom" _,
+    ACCEPT "accept" _,
+    GETPEERNAME "getpeername" _,
+    GETSOCKNAME "getsockname" _,
+    ACCESS "access" _,
+    CHFLAGS "chflags" _,
+    FCHFLAGS "fchflags" _,
+    SYNC "sync" _,
+    KILL "kill" _,
+    GETPPID "getppid" _,
+    DUP "dup" _,
+    FREEBSD10_PIPE "freebsd10_pipe" _,
+    GETEGID "getegid" _,
+    PROFIL "profil" _,
+    KTRACE "ktrace" _,
+    GETGID "getgid" _,
+    GETLOGIN "getlogin" _,
+    SETLOGIN "setlogin" _,
+    ACCT "acct" _,
+    SIGALTSTACK "sigaltstack" _,
+    IOCTL "ioctl" _,
+    REBOOT "reboot" _,
+    REVOKE "revoke" _,
+    SYMLINK "symlink" _,
+    READLINK "readlink" _,
+    EXECVE "execve" _,
+    UMASK "umask" _,
+    CHROOT "chroot" _,
+    MSYNC "msync" _,
+    VFORK "vfork" _,
+    SBRK "sbrk" _,
+    SSTK "sstk" _,
+    FREEBSD11_VADVISE "freebsd11_vadvise" _,
+    MUNMAP "munmap" _,
+    MPROTECT "mprotect" _,
+    MADVISE "madvise" _,
+    MINCORE "mincore" _,
+    GETGROUPS "getgroups" _,
+    SETGROUPS "setgroups" _,
+    GETPGRP "getpgrp" _,
+    SETPGID "setpgid" _,
+    SETITIMER "setitimer" _,
+    SWAPON "swapon" _,
+    GETITIMER "getitimer" _,
+    GETDTABLESIZE "getdtablesize" _,
+    DUP2 "dup2" _,
+    FCNTL "fcntl" _,
+    SELECT "select" _,
+    FSYNC "fsync" _,
+    SETPRIORITY "setpriority" _,
+    SOCKET "socket" _,
+    CONNECT "connect" _,
+    GETPRIORITY "getpriority" _,
+    BIND "bind" _,
+    SETSOCKOPT "setsockopt" _,
+    LISTEN "listen" _,
+    GETTIMEOFDAY "gettimeofday" _,
+    GETRUSAGE "getrusage" _,
+    GETSOCKOPT "getsockopt" _,
+    READV "readv" _,
+    WRITEV "writev" _,
+    SETTIMEOFDAY "settimeofday" _,
+    FCHOWN "fchown" _,
+    FCHMOD "fchmod" _,
+    SETREUID "setreuid" _,
+    SETREGID "setregid" _,
+    RENAME "rename" _,
+    FLOCK "flock" _,
+    MKFIFO "mkfifo" _,
+    SENDTO "sendto" _,
+    SHUTDOWN "shutdown" _,
+    SOCKETPAIR "socketpair" _,
+    MKDIR "mkdir" _,
+    RMDIR "rmdir" _,
+    UTIMES "utimes" _,
+    ADJTIME "adjtime" _,
+    SETSID "setsid" _,
+    QUOTACTL "quotactl" _,
+    NLM_SYSCALL "nlm_syscall" _,
+    NFSSVC "nfssvc" _,
+    FREEBSD4_STATFS "freebsd4_statfs" _,
+    FREEBSD4_FSTATFS "freebsd4_fstatfs" _,
+    LGETFH "lgetfh" _,
+    GETFH "getfh" _,
+    FREEBSD4_GETDOMAINNAME "freebsd4_getdomainname" _,
+    FREEBSD4_SETDOMAINNAME "freebsd4_setdomainname" _,
+    FREEBSD4_UNAME "freebsd4_uname" _,
+    SYSARCH "sysarch" _,
+    RTPRIO "rtprio" _,
+    SEMSYS "semsys" _,
+    MSGSYS "msgsys" _,
+    SHMSYS "shmsys" _,
+    FREEBSD6_PREAD "freebsd6_pread" _,
+    FREEBSD6_PWRITE "freebsd6_pwrite" _,
+    SETFIB "setfib" _,
+    NTP_ADJTIME "ntp_adjtime" _,
+    SETGID "setgid" _,
+    SETEGID "setegid" _,
+    SETEUID "seteuid" _,
+    FREEBSD11_STAT "freebsd11_stat" _,
+    FREEBSD11_FSTAT "freebsd11_fstat" _,
+    FREEBSD11_LSTAT "freebsd11_lstat" _,
+    PATHCONF "pathconf" _,
+    FPATHCONF "fpathconf" _,
+    GETRLIMIT "getrlimit" _,
+    SETRLIMIT "setrlimit" _,
+    FREEBSD11_GETDIRENTRIES "freebsd11_getdirentries" _,
+    FREEBSD6_MMAP "freebsd6_mmap" _,
+    _SYSCALL "_syscall" _,
+    FREEBSD6_LSEEK "freebsd6_lseek" _,
+    FREEBSD6_TRUNCATE "freebsd6_truncate" _,
+    FREEBSD6_FTRUNCATE "freebsd6_ftruncate" _,
+    SYSCTL "sysctl" _,
+    MLOCK "mlock" _,
+    MUNLOCK "munlock" _,
+    UNDELETE "undelete" _,
+    FUTIMES "futimes" _,
+    GETPGID "getpgid" _,
+    POLL "poll" _,
+    FREEBSD7_SEMCTL "freebsd7_semctl" _,
+    SEMGET "semget" _,
+    SEMOP "semop" _,
+    FREEBSD7_MSGCTL "freebsd7_msgctl" _,
+    MSGGET "msgget" _,
+    MSGSND "msgsnd" _,
+    MSGRCV "msgrcv" _,
+    SHMAT "shmat" _,
+    FREEBSD7_SHMCTL "freebsd7_shmctl" _,
+    SHMDT "shmdt" _,
+    SHMGET "shmget" _,
+    CLOCK_GETTIME "clock_gettime" _,
+    CLOCK_SETTIME "clock_settime" _,
+    CLOCK_GETRES "clock_getres" _,
+    KTIMER_CREATE "ktimer_create" _,
+    KTIMER_DELETE "ktimer_delete" _,
+    KTIMER_SETTIME "ktimer_settime" _,
+    KTIMER_GETTIME "ktimer_gettime" _,
+    KTIMER_GETOVERRUN "ktimer_getoverrun" _,
+    NANOSLEEP "nanosleep" _,
+    FFCLOCK_GETCOUNTER "ffclock_getcounter" _,
+    FFCLOCK_SETESTIMATE "ffclock_setestimate" _,
+    FFCLOCK_GETESTIMATE "ffclock_getestimate" _,
+    CLOCK_NANOSLEEP "clock_nanosleep" _,
+    CLOCK_GETCPUCLOCKID2 "clock_getcpuclockid2" _,
+    NTP_GETTIME "ntp_gettime" _,
+    MINHERIT "minherit" _,
+    RFORK "rfork" _,
+    ISSETUGID "issetugid" _,
+    LCHOWN "lchown" _,
+    AIO_READ "aio_read" _,
+    AIO_WRITE "aio_write" _,
+    LIO_LISTIO "lio_listio" _,
+    FREEBSD11_GETDENTS "freebsd11_getdents" _,
+    LCHMOD "lchmod" _,
+    LUTIMES "lutimes" _,
+    FREEBSD11_NSTAT "freebsd11_nstat" _,
+    FREEBSD11_NFSTAT "freebsd11_nfstat" _,
+    FREEBSD11_NLSTAT "freebsd11_nlstat" _,
+    PREADV "preadv" _,
+    PWRITEV "pwritev" _,
+    FREEBSD4_FHSTATFS "freebsd4_fhstatfs" _,
+    FHOPEN "fhopen" _,
+    FREEBSD11_FHSTAT "freebsd11_fhstat" _,
+    MODNEXT "modnext" _,
+    MODSTAT "modstat" _,
+    MODFNEXT "modfnext" _,
+    MODFIND "modfind" _,
+    KLDLOAD "kldload" _,
+    KLDUNLOAD "kldunload" _,
+    KLDFIND "kldfind" _,
+    KLDNEXT "kldnext" _,
+    KLDSTAT "kldstat" _,
+    KLDFIRSTMOD "kldfirstmod" _,
+    GETSID "getsid" _,
+    SETRESUID "setresuid" _,
+    SETRESGID "setresgid" _,
+    AIO_RETURN "aio_return" _,
+    AIO_SUSPEND "aio_suspend" _,
+    AIO_CANCEL "aio_cancel" _,
+    AIO_ERROR "aio_error" _,
+    FREEBSD6_AIO_READ "freebsd6_aio_read" _,
+    FREEBSD6_AIO_WRITE "freebsd6_aio_write" _,
+    FREEBSD6_LIO_LISTIO "freebsd6_lio_listio" _,
+    YIELD "yield" _,
+    MLOCKALL "mlockall" _,
+    MUNLOCKALL "munlockall" _,
+    GETCWD "getcwd" _,
+    SCHED_SETPARAM "sched_setparam" _,
+    SCHED_GETPARAM "sched_getparam" _,
+    SCHED_SETSCHEDULER "sched_setscheduler" _,
+    SCHED_GETSCHEDULER "sched_getscheduler" _,
+    SCHED_YIELD "sched_yield" _,
+    SCHED_GET_PRIORITY_MAX "sched_get_priority_max" _,
+    SCHED_GET_PRIORITY_MIN "sched_get_priority_min" _,
+    SCHED_RR_GET_INTERVAL "sched_rr_get_interval" _,
+    UTRACE "utrace" _,
+    FREEBSD4_SENDFILE "freebsd4_sendfile" _,
+    KLDSYM "kldsym" _,
+    JAIL "jail" _,
+    NNPFS_SYSCALL "nnpfs_syscall" _,
+    SIGPROCMASK "sigprocmask" _,
+    SIGSUSPEND "sigsuspend" _,
+    FREEBSD4_SIGACTION "freebsd4_sigaction" _,
+    SIGPENDING "sigpending" _,
+    FREEBSD4_SIGRETURN "freebsd4_sigreturn" _,
+    SIGTIMEDWAIT "sigtimedwait" _,
+    SIGWAITINFO "sigwaitinfo" _,
+    ACL_GET_FILE "acl_get_file" _,
+    ACL_SET_FILE "acl_set_file" _,
+    ACL_GET_FD "acl_get_fd" _,
+    ACL_SET_FD "acl_set_fd" _,
+    ACL_DELETE_FILE "acl_delete_file" _,
+    ACL_DELETE_FD "acl_delete_fd" _,
+    ACL_ACLCHECK_FILE "acl_aclcheck_file" _,
+    ACL_ACLCHECK_FD "acl_aclcheck_fd" _,
+    EXTATTRCTL "extattrctl" _,
+    EXTATTR_SET_FILE "extattr_set_file" _,
+    EXTATTR_GET_FILE "extattr_get_file" _,
+    EXTATTR_DELETE_FILE "extattr_delete_file" _,
+    AIO_WAITCOMPLETE "aio_waitcomplete" _,
+    GETRESUID "getresuid" _,
+    GETRESGID "getresgid" _,
+    KQUEUE "kqueue" _,
+    FREEBSD11_KEVENT "freebsd11_kevent" _,
+    EXTATTR_SET_FD "extattr_set_fd" _,
+    EXTATTR_GET_FD "extattr_get_fd" _,
+    EXTATTR_DELETE_FD "extattr_delete_fd" _,
+    SETUGID "setugid" _,
+    EACCESS "eaccess" _,
+    AFS3_SYSCALL "afs3_syscall" _,
+    NMOUNT "nmount" _,
+    MAC_GET_PROC "mac_get_proc" _,
+    MAC_SET_PROC "mac_set_proc" _,
+    MAC_GET_FD "mac_get_fd" _,
+    MAC_GET_FILE "mac_get_file" _,
+    MAC_SET_FD "mac_set_fd" _,
+    MAC_SET_FILE "mac_set_file" _,
+    KENV "kenv" _,
+    LCHFLAGS "lchflags" _,
+    UUIDGEN "uuidgen" _,
+    SENDFILE "sendfile" _,
+    MAC_SYSCALL "mac_syscall" _,
+    FREEBSD11_GETFSSTAT "freebsd11_getfsstat" _,
+    FREEBSD11_STATFS "freebsd11_statfs" _,
+    FREEBSD11_FSTATFS "freebsd11_fstatfs" _,
+    FREEBSD11_FHSTATFS "freebsd11_fhstatfs" _,
+    KSEM_CLOSE "ksem_close" _,
+    KSEM_POST "ksem_post" _,
+    KSEM_WAIT "ksem_wait" _,
+    KSEM_TRYWAIT "ksem_trywait" _,
+    KSEM_INIT "ksem_init" _,
+    KSEM_OPEN "ksem_open" _,
+    KSEM_UNLINK "ksem_unlink" _,
+    KSEM_GETVALUE "ksem_getvalue" _,
+    KSEM_DESTROY "ksem_destroy" _,
+    MAC_GET_PID "mac_get_pid" _,
+    MAC_GET_LINK "mac_get_link" _,
+    MAC_SET_LINK "mac_set_link" _,
+    EXTATTR_SET_LINK "extattr_set_link" _,
+    EXTATTR_GET_LINK "extattr_get_link" _,
+    EXTATTR_DELETE_LINK "extattr_delete_link" _,
+    MAC_EXECVE "mac_execve" _,
+    SIGACTION "sigaction" _,
+    SIGRETURN "sigreturn" _,
+    GETCONTEXT "getcontext" _,
+    SETCONTEXT "setcontext" _,
+    SWAPCONTEXT "swapcontext" _,
+    SWAPOFF "swapoff" _,
+    ACL_GET_LINK "acl_get_link" _,
+    ACL_SET_LINK "acl_set_link" _,
+    ACL_DELETE_LINK "acl_delete_link" _,
+    ACL_ACLCHECK_LINK "acl_aclcheck_link" _,
+    SIGWAIT "sigwait" _,
+    THR_CREATE "thr_create" _,
+    THR_EXIT "thr_exit" _,
+    THR_SELF "thr_self" _,
+    THR_KILL "thr_kill" _,
+    JAIL_ATTACH "jail_attach" _,
+    EXTATTR_LIST_FD "extattr_list_fd" _,
+    EXTATTR_LIST_FILE "extattr_list_file" _,
+    EXTATTR_LIST_LINK "extattr_list_link" _,
+    KSEM_TIMEDWAIT "ksem_timedwait" _,
+    THR_SUSPEND "thr_suspend" _,
+    THR_WAKE "thr_wake" _,
+    KLDUNLOADF "kldunloadf" _,
+    AUDIT "audit" _,
+    AUDITON "auditon" _,
+    GETAUID "getauid" _,
+    SETAUID "setauid" _,
+    GETAUDIT "getaudit" _,
+    SETAUDIT "setaudit" _,
+    GETAUDIT_ADDR "getaudit_addr" _,
+    SETAUDIT_ADDR "setaudit_addr" _,
+    AUDITCTL "auditctl" _,
+    _UMTX_OP "_umtx_op" _,
+    THR_NEW "thr_new" _,
+    SIGQUEUE "sigqueue" _,
+    KMQ_OPEN "kmq_open" _,
+    KMQ_SETATTR "kmq_setattr" _,
+    KMQ_TIMEDRECEIVE "kmq_timedreceive" _,
+    KMQ_TIMEDSEND "kmq_timedsend" _,
+    KMQ_NOTIFY "kmq_notify" _,
+    KMQ_UNLINK "kmq_unlink" _,
+    ABORT2 "abort2" _,
+    THR_SET_NAME "thr_set_name" _,
+    AIO_FSYNC "aio_fsync" _,
+    RTPRIO_THREAD "rtprio_thread" _,
+    SCTP_PEELOFF "sctp_peeloff" _,
+    SCTP_GENERIC_SENDMSG "sctp_generic_sendmsg" _,
+    SCTP_GENERIC_SENDMSG_IOV "sctp_generic_sendmsg_iov" _,
+    SCTP_GENERIC_RECVMSG "sctp_generic_recvmsg" _,
+    PREAD "pread" _,
+    PWRITE "pwrite" _,
+    MMAP "mmap" _,
+    LSEEK "lseek" _,
+    TRUNCATE "truncate" _,
+    FTRUNCATE "ftruncate" _,
+    THR_KILL2 "thr_kill2" _,
+    FREEBSD12_SHM_OPEN "freebsd12_shm_open" _,
+    SHM_UNLINK "shm_unlink" _,
+    CPUSET "cpuset" _,
+    CPUSET_SETID "cpuset_setid" _,
+    CPUSET_GETID "cpuset_getid" _,
+    CPUSET_GETAFFINITY "cpuset_getaffinity" _,
+    CPUSET_SETAFFINITY "cpuset_setaffinity" _,
+    FACCESSAT "faccessat" _,
+    FCHMODAT "fchmodat" _,
+    FCHOWNAT "fchownat" _,
+    FEXECVE "fexecve" _,
+    FREEBSD11_FSTATAT "freebsd11_fstatat" _,
+    FUTIMESAT "futimesat" _,
+    LINKAT "linkat" _,
+    MKDIRAT "mkdirat" _,
+    MKFIFOAT "mkfifoat" _,
+    FREEBSD11_MKNODAT "freebsd11_mknodat" _,
+    OPENAT "openat" _,
+    READLINKAT "readlinkat" _,
+    RENAMEAT "renameat" _,
+    SYMLINKAT "symlinkat" _,
+    UNLINKAT "unlinkat" _,
+    POSIX_OPENPT "posix_openpt" _,
+    GSSD_SYSCALL "gssd_syscall" _,
+    JAIL_GET "jail_get" _,
+    JAIL_SET "jail_set" _,
+    JAIL_REMOVE "jail_remove" _,
+    FREEBSD12_CLOSEFROM "freebsd12_closefrom" _,
+    SEMCTL "semctl" _,
+    MSGCTL "msgctl" _,
+    SHMCTL "shmctl" _,
+    LPATHCONF "lpathconf" _,
+    CAP_RIGHTS_GET "cap_rights_get" _,
+    CAP_ENTER "cap_enter" _,
+    CAP_GETMODE "cap_getmode" _,
+    PDFORK "pdfork" _,
+    PDKILL "pdkill" _,
+    PDGETPID "pdgetpid" _,
+    PSELECT "pselect" _,
+    GETLOGINCLASS "getloginclass" _,
+    SETLOGINCLASS "setloginclass" _,
+    RCTL_GET_RACCT "rctl_get_racct" _,
+    RCTL_GET_RULES "rctl_get_rules" _,
+    RCTL_GET_LIMITS "rctl_get_limits" _,
+    RCTL_ADD_RULE "rctl_add_rule" _,
+    RCTL_REMOVE_RULE "rctl_remove_rule" _,
+    POSIX_FALLOCATE "posix_fallocate" _,
+    POSIX_FADVISE "posix_fadvise" _,
+    WAIT6 "wait6" _,
+    CAP_RIGHTS_LIMIT "cap_rights_limit" _,
+    CAP_IOCTLS_LIMIT "cap_ioctls_limit" _,
+    CAP_IOCTLS_GET "cap_ioctls_get" _,
+    CAP_FCNTLS_LIMIT "cap_fcntls_limit" _,
+    CAP_FCNTLS_GET "cap_fcntls_get" _,
+    BINDAT "bindat" _,
+    CONNECTAT "connectat" _,
+    CHFLAGSAT "chflagsat" _,
+    ACCEPT4 "accept4" _,
+    PIPE2 "pipe2" _,
+    AIO_MLOCK "aio_mlock" _,
+    PROCCTL "procctl" _,
+    PPOLL "ppoll" _,
+    FUTIMENS "futimens" _,
+    UTIMENSAT "utimensat" _,
+    FDATASYNC "fdatasync" _,
+    FSTAT "fstat" _,
+    FSTATAT "fstatat" _,
+    FHSTAT "fhstat" _,
+    GETDIRENTRIES "getdirentries" _,
+    STATFS "statfs" _,
+    FSTATFS "fstatfs" _,
+    GETFSSTAT "getfsstat" _,
+    FHSTATFS "fhstatfs" _,
+    MKNODAT "mknodat" _,
+    KEVENT "kevent" _,
+    CPUSET_GETDOMAIN "cpuset_getdomain" _,
+    CPUSET_SETDOMAIN "cpuset_setdomain" _,
+    GETRANDOM "getrandom" _,
+    GETFHAT "getfhat" _,
+    FHLINK "fhlink" _,
+    FHLINKAT "fhlinkat" _,
+    FHREADLINK "fhreadlink" _,
+    FUNLINKAT "funlinkat" _,
+    COPY_FILE_RANGE "copy_file_range" _,
+    SYSCTLBYNAME "sysctlbyname" _,
+    SHM_OPEN2 "shm_open2" _,
+    SHM_RENAME "shm_rename" _,
+    SIGFASTBLOCK "sigfastblock" _,
+    REALPATHAT "realpathat" _,
+    CLOSE_RANGE "close_range" _,
+    RPCTLS_SYSCALL "rpctls_syscall" _,
+    SPECIALFD "specialfd" _,
+    AIO_WRITEV "aio_writev" _,
+    AIO_READV "aio_readv" _,
+}
@@ -9,11 +9,21 @@
 
 //! This library was built for aarch64 Linux.
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
+
+mod restore;
+pub(crate) use restore::*;
 
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -24,7 +34,8 @@ pub unsafe fn syscall0(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -35,7 +46,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -47,7 +59,8 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -60,7 +73,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -74,7 +94,15 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 }
 
 #[inline(always)]
-pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> usize {
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -90,7 +118,7 @@ pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5:
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -98,6 +126,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "svc 0",
@@ -112,6 +141,42 @@ pub unsafe fn syscall6(
     ret
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: usize;
+    asm!(
+        "svc 0",
+        "cbnz x0, 2f",
+        "mov x29, xzr",
+        "mov x30, xzr",
+        "mov x0, sp",
+        "ldr x1, [sp]",
+        "blr x1",
+        "mov x8, #{exit}",
+        "svc 0",
+        "brk #0",
+        "2:",
+        exit = const crate::nr::EXIT,
+        in("x8") n,
+        inout("x0") a1 => ret,
+        in("x1") a2,
+        in("x2") a3,
+        in("x3") a4,
+        in("x4") a5,
+    );
+    ret
+}
+
 pub use syscall0 as syscall0_nofail;
 pub use syscall1 as syscall1_nofail;
 pub use syscall2 as syscall2_nofail;
@@ -0,0 +1,19 @@
+// The signal return trampoline used as `sa_restorer` by `signal::sigaction()`.
+//
+// This is optional on AArch64 (without `SA_RESTORER`, the kernel uses the one in the vDSO), but
+// providing it means that signal delivery doesn't depend on the vDSO.
+global_asm!(
+    ".text",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, %function",
+    "_scall_internal_restore_rt:",
+    "mov x8, #{rt_sigreturn}",
+    "svc #0",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+extern "C" {
+    pub(crate) fn _scall_internal_restore_rt();
+}
@@ -0,0 +1,16 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, sp points to `argc` (and is already 16-byte aligned). The frame pointer and link
+// register are cleared to mark the outermost frame.
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, %function",
+    "_start:",
+    "mov x29, #0",
+    "mov x30, #0",
+    "mov x0, sp",
+    "bl _scall_internal_start",
+    "brk #0",
+    ".size _start, . - _start",
+);
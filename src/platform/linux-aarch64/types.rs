@@ -0,0 +1,155 @@
+//! Kernel ABI structures for aarch64 Linux.
+
+#![allow(non_camel_case_types)]
+
+use core::ffi::c_void;
+
+/// A time value with nanosecond precision (`struct timespec`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct timespec {
+    pub tv_sec: isize,
+    pub tv_nsec: isize,
+}
+
+/// A time value with microsecond precision (`struct timeval`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct timeval {
+    pub tv_sec: isize,
+    pub tv_usec: isize,
+}
+
+/// A buffer for scatter/gather I/O (`struct iovec`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct iovec {
+    pub iov_base: *mut c_void,
+    pub iov_len: usize,
+}
+
+/// A message for `sendmsg()`/`recvmsg()` (`struct user_msghdr` in the kernel).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct msghdr {
+    pub msg_name: *mut c_void,
+    pub msg_namelen: i32,
+    pub msg_iov: *mut iovec,
+    pub msg_iovlen: usize,
+    pub msg_control: *mut c_void,
+    pub msg_controllen: usize,
+    pub msg_flags: u32,
+}
+
+/// The header of a control message (`struct cmsghdr`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct cmsghdr {
+    pub cmsg_len: usize,
+    pub cmsg_level: i32,
+    pub cmsg_type: i32,
+}
+
+/// A file descriptor to wait on with `poll()`/`ppoll()` (`struct pollfd`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct pollfd {
+    pub fd: i32,
+    pub events: i16,
+    pub revents: i16,
+}
+
+/// File status information, as returned by `fstat()` and `newfstatat()`.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct stat {
+    pub st_dev: usize,
+    pub st_ino: usize,
+    pub st_mode: u32,
+    pub st_nlink: u32,
+    pub st_uid: u32,
+    pub st_gid: u32,
+    pub st_rdev: usize,
+    pub __pad1: usize,
+    pub st_size: isize,
+    pub st_blksize: i32,
+    pub __pad2: i32,
+    pub st_blocks: isize,
+    pub st_atime: isize,
+    pub st_atime_nsec: usize,
+    pub st_mtime: isize,
+    pub st_mtime_nsec: usize,
+    pub st_ctime: isize,
+    pub st_ctime_nsec: usize,
+    pub __unused4: u32,
+    pub __unused5: u32,
+}
+
+/// The kernel's signal set (`sigset_t`), which holds 64 signals.
+///
+/// This is the type whose size should be passed as the `sigsetsize` argument of the `rt_sig*()`
+/// syscalls. It is *not* the same size as the C library's `sigset_t`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct sigset_t {
+    pub sig: [usize; 1],
+}
+
+/// The structure passed to `rt_sigaction()` (`struct sigaction` in the kernel).
+///
+/// `sa_handler` can be `SIG_DFL` (0), `SIG_IGN` (1), or the address of a handler function.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct sigaction {
+    pub sa_handler: usize,
+    pub sa_flags: usize,
+    pub sa_restorer: usize,
+    pub sa_mask: sigset_t,
+}
+
+/// An event for `epoll_ctl()`/`epoll_wait()` (`struct epoll_event`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct epoll_event {
+    pub events: u32,
+    pub data: u64,
+}
+
+/// The arguments to `clone3()` (`struct clone_args`).
+///
+/// Pointers are passed as `u64`s, regardless of the architecture's pointer size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct clone_args {
+    pub flags: u64,
+    pub pidfd: u64,
+    pub child_tid: u64,
+    pub parent_tid: u64,
+    pub exit_signal: u64,
+    pub stack: u64,
+    pub stack_size: u64,
+    pub tls: u64,
+    pub set_tid: u64,
+    pub set_tid_size: u64,
+    pub cgroup: u64,
+}
+
+/// An alternate signal stack for `sigaltstack()` (`stack_t`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct stack_t {
+    pub ss_sp: *mut c_void,
+    pub ss_flags: i32,
+    pub ss_size: usize,
+}
+
+/// The general-purpose registers of a stopped tracee, as read with `PTRACE_GETREGSET` and
+/// `NT_PRSTATUS` (`struct user_pt_regs` in the kernel).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct user_regs_struct {
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
@@ -0,0 +1,199 @@
+//! Flag constants for arm Linux.
+
+/* automatically generated by consts_from_src.py */
+
+pub const AT_EACCESS: i32 = 0x200;
+pub const AT_EMPTY_PATH: i32 = 0x1000;
+pub const AT_FDCWD: i32 = -100;
+pub const AT_NO_AUTOMOUNT: i32 = 0x800;
+pub const AT_RECURSIVE: i32 = 0x8000;
+pub const AT_REMOVEDIR: i32 = 0x200;
+pub const AT_STATX_DONT_SYNC: i32 = 0x4000;
+pub const AT_STATX_FORCE_SYNC: i32 = 0x2000;
+pub const AT_STATX_SYNC_AS_STAT: i32 = 0;
+pub const AT_STATX_SYNC_TYPE: i32 = 0x6000;
+pub const AT_SYMLINK_FOLLOW: i32 = 0x400;
+pub const AT_SYMLINK_NOFOLLOW: i32 = 0x100;
+pub const CLOCK_BOOTTIME: i32 = 7;
+pub const CLOCK_BOOTTIME_ALARM: i32 = 9;
+pub const CLOCK_MONOTONIC: i32 = 1;
+pub const CLOCK_MONOTONIC_COARSE: i32 = 6;
+pub const CLOCK_MONOTONIC_RAW: i32 = 4;
+pub const CLOCK_PROCESS_CPUTIME_ID: i32 = 2;
+pub const CLOCK_REALTIME: i32 = 0;
+pub const CLOCK_REALTIME_ALARM: i32 = 8;
+pub const CLOCK_REALTIME_COARSE: i32 = 5;
+pub const CLOCK_TAI: i32 = 11;
+pub const CLOCK_THREAD_CPUTIME_ID: i32 = 3;
+pub const CLONE_CHILD_CLEARTID: u64 = 0x200000;
+pub const CLONE_CHILD_SETTID: u64 = 0x1000000;
+pub const CLONE_CLEAR_SIGHAND: u64 = 0x100000000;
+pub const CLONE_DETACHED: u64 = 0x400000;
+pub const CLONE_FILES: u64 = 0x400;
+pub const CLONE_FS: u64 = 0x200;
+pub const CLONE_INTO_CGROUP: u64 = 0x200000000;
+pub const CLONE_IO: u64 = 0x80000000;
+pub const CLONE_NEWCGROUP: u64 = 0x2000000;
+pub const CLONE_NEWIPC: u64 = 0x8000000;
+pub const CLONE_NEWNET: u64 = 0x40000000;
+pub const CLONE_NEWNS: u64 = 0x20000;
+pub const CLONE_NEWPID: u64 = 0x20000000;
+pub const CLONE_NEWTIME: u64 = 0x80;
+pub const CLONE_NEWUSER: u64 = 0x10000000;
+pub const CLONE_NEWUTS: u64 = 0x4000000;
+pub const CLONE_PARENT: u64 = 0x8000;
+pub const CLONE_PARENT_SETTID: u64 = 0x100000;
+pub const CLONE_PIDFD: u64 = 0x1000;
+pub const CLONE_PTRACE: u64 = 0x2000;
+pub const CLONE_SETTLS: u64 = 0x80000;
+pub const CLONE_SIGHAND: u64 = 0x800;
+pub const CLONE_SYSVSEM: u64 = 0x40000;
+pub const CLONE_THREAD: u64 = 0x10000;
+pub const CLONE_UNTRACED: u64 = 0x800000;
+pub const CLONE_VFORK: u64 = 0x4000;
+pub const CLONE_VM: u64 = 0x100;
+pub const CSIGNAL: u64 = 0xff;
+pub const FD_CLOEXEC: i32 = 1;
+pub const F_ADD_SEALS: i32 = 0x409;
+pub const F_CANCELLK: i32 = 0x405;
+pub const F_DUPFD: i32 = 0;
+pub const F_DUPFD_CLOEXEC: i32 = 0x406;
+pub const F_EXLCK: i32 = 4;
+pub const F_GETFD: i32 = 1;
+pub const F_GETFL: i32 = 3;
+pub const F_GETLEASE: i32 = 0x401;
+pub const F_GETLK: i32 = 5;
+pub const F_GETLK64: i32 = 12;
+pub const F_GETOWN: i32 = 9;
+pub const F_GETOWNER_UIDS: i32 = 0x11;
+pub const F_GETOWN_EX: i32 = 0x10;
+pub const F_GETPIPE_SZ: i32 = 0x408;
+pub const F_GETSIG: i32 = 11;
+pub const F_GET_FILE_RW_HINT: i32 = 0x40d;
+pub const F_GET_RW_HINT: i32 = 0x40b;
+pub const F_GET_SEALS: i32 = 0x40a;
+pub const F_NOTIFY: i32 = 0x402;
+pub const F_OFD_GETLK: i32 = 0x24;
+pub const F_OFD_SETLK: i32 = 0x25;
+pub const F_OFD_SETLKW: i32 = 0x26;
+pub const F_OWNER_PGRP: i32 = 2;
+pub const F_OWNER_PID: i32 = 1;
+pub const F_OWNER_TID: i32 = 0;
+pub const F_RDLCK: i32 = 0;
+pub const F_SEAL_FUTURE_WRITE: i32 = 0x10;
+pub const F_SEAL_GROW: i32 = 4;
+pub const F_SEAL_SEAL: i32 = 1;
+pub const F_SEAL_SHRINK: i32 = 2;
+pub const F_SEAL_WRITE: i32 = 8;
+pub const F_SETFD: i32 = 2;
+pub const F_SETFL: i32 = 4;
+pub const F_SETLEASE: i32 = 0x400;
+pub const F_SETLK: i32 = 6;
+pub const F_SETLK64: i32 = 13;
+pub const F_SETLKW: i32 = 7;
+pub const F_SETLKW64: i32 = 14;
+pub const F_SETOWN: i32 = 8;
+pub const F_SETOWN_EX: i32 = 15;
+pub const F_SETPIPE_SZ: i32 = 0x407;
+pub const F_SETSIG: i32 = 10;
+pub const F_SET_FILE_RW_HINT: i32 = 0x40e;
+pub const F_SET_RW_HINT: i32 = 0x40c;
+pub const F_SHLCK: i32 = 8;
+pub const F_UNLCK: i32 = 2;
+pub const F_WRLCK: i32 = 1;
+pub const MADV_COLD: i32 = 0x14;
+pub const MADV_DODUMP: i32 = 0x11;
+pub const MADV_DOFORK: i32 = 11;
+pub const MADV_DONTDUMP: i32 = 0x10;
+pub const MADV_DONTFORK: i32 = 10;
+pub const MADV_DONTNEED: i32 = 4;
+pub const MADV_FREE: i32 = 8;
+pub const MADV_HUGEPAGE: i32 = 14;
+pub const MADV_HWPOISON: i32 = 0x64;
+pub const MADV_KEEPONFORK: i32 = 0x13;
+pub const MADV_MERGEABLE: i32 = 12;
+pub const MADV_NOHUGEPAGE: i32 = 15;
+pub const MADV_NORMAL: i32 = 0;
+pub const MADV_PAGEOUT: i32 = 0x15;
+pub const MADV_RANDOM: i32 = 1;
+pub const MADV_REMOVE: i32 = 9;
+pub const MADV_SEQUENTIAL: i32 = 2;
+pub const MADV_SOFT_OFFLINE: i32 = 0x65;
+pub const MADV_UNMERGEABLE: i32 = 13;
+pub const MADV_WILLNEED: i32 = 3;
+pub const MADV_WIPEONFORK: i32 = 0x12;
+pub const MAP_ANONYMOUS: i32 = 0x20;
+pub const MAP_DENYWRITE: i32 = 0x800;
+pub const MAP_EXECUTABLE: i32 = 0x1000;
+pub const MAP_FILE: i32 = 0;
+pub const MAP_FIXED: i32 = 0x10;
+pub const MAP_FIXED_NOREPLACE: i32 = 0x100000;
+pub const MAP_GROWSDOWN: i32 = 0x100;
+pub const MAP_HUGETLB: i32 = 0x40000;
+pub const MAP_HUGE_MASK: i32 = 0x3f;
+pub const MAP_HUGE_SHIFT: i32 = 0x1a;
+pub const MAP_LOCKED: i32 = 0x2000;
+pub const MAP_NONBLOCK: i32 = 0x10000;
+pub const MAP_NORESERVE: i32 = 0x4000;
+pub const MAP_POPULATE: i32 = 0x8000;
+pub const MAP_PRIVATE: i32 = 2;
+pub const MAP_SHARED: i32 = 1;
+pub const MAP_SHARED_VALIDATE: i32 = 3;
+pub const MAP_STACK: i32 = 0x20000;
+pub const MAP_SYNC: i32 = 0x80000;
+pub const MAP_TYPE: i32 = 15;
+pub const MAP_UNINITIALIZED: i32 = 0x4000000;
+pub const MCL_CURRENT: i32 = 1;
+pub const MCL_FUTURE: i32 = 2;
+pub const MCL_ONFAULT: i32 = 4;
+pub const MLOCK_ONFAULT: i32 = 1;
+pub const MREMAP_DONTUNMAP: i32 = 4;
+pub const MREMAP_FIXED: i32 = 2;
+pub const MREMAP_MAYMOVE: i32 = 1;
+pub const MS_ASYNC: i32 = 1;
+pub const MS_INVALIDATE: i32 = 2;
+pub const MS_SYNC: i32 = 4;
+pub const O_ACCMODE: i32 = 3;
+pub const O_APPEND: i32 = 0x400;
+pub const O_CLOEXEC: i32 = 0x80000;
+pub const O_CREAT: i32 = 0x40;
+pub const O_DIRECT: i32 = 0x10000;
+pub const O_DIRECTORY: i32 = 0x4000;
+pub const O_DSYNC: i32 = 0x1000;
+pub const O_EXCL: i32 = 0x80;
+pub const O_LARGEFILE: i32 = 0x20000;
+pub const O_NDELAY: i32 = 0x800;
+pub const O_NOATIME: i32 = 0x40000;
+pub const O_NOCTTY: i32 = 0x100;
+pub const O_NOFOLLOW: i32 = 0x8000;
+pub const O_NONBLOCK: i32 = 0x800;
+pub const O_PATH: i32 = 0x200000;
+pub const O_RDONLY: i32 = 0;
+pub const O_RDWR: i32 = 2;
+pub const O_SYNC: i32 = 0x101000;
+pub const O_TMPFILE: i32 = 0x404000;
+pub const O_TRUNC: i32 = 0x200;
+pub const O_WRONLY: i32 = 1;
+pub const PROT_EXEC: i32 = 4;
+pub const PROT_GROWSDOWN: i32 = 0x1000000;
+pub const PROT_GROWSUP: i32 = 0x2000000;
+pub const PROT_NONE: i32 = 0;
+pub const PROT_READ: i32 = 1;
+pub const PROT_SEM: i32 = 8;
+pub const PROT_WRITE: i32 = 2;
+pub const P_ALL: i32 = 0;
+pub const P_PGID: i32 = 2;
+pub const P_PID: i32 = 1;
+pub const P_PIDFD: i32 = 3;
+pub const SEEK_CUR: i32 = 1;
+pub const SEEK_DATA: i32 = 3;
+pub const SEEK_END: i32 = 2;
+pub const SEEK_HOLE: i32 = 4;
+pub const SEEK_SET: i32 = 0;
+pub const TIMER_ABSTIME: i32 = 1;
+pub const WCONTINUED: i32 = 8;
+pub const WEXITED: i32 = 4;
+pub const WNOHANG: i32 = 1;
+pub const WNOWAIT: i32 = 0x1000000;
+pub const WSTOPPED: i32 = 2;
+pub const WUNTRACED: i32 = 2;
@@ -9,8 +9,17 @@
 
 //! This library was built for arm Linux.
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
+
+mod restore;
+pub(crate) use restore::*;
 
 #[cfg(target_os = "android")]
 mod thumb;
@@ -1,5 +1,6 @@
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -11,7 +12,8 @@ pub unsafe fn syscall0(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -23,7 +25,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -36,7 +39,8 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -50,7 +54,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -65,7 +76,15 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 }
 
 #[inline(always)]
-pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> usize {
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -82,7 +101,7 @@ pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5:
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -90,6 +109,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "swi #0",
@@ -105,9 +125,47 @@ pub unsafe fn syscall6(
     ret
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: usize;
+    asm!(
+        "swi #0",
+        "cmp r0, #0",
+        "bne 2f",
+        "mov r11, #0",
+        "mov lr, #0",
+        "mov r0, sp",
+        "ldr r1, [sp]",
+        "blx r1",
+        "mov r7, #{exit}",
+        "swi #0",
+        "udf #0",
+        "2:",
+        exit = const crate::nr::EXIT,
+        in("r7") n,
+        inout("r0") a1 => ret,
+        in("r1") a2,
+        in("r2") a3,
+        in("r3") a4,
+        in("r4") a5,
+        out("lr") _,
+    );
+    ret
+}
+
 #[inline(always)]
 pub unsafe fn syscall7(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -116,6 +174,7 @@ pub unsafe fn syscall7(
     a6: usize,
     a7: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "push {{r6}}",
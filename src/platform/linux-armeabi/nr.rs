@@ -1,6 +1,6 @@
 //! System call numbers for arm Linux.
 
-/* automatically generated by nr_from_src.py */
+/* automatically generated by nr_from_src.py from Linux 5.17 */
 
 pub const ARM_BREAKPOINT: usize = 983041;
 pub const ARM_CACHEFLUSH: usize = 983042;
@@ -98,6 +98,7 @@ pub const FTRUNCATE: usize = 93;
 pub const FTRUNCATE64: usize = 194;
 pub const FUTEX: usize = 240;
 pub const FUTEX_TIME64: usize = 422;
+pub const FUTEX_WAITV: usize = 449;
 pub const FUTIMESAT: usize = 326;
 pub const GET_MEMPOLICY: usize = 320;
 pub const GET_ROBUST_LIST: usize = 339;
@@ -243,6 +244,7 @@ pub const PREADV: usize = 361;
 pub const PREADV2: usize = 392;
 pub const PRLIMIT64: usize = 369;
 pub const PROCESS_MADVISE: usize = 440;
+pub const PROCESS_MRELEASE: usize = 448;
 pub const PROCESS_VM_READV: usize = 376;
 pub const PROCESS_VM_WRITEV: usize = 377;
 pub const PSELECT6: usize = 335;
@@ -308,6 +310,7 @@ pub const SENDMMSG: usize = 374;
 pub const SENDMSG: usize = 296;
 pub const SENDTO: usize = 290;
 pub const SET_MEMPOLICY: usize = 321;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 450;
 pub const SET_ROBUST_LIST: usize = 338;
 pub const SET_TID_ADDRESS: usize = 256;
 pub const SETDOMAINNAME: usize = 121;
@@ -409,3 +412,416 @@ pub const WAIT4: usize = 114;
 pub const WAITID: usize = 280;
 pub const WRITE: usize = 4;
 pub const WRITEV: usize = 146;
+
+sysno_table! {
+    _LLSEEK "_llseek" 5 "unsigned int fd, unsigned long offset_high, unsigned long offset_low, loff_t __user *result, unsigned int whence",
+    _NEWSELECT "_newselect" 5 "int n, fd_set __user *inp, fd_set __user *outp, fd_set __user *exp, struct __kernel_old_timeval __user *tvp",
+    _SYSCTL "_sysctl" 1 "struct __sysctl_args __user *args",
+    ACCEPT "accept" 3 "int fd, struct sockaddr __user *upeer_sockaddr, int __user *upeer_addrlen",
+    ACCEPT4 "accept4" 4 "int fd, struct sockaddr __user *upeer_sockaddr, int __user *upeer_addrlen, int flags",
+    ACCESS "access" 2 "const char __user *filename, int mode",
+    ACCT "acct" 1 "const char __user *name",
+    ADD_KEY "add_key" 5 "const char __user *_type, const char __user *_description, const void __user *_payload, size_t plen, key_serial_t ringid",
+    ADJTIMEX "adjtimex" 1 "struct __kernel_timex __user *txc_p",
+    ARM_BREAKPOINT "arm_breakpoint" _,
+    ARM_CACHEFLUSH "arm_cacheflush" _,
+    ARM_FADVISE64_64 "arm_fadvise64_64" _,
+    ARM_GET_TLS "arm_get_tls" _,
+    ARM_SET_TLS "arm_set_tls" _,
+    ARM_SYNC_FILE_RANGE "arm_sync_file_range" _,
+    ARM_USR26 "arm_usr26" _,
+    ARM_USR32 "arm_usr32" _,
+    BDFLUSH "bdflush" 2 "int func, long data",
+    BIND "bind" 3 "int fd, struct sockaddr __user *umyaddr, int addrlen",
+    BPF "bpf" 3 "int cmd, union bpf_attr __user *uattr, unsigned int size",
+    BRK "brk" 1 "unsigned long brk",
+    CAPGET "capget" 2 "cap_user_header_t header, cap_user_data_t dataptr",
+    CAPSET "capset" 2 "cap_user_header_t header, const cap_user_data_t data",
+    CHDIR "chdir" 1 "const char __user *filename",
+    CHMOD "chmod" 2 "const char __user *filename, umode_t mode",
+    CHOWN "chown" 3 "const char __user *filename, uid_t user, gid_t group",
+    CHOWN32 "chown32" 3 "const char __user *filename, uid_t user, gid_t group",
+    CHROOT "chroot" 1 "const char __user *filename",
+    CLOCK_ADJTIME "clock_adjtime" 2 "const clockid_t which_clock, struct __kernel_timex __user *utx",
+    CLOCK_ADJTIME64 "clock_adjtime64" _,
+    CLOCK_GETRES "clock_getres" 2 "const clockid_t which_clock, struct __kernel_timespec __user *tp",
+    CLOCK_GETRES_TIME64 "clock_getres_time64" _,
+    CLOCK_GETTIME "clock_gettime" 2 "const clockid_t which_clock, struct __kernel_timespec __user *tp",
+    CLOCK_GETTIME64 "clock_gettime64" _,
+    CLOCK_NANOSLEEP "clock_nanosleep" 4 "const clockid_t which_clock, int flags, const struct __kernel_timespec __user *rqtp, struct __kernel_timespec __user *rmtp",
+    CLOCK_NANOSLEEP_TIME64 "clock_nanosleep_time64" _,
+    CLOCK_SETTIME "clock_settime" 2 "const clockid_t which_clock, const struct __kernel_timespec __user *tp",
+    CLOCK_SETTIME64 "clock_settime64" _,
+    CLONE "clone" 5 "unsigned long clone_flags, unsigned long newsp, int __user *parent_tidptr, unsigned long tls, int __user *child_tidptr",
+    CLONE3 "clone3" 2 "struct clone_args __user *uargs, size_t size",
+    CLOSE "close" 1 "unsigned int fd",
+    CLOSE_RANGE "close_range" 3 "unsigned int fd, unsigned int max_fd, unsigned int flags",
+    CONNECT "connect" 3 "int fd, struct sockaddr __user *uservaddr, int addrlen",
+    COPY_FILE_RANGE "copy_file_range" 6 "int fd_in, loff_t __user *off_in, int fd_out, loff_t __user *off_out, size_t len, unsigned int flags",
+    CREAT "creat" 2 "const char __user *pathname, umode_t mode",
+    DELETE_MODULE "delete_module" 2 "const char __user *name_user, unsigned int flags",
+    DUP "dup" 1 "unsigned int fildes",
+    DUP2 "dup2" 2 "unsigned int oldfd, unsigned int newfd",
+    DUP3 "dup3" 3 "unsigned int oldfd, unsigned int newfd, int flags",
+    EPOLL_CREATE "epoll_create" 1 "int size",
+    EPOLL_CREATE1 "epoll_create1" 1 "int flags",
+    EPOLL_CTL "epoll_ctl" 4 "int epfd, int op, int fd, struct epoll_event __user *event",
+    EPOLL_PWAIT "epoll_pwait" 6 "int epfd, struct epoll_event __user *events, int maxevents, int timeout, const sigset_t __user *sigmask, size_t sigsetsize",
+    EPOLL_PWAIT2 "epoll_pwait2" 6 "int epfd, struct epoll_event __user *events, int maxevents, const struct __kernel_timespec __user *timeout, const sigset_t __user *sigmask, size_t sigsetsize",
+    EPOLL_WAIT "epoll_wait" 4 "int epfd, struct epoll_event __user *events, int maxevents, int timeout",
+    EVENTFD "eventfd" 1 "unsigned int count",
+    EVENTFD2 "eventfd2" 2 "unsigned int count, int flags",
+    EXECVE "execve" 3 "const char __user *filename, const char __user *const __user *argv, const char __user *const __user *envp",
+    EXECVEAT "execveat" 5 "int fd, const char __user *filename, const char __user *const __user *argv, const char __user *const __user *envp, int flags",
+    EXIT "exit" 1 "int error_code",
+    EXIT_GROUP "exit_group" 1 "int error_code",
+    FACCESSAT "faccessat" 3 "int dfd, const char __user *filename, int mode",
+    FACCESSAT2 "faccessat2" 4 "int dfd, const char __user *filename, int mode, int flags",
+    FALLOCATE "fallocate" _ "int fd, int mode, loff_t offset, loff_t len",
+    FANOTIFY_INIT "fanotify_init" 2 "unsigned int flags, unsigned int event_f_flags",
+    FANOTIFY_MARK "fanotify_mark" _ "int fanotify_fd, unsigned int flags, __u64 mask, int dfd, const char __user *pathname",
+    FCHDIR "fchdir" 1 "unsigned int fd",
+    FCHMOD "fchmod" 2 "unsigned int fd, umode_t mode",
+    FCHMODAT "fchmodat" 3 "int dfd, const char __user *filename, umode_t mode",
+    FCHOWN "fchown" 3 "unsigned int fd, uid_t user, gid_t group",
+    FCHOWN32 "fchown32" 3 "unsigned int fd, uid_t user, gid_t group",
+    FCHOWNAT "fchownat" 5 "int dfd, const char __user *filename, uid_t user, gid_t group, int flag",
+    FCNTL "fcntl" 3 "unsigned int fd, unsigned int cmd, unsigned long arg",
+    FCNTL64 "fcntl64" 3 "unsigned int fd, unsigned int cmd, unsigned long arg",
+    FDATASYNC "fdatasync" 1 "unsigned int fd",
+    FGETXATTR "fgetxattr" 4 "int fd, const char __user *name, void __user *value, size_t size",
+    FINIT_MODULE "finit_module" 3 "int fd, const char __user *uargs, int flags",
+    FLISTXATTR "flistxattr" 3 "int fd, char __user *list, size_t size",
+    FLOCK "flock" 2 "unsigned int fd, unsigned int cmd",
+    FORK "fork" 0 "",
+    FREMOVEXATTR "fremovexattr" 2 "int fd, const char __user *name",
+    FSCONFIG "fsconfig" 5 "int fd, unsigned int cmd, const char __user *_key, const void __user *_value, int aux",
+    FSETXATTR "fsetxattr" 5 "int fd, const char __user *name, const void __user *value, size_t size, int flags",
+    FSMOUNT "fsmount" 3 "int fs_fd, unsigned int flags, unsigned int attr_flags",
+    FSOPEN "fsopen" 2 "const char __user *_fs_name, unsigned int flags",
+    FSPICK "fspick" 3 "int dfd, const char __user *path, unsigned int flags",
+    FSTAT "fstat" 2 "unsigned int fd, struct stat __user *statbuf",
+    FSTAT64 "fstat64" 2 "unsigned long fd, struct stat64 __user *statbuf",
+    FSTATAT64 "fstatat64" 4 "int dfd, const char __user *filename, struct stat64 __user *statbuf, int flag",
+    FSTATFS "fstatfs" 2 "unsigned int fd, struct statfs __user *buf",
+    FSTATFS64 "fstatfs64" _,
+    FSYNC "fsync" 1 "unsigned int fd",
+    FTRUNCATE "ftruncate" 2 "unsigned int fd, unsigned long length",
+    FTRUNCATE64 "ftruncate64" _ "unsigned int fd, loff_t length",
+    FUTEX "futex" 6 "u32 __user *uaddr, int op, u32 val, const struct __kernel_timespec __user *utime, u32 __user *uaddr2, u32 val3",
+    FUTEX_TIME64 "futex_time64" _,
+    FUTEX_WAITV "futex_waitv" 5 "struct futex_waitv __user *waiters, unsigned int nr_futexes, unsigned int flags, struct __kernel_timespec __user *timeout, clockid_t clockid",
+    FUTIMESAT "futimesat" 3 "int dfd, const char __user *filename, struct __kernel_old_timeval __user *utimes",
+    GET_MEMPOLICY "get_mempolicy" 5 "int __user *policy, unsigned long __user *nmask, unsigned long maxnode, unsigned long addr, unsigned long flags",
+    GET_ROBUST_LIST "get_robust_list" 3 "int pid, struct robust_list_head __user * __user *head_ptr, size_t __user *len_ptr",
+    GETCPU "getcpu" 3 "unsigned __user *cpup, unsigned __user *nodep, struct getcpu_cache __user *unused",
+    GETCWD "getcwd" 2 "char __user *buf, unsigned long size",
+    GETDENTS "getdents" 3 "unsigned int fd, struct linux_dirent __user *dirent, unsigned int count",
+    GETDENTS64 "getdents64" 3 "unsigned int fd, struct linux_dirent64 __user *dirent, unsigned int count",
+    GETEGID "getegid" 0 "",
+    GETEGID32 "getegid32" 0 "",
+    GETEUID "geteuid" 0 "",
+    GETEUID32 "geteuid32" 0 "",
+    GETGID "getgid" 0 "",
+    GETGID32 "getgid32" 0 "",
+    GETGROUPS "getgroups" 2 "int gidsetsize, gid_t __user *grouplist",
+    GETGROUPS32 "getgroups32" 2 "int gidsetsize, gid_t __user *grouplist",
+    GETITIMER "getitimer" 2 "int which, struct __kernel_old_itimerval __user *value",
+    GETPEERNAME "getpeername" 3 "int fd, struct sockaddr __user *usockaddr, int __user *usockaddr_len",
+    GETPGID "getpgid" 1 "pid_t pid",
+    GETPGRP "getpgrp" 0 "",
+    GETPID "getpid" 0 "",
+    GETPPID "getppid" 0 "",
+    GETPRIORITY "getpriority" 2 "int which, int who",
+    GETRANDOM "getrandom" 3 "char __user *ubuf, size_t len, unsigned int flags",
+    GETRESGID "getresgid" 3 "gid_t __user *rgidp, gid_t __user *egidp, gid_t __user *sgidp",
+    GETRESGID32 "getresgid32" 3 "gid_t __user *rgidp, gid_t __user *egidp, gid_t __user *sgidp",
+    GETRESUID "getresuid" 3 "uid_t __user *ruidp, uid_t __user *euidp, uid_t __user *suidp",
+    GETRESUID32 "getresuid32" 3 "uid_t __user *ruidp, uid_t __user *euidp, uid_t __user *suidp",
+    GETRUSAGE "getrusage" 2 "int who, struct rusage __user *ru",
+    GETSID "getsid" 1 "pid_t pid",
+    GETSOCKNAME "getsockname" 3 "int fd, struct sockaddr __user *usockaddr, int __user *usockaddr_len",
+    GETSOCKOPT "getsockopt" 5 "int fd, int level, int optname, char __user *optval, int __user *optlen",
+    GETTID "gettid" 0 "",
+    GETTIMEOFDAY "gettimeofday" 2 "struct __kernel_old_timeval __user *tv, struct timezone __user *tz",
+    GETUID "getuid" 0 "",
+    GETUID32 "getuid32" 0 "",
+    GETXATTR "getxattr" 4 "const char __user *pathname, const char __user *name, void __user *value, size_t size",
+    INIT_MODULE "init_module" 3 "void __user *umod, unsigned long len, const char __user *uargs",
+    INOTIFY_ADD_WATCH "inotify_add_watch" 3 "int fd, const char __user *pathname, u32 mask",
+    INOTIFY_INIT "inotify_init" 0 "",
+    INOTIFY_INIT1 "inotify_init1" 1 "int flags",
+    INOTIFY_RM_WATCH "inotify_rm_watch" 2 "int fd, __s32 wd",
+    IO_CANCEL "io_cancel" 3 "aio_context_t ctx_id, struct iocb __user *iocb, struct io_event __user *result",
+    IO_DESTROY "io_destroy" 1 "aio_context_t ctx",
+    IO_GETEVENTS "io_getevents" 5 "aio_context_t ctx_id, long min_nr, long nr, struct io_event __user *events, struct __kernel_timespec __user *timeout",
+    IO_PGETEVENTS "io_pgetevents" 6 "aio_context_t ctx_id, long min_nr, long nr, struct io_event __user *events, struct __kernel_timespec __user *timeout, const struct __aio_sigset __user *usig",
+    IO_PGETEVENTS_TIME64 "io_pgetevents_time64" _,
+    IO_SETUP "io_setup" 2 "unsigned nr_events, aio_context_t __user *ctxp",
+    IO_SUBMIT "io_submit" 3 "aio_context_t ctx_id, long nr, struct iocb __user * __user *iocbpp",
+    IO_URING_ENTER "io_uring_enter" 6 "unsigned int fd, u32 to_submit, u32 min_complete, u32 flags, const void __user *argp, size_t argsz",
+    IO_URING_REGISTER "io_uring_register" 4 "unsigned int fd, unsigned int opcode, void __user *arg, unsigned int nr_args",
+    IO_URING_SETUP "io_uring_setup" 2 "u32 entries, struct io_uring_params __user *params",
+    IOCTL "ioctl" 3 "unsigned int fd, unsigned int cmd, unsigned long arg",
+    IOPRIO_GET "ioprio_get" 2 "int which, int who",
+    IOPRIO_SET "ioprio_set" 3 "int which, int who, int ioprio",
+    KCMP "kcmp" 5 "pid_t pid1, pid_t pid2, int type, unsigned long idx1, unsigned long idx2",
+    KEXEC_FILE_LOAD "kexec_file_load" 5 "int kernel_fd, int initrd_fd, unsigned long cmdline_len, const char __user *cmdline_ptr, unsigned long flags",
+    KEXEC_LOAD "kexec_load" 4 "unsigned long entry, unsigned long nr_segments, struct kexec_segment __user *segments, unsigned long flags",
+    KEYCTL "keyctl" 5 "int option, unsigned long arg2, unsigned long arg3, unsigned long arg4, unsigned long arg5",
+    KILL "kill" 2 "pid_t pid, int sig",
+    LANDLOCK_ADD_RULE "landlock_add_rule" 4 "const int ruleset_fd, const enum landlock_rule_type rule_type, const void __user *const rule_attr, const __u32 flags",
+    LANDLOCK_CREATE_RULESET "landlock_create_ruleset" 3 "const struct landlock_ruleset_attr __user *const attr, const size_t size, const __u32 flags",
+    LANDLOCK_RESTRICT_SELF "landlock_restrict_self" 2 "const int ruleset_fd, const __u32 flags",
+    LCHOWN "lchown" 3 "const char __user *filename, uid_t user, gid_t group",
+    LCHOWN32 "lchown32" 3 "const char __user *filename, uid_t user, gid_t group",
+    LGETXATTR "lgetxattr" 4 "const char __user *pathname, const char __user *name, void __user *value, size_t size",
+    LINK "link" 2 "const char __user *oldname, const char __user *newname",
+    LINKAT "linkat" 5 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname, int flags",
+    LISTEN "listen" 2 "int fd, int backlog",
+    LISTXATTR "listxattr" 3 "const char __user *pathname, char __user *list, size_t size",
+    LLISTXATTR "llistxattr" 3 "const char __user *pathname, char __user *list, size_t size",
+    LOOKUP_DCOOKIE "lookup_dcookie" _ "u64 cookie64, char __user *buf, size_t len",
+    LREMOVEXATTR "lremovexattr" 2 "const char __user *pathname, const char __user *name",
+    LSEEK "lseek" 3 "unsigned int fd, off_t offset, unsigned int whence",
+    LSETXATTR "lsetxattr" 5 "const char __user *pathname, const char __user *name, const void __user *value, size_t size, int flags",
+    LSTAT "lstat" 2 "const char __user *filename, struct stat __user *statbuf",
+    LSTAT64 "lstat64" 2 "const char __user *filename, struct stat64 __user *statbuf",
+    MADVISE "madvise" 3 "unsigned long start, size_t len_in, int behavior",
+    MBIND "mbind" 6 "unsigned long start, unsigned long len, unsigned long mode, const unsigned long __user *nmask, unsigned long maxnode, unsigned int flags",
+    MEMBARRIER "membarrier" 3 "int cmd, unsigned int flags, int cpu_id",
+    MEMFD_CREATE "memfd_create" 2 "const char __user *uname, unsigned int flags",
+    MIGRATE_PAGES "migrate_pages" 4 "pid_t pid, unsigned long maxnode, const unsigned long __user *old_nodes, const unsigned long __user *new_nodes",
+    MINCORE "mincore" 3 "unsigned long start, size_t len, unsigned char __user *vec",
+    MKDIR "mkdir" 2 "const char __user *pathname, umode_t mode",
+    MKDIRAT "mkdirat" 3 "int dfd, const char __user *pathname, umode_t mode",
+    MKNOD "mknod" 3 "const char __user *filename, umode_t mode, unsigned dev",
+    MKNODAT "mknodat" 4 "int dfd, const char __user *filename, umode_t mode, unsigned int dev",
+    MLOCK "mlock" 2 "unsigned long start, size_t len",
+    MLOCK2 "mlock2" 3 "unsigned long start, size_t len, int flags",
+    MLOCKALL "mlockall" 1 "int flags",
+    MMAP2 "mmap2" _,
+    MOUNT "mount" 5 "char __user *dev_name, char __user *dir_name, char __user *type, unsigned long flags, void __user *data",
+    MOUNT_SETATTR "mount_setattr" 5 "int dfd, const char __user *path, unsigned int flags, struct mount_attr __user *uattr, size_t usize",
+    MOVE_MOUNT "move_mount" 5 "int from_dfd, const char __user *from_pathname, int to_dfd, const char __user *to_pathname, unsigned int flags",
+    MOVE_PAGES "move_pages" 6 "pid_t pid, unsigned long nr_pages, const void __user * __user *pages, const int __user *nodes, int __user *status, int flags",
+    MPROTECT "mprotect" 3 "unsigned long start, size_t len, unsigned long prot",
+    MQ_GETSETATTR "mq_getsetattr" 3 "mqd_t mqdes, const struct mq_attr __user *u_mqstat, struct mq_attr __user *u_omqstat",
+    MQ_NOTIFY "mq_notify" 2 "mqd_t mqdes, const struct sigevent __user *u_notification",
+    MQ_OPEN "mq_open" 4 "const char __user *u_name, int oflag, umode_t mode, struct mq_attr __user *u_attr",
+    MQ_TIMEDRECEIVE "mq_timedreceive" 5 "mqd_t mqdes, char __user *u_msg_ptr, size_t msg_len, unsigned int __user *u_msg_prio, const struct __kernel_timespec __user *u_abs_timeout",
+    MQ_TIMEDRECEIVE_TIME64 "mq_timedreceive_time64" _,
+    MQ_TIMEDSEND "mq_timedsend" 5 "mqd_t mqdes, const char __user *u_msg_ptr, size_t msg_len, unsigned int msg_prio, const struct __kernel_timespec __user *u_abs_timeout",
+    MQ_TIMEDSEND_TIME64 "mq_timedsend_time64" _,
+    MQ_UNLINK "mq_unlink" 1 "const char __user *u_name",
+    MREMAP "mremap" 5 "unsigned long addr, unsigned long old_len, unsigned long new_len, unsigned long flags, unsigned long new_addr",
+    MSGCTL "msgctl" 3 "int msqid, int cmd, struct msqid_ds __user *buf",
+    MSGGET "msgget" 2 "key_t key, int msgflg",
+    MSGRCV "msgrcv" 5 "int msqid, struct msgbuf __user *msgp, size_t msgsz, long msgtyp, int msgflg",
+    MSGSND "msgsnd" 4 "int msqid, struct msgbuf __user *msgp, size_t msgsz, int msgflg",
+    MSYNC "msync" 3 "unsigned long start, size_t len, int flags",
+    MUNLOCK "munlock" 2 "unsigned long start, size_t len",
+    MUNLOCKALL "munlockall" 0 "",
+    MUNMAP "munmap" 2 "unsigned long addr, size_t len",
+    NAME_TO_HANDLE_AT "name_to_handle_at" 5 "int dfd, const char __user *name, struct file_handle __user *handle, int __user *mnt_id, int flag",
+    NANOSLEEP "nanosleep" 2 "struct __kernel_timespec __user *rqtp, struct __kernel_timespec __user *rmtp",
+    NFSSERVCTL "nfsservctl" _,
+    NICE "nice" 1 "int increment",
+    OPEN "open" 3 "const char __user *filename, int flags, umode_t mode",
+    OPEN_BY_HANDLE_AT "open_by_handle_at" 3 "int mountdirfd, struct file_handle __user *handle, int flags",
+    OPEN_TREE "open_tree" 3 "int dfd, const char __user *filename, unsigned flags",
+    OPENAT "openat" 4 "int dfd, const char __user *filename, int flags, umode_t mode",
+    OPENAT2 "openat2" 4 "int dfd, const char __user *filename, struct open_how __user *how, size_t usize",
+    PAUSE "pause" 0 "",
+    PCICONFIG_IOBASE "pciconfig_iobase" _,
+    PCICONFIG_READ "pciconfig_read" _,
+    PCICONFIG_WRITE "pciconfig_write" _,
+    PERF_EVENT_OPEN "perf_event_open" 5 "struct perf_event_attr __user *attr_uptr, pid_t pid, int cpu, int group_fd, unsigned long flags",
+    PERSONALITY "personality" 1 "unsigned int personality",
+    PIDFD_GETFD "pidfd_getfd" 3 "int pidfd, int fd, unsigned int flags",
+    PIDFD_OPEN "pidfd_open" 2 "pid_t pid, unsigned int flags",
+    PIDFD_SEND_SIGNAL "pidfd_send_signal" 4 "int pidfd, int sig, siginfo_t __user *info, unsigned int flags",
+    PIPE "pipe" 1 "int __user *fildes",
+    PIPE2 "pipe2" 2 "int __user *fildes, int flags",
+    PIVOT_ROOT "pivot_root" 2 "const char __user *new_root, const char __user *put_old",
+    PKEY_ALLOC "pkey_alloc" 2 "unsigned long flags, unsigned long init_val",
+    PKEY_FREE "pkey_free" 1 "int pkey",
+    PKEY_MPROTECT "pkey_mprotect" 4 "unsigned long start, size_t len, unsigned long prot, int pkey",
+    POLL "poll" 3 "struct pollfd __user *ufds, unsigned int nfds, int timeout_msecs",
+    PPOLL "ppoll" 5 "struct pollfd __user *ufds, unsigned int nfds, struct __kernel_timespec __user *tsp, const sigset_t __user *sigmask, size_t sigsetsize",
+    PPOLL_TIME64 "ppoll_time64" _,
+    PRCTL "prctl" 5 "int option, unsigned long arg2, unsigned long arg3, unsigned long arg4, unsigned long arg5",
+    PREAD64 "pread64" _ "unsigned int fd, char __user *buf, size_t count, loff_t pos",
+    PREADV "preadv" 5 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen, unsigned long pos_l, unsigned long pos_h",
+    PREADV2 "preadv2" 6 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen, unsigned long pos_l, unsigned long pos_h, rwf_t flags",
+    PRLIMIT64 "prlimit64" 4 "pid_t pid, unsigned int resource, const struct rlimit64 __user *new_rlim, struct rlimit64 __user *old_rlim",
+    PROCESS_MADVISE "process_madvise" 5 "int pidfd, const struct iovec __user *vec, size_t vlen, int behavior, unsigned int flags",
+    PROCESS_MRELEASE "process_mrelease" 2 "int pidfd, unsigned int flags",
+    PROCESS_VM_READV "process_vm_readv" 6 "pid_t pid, const struct iovec __user *lvec, unsigned long liovcnt, const struct iovec __user *rvec, unsigned long riovcnt, unsigned long flags",
+    PROCESS_VM_WRITEV "process_vm_writev" 6 "pid_t pid, const struct iovec __user *lvec, unsigned long liovcnt, const struct iovec __user *rvec, unsigned long riovcnt, unsigned long flags",
+    PSELECT6 "pselect6" 6 "int n, fd_set __user *inp, fd_set __user *outp, fd_set __user *exp, struct __kernel_timespec __user *tsp, void __user *sig",
+    PSELECT6_TIME64 "pselect6_time64" _,
+    PTRACE "ptrace" 4 "long request, long pid, unsigned long addr, unsigned long data",
+    PWRITE64 "pwrite64" _ "unsigned int fd, const char __user *buf, size_t count, loff_t pos",
+    PWRITEV "pwritev" 5 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen, unsigned long pos_l, unsigned long pos_h",
+    PWRITEV2 "pwritev2" 6 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen, unsigned long pos_l, unsigned long pos_h, rwf_t flags",
+    QUOTACTL "quotactl" 4 "unsigned int cmd, const char __user *special, qid_t id, void __user *addr",
+    QUOTACTL_FD "quotactl_fd" 4 "unsigned int fd, unsigned int cmd, qid_t id, void __user *addr",
+    READ "read" 3 "unsigned int fd, char __user *buf, size_t count",
+    READAHEAD "readahead" _ "int fd, loff_t offset, size_t count",
+    READLINK "readlink" 3 "const char __user *path, char __user *buf, int bufsiz",
+    READLINKAT "readlinkat" 4 "int dfd, const char __user *pathname, char __user *buf, int bufsiz",
+    READV "readv" 3 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen",
+    REBOOT "reboot" 4 "int magic1, int magic2, unsigned int cmd, void __user *arg",
+    RECV "recv" 4 "int fd, void __user *ubuf, size_t size, unsigned int flags",
+    RECVFROM "recvfrom" 6 "int fd, void __user *ubuf, size_t size, unsigned int flags, struct sockaddr __user *addr, int __user *addr_len",
+    RECVMMSG "recvmmsg" 5 "int fd, struct mmsghdr __user *mmsg, unsigned int vlen, unsigned int flags, struct __kernel_timespec __user *timeout",
+    RECVMMSG_TIME64 "recvmmsg_time64" _,
+    RECVMSG "recvmsg" 3 "int fd, struct user_msghdr __user *msg, unsigned int flags",
+    REMAP_FILE_PAGES "remap_file_pages" 5 "unsigned long start, unsigned long size, unsigned long prot, unsigned long pgoff, unsigned long flags",
+    REMOVEXATTR "removexattr" 2 "const char __user *pathname, const char __user *name",
+    RENAME "rename" 2 "const char __user *oldname, const char __user *newname",
+    RENAMEAT "renameat" 4 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname",
+    RENAMEAT2 "renameat2" 5 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname, unsigned int flags",
+    REQUEST_KEY "request_key" 4 "const char __user *_type, const char __user *_description, const char __user *_callout_info, key_serial_t destringid",
+    RESTART_SYSCALL "restart_syscall" 0 "",
+    RMDIR "rmdir" 1 "const char __user *pathname",
+    RSEQ "rseq" 4 "struct rseq __user *rseq, u32 rseq_len, int flags, u32 sig",
+    RT_SIGACTION "rt_sigaction" 4 "int sig, const struct sigaction __user *act, struct sigaction __user *oact, size_t sigsetsize",
+    RT_SIGPENDING "rt_sigpending" 2 "sigset_t __user *uset, size_t sigsetsize",
+    RT_SIGPROCMASK "rt_sigprocmask" 4 "int how, sigset_t __user *nset, sigset_t __user *oset, size_t sigsetsize",
+    RT_SIGQUEUEINFO "rt_sigqueueinfo" 3 "pid_t pid, int sig, siginfo_t __user *uinfo",
+    RT_SIGRETURN "rt_sigreturn" _,
+    RT_SIGSUSPEND "rt_sigsuspend" 2 "sigset_t __user *unewset, size_t sigsetsize",
+    RT_SIGTIMEDWAIT "rt_sigtimedwait" 4 "const sigset_t __user *uthese, siginfo_t __user *uinfo, const struct __kernel_timespec __user *uts, size_t sigsetsize",
+    RT_SIGTIMEDWAIT_TIME64 "rt_sigtimedwait_time64" _,
+    RT_TGSIGQUEUEINFO "rt_tgsigqueueinfo" 4 "pid_t tgid, pid_t pid, int sig, siginfo_t __user *uinfo",
+    SCHED_GET_PRIORITY_MAX "sched_get_priority_max" 1 "int policy",
+    SCHED_GET_PRIORITY_MIN "sched_get_priority_min" 1 "int policy",
+    SCHED_GETAFFINITY "sched_getaffinity" 3 "pid_t pid, unsigned int len, unsigned long __user *user_mask_ptr",
+    SCHED_GETATTR "sched_getattr" 4 "pid_t pid, struct sched_attr __user *uattr, unsigned int usize, unsigned int flags",
+    SCHED_GETPARAM "sched_getparam" 2 "pid_t pid, struct sched_param __user *param",
+    SCHED_GETSCHEDULER "sched_getscheduler" 1 "pid_t pid",
+    SCHED_RR_GET_INTERVAL "sched_rr_get_interval" 2 "pid_t pid, struct __kernel_timespec __user *interval",
+    SCHED_RR_GET_INTERVAL_TIME64 "sched_rr_get_interval_time64" _,
+    SCHED_SETAFFINITY "sched_setaffinity" 3 "pid_t pid, unsigned int len, unsigned long __user *user_mask_ptr",
+    SCHED_SETATTR "sched_setattr" 3 "pid_t pid, struct sched_attr __user *uattr, unsigned int flags",
+    SCHED_SETPARAM "sched_setparam" 2 "pid_t pid, struct sched_param __user *param",
+    SCHED_SETSCHEDULER "sched_setscheduler" 3 "pid_t pid, int policy, struct sched_param __user *param",
+    SCHED_YIELD "sched_yield" 0 "",
+    SECCOMP "seccomp" 3 "unsigned int op, unsigned int flags, void __user *uargs",
+    SEMCTL "semctl" 4 "int semid, int semnum, int cmd, unsigned long arg",
+    SEMGET "semget" 3 "key_t key, int nsems, int semflg",
+    SEMOP "semop" 3 "int semid, struct sembuf __user *tsops, unsigned nsops",
+    SEMTIMEDOP "semtimedop" 4 "int semid, struct sembuf __user *tsops, unsigned int nsops, const struct __kernel_timespec __user *timeout",
+    SEMTIMEDOP_TIME64 "semtimedop_time64" _,
+    SEND "send" 4 "int fd, void __user *buff, size_t len, unsigned int flags",
+    SENDFILE "sendfile" 4 "int out_fd, int in_fd, off_t __user *offset, size_t count",
+    SENDFILE64 "sendfile64" 4 "int out_fd, int in_fd, loff_t __user *offset, size_t count",
+    SENDMMSG "sendmmsg" 4 "int fd, struct mmsghdr __user *mmsg, unsigned int vlen, unsigned int flags",
+    SENDMSG "sendmsg" 3 "int fd, struct user_msghdr __user *msg, unsigned int flags",
+    SENDTO "sendto" 6 "int fd, void __user *buff, size_t len, unsigned int flags, struct sockaddr __user *addr, int addr_len",
+    SET_MEMPOLICY "set_mempolicy" 3 "int mode, const unsigned long __user *nmask, unsigned long maxnode",
+    SET_MEMPOLICY_HOME_NODE "set_mempolicy_home_node" 4 "unsigned long start, unsigned long len, unsigned long home_node, unsigned long flags",
+    SET_ROBUST_LIST "set_robust_list" 2 "struct robust_list_head __user *head, size_t len",
+    SET_TID_ADDRESS "set_tid_address" 1 "int __user *tidptr",
+    SETDOMAINNAME "setdomainname" 2 "char __user *name, int len",
+    SETFSGID "setfsgid" 1 "gid_t gid",
+    SETFSGID32 "setfsgid32" 1 "gid_t gid",
+    SETFSUID "setfsuid" 1 "uid_t uid",
+    SETFSUID32 "setfsuid32" 1 "uid_t uid",
+    SETGID "setgid" 1 "gid_t gid",
+    SETGID32 "setgid32" 1 "gid_t gid",
+    SETGROUPS "setgroups" 2 "int gidsetsize, gid_t __user *grouplist",
+    SETGROUPS32 "setgroups32" 2 "int gidsetsize, gid_t __user *grouplist",
+    SETHOSTNAME "sethostname" 2 "char __user *name, int len",
+    SETITIMER "setitimer" 3 "int which, struct __kernel_old_itimerval __user *value, struct __kernel_old_itimerval __user *ovalue",
+    SETNS "setns" 2 "int fd, int flags",
+    SETPGID "setpgid" 2 "pid_t pid, pid_t pgid",
+    SETPRIORITY "setpriority" 3 "int which, int who, int niceval",
+    SETREGID "setregid" 2 "gid_t rgid, gid_t egid",
+    SETREGID32 "setregid32" 2 "gid_t rgid, gid_t egid",
+    SETRESGID "setresgid" 3 "gid_t rgid, gid_t egid, gid_t sgid",
+    SETRESGID32 "setresgid32" 3 "gid_t rgid, gid_t egid, gid_t sgid",
+    SETRESUID "setresuid" 3 "uid_t ruid, uid_t euid, uid_t suid",
+    SETRESUID32 "setresuid32" 3 "uid_t ruid, uid_t euid, uid_t suid",
+    SETREUID "setreuid" 2 "uid_t ruid, uid_t euid",
+    SETREUID32 "setreuid32" 2 "uid_t ruid, uid_t euid",
+    SETRLIMIT "setrlimit" 2 "unsigned int resource, struct rlimit __user *rlim",
+    SETSID "setsid" 0 "",
+    SETSOCKOPT "setsockopt" 5 "int fd, int level, int optname, char __user *optval, int optlen",
+    SETTIMEOFDAY "settimeofday" 2 "struct __kernel_old_timeval __user *tv, struct timezone __user *tz",
+    SETUID "setuid" 1 "uid_t uid",
+    SETUID32 "setuid32" 1 "uid_t uid",
+    SETXATTR "setxattr" 5 "const char __user *pathname, const char __user *name, const void __user *value, size_t size, int flags",
+    SHMAT "shmat" 3 "int shmid, char __user *shmaddr, int shmflg",
+    SHMCTL "shmctl" 3 "int shmid, int cmd, struct shmid_ds __user *buf",
+    SHMDT "shmdt" 1 "char __user *shmaddr",
+    SHMGET "shmget" 3 "key_t key, size_t size, int shmflg",
+    SHUTDOWN "shutdown" 2 "int fd, int how",
+    SIGACTION "sigaction" 3 "int sig, const struct old_sigaction __user *act, struct old_sigaction __user *oact",
+    SIGALTSTACK "sigaltstack" 2 "const stack_t __user *uss, stack_t __user *uoss",
+    SIGNALFD "signalfd" 3 "int ufd, sigset_t __user *user_mask, size_t sizemask",
+    SIGNALFD4 "signalfd4" 4 "int ufd, sigset_t __user *user_mask, size_t sizemask, int flags",
+    SIGPENDING "sigpending" 1 "old_sigset_t __user *uset",
+    SIGPROCMASK "sigprocmask" 3 "int how, old_sigset_t __user *nset, old_sigset_t __user *oset",
+    SIGRETURN "sigreturn" _,
+    SIGSUSPEND "sigsuspend" _,
+    SOCKET "socket" 3 "int family, int type, int protocol",
+    SOCKETPAIR "socketpair" 4 "int family, int type, int protocol, int __user *usockvec",
+    SPLICE "splice" 6 "int fd_in, loff_t __user *off_in, int fd_out, loff_t __user *off_out, size_t len, unsigned int flags",
+    STAT "stat" 2 "const char __user *filename, struct stat __user *statbuf",
+    STAT64 "stat64" 2 "const char __user *filename, struct stat64 __user *statbuf",
+    STATFS "statfs" 2 "const char __user *pathname, struct statfs __user *buf",
+    STATFS64 "statfs64" _,
+    STATX "statx" 5 "int dfd, const char __user *filename, unsigned flags, unsigned int mask, struct statx __user *buffer",
+    SWAPOFF "swapoff" 1 "const char __user *specialfile",
+    SWAPON "swapon" 2 "const char __user *specialfile, int swap_flags",
+    SYMLINK "symlink" 2 "const char __user *oldname, const char __user *newname",
+    SYMLINKAT "symlinkat" 3 "const char __user *oldname, int newdfd, const char __user *newname",
+    SYNC "sync" 0 "",
+    SYNCFS "syncfs" 1 "int fd",
+    SYSFS "sysfs" 3 "int option, unsigned long arg1, unsigned long arg2",
+    SYSINFO "sysinfo" 1 "struct sysinfo __user *info",
+    SYSLOG "syslog" 3 "int type, char __user *buf, int len",
+    TEE "tee" 4 "int fdin, int fdout, size_t len, unsigned int flags",
+    TGKILL "tgkill" 3 "pid_t tgid, pid_t pid, int sig",
+    TIMER_CREATE "timer_create" 3 "const clockid_t which_clock, struct sigevent __user *timer_event_spec, timer_t __user *created_timer_id",
+    TIMER_DELETE "timer_delete" 1 "timer_t timer_id",
+    TIMER_GETOVERRUN "timer_getoverrun" 1 "timer_t timer_id",
+    TIMER_GETTIME "timer_gettime" 2 "timer_t timer_id, struct __kernel_itimerspec __user *setting",
+    TIMER_GETTIME64 "timer_gettime64" _,
+    TIMER_SETTIME "timer_settime" 4 "timer_t timer_id, int flags, const struct __kernel_itimerspec __user *new_setting, struct __kernel_itimerspec __user *old_setting",
+    TIMER_SETTIME64 "timer_settime64" _,
+    TIMERFD_CREATE "timerfd_create" 2 "int clockid, int flags",
+    TIMERFD_GETTIME "timerfd_gettime" 2 "int ufd, struct __kernel_itimerspec __user *otmr",
+    TIMERFD_GETTIME64 "timerfd_gettime64" _,
+    TIMERFD_SETTIME "timerfd_settime" 4 "int ufd, int flags, const struct __kernel_itimerspec __user *utmr, struct __kernel_itimerspec __user *otmr",
+    TIMERFD_SETTIME64 "timerfd_settime64" _,
+    TIMES "times" 1 "struct tms __user *tbuf",
+    TKILL "tkill" 2 "pid_t pid, int sig",
+    TRUNCATE "truncate" 2 "const char __user *path, long length",
+    TRUNCATE64 "truncate64" _ "const char __user *pathname, loff_t length",
+    UGETRLIMIT "ugetrlimit" 2 "unsigned int resource, struct rlimit __user *rlim",
+    UMASK "umask" 1 "int mask",
+    UMOUNT2 "umount2" 2 "char __user *name, int flags",
+    UNAME "uname" 1 "struct new_utsname __user *name",
+    UNLINK "unlink" 1 "const char __user *pathname",
+    UNLINKAT "unlinkat" 3 "int dfd, const char __user *pathname, int flag",
+    UNSHARE "unshare" 1 "unsigned long unshare_flags",
+    USELIB "uselib" 1 "const char __user *library",
+    USERFAULTFD "userfaultfd" 1 "int flags",
+    USTAT "ustat" 2 "unsigned dev, struct ustat __user *ubuf",
+    UTIMENSAT "utimensat" 4 "int dfd, const char __user *filename, struct __kernel_timespec __user *utimes, int flags",
+    UTIMENSAT_TIME64 "utimensat_time64" _,
+    UTIMES "utimes" 2 "char __user *filename, struct __kernel_old_timeval __user *utimes",
+    VFORK "vfork" 0 "",
+    VHANGUP "vhangup" 0 "",
+    VMSPLICE "vmsplice" 4 "int fd, const struct iovec __user *uiov, unsigned long nr_segs, unsigned int flags",
+    VSERVER "vserver" _,
+    WAIT4 "wait4" 4 "pid_t upid, int __user *stat_addr, int options, struct rusage __user *ru",
+    WAITID "waitid" 5 "int which, pid_t upid, struct siginfo __user *infop, int options, struct rusage __user *ru",
+    WRITE "write" 3 "unsigned int fd, const char __user *buf, size_t count",
+    WRITEV "writev" 3 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen",
+    @alias SYNC_FILE_RANGE2 = ARM_SYNC_FILE_RANGE,
+}
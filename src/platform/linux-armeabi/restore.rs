@@ -0,0 +1,61 @@
+// The signal return trampolines used as `sa_restorer` by `signal::sigaction()`.
+//
+// Handlers installed without `SA_SIGINFO` get an old-style signal frame (unwound by
+// `sigreturn()`); handlers installed with `SA_SIGINFO` get an `rt_sigreturn()` frame. The handler
+// returns to `sa_restorer` with `bx lr`, so the trampolines can be in either instruction set; like
+// the syscall wrappers, Android builds use Thumb code.
+
+#[cfg(target_os = "android")]
+global_asm!(
+    ".text",
+    ".syntax unified",
+    ".thumb",
+    ".globl _scall_internal_restore",
+    ".hidden _scall_internal_restore",
+    ".type _scall_internal_restore, %function",
+    ".thumb_func",
+    "_scall_internal_restore:",
+    "movs r7, #{sigreturn}",
+    "svc #0",
+    ".size _scall_internal_restore, . - _scall_internal_restore",
+    "",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, %function",
+    ".thumb_func",
+    "_scall_internal_restore_rt:",
+    "movs r7, #{rt_sigreturn}",
+    "svc #0",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    sigreturn = const crate::nr::SIGRETURN,
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+#[cfg(not(target_os = "android"))]
+global_asm!(
+    ".text",
+    ".syntax unified",
+    ".arm",
+    ".globl _scall_internal_restore",
+    ".hidden _scall_internal_restore",
+    ".type _scall_internal_restore, %function",
+    "_scall_internal_restore:",
+    "mov r7, #{sigreturn}",
+    "svc #0",
+    ".size _scall_internal_restore, . - _scall_internal_restore",
+    "",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, %function",
+    "_scall_internal_restore_rt:",
+    "mov r7, #{rt_sigreturn}",
+    "svc #0",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    sigreturn = const crate::nr::SIGRETURN,
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+extern "C" {
+    pub(crate) fn _scall_internal_restore();
+    pub(crate) fn _scall_internal_restore_rt();
+}
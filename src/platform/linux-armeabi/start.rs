@@ -0,0 +1,47 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, sp points to `argc`. The stack is realigned to 8 bytes (as the AAPCS requires; we use
+// 16) through a scratch register, since Thumb code cannot use sp as the operand of `bic`. Both
+// possible frame pointers (r7 in Thumb code and r11 in ARM code) are cleared, along with lr, to
+// mark the outermost frame.
+//
+// Like the syscall wrappers, this assumes that Android builds use Thumb code (see `thumb.rs`).
+
+#[cfg(target_os = "android")]
+global_asm!(
+    ".text",
+    ".syntax unified",
+    ".thumb",
+    ".weak _start",
+    ".type _start, %function",
+    ".thumb_func",
+    "_start:",
+    "movs r7, #0",
+    "mov r11, r7",
+    "mov lr, r7",
+    "mov r0, sp",
+    "mov r1, sp",
+    "bic r1, r1, #15",
+    "mov sp, r1",
+    "bl _scall_internal_start",
+    "udf #0",
+    ".size _start, . - _start",
+);
+
+#[cfg(not(target_os = "android"))]
+global_asm!(
+    ".text",
+    ".syntax unified",
+    ".arm",
+    ".weak _start",
+    ".type _start, %function",
+    "_start:",
+    "mov r7, #0",
+    "mov r11, #0",
+    "mov lr, #0",
+    "mov r0, sp",
+    "bic sp, sp, #15",
+    "bl _scall_internal_start",
+    "udf #0",
+    ".size _start, . - _start",
+);
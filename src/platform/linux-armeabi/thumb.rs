@@ -1,5 +1,6 @@
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -15,7 +16,8 @@ pub unsafe fn syscall0(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -31,7 +33,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -48,7 +51,8 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -66,7 +70,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -85,7 +96,15 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 }
 
 #[inline(always)]
-pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> usize {
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -106,7 +125,7 @@ pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5:
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -114,6 +133,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "mov {r7_tmp}, r7",
@@ -133,9 +153,52 @@ pub unsafe fn syscall6(
     ret
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: usize;
+    asm!(
+        "mov {r7_tmp}, r7",
+        "mov r7, {n}",
+        "swi #0",
+        "cmp r0, #0",
+        "bne 2f",
+        "movs r7, #0",
+        "mov r11, r7",
+        "mov lr, r7",
+        "mov r0, sp",
+        "ldr r1, [sp]",
+        "blx r1",
+        "movs r7, #{exit}",
+        "swi #0",
+        "udf #0",
+        "2:",
+        "mov r7, {r7_tmp}",
+        exit = const crate::nr::EXIT,
+        r7_tmp = out(reg) _,
+        n = in(reg) n,
+        inout("r0") a1 => ret,
+        in("r1") a2,
+        in("r2") a3,
+        in("r3") a4,
+        in("r4") a5,
+        out("lr") _,
+    );
+    ret
+}
+
 #[inline(always)]
 pub unsafe fn syscall7(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -144,6 +207,7 @@ pub unsafe fn syscall7(
     a6: usize,
     a7: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "push {{r6}}",
@@ -15,11 +15,18 @@
 // Role of registers
 // See: https://www.linux-mips.org/wiki/Syscall
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
 
 #[inline(always)]
-pub unsafe fn syscall0(nr: usize) -> (usize, bool) {
+pub unsafe fn syscall0(nr: impl crate::SyscallNumber) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -41,7 +48,8 @@ pub unsafe fn syscall0(nr: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(nr: usize, a1: usize) -> (usize, bool) {
+pub unsafe fn syscall1(nr: impl crate::SyscallNumber, a1: usize) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -64,7 +72,8 @@ pub unsafe fn syscall1(nr: usize, a1: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(nr: usize, a1: usize, a2: usize) -> (usize, bool) {
+pub unsafe fn syscall2(nr: impl crate::SyscallNumber, a1: usize, a2: usize) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -88,7 +97,13 @@ pub unsafe fn syscall2(nr: usize, a1: usize, a2: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(nr: usize, a1: usize, a2: usize, a3: usize) -> (usize, bool) {
+pub unsafe fn syscall3(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -113,7 +128,14 @@ pub unsafe fn syscall3(nr: usize, a1: usize, a2: usize, a3: usize) -> (usize, bo
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> (usize, bool) {
+pub unsafe fn syscall4(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -139,13 +161,14 @@ pub unsafe fn syscall4(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -177,7 +200,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -185,6 +208,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -216,9 +240,70 @@ pub unsafe fn syscall6(
     (ret, is_err != 0)
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+//
+// If the syscall fails, this returns the error number (with the error flag set), like syscall5().
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    nr: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> (usize, bool) {
+    let ret: usize;
+    let is_err: usize;
+    asm!(
+        ".set push",
+        ".set noat",
+        ".set noreorder",
+        "subu $29, 20",
+        "sw {}, 16($29)",
+        "syscall",
+        "bnez $7, 2f",
+        "nop",
+        "bnez $2, 2f",
+        "nop",
+        // In the child: the stack pointer is now the new stack
+        "move $fp, $0",
+        "move $4, $29",
+        "lw $25, 0($29)",
+        "jalr $25",
+        "subu $29, 16",
+        "move $4, $2",
+        "li $2, {exit}",
+        "syscall",
+        "break",
+        "2:",
+        "addiu $29, 20",
+        ".set pop",
+        in(reg) a5,
+        exit = const crate::nr::EXIT,
+        inout("$2") nr => ret,
+        in("$4") a1,
+        in("$5") a2,
+        in("$6") a3,
+        inout("$7") a4 => is_err,
+        out("$8") _,
+        out("$9") _,
+        out("$10") _,
+        out("$11") _,
+        out("$12") _,
+        out("$13") _,
+        out("$14") _,
+        out("$15") _,
+        out("$24") _,
+        out("$25") _,
+    );
+    (ret, is_err != 0)
+}
+
 #[inline(always)]
 pub unsafe fn syscall7(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -227,6 +312,7 @@ pub unsafe fn syscall7(
     a6: usize,
     a7: usize,
 ) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -261,7 +347,8 @@ pub unsafe fn syscall7(
 }
 
 #[inline(always)]
-pub unsafe fn syscall0_nofail(nr: usize) -> usize {
+pub unsafe fn syscall0_nofail(nr: impl crate::SyscallNumber) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -282,7 +369,8 @@ pub unsafe fn syscall0_nofail(nr: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(nr: usize, a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(nr: impl crate::SyscallNumber, a1: usize) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -304,7 +392,8 @@ pub unsafe fn syscall1_nofail(nr: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(nr: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(nr: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -327,7 +416,13 @@ pub unsafe fn syscall2_nofail(nr: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(nr: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -351,7 +446,14 @@ pub unsafe fn syscall3_nofail(nr: usize, a1: usize, a2: usize, a3: usize) -> usi
 }
 
 #[inline(always)]
-pub unsafe fn syscall4_nofail(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4_nofail(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -376,13 +478,14 @@ pub unsafe fn syscall4_nofail(nr: usize, a1: usize, a2: usize, a3: usize, a4: us
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         ".set noat",
@@ -413,7 +516,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -421,6 +524,7 @@ pub unsafe fn syscall6_nofail(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         ".set noat",
@@ -453,7 +557,7 @@ pub unsafe fn syscall6_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall7_nofail(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -462,6 +566,7 @@ pub unsafe fn syscall7_nofail(
     a6: usize,
     a7: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         ".set noat",
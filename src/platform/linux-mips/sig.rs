@@ -0,0 +1,57 @@
+//! Signal numbers and flags for MIPS Linux.
+
+/* automatically generated by sig_from_src.py */
+
+pub const MINSIGSTKSZ: usize = 2048;
+pub const SA_EXPOSE_TAGBITS: u32 = 0x800;
+pub const SA_NOCLDSTOP: u32 = 1;
+pub const SA_NOCLDWAIT: u32 = 0x10000;
+pub const SA_NODEFER: u32 = 0x40000000;
+pub const SA_ONSTACK: u32 = 0x8000000;
+pub const SA_RESETHAND: u32 = 0x80000000;
+pub const SA_RESTART: u32 = 0x10000000;
+pub const SA_SIGINFO: u32 = 8;
+pub const SA_UNSUPPORTED: u32 = 0x400;
+pub const SIGABRT: i32 = 6;
+pub const SIGALRM: i32 = 14;
+pub const SIGBUS: i32 = 10;
+pub const SIGCHLD: i32 = 18;
+pub const SIGCLD: i32 = 18;
+pub const SIGCONT: i32 = 25;
+pub const SIGEMT: i32 = 7;
+pub const SIGFPE: i32 = 8;
+pub const SIGHUP: i32 = 1;
+pub const SIGILL: i32 = 4;
+pub const SIGINT: i32 = 2;
+pub const SIGIO: i32 = 22;
+pub const SIGIOT: i32 = 6;
+pub const SIGKILL: i32 = 9;
+pub const SIGPIPE: i32 = 13;
+pub const SIGPOLL: i32 = 22;
+pub const SIGPROF: i32 = 29;
+pub const SIGPWR: i32 = 19;
+pub const SIGQUIT: i32 = 3;
+pub const SIGRTMAX: i32 = 128;
+pub const SIGRTMIN: i32 = 32;
+pub const SIGSEGV: i32 = 11;
+pub const SIGSTKSZ: usize = 8192;
+pub const SIGSTOP: i32 = 23;
+pub const SIGSYS: i32 = 12;
+pub const SIGTERM: i32 = 15;
+pub const SIGTRAP: i32 = 5;
+pub const SIGTSTP: i32 = 24;
+pub const SIGTTIN: i32 = 26;
+pub const SIGTTOU: i32 = 27;
+pub const SIGURG: i32 = 21;
+pub const SIGUSR1: i32 = 16;
+pub const SIGUSR2: i32 = 17;
+pub const SIGVTALRM: i32 = 28;
+pub const SIGWINCH: i32 = 20;
+pub const SIGXCPU: i32 = 30;
+pub const SIGXFSZ: i32 = 31;
+pub const SIG_BLOCK: i32 = 1;
+pub const SIG_SETMASK: i32 = 3;
+pub const SIG_UNBLOCK: i32 = 2;
+pub const SS_AUTODISARM: u32 = 0x80000000;
+pub const SS_DISABLE: u32 = 2;
+pub const SS_ONSTACK: u32 = 1;
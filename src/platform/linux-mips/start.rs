@@ -0,0 +1,32 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, $sp points to `argc`. Since the code may be position-independent, the address of the
+// Rust entry point is computed from two `.gpword`s (gp-relative offsets) placed right after the
+// `bal`, which also yields the value of $gp. The o32 ABI requires 16 bytes of argument space
+// below the stack pointer for the callee.
+global_asm!(
+    ".text",
+    ".set push",
+    ".set noreorder",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    "bal 1f",
+    "move $fp, $0",
+    "2:",
+    ".gpword 2b",
+    ".gpword _scall_internal_start",
+    "1:",
+    "lw $gp, 0($ra)",
+    "subu $gp, $ra, $gp",
+    "lw $25, 4($ra)",
+    "addu $25, $25, $gp",
+    "move $4, $sp",
+    "and $sp, $sp, -16",
+    "subu $sp, $sp, 16",
+    "jalr $25",
+    "nop",
+    "break",
+    ".size _start, . - _start",
+    ".set pop",
+);
@@ -11,11 +11,18 @@
 
 // For more information see src/platform/linux-mips/mod.rs
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
 
 #[inline(always)]
-pub unsafe fn syscall0(nr: usize) -> (usize, bool) {
+pub unsafe fn syscall0(nr: impl crate::SyscallNumber) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -37,7 +44,8 @@ pub unsafe fn syscall0(nr: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(nr: usize, a1: usize) -> (usize, bool) {
+pub unsafe fn syscall1(nr: impl crate::SyscallNumber, a1: usize) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -60,7 +68,8 @@ pub unsafe fn syscall1(nr: usize, a1: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(nr: usize, a1: usize, a2: usize) -> (usize, bool) {
+pub unsafe fn syscall2(nr: impl crate::SyscallNumber, a1: usize, a2: usize) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -84,7 +93,13 @@ pub unsafe fn syscall2(nr: usize, a1: usize, a2: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(nr: usize, a1: usize, a2: usize, a3: usize) -> (usize, bool) {
+pub unsafe fn syscall3(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -109,7 +124,14 @@ pub unsafe fn syscall3(nr: usize, a1: usize, a2: usize, a3: usize) -> (usize, bo
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> (usize, bool) {
+pub unsafe fn syscall4(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -135,13 +157,14 @@ pub unsafe fn syscall4(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -167,7 +190,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -175,6 +198,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> (usize, bool) {
+    let nr = nr.into_nr();
     let ret: usize;
     let is_err: usize;
     asm!(
@@ -198,8 +222,63 @@ pub unsafe fn syscall6(
     (ret, is_err != 0)
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    nr: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> (usize, bool) {
+    let ret: usize;
+    let is_err: usize;
+    asm!(
+        ".set push",
+        ".set noreorder",
+        "syscall",
+        "bnez $7, 2f",
+        "nop",
+        "bnez $2, 2f",
+        "nop",
+        // In the child: the stack pointer is now the new stack
+        "move $fp, $0",
+        "move $4, $29",
+        "ld $25, 0($29)",
+        "jalr $25",
+        "nop",
+        "move $4, $2",
+        "li $2, {exit}",
+        "syscall",
+        "break",
+        "2:",
+        ".set pop",
+        exit = const crate::nr::EXIT,
+        inout("$2") nr => ret,
+        in("$4") a1,
+        in("$5") a2,
+        in("$6") a3,
+        inout("$7") a4 => is_err,
+        inout("$8") a5 => _,
+        out("$9") _,
+        out("$10") _,
+        out("$11") _,
+        out("$12") _,
+        out("$13") _,
+        out("$14") _,
+        out("$15") _,
+        out("$24") _,
+        out("$25") _,
+    );
+    (ret, is_err != 0)
+}
+
 #[inline(always)]
-pub unsafe fn syscall0_nofail(nr: usize) -> usize {
+pub unsafe fn syscall0_nofail(nr: impl crate::SyscallNumber) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -220,7 +299,8 @@ pub unsafe fn syscall0_nofail(nr: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(nr: usize, a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(nr: impl crate::SyscallNumber, a1: usize) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -242,7 +322,8 @@ pub unsafe fn syscall1_nofail(nr: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(nr: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(nr: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -265,7 +346,13 @@ pub unsafe fn syscall2_nofail(nr: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(nr: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -289,7 +376,14 @@ pub unsafe fn syscall3_nofail(nr: usize, a1: usize, a2: usize, a3: usize) -> usi
 }
 
 #[inline(always)]
-pub unsafe fn syscall4_nofail(nr: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4_nofail(
+    nr: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -314,13 +408,14 @@ pub unsafe fn syscall4_nofail(nr: usize, a1: usize, a2: usize, a3: usize, a4: us
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -345,7 +440,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -353,6 +448,7 @@ pub unsafe fn syscall6_nofail(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -0,0 +1,33 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, $sp points to `argc`. Since the code may be position-independent, the address of the
+// Rust entry point is computed from two `.gpdword`s (gp-relative offsets) placed right after the
+// `bal`, which also yields the value of $gp. `_start` is 8-byte aligned so that the `.gpdword`s
+// are too, without any padding between them and the `bal` (which would make $ra point at the
+// padding).
+global_asm!(
+    ".text",
+    ".set push",
+    ".set noreorder",
+    ".weak _start",
+    ".type _start, @function",
+    ".align 3",
+    "_start:",
+    "bal 1f",
+    "move $fp, $0",
+    "2:",
+    ".gpdword 2b",
+    ".gpdword _scall_internal_start",
+    "1:",
+    "ld $gp, 0($ra)",
+    "dsubu $gp, $ra, $gp",
+    "ld $25, 8($ra)",
+    "daddu $25, $25, $gp",
+    "move $4, $sp",
+    "and $sp, $sp, -16",
+    "jalr $25",
+    "nop",
+    "break",
+    ".size _start, . - _start",
+    ".set pop",
+);
@@ -17,11 +17,18 @@
 #![allow(unused_assignments)]
 #![allow(unused_variables)]
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
 
 #[inline(always)]
-pub unsafe fn syscall0(mut n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let mut n = n.into_nr();
     let ret: usize;
     llvm_asm!("sc
           bns+ 1f
@@ -35,7 +42,8 @@ pub unsafe fn syscall0(mut n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(mut n: usize, mut a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, mut a1: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -48,7 +56,8 @@ pub unsafe fn syscall1(mut n: usize, mut a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, mut a1: usize, mut a2: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -61,7 +70,13 @@ pub unsafe fn syscall2(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize) -> usize {
+pub unsafe fn syscall3(
+    n: impl crate::SyscallNumber,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -75,12 +90,13 @@ pub unsafe fn syscall3(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize
 
 #[inline(always)]
 pub unsafe fn syscall4(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -94,13 +110,14 @@ pub unsafe fn syscall4(
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
     mut a5: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -115,7 +132,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
@@ -123,6 +140,7 @@ pub unsafe fn syscall6(
     mut a5: usize,
     mut a6: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -135,8 +153,48 @@ pub unsafe fn syscall6(
     a1
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+    mut a4: usize,
+    mut a5: usize,
+) -> usize {
+    let mut n = n;
+    llvm_asm!("sc
+          bns+ 1f
+          neg $1, $1
+          b 2f
+          1:
+          cmpwi $1, 0
+          bne 2f
+          mr 3, 1
+          lwz 0, 0(1)
+          mtctr 0
+          li 0, 0
+          stwu 0, -16(1)
+          mtlr 0
+          bctrl
+          li 0, $6
+          sc
+          trap
+          2:"
+         : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
+           "+{r7}"(a5)
+         : "i"(crate::nr::EXIT)
+         : "cr0" "memory" "r8" "r9" "r10" "r11" "r12"
+         : "volatile");
+    a1
+}
+
 #[inline(always)]
-pub unsafe fn syscall0_nofail(mut n: usize) -> usize {
+pub unsafe fn syscall0_nofail(n: impl crate::SyscallNumber) -> usize {
+    let mut n = n.into_nr();
     let ret: usize;
     llvm_asm!("sc"
          : "+{r0}"(n) "={r3}"(ret)
@@ -147,7 +205,8 @@ pub unsafe fn syscall0_nofail(mut n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(mut n: usize, mut a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(n: impl crate::SyscallNumber, mut a1: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1)
          :
@@ -157,7 +216,8 @@ pub unsafe fn syscall1_nofail(mut n: usize, mut a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(n: impl crate::SyscallNumber, mut a1: usize, mut a2: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2)
          :
@@ -167,7 +227,13 @@ pub unsafe fn syscall2_nofail(mut n: usize, mut a1: usize, mut a2: usize) -> usi
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    n: impl crate::SyscallNumber,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3)
          :
@@ -178,12 +244,13 @@ pub unsafe fn syscall3_nofail(mut n: usize, mut a1: usize, mut a2: usize, mut a3
 
 #[inline(always)]
 pub unsafe fn syscall4_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
          :
@@ -194,13 +261,14 @@ pub unsafe fn syscall4_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
     mut a5: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
            "+{r7}"(a5)
@@ -212,7 +280,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
@@ -220,6 +288,7 @@ pub unsafe fn syscall6_nofail(
     mut a5: usize,
     mut a6: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
            "+{r7}"(a5) "+{r8}"(a6)
@@ -0,0 +1,19 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, r1 points to `argc`. A minimal 16-byte frame is allocated with a null back chain
+// (and a null saved LR) to mark the outermost frame.
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    "mr 3, 1",
+    "clrrwi 1, 1, 4",
+    "li 0, 0",
+    "stwu 1, -16(1)",
+    "mtlr 0",
+    "stw 0, 0(1)",
+    "bl _scall_internal_start",
+    "trap",
+    ".size _start, . - _start",
+);
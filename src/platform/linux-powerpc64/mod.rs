@@ -14,11 +14,18 @@
 #![allow(unused_assignments)]
 #![allow(unused_variables)]
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
 
 #[inline(always)]
-pub unsafe fn syscall0(mut n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let mut n = n.into_nr();
     let ret: usize;
     llvm_asm!("sc
           bns+ 1f
@@ -32,7 +39,8 @@ pub unsafe fn syscall0(mut n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(mut n: usize, mut a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, mut a1: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -45,7 +53,8 @@ pub unsafe fn syscall1(mut n: usize, mut a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, mut a1: usize, mut a2: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -58,7 +67,13 @@ pub unsafe fn syscall2(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize) -> usize {
+pub unsafe fn syscall3(
+    n: impl crate::SyscallNumber,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -72,12 +87,13 @@ pub unsafe fn syscall3(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize
 
 #[inline(always)]
 pub unsafe fn syscall4(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -91,13 +107,14 @@ pub unsafe fn syscall4(
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
     mut a5: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -112,7 +129,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
@@ -120,6 +137,7 @@ pub unsafe fn syscall6(
     mut a5: usize,
     mut a6: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc
           bns+ 1f
           neg $1, $1
@@ -132,8 +150,95 @@ pub unsafe fn syscall6(
     a1
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+//
+// With the ELFv1 ABI (big-endian), function pointers point to function descriptors, which contain
+// the address of the code and the TOC pointer to use.
+#[cfg(target_endian = "big")]
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+    mut a4: usize,
+    mut a5: usize,
+) -> usize {
+    let mut n = n;
+    llvm_asm!("sc
+          bns+ 1f
+          neg $1, $1
+          b 2f
+          1:
+          cmpdi $1, 0
+          bne 2f
+          mr 3, 1
+          ld 11, 0(1)
+          ld 0, 0(11)
+          ld 2, 8(11)
+          mtctr 0
+          li 0, 0
+          stdu 0, -112(1)
+          mtlr 0
+          bctrl
+          nop
+          li 0, $6
+          sc
+          trap
+          2:"
+         : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
+           "+{r7}"(a5)
+         : "i"(crate::nr::EXIT)
+         : "cr0" "memory" "r8" "r9" "r10" "r11" "r12"
+         : "volatile");
+    a1
+}
+
+// With the ELFv2 ABI (little-endian), r12 must contain the address of the function on entry, so it
+// can compute its TOC pointer.
+#[cfg(target_endian = "little")]
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+    mut a4: usize,
+    mut a5: usize,
+) -> usize {
+    let mut n = n;
+    llvm_asm!("sc
+          bns+ 1f
+          neg $1, $1
+          b 2f
+          1:
+          cmpdi $1, 0
+          bne 2f
+          mr 3, 1
+          ld 12, 0(1)
+          mtctr 12
+          li 0, 0
+          stdu 0, -32(1)
+          mtlr 0
+          bctrl
+          nop
+          li 0, $6
+          sc
+          trap
+          2:"
+         : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
+           "+{r7}"(a5)
+         : "i"(crate::nr::EXIT)
+         : "cr0" "memory" "r8" "r9" "r10" "r11" "r12"
+         : "volatile");
+    a1
+}
+
 #[inline(always)]
-pub unsafe fn syscall0_nofail(mut n: usize) -> usize {
+pub unsafe fn syscall0_nofail(n: impl crate::SyscallNumber) -> usize {
+    let mut n = n.into_nr();
     let ret: usize;
     llvm_asm!("sc"
          : "+{r0}"(n) "={r3}"(ret)
@@ -144,7 +249,8 @@ pub unsafe fn syscall0_nofail(mut n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(mut n: usize, mut a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(n: impl crate::SyscallNumber, mut a1: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1)
          :
@@ -154,7 +260,8 @@ pub unsafe fn syscall1_nofail(mut n: usize, mut a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(mut n: usize, mut a1: usize, mut a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(n: impl crate::SyscallNumber, mut a1: usize, mut a2: usize) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2)
          :
@@ -164,7 +271,13 @@ pub unsafe fn syscall2_nofail(mut n: usize, mut a1: usize, mut a2: usize) -> usi
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(mut n: usize, mut a1: usize, mut a2: usize, mut a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    n: impl crate::SyscallNumber,
+    mut a1: usize,
+    mut a2: usize,
+    mut a3: usize,
+) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3)
          :
@@ -175,12 +288,13 @@ pub unsafe fn syscall3_nofail(mut n: usize, mut a1: usize, mut a2: usize, mut a3
 
 #[inline(always)]
 pub unsafe fn syscall4_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
          :
@@ -191,13 +305,14 @@ pub unsafe fn syscall4_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
     mut a4: usize,
     mut a5: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
            "+{r7}"(a5)
@@ -209,7 +324,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    mut n: usize,
+    n: impl crate::SyscallNumber,
     mut a1: usize,
     mut a2: usize,
     mut a3: usize,
@@ -217,6 +332,7 @@ pub unsafe fn syscall6_nofail(
     mut a5: usize,
     mut a6: usize,
 ) -> usize {
+    let mut n = n.into_nr();
     llvm_asm!("sc"
          : "+{r0}"(n) "+{r3}"(a1) "+{r4}"(a2) "+{r5}"(a3) "+{r6}"(a4)
            "+{r7}"(a5) "+{r8}"(a6)
@@ -0,0 +1,49 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, r1 points to `argc`. A frame is allocated with a null back chain to mark the
+// outermost frame.
+//
+// Big-endian powerpc64 uses the ELFv1 ABI, where `_start` is a function descriptor in `.opd` and
+// the kernel loads the TOC pointer (r2) from it. Little-endian powerpc64 uses ELFv2, where the
+// kernel sets r12 to the address of `_start` so that r2 can be computed from it.
+
+#[cfg(target_endian = "big")]
+global_asm!(
+    ".section .opd, \"aw\"",
+    ".align 3",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    ".quad .L_scall_start, .TOC.@tocbase, 0",
+    ".text",
+    ".L_scall_start:",
+    "mr 3, 1",
+    "clrrdi 1, 1, 4",
+    "li 0, 0",
+    "stdu 0, -128(1)",
+    "mtlr 0",
+    "bl _scall_internal_start",
+    "nop",
+    "trap",
+    ".size _start, 24",
+);
+
+#[cfg(target_endian = "little")]
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    "addis 2, 12, .TOC.-_start@ha",
+    "addi 2, 2, .TOC.-_start@l",
+    ".localentry _start, . - _start",
+    "mr 3, 1",
+    "clrrdi 1, 1, 4",
+    "li 0, 0",
+    "stdu 0, -32(1)",
+    "mtlr 0",
+    "bl _scall_internal_start",
+    "nop",
+    "trap",
+    ".size _start, . - _start",
+);
@@ -11,11 +11,18 @@
 
 // https://github.com/riscv/riscv-elf-psabi-doc/blob/master/riscv-elf.md#-integer-calling-convention
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
 
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -26,7 +33,8 @@ pub unsafe fn syscall0(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -37,7 +45,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -49,7 +58,8 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -62,7 +72,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -76,7 +93,15 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 }
 
 #[inline(always)]
-pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> usize {
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -92,7 +117,7 @@ pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5:
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -100,6 +125,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "ecall",
@@ -114,6 +140,42 @@ pub unsafe fn syscall6(
     ret
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: usize;
+    asm!(
+        "ecall",
+        "bnez a0, 2f",
+        "li s0, 0",
+        "li ra, 0",
+        "mv a0, sp",
+        "ld a1, 0(sp)",
+        "jalr a1",
+        "li a7, {exit}",
+        "ecall",
+        "unimp",
+        "2:",
+        exit = const crate::nr::EXIT,
+        in("a7") n,
+        inout("a0") a1 => ret,
+        in("a1") a2,
+        in("a2") a3,
+        in("a3") a4,
+        in("a4") a5,
+    );
+    ret
+}
+
 pub use syscall0 as syscall0_nofail;
 pub use syscall1 as syscall1_nofail;
 pub use syscall2 as syscall2_nofail;
@@ -0,0 +1,21 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, sp points to `argc`. gp has to be set up by hand (with relaxation disabled, so the
+// linker doesn't turn the `lla` into a gp-relative load of gp itself).
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    ".option push",
+    ".option norelax",
+    "lla gp, __global_pointer$",
+    ".option pop",
+    "mv a0, sp",
+    "andi sp, sp, -16",
+    "li s0, 0",
+    "li ra, 0",
+    "call _scall_internal_start",
+    "unimp",
+    ".size _start, . - _start",
+);
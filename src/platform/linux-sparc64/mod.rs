@@ -12,11 +12,21 @@
 // Reference:
 // http://math-atlas.sourceforge.net/devel/assembly/abi_sysV_sparc.pdf
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
+
+mod restore;
+pub(crate) use restore::*;
 
 #[inline(always)]
-pub unsafe fn syscall0(nr: usize) -> usize {
+pub unsafe fn syscall0(nr: impl crate::SyscallNumber) -> usize {
+    let nr = nr.into_nr();
     let ret;
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
@@ -30,7 +40,8 @@ pub unsafe fn syscall0(nr: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(nr: usize, mut a1: usize) -> usize {
+pub unsafe fn syscall1(nr: impl crate::SyscallNumber, mut a1: usize) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -43,7 +54,8 @@ pub unsafe fn syscall1(nr: usize, mut a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(nr: usize, mut a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(nr: impl crate::SyscallNumber, mut a1: usize, a2: usize) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -56,7 +68,13 @@ pub unsafe fn syscall2(nr: usize, mut a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(nr: usize, mut a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(
+    nr: impl crate::SyscallNumber,
+    mut a1: usize,
+    a2: usize,
+    a3: usize,
+) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -69,7 +87,14 @@ pub unsafe fn syscall3(nr: usize, mut a1: usize, a2: usize, a3: usize) -> usize
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(nr: usize, mut a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    nr: impl crate::SyscallNumber,
+    mut a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -83,13 +108,14 @@ pub unsafe fn syscall4(nr: usize, mut a1: usize, a2: usize, a3: usize, a4: usize
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     mut a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -103,7 +129,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    nr: usize,
+    nr: impl crate::SyscallNumber,
     mut a1: usize,
     a2: usize,
     a3: usize,
@@ -111,6 +137,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let nr = nr.into_nr();
     llvm_asm!("t 109
           bcc,pt %xcc, 1f
           sub %g0, %o0, %o0
@@ -123,6 +150,45 @@ pub unsafe fn syscall6(
     a1
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+//
+// The kernel returns 0 in %o1 to the parent and 1 to the child. The child's stack pointer (which
+// includes the stack bias) must leave room for a minimal stack frame below the top of the stack.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    nr: usize,
+    mut a1: usize,
+    mut a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    llvm_asm!("t 109
+          bcs,pn %xcc, 1f
+          nop
+          brz,pt %o1, 2f
+          nop
+          mov %g0, %fp
+          mov %g0, %i7
+          add %sp, 2047 + 176, %o0
+          ldx [%o0], %o1
+          call %o1
+          nop
+          mov $6, %g1
+          t 109
+          unimp 0
+          1:
+          sub %g0, %o0, %o0
+          2:"
+         : "+{o0}"(a1) "+{o1}"(a2)
+         : "{g1}"(nr) "{o2}"(a3) "{o3}"(a4) "{o4}"(a5) "i"(crate::nr::EXIT)
+         : "cc" "memory"
+         : "volatile");
+    a1
+}
+
 pub use syscall0 as syscall0_nofail;
 pub use syscall1 as syscall1_nofail;
 pub use syscall2 as syscall2_nofail;
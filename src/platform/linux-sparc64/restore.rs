@@ -0,0 +1,20 @@
+// The signal return trampoline used by `signal::sigaction()`.
+//
+// On SPARC, the restorer is passed to `rt_sigaction()` as a separate argument. The kernel puts it
+// in the handler's return address register, and handlers return with `ret` (which jumps to
+// %i7 + 8), so the address that is passed is the restorer's address minus 8.
+global_asm!(
+    ".text",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, #function",
+    "_scall_internal_restore_rt:",
+    "mov {rt_sigreturn}, %g1",
+    "t 0x6d",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+extern "C" {
+    pub(crate) fn _scall_internal_restore_rt();
+}
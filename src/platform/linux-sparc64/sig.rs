@@ -0,0 +1,58 @@
+//! Signal numbers and flags for SPARC64 Linux.
+
+/* automatically generated by sig_from_src.py */
+
+pub const MINSIGSTKSZ: usize = 4096;
+pub const SA_EXPOSE_TAGBITS: u32 = 0x800;
+pub const SA_NOCLDSTOP: u32 = 8;
+pub const SA_NOCLDWAIT: u32 = 0x100;
+pub const SA_NODEFER: u32 = 0x20;
+pub const SA_ONSTACK: u32 = 1;
+pub const SA_RESETHAND: u32 = 4;
+pub const SA_RESTART: u32 = 2;
+pub const SA_SIGINFO: u32 = 0x200;
+pub const SA_STACK: u32 = 1;
+pub const SA_UNSUPPORTED: u32 = 0x400;
+pub const SIGABRT: i32 = 6;
+pub const SIGALRM: i32 = 14;
+pub const SIGBUS: i32 = 10;
+pub const SIGCHLD: i32 = 20;
+pub const SIGCONT: i32 = 19;
+pub const SIGEMT: i32 = 7;
+pub const SIGFPE: i32 = 8;
+pub const SIGHUP: i32 = 1;
+pub const SIGILL: i32 = 4;
+pub const SIGINT: i32 = 2;
+pub const SIGIO: i32 = 23;
+pub const SIGIOT: i32 = 6;
+pub const SIGKILL: i32 = 9;
+pub const SIGLOST: i32 = 29;
+pub const SIGPIPE: i32 = 13;
+pub const SIGPOLL: i32 = 23;
+pub const SIGPROF: i32 = 27;
+pub const SIGPWR: i32 = 29;
+pub const SIGQUIT: i32 = 3;
+pub const SIGRTMAX: i32 = 64;
+pub const SIGRTMIN: i32 = 32;
+pub const SIGSEGV: i32 = 11;
+pub const SIGSTKSZ: usize = 16384;
+pub const SIGSTOP: i32 = 17;
+pub const SIGSYS: i32 = 12;
+pub const SIGTERM: i32 = 15;
+pub const SIGTRAP: i32 = 5;
+pub const SIGTSTP: i32 = 18;
+pub const SIGTTIN: i32 = 21;
+pub const SIGTTOU: i32 = 22;
+pub const SIGURG: i32 = 16;
+pub const SIGUSR1: i32 = 30;
+pub const SIGUSR2: i32 = 31;
+pub const SIGVTALRM: i32 = 26;
+pub const SIGWINCH: i32 = 28;
+pub const SIGXCPU: i32 = 24;
+pub const SIGXFSZ: i32 = 25;
+pub const SIG_BLOCK: i32 = 1;
+pub const SIG_SETMASK: i32 = 4;
+pub const SIG_UNBLOCK: i32 = 2;
+pub const SS_AUTODISARM: u32 = 0x80000000;
+pub const SS_DISABLE: u32 = 2;
+pub const SS_ONSTACK: u32 = 1;
@@ -0,0 +1,18 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, `argc` is just above the register window save area (16 registers) of the initial
+// frame, which is offset by the stack bias (2047). A new frame (of the minimum size of 176 bytes)
+// is allocated so that the callee doesn't overwrite `argc` and `argv` when it saves its arguments.
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, #function",
+    "_start:",
+    "mov %g0, %fp",
+    "mov %g0, %i7",
+    "add %sp, 2047 + 16 * 8, %o0",
+    "call _scall_internal_start",
+    "sub %sp, 176, %sp",
+    "unimp 0",
+    ".size _start, . - _start",
+);
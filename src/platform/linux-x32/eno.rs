@@ -0,0 +1,273 @@
+//! Error numbers for x32 Linux.
+
+/* automatically generated by eno_from_src.py */
+
+pub const E2BIG: i32 = 7;
+pub const EACCES: i32 = 13;
+pub const EADDRINUSE: i32 = 98;
+pub const EADDRNOTAVAIL: i32 = 99;
+pub const EADV: i32 = 68;
+pub const EAFNOSUPPORT: i32 = 97;
+pub const EAGAIN: i32 = 11;
+pub const EALREADY: i32 = 114;
+pub const EBADE: i32 = 52;
+pub const EBADF: i32 = 9;
+pub const EBADFD: i32 = 77;
+pub const EBADMSG: i32 = 74;
+pub const EBADR: i32 = 53;
+pub const EBADRQC: i32 = 56;
+pub const EBADSLT: i32 = 57;
+pub const EBFONT: i32 = 59;
+pub const EBUSY: i32 = 16;
+pub const ECANCELED: i32 = 125;
+pub const ECHILD: i32 = 10;
+pub const ECHRNG: i32 = 44;
+pub const ECOMM: i32 = 70;
+pub const ECONNABORTED: i32 = 103;
+pub const ECONNREFUSED: i32 = 111;
+pub const ECONNRESET: i32 = 104;
+pub const EDEADLK: i32 = 35;
+pub const EDEADLOCK: i32 = 35;
+pub const EDESTADDRREQ: i32 = 89;
+pub const EDOM: i32 = 33;
+pub const EDOTDOT: i32 = 73;
+pub const EDQUOT: i32 = 122;
+pub const EEXIST: i32 = 17;
+pub const EFAULT: i32 = 14;
+pub const EFBIG: i32 = 27;
+pub const EHOSTDOWN: i32 = 112;
+pub const EHOSTUNREACH: i32 = 113;
+pub const EHWPOISON: i32 = 133;
+pub const EIDRM: i32 = 43;
+pub const EILSEQ: i32 = 84;
+pub const EINPROGRESS: i32 = 115;
+pub const EINTR: i32 = 4;
+pub const EINVAL: i32 = 22;
+pub const EIO: i32 = 5;
+pub const EISCONN: i32 = 106;
+pub const EISDIR: i32 = 21;
+pub const EISNAM: i32 = 120;
+pub const EKEYEXPIRED: i32 = 127;
+pub const EKEYREJECTED: i32 = 129;
+pub const EKEYREVOKED: i32 = 128;
+pub const EL2HLT: i32 = 51;
+pub const EL2NSYNC: i32 = 45;
+pub const EL3HLT: i32 = 46;
+pub const EL3RST: i32 = 47;
+pub const ELIBACC: i32 = 79;
+pub const ELIBBAD: i32 = 80;
+pub const ELIBEXEC: i32 = 83;
+pub const ELIBMAX: i32 = 82;
+pub const ELIBSCN: i32 = 81;
+pub const ELNRNG: i32 = 48;
+pub const ELOOP: i32 = 40;
+pub const EMEDIUMTYPE: i32 = 124;
+pub const EMFILE: i32 = 24;
+pub const EMLINK: i32 = 31;
+pub const EMSGSIZE: i32 = 90;
+pub const EMULTIHOP: i32 = 72;
+pub const ENAMETOOLONG: i32 = 36;
+pub const ENAVAIL: i32 = 119;
+pub const ENETDOWN: i32 = 100;
+pub const ENETRESET: i32 = 102;
+pub const ENETUNREACH: i32 = 101;
+pub const ENFILE: i32 = 23;
+pub const ENOANO: i32 = 55;
+pub const ENOBUFS: i32 = 105;
+pub const ENOCSI: i32 = 50;
+pub const ENODATA: i32 = 61;
+pub const ENODEV: i32 = 19;
+pub const ENOENT: i32 = 2;
+pub const ENOEXEC: i32 = 8;
+pub const ENOKEY: i32 = 126;
+pub const ENOLCK: i32 = 37;
+pub const ENOLINK: i32 = 67;
+pub const ENOMEDIUM: i32 = 123;
+pub const ENOMEM: i32 = 12;
+pub const ENOMSG: i32 = 42;
+pub const ENONET: i32 = 64;
+pub const ENOPKG: i32 = 65;
+pub const ENOPROTOOPT: i32 = 92;
+pub const ENOSPC: i32 = 28;
+pub const ENOSR: i32 = 63;
+pub const ENOSTR: i32 = 60;
+pub const ENOSYS: i32 = 38;
+pub const ENOTBLK: i32 = 15;
+pub const ENOTCONN: i32 = 107;
+pub const ENOTDIR: i32 = 20;
+pub const ENOTEMPTY: i32 = 39;
+pub const ENOTNAM: i32 = 118;
+pub const ENOTRECOVERABLE: i32 = 131;
+pub const ENOTSOCK: i32 = 88;
+pub const ENOTTY: i32 = 25;
+pub const ENOTUNIQ: i32 = 76;
+pub const ENXIO: i32 = 6;
+pub const EOPNOTSUPP: i32 = 95;
+pub const EOVERFLOW: i32 = 75;
+pub const EOWNERDEAD: i32 = 130;
+pub const EPERM: i32 = 1;
+pub const EPFNOSUPPORT: i32 = 96;
+pub const EPIPE: i32 = 32;
+pub const EPROTO: i32 = 71;
+pub const EPROTONOSUPPORT: i32 = 93;
+pub const EPROTOTYPE: i32 = 91;
+pub const ERANGE: i32 = 34;
+pub const EREMCHG: i32 = 78;
+pub const EREMOTE: i32 = 66;
+pub const EREMOTEIO: i32 = 121;
+pub const ERESTART: i32 = 85;
+pub const ERFKILL: i32 = 132;
+pub const EROFS: i32 = 30;
+pub const ESHUTDOWN: i32 = 108;
+pub const ESOCKTNOSUPPORT: i32 = 94;
+pub const ESPIPE: i32 = 29;
+pub const ESRCH: i32 = 3;
+pub const ESRMNT: i32 = 69;
+pub const ESTALE: i32 = 116;
+pub const ESTRPIPE: i32 = 86;
+pub const ETIME: i32 = 62;
+pub const ETIMEDOUT: i32 = 110;
+pub const ETOOMANYREFS: i32 = 109;
+pub const ETXTBSY: i32 = 26;
+pub const EUCLEAN: i32 = 117;
+pub const EUNATCH: i32 = 49;
+pub const EUSERS: i32 = 87;
+pub const EWOULDBLOCK: i32 = 11;
+pub const EXDEV: i32 = 18;
+pub const EXFULL: i32 = 54;
+
+errno_table! {
+    E2BIG "Argument list too long",
+    EACCES "Permission denied",
+    EADDRINUSE "Address already in use",
+    EADDRNOTAVAIL "Cannot assign requested address",
+    EADV "Advertise error",
+    EAFNOSUPPORT "Address family not supported by protocol",
+    EAGAIN "Try again",
+    EALREADY "Operation already in progress",
+    EBADE "Invalid exchange",
+    EBADF "Bad file number",
+    EBADFD "File descriptor in bad state",
+    EBADMSG "Not a data message",
+    EBADR "Invalid request descriptor",
+    EBADRQC "Invalid request code",
+    EBADSLT "Invalid slot",
+    EBFONT "Bad font file format",
+    EBUSY "Device or resource busy",
+    ECANCELED "Operation Canceled",
+    ECHILD "No child processes",
+    ECHRNG "Channel number out of range",
+    ECOMM "Communication error on send",
+    ECONNABORTED "Software caused connection abort",
+    ECONNREFUSED "Connection refused",
+    ECONNRESET "Connection reset by peer",
+    EDEADLK "Resource deadlock would occur",
+    EDESTADDRREQ "Destination address required",
+    EDOM "Math argument out of domain of func",
+    EDOTDOT "RFS specific error",
+    EDQUOT "Quota exceeded",
+    EEXIST "File exists",
+    EFAULT "Bad address",
+    EFBIG "File too large",
+    EHOSTDOWN "Host is down",
+    EHOSTUNREACH "No route to host",
+    EHWPOISON "Memory page has hardware error",
+    EIDRM "Identifier removed",
+    EILSEQ "Illegal byte sequence",
+    EINPROGRESS "Operation now in progress",
+    EINTR "Interrupted system call",
+    EINVAL "Invalid argument",
+    EIO "I/O error",
+    EISCONN "Transport endpoint is already connected",
+    EISDIR "Is a directory",
+    EISNAM "Is a named type file",
+    EKEYEXPIRED "Key has expired",
+    EKEYREJECTED "Key was rejected by service",
+    EKEYREVOKED "Key has been revoked",
+    EL2HLT "Level 2 halted",
+    EL2NSYNC "Level 2 not synchronized",
+    EL3HLT "Level 3 halted",
+    EL3RST "Level 3 reset",
+    ELIBACC "Can not access a needed shared library",
+    ELIBBAD "Accessing a corrupted shared library",
+    ELIBEXEC "Cannot exec a shared library directly",
+    ELIBMAX "Attempting to link in too many shared libraries",
+    ELIBSCN ".lib section in a.out corrupted",
+    ELNRNG "Link number out of range",
+    ELOOP "Too many symbolic links encountered",
+    EMEDIUMTYPE "Wrong medium type",
+    EMFILE "Too many open files",
+    EMLINK "Too many links",
+    EMSGSIZE "Message too long",
+    EMULTIHOP "Multihop attempted",
+    ENAMETOOLONG "File name too long",
+    ENAVAIL "No XENIX semaphores available",
+    ENETDOWN "Network is down",
+    ENETRESET "Network dropped connection because of reset",
+    ENETUNREACH "Network is unreachable",
+    ENFILE "File table overflow",
+    ENOANO "No anode",
+    ENOBUFS "No buffer space available",
+    ENOCSI "No CSI structure available",
+    ENODATA "No data available",
+    ENODEV "No such device",
+    ENOENT "No such file or directory",
+    ENOEXEC "Exec format error",
+    ENOKEY "Required key not available",
+    ENOLCK "No record locks available",
+    ENOLINK "Link has been severed",
+    ENOMEDIUM "No medium found",
+    ENOMEM "Out of memory",
+    ENOMSG "No message of desired type",
+    ENONET "Machine is not on the network",
+    ENOPKG "Package not installed",
+    ENOPROTOOPT "Protocol not available",
+    ENOSPC "No space left on device",
+    ENOSR "Out of streams resources",
+    ENOSTR "Device not a stream",
+    ENOSYS "Invalid system call number",
+    ENOTBLK "Block device required",
+    ENOTCONN "Transport endpoint is not connected",
+    ENOTDIR "Not a directory",
+    ENOTEMPTY "Directory not empty",
+    ENOTNAM "Not a XENIX named type file",
+    ENOTRECOVERABLE "State not recoverable",
+    ENOTSOCK "Socket operation on non-socket",
+    ENOTTY "Not a typewriter",
+    ENOTUNIQ "Name not unique on network",
+    ENXIO "No such device or address",
+    EOPNOTSUPP "Operation not supported on transport endpoint",
+    EOVERFLOW "Value too large for defined data type",
+    EOWNERDEAD "Owner died",
+    EPERM "Operation not permitted",
+    EPFNOSUPPORT "Protocol family not supported",
+    EPIPE "Broken pipe",
+    EPROTO "Protocol error",
+    EPROTONOSUPPORT "Protocol not supported",
+    EPROTOTYPE "Protocol wrong type for socket",
+    ERANGE "Math result not representable",
+    EREMCHG "Remote address changed",
+    EREMOTE "Object is remote",
+    EREMOTEIO "Remote I/O error",
+    ERESTART "Interrupted system call should be restarted",
+    ERFKILL "Operation not possible due to RF-kill",
+    EROFS "Read-only file system",
+    ESHUTDOWN "Cannot send after transport endpoint shutdown",
+    ESOCKTNOSUPPORT "Socket type not supported",
+    ESPIPE "Illegal seek",
+    ESRCH "No such process",
+    ESRMNT "Srmount error",
+    ESTALE "Stale file handle",
+    ESTRPIPE "Streams pipe error",
+    ETIME "Timer expired",
+    ETIMEDOUT "Connection timed out",
+    ETOOMANYREFS "Too many references: cannot splice",
+    ETXTBSY "Text file busy",
+    EUCLEAN "Structure needs cleaning",
+    EUNATCH "Protocol driver not attached",
+    EUSERS "Too many users",
+    EXDEV "Cross-device link",
+    EXFULL "Exchange full",
+    @alias EDEADLOCK = EDEADLK,
+    @alias EWOULDBLOCK = EAGAIN,
+}
@@ -0,0 +1,232 @@
+// Copyright 2014 The syscall.rs Project Developers. See the
+// COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This library was built for x32 Linux.
+
+// x32 uses the x86-64 `syscall` instruction and registers, but pointers (and `usize`) are 32 bits.
+// Arguments are zero-extended to fill the 64-bit registers, which is what the kernel expects for
+// pointers and sizes; 64-bit values have to be passed with `syscall_args64!`, which uses
+// `syscall_u64()` to put them in a single register.
+
+pub mod consts;
+pub mod eno;
+pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
+
+mod restore;
+pub(crate) use restore::*;
+
+#[inline(always)]
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        in("rdx") a3 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        in("rdx") a3 as u64,
+        in("r10") a4 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        in("rdx") a3 as u64,
+        in("r10") a4 as u64,
+        in("r8") a5 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+#[inline(always)]
+pub unsafe fn syscall6(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+    a6: usize,
+) -> usize {
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        in("rdx") a3 as u64,
+        in("r10") a4 as u64,
+        in("r8") a5 as u64,
+        in("r9") a6 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns. (The function pointer is only 32 bits wide.)
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: u64;
+    asm!(
+        "syscall",
+        "test rax, rax",
+        "jnz 2f",
+        "xor ebp, ebp",
+        "mov rdi, rsp",
+        "mov eax, dword ptr [rsp]",
+        "call rax",
+        "mov edi, eax",
+        "mov eax, {exit}",
+        "syscall",
+        "ud2",
+        "2:",
+        exit = const crate::nr::EXIT,
+        inout("rax") n as u64 => ret,
+        in("rdi") a1 as u64,
+        in("rsi") a2 as u64,
+        in("rdx") a3 as u64,
+        in("r10") a4 as u64,
+        in("r8") a5 as u64,
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+// Used by `syscall_args64!`, which needs to pass full 64-bit values. Unused arguments are passed as
+// 0.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn syscall_u64(n: impl crate::SyscallNumber, args: &[u64]) -> usize {
+    let mut a = [0u64; 6];
+    a[..args.len()].copy_from_slice(args);
+
+    let n = n.into_nr() as u64;
+    let ret: u64;
+    asm!(
+        "syscall",
+        inout("rax") n => ret,
+        in("rdi") a[0],
+        in("rsi") a[1],
+        in("rdx") a[2],
+        in("r10") a[3],
+        in("r8") a[4],
+        in("r9") a[5],
+        out("rcx") _,
+        out("r11") _,
+    );
+    ret as usize
+}
+
+pub use syscall0 as syscall0_nofail;
+pub use syscall1 as syscall1_nofail;
+pub use syscall2 as syscall2_nofail;
+pub use syscall3 as syscall3_nofail;
+pub use syscall4 as syscall4_nofail;
+pub use syscall5 as syscall5_nofail;
+pub use syscall6 as syscall6_nofail;
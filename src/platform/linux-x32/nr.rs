@@ -0,0 +1,741 @@
+//! System call numbers for x32 Linux.
+
+/* automatically generated by nr_from_src.py from Linux 5.17 */
+
+pub const ACCEPT: usize = 1073741867;
+pub const ACCEPT4: usize = 1073742112;
+pub const ACCESS: usize = 1073741845;
+pub const ACCT: usize = 1073741987;
+pub const ADD_KEY: usize = 1073742072;
+pub const ADJTIMEX: usize = 1073741983;
+pub const AFS_SYSCALL: usize = 1073742007;
+pub const ALARM: usize = 1073741861;
+pub const ARCH_PRCTL: usize = 1073741982;
+pub const BIND: usize = 1073741873;
+pub const BPF: usize = 1073742145;
+pub const BRK: usize = 1073741836;
+pub const CAPGET: usize = 1073741949;
+pub const CAPSET: usize = 1073741950;
+pub const CHDIR: usize = 1073741904;
+pub const CHMOD: usize = 1073741914;
+pub const CHOWN: usize = 1073741916;
+pub const CHROOT: usize = 1073741985;
+pub const CLOCK_ADJTIME: usize = 1073742129;
+pub const CLOCK_GETRES: usize = 1073742053;
+pub const CLOCK_GETTIME: usize = 1073742052;
+pub const CLOCK_NANOSLEEP: usize = 1073742054;
+pub const CLOCK_SETTIME: usize = 1073742051;
+pub const CLONE: usize = 1073741880;
+pub const CLONE3: usize = 1073742259;
+pub const CLOSE: usize = 1073741827;
+pub const CLOSE_RANGE: usize = 1073742260;
+pub const CONNECT: usize = 1073741866;
+pub const COPY_FILE_RANGE: usize = 1073742150;
+pub const CREAT: usize = 1073741909;
+pub const DELETE_MODULE: usize = 1073742000;
+pub const DUP: usize = 1073741856;
+pub const DUP2: usize = 1073741857;
+pub const DUP3: usize = 1073742116;
+pub const EPOLL_CREATE: usize = 1073742037;
+pub const EPOLL_CREATE1: usize = 1073742115;
+pub const EPOLL_CTL: usize = 1073742057;
+pub const EPOLL_PWAIT: usize = 1073742105;
+pub const EPOLL_PWAIT2: usize = 1073742265;
+pub const EPOLL_WAIT: usize = 1073742056;
+pub const EVENTFD: usize = 1073742108;
+pub const EVENTFD2: usize = 1073742114;
+pub const EXECVE: usize = 1073742344;
+pub const EXECVEAT: usize = 1073742369;
+pub const EXIT: usize = 1073741884;
+pub const EXIT_GROUP: usize = 1073742055;
+pub const FACCESSAT: usize = 1073742093;
+pub const FACCESSAT2: usize = 1073742263;
+pub const FADVISE64: usize = 1073742045;
+pub const FALLOCATE: usize = 1073742109;
+pub const FANOTIFY_INIT: usize = 1073742124;
+pub const FANOTIFY_MARK: usize = 1073742125;
+pub const FCHDIR: usize = 1073741905;
+pub const FCHMOD: usize = 1073741915;
+pub const FCHMODAT: usize = 1073742092;
+pub const FCHOWN: usize = 1073741917;
+pub const FCHOWNAT: usize = 1073742084;
+pub const FCNTL: usize = 1073741896;
+pub const FDATASYNC: usize = 1073741899;
+pub const FGETXATTR: usize = 1073742017;
+pub const FINIT_MODULE: usize = 1073742137;
+pub const FLISTXATTR: usize = 1073742020;
+pub const FLOCK: usize = 1073741897;
+pub const FORK: usize = 1073741881;
+pub const FREMOVEXATTR: usize = 1073742023;
+pub const FSCONFIG: usize = 1073742255;
+pub const FSETXATTR: usize = 1073742014;
+pub const FSMOUNT: usize = 1073742256;
+pub const FSOPEN: usize = 1073742254;
+pub const FSPICK: usize = 1073742257;
+pub const FSTAT: usize = 1073741829;
+pub const FSTATFS: usize = 1073741962;
+pub const FSYNC: usize = 1073741898;
+pub const FTRUNCATE: usize = 1073741901;
+pub const FUTEX: usize = 1073742026;
+pub const FUTEX_WAITV: usize = 1073742273;
+pub const FUTIMESAT: usize = 1073742085;
+pub const GET_MEMPOLICY: usize = 1073742063;
+pub const GET_ROBUST_LIST: usize = 1073742355;
+pub const GETCPU: usize = 1073742133;
+pub const GETCWD: usize = 1073741903;
+pub const GETDENTS: usize = 1073741902;
+pub const GETDENTS64: usize = 1073742041;
+pub const GETEGID: usize = 1073741932;
+pub const GETEGID32: usize = 1073741932;
+pub const GETEUID: usize = 1073741931;
+pub const GETEUID32: usize = 1073741931;
+pub const GETGID: usize = 1073741928;
+pub const GETGID32: usize = 1073741928;
+pub const GETGROUPS: usize = 1073741939;
+pub const GETGROUPS32: usize = 1073741939;
+pub const GETITIMER: usize = 1073741860;
+pub const GETPEERNAME: usize = 1073741876;
+pub const GETPGID: usize = 1073741945;
+pub const GETPGRP: usize = 1073741935;
+pub const GETPID: usize = 1073741863;
+pub const GETPMSG: usize = 1073742005;
+pub const GETPPID: usize = 1073741934;
+pub const GETPRIORITY: usize = 1073741964;
+pub const GETRANDOM: usize = 1073742142;
+pub const GETRESGID: usize = 1073741944;
+pub const GETRESGID32: usize = 1073741944;
+pub const GETRESUID: usize = 1073741942;
+pub const GETRESUID32: usize = 1073741942;
+pub const GETRLIMIT: usize = 1073741921;
+pub const GETRUSAGE: usize = 1073741922;
+pub const GETSID: usize = 1073741948;
+pub const GETSOCKNAME: usize = 1073741875;
+pub const GETSOCKOPT: usize = 1073742366;
+pub const GETTID: usize = 1073742010;
+pub const GETTIMEOFDAY: usize = 1073741920;
+pub const GETUID: usize = 1073741926;
+pub const GETUID32: usize = 1073741926;
+pub const GETXATTR: usize = 1073742015;
+pub const INIT_MODULE: usize = 1073741999;
+pub const INOTIFY_ADD_WATCH: usize = 1073742078;
+pub const INOTIFY_INIT: usize = 1073742077;
+pub const INOTIFY_INIT1: usize = 1073742118;
+pub const INOTIFY_RM_WATCH: usize = 1073742079;
+pub const IO_CANCEL: usize = 1073742034;
+pub const IO_DESTROY: usize = 1073742031;
+pub const IO_GETEVENTS: usize = 1073742032;
+pub const IO_PGETEVENTS: usize = 1073742157;
+pub const IO_SETUP: usize = 1073742367;
+pub const IO_SUBMIT: usize = 1073742368;
+pub const IO_URING_ENTER: usize = 1073742250;
+pub const IO_URING_REGISTER: usize = 1073742251;
+pub const IO_URING_SETUP: usize = 1073742249;
+pub const IOCTL: usize = 1073742338;
+pub const IOPERM: usize = 1073741997;
+pub const IOPL: usize = 1073741996;
+pub const IOPRIO_GET: usize = 1073742076;
+pub const IOPRIO_SET: usize = 1073742075;
+pub const KCMP: usize = 1073742136;
+pub const KEXEC_FILE_LOAD: usize = 1073742144;
+pub const KEXEC_LOAD: usize = 1073742352;
+pub const KEYCTL: usize = 1073742074;
+pub const KILL: usize = 1073741886;
+pub const LANDLOCK_ADD_RULE: usize = 1073742269;
+pub const LANDLOCK_CREATE_RULESET: usize = 1073742268;
+pub const LANDLOCK_RESTRICT_SELF: usize = 1073742270;
+pub const LCHOWN: usize = 1073741918;
+pub const LGETXATTR: usize = 1073742016;
+pub const LINK: usize = 1073741910;
+pub const LINKAT: usize = 1073742089;
+pub const LISTEN: usize = 1073741874;
+pub const LISTXATTR: usize = 1073742018;
+pub const LLISTXATTR: usize = 1073742019;
+pub const LOOKUP_DCOOKIE: usize = 1073742036;
+pub const LREMOVEXATTR: usize = 1073742022;
+pub const LSEEK: usize = 1073741832;
+pub const LSETXATTR: usize = 1073742013;
+pub const LSTAT: usize = 1073741830;
+pub const MADVISE: usize = 1073741852;
+pub const MBIND: usize = 1073742061;
+pub const MEMBARRIER: usize = 1073742148;
+pub const MEMFD_CREATE: usize = 1073742143;
+pub const MEMFD_SECRET: usize = 1073742271;
+pub const MIGRATE_PAGES: usize = 1073742080;
+pub const MINCORE: usize = 1073741851;
+pub const MKDIR: usize = 1073741907;
+pub const MKDIRAT: usize = 1073742082;
+pub const MKNOD: usize = 1073741957;
+pub const MKNODAT: usize = 1073742083;
+pub const MLOCK: usize = 1073741973;
+pub const MLOCK2: usize = 1073742149;
+pub const MLOCKALL: usize = 1073741975;
+pub const MMAP: usize = 1073741833;
+pub const MODIFY_LDT: usize = 1073741978;
+pub const MOUNT: usize = 1073741989;
+pub const MOUNT_SETATTR: usize = 1073742266;
+pub const MOVE_MOUNT: usize = 1073742253;
+pub const MOVE_PAGES: usize = 1073742357;
+pub const MPROTECT: usize = 1073741834;
+pub const MQ_GETSETATTR: usize = 1073742069;
+pub const MQ_NOTIFY: usize = 1073742351;
+pub const MQ_OPEN: usize = 1073742064;
+pub const MQ_TIMEDRECEIVE: usize = 1073742067;
+pub const MQ_TIMEDSEND: usize = 1073742066;
+pub const MQ_UNLINK: usize = 1073742065;
+pub const MREMAP: usize = 1073741849;
+pub const MSGCTL: usize = 1073741895;
+pub const MSGGET: usize = 1073741892;
+pub const MSGRCV: usize = 1073741894;
+pub const MSGSND: usize = 1073741893;
+pub const MSYNC: usize = 1073741850;
+pub const MUNLOCK: usize = 1073741974;
+pub const MUNLOCKALL: usize = 1073741976;
+pub const MUNMAP: usize = 1073741835;
+pub const NAME_TO_HANDLE_AT: usize = 1073742127;
+pub const NANOSLEEP: usize = 1073741859;
+pub const NEWFSTATAT: usize = 1073742086;
+pub const OPEN: usize = 1073741826;
+pub const OPEN_BY_HANDLE_AT: usize = 1073742128;
+pub const OPEN_TREE: usize = 1073742252;
+pub const OPENAT: usize = 1073742081;
+pub const OPENAT2: usize = 1073742261;
+pub const PAUSE: usize = 1073741858;
+pub const PERF_EVENT_OPEN: usize = 1073742122;
+pub const PERSONALITY: usize = 1073741959;
+pub const PIDFD_GETFD: usize = 1073742262;
+pub const PIDFD_OPEN: usize = 1073742258;
+pub const PIDFD_SEND_SIGNAL: usize = 1073742248;
+pub const PIPE: usize = 1073741846;
+pub const PIPE2: usize = 1073742117;
+pub const PIVOT_ROOT: usize = 1073741979;
+pub const PKEY_ALLOC: usize = 1073742154;
+pub const PKEY_FREE: usize = 1073742155;
+pub const PKEY_MPROTECT: usize = 1073742153;
+pub const POLL: usize = 1073741831;
+pub const PPOLL: usize = 1073742095;
+pub const PRCTL: usize = 1073741981;
+pub const PREAD64: usize = 1073741841;
+pub const PREADV: usize = 1073742358;
+pub const PREADV2: usize = 1073742370;
+pub const PRLIMIT64: usize = 1073742126;
+pub const PROCESS_MADVISE: usize = 1073742264;
+pub const PROCESS_MRELEASE: usize = 1073742272;
+pub const PROCESS_VM_READV: usize = 1073742363;
+pub const PROCESS_VM_WRITEV: usize = 1073742364;
+pub const PSELECT6: usize = 1073742094;
+pub const PTRACE: usize = 1073742345;
+pub const PUTPMSG: usize = 1073742006;
+pub const PWRITE64: usize = 1073741842;
+pub const PWRITEV: usize = 1073742359;
+pub const PWRITEV2: usize = 1073742371;
+pub const QUOTACTL: usize = 1073742003;
+pub const QUOTACTL_FD: usize = 1073742267;
+pub const READ: usize = 1073741824;
+pub const READAHEAD: usize = 1073742011;
+pub const READLINK: usize = 1073741913;
+pub const READLINKAT: usize = 1073742091;
+pub const READV: usize = 1073742339;
+pub const REBOOT: usize = 1073741993;
+pub const RECVFROM: usize = 1073742341;
+pub const RECVMMSG: usize = 1073742361;
+pub const RECVMSG: usize = 1073742343;
+pub const REMAP_FILE_PAGES: usize = 1073742040;
+pub const REMOVEXATTR: usize = 1073742021;
+pub const RENAME: usize = 1073741906;
+pub const RENAMEAT: usize = 1073742088;
+pub const RENAMEAT2: usize = 1073742140;
+pub const REQUEST_KEY: usize = 1073742073;
+pub const RESTART_SYSCALL: usize = 1073742043;
+pub const RMDIR: usize = 1073741908;
+pub const RSEQ: usize = 1073742158;
+pub const RT_SIGACTION: usize = 1073742336;
+pub const RT_SIGPENDING: usize = 1073742346;
+pub const RT_SIGPROCMASK: usize = 1073741838;
+pub const RT_SIGQUEUEINFO: usize = 1073742348;
+pub const RT_SIGRETURN: usize = 1073742337;
+pub const RT_SIGSUSPEND: usize = 1073741954;
+pub const RT_SIGTIMEDWAIT: usize = 1073742347;
+pub const RT_TGSIGQUEUEINFO: usize = 1073742360;
+pub const SCHED_GET_PRIORITY_MAX: usize = 1073741970;
+pub const SCHED_GET_PRIORITY_MIN: usize = 1073741971;
+pub const SCHED_GETAFFINITY: usize = 1073742028;
+pub const SCHED_GETATTR: usize = 1073742139;
+pub const SCHED_GETPARAM: usize = 1073741967;
+pub const SCHED_GETSCHEDULER: usize = 1073741969;
+pub const SCHED_RR_GET_INTERVAL: usize = 1073741972;
+pub const SCHED_SETAFFINITY: usize = 1073742027;
+pub const SCHED_SETATTR: usize = 1073742138;
+pub const SCHED_SETPARAM: usize = 1073741966;
+pub const SCHED_SETSCHEDULER: usize = 1073741968;
+pub const SCHED_YIELD: usize = 1073741848;
+pub const SECCOMP: usize = 1073742141;
+pub const SECURITY: usize = 1073742009;
+pub const SELECT: usize = 1073741847;
+pub const SEMCTL: usize = 1073741890;
+pub const SEMGET: usize = 1073741888;
+pub const SEMOP: usize = 1073741889;
+pub const SEMTIMEDOP: usize = 1073742044;
+pub const SENDFILE: usize = 1073741864;
+pub const SENDMMSG: usize = 1073742362;
+pub const SENDMSG: usize = 1073742342;
+pub const SENDTO: usize = 1073741868;
+pub const SET_MEMPOLICY: usize = 1073742062;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 1073742274;
+pub const SET_ROBUST_LIST: usize = 1073742354;
+pub const SET_TID_ADDRESS: usize = 1073742042;
+pub const SETDOMAINNAME: usize = 1073741995;
+pub const SETFSGID: usize = 1073741947;
+pub const SETFSGID32: usize = 1073741947;
+pub const SETFSUID: usize = 1073741946;
+pub const SETFSUID32: usize = 1073741946;
+pub const SETGID: usize = 1073741930;
+pub const SETGID32: usize = 1073741930;
+pub const SETGROUPS: usize = 1073741940;
+pub const SETGROUPS32: usize = 1073741940;
+pub const SETHOSTNAME: usize = 1073741994;
+pub const SETITIMER: usize = 1073741862;
+pub const SETNS: usize = 1073742132;
+pub const SETPGID: usize = 1073741933;
+pub const SETPRIORITY: usize = 1073741965;
+pub const SETREGID: usize = 1073741938;
+pub const SETREGID32: usize = 1073741938;
+pub const SETRESGID: usize = 1073741943;
+pub const SETRESGID32: usize = 1073741943;
+pub const SETRESUID: usize = 1073741941;
+pub const SETRESUID32: usize = 1073741941;
+pub const SETREUID: usize = 1073741937;
+pub const SETREUID32: usize = 1073741937;
+pub const SETRLIMIT: usize = 1073741984;
+pub const SETSID: usize = 1073741936;
+pub const SETSOCKOPT: usize = 1073742365;
+pub const SETTIMEOFDAY: usize = 1073741988;
+pub const SETUID: usize = 1073741929;
+pub const SETUID32: usize = 1073741929;
+pub const SETXATTR: usize = 1073742012;
+pub const SHMAT: usize = 1073741854;
+pub const SHMCTL: usize = 1073741855;
+pub const SHMDT: usize = 1073741891;
+pub const SHMGET: usize = 1073741853;
+pub const SHUTDOWN: usize = 1073741872;
+pub const SIGALTSTACK: usize = 1073742349;
+pub const SIGNALFD: usize = 1073742106;
+pub const SIGNALFD4: usize = 1073742113;
+pub const SOCKET: usize = 1073741865;
+pub const SOCKETPAIR: usize = 1073741877;
+pub const SPLICE: usize = 1073742099;
+pub const STAT: usize = 1073741828;
+pub const STATFS: usize = 1073741961;
+pub const STATX: usize = 1073742156;
+pub const SWAPOFF: usize = 1073741992;
+pub const SWAPON: usize = 1073741991;
+pub const SYMLINK: usize = 1073741912;
+pub const SYMLINKAT: usize = 1073742090;
+pub const SYNC: usize = 1073741986;
+pub const SYNC_FILE_RANGE: usize = 1073742101;
+pub const SYNCFS: usize = 1073742130;
+pub const SYSFS: usize = 1073741963;
+pub const SYSINFO: usize = 1073741923;
+pub const SYSLOG: usize = 1073741927;
+pub const TEE: usize = 1073742100;
+pub const TGKILL: usize = 1073742058;
+pub const TIME: usize = 1073742025;
+pub const TIMER_CREATE: usize = 1073742350;
+pub const TIMER_DELETE: usize = 1073742050;
+pub const TIMER_GETOVERRUN: usize = 1073742049;
+pub const TIMER_GETTIME: usize = 1073742048;
+pub const TIMER_SETTIME: usize = 1073742047;
+pub const TIMERFD_CREATE: usize = 1073742107;
+pub const TIMERFD_GETTIME: usize = 1073742111;
+pub const TIMERFD_SETTIME: usize = 1073742110;
+pub const TIMES: usize = 1073741924;
+pub const TKILL: usize = 1073742024;
+pub const TRUNCATE: usize = 1073741900;
+pub const TUXCALL: usize = 1073742008;
+pub const UMASK: usize = 1073741919;
+pub const UMOUNT2: usize = 1073741990;
+pub const UNAME: usize = 1073741887;
+pub const UNLINK: usize = 1073741911;
+pub const UNLINKAT: usize = 1073742087;
+pub const UNSHARE: usize = 1073742096;
+pub const USERFAULTFD: usize = 1073742147;
+pub const USTAT: usize = 1073741960;
+pub const UTIME: usize = 1073741956;
+pub const UTIMENSAT: usize = 1073742104;
+pub const UTIMES: usize = 1073742059;
+pub const VFORK: usize = 1073741882;
+pub const VHANGUP: usize = 1073741977;
+pub const VMSPLICE: usize = 1073742356;
+pub const WAIT4: usize = 1073741885;
+pub const WAITID: usize = 1073742353;
+pub const WRITE: usize = 1073741825;
+pub const WRITEV: usize = 1073742340;
+
+sysno_table! {
+    ACCEPT "accept" 3 "int fd, struct sockaddr __user *upeer_sockaddr, int __user *upeer_addrlen",
+    ACCEPT4 "accept4" 4 "int fd, struct sockaddr __user *upeer_sockaddr, int __user *upeer_addrlen, int flags",
+    ACCESS "access" 2 "const char __user *filename, int mode",
+    ACCT "acct" 1 "const char __user *name",
+    ADD_KEY "add_key" 5 "const char __user *_type, const char __user *_description, const void __user *_payload, size_t plen, key_serial_t ringid",
+    ADJTIMEX "adjtimex" 1 "struct __kernel_timex __user *txc_p",
+    AFS_SYSCALL "afs_syscall" _,
+    ALARM "alarm" 1 "unsigned int seconds",
+    ARCH_PRCTL "arch_prctl" 2 "int option, unsigned long arg2",
+    BIND "bind" 3 "int fd, struct sockaddr __user *umyaddr, int addrlen",
+    BPF "bpf" 3 "int cmd, union bpf_attr __user *uattr, unsigned int size",
+    BRK "brk" 1 "unsigned long brk",
+    CAPGET "capget" 2 "cap_user_header_t header, cap_user_data_t dataptr",
+    CAPSET "capset" 2 "cap_user_header_t header, const cap_user_data_t data",
+    CHDIR "chdir" 1 "const char __user *filename",
+    CHMOD "chmod" 2 "const char __user *filename, umode_t mode",
+    CHOWN "chown" 3 "const char __user *filename, uid_t user, gid_t group",
+    CHROOT "chroot" 1 "const char __user *filename",
+    CLOCK_ADJTIME "clock_adjtime" 2 "const clockid_t which_clock, struct __kernel_timex __user *utx",
+    CLOCK_GETRES "clock_getres" 2 "const clockid_t which_clock, struct __kernel_timespec __user *tp",
+    CLOCK_GETTIME "clock_gettime" 2 "const clockid_t which_clock, struct __kernel_timespec __user *tp",
+    CLOCK_NANOSLEEP "clock_nanosleep" 4 "const clockid_t which_clock, int flags, const struct __kernel_timespec __user *rqtp, struct __kernel_timespec __user *rmtp",
+    CLOCK_SETTIME "clock_settime" 2 "const clockid_t which_clock, const struct __kernel_timespec __user *tp",
+    CLONE "clone" 5 "unsigned long clone_flags, unsigned long newsp, int __user *parent_tidptr, int __user *child_tidptr, unsigned long tls",
+    CLONE3 "clone3" 2 "struct clone_args __user *uargs, size_t size",
+    CLOSE "close" 1 "unsigned int fd",
+    CLOSE_RANGE "close_range" 3 "unsigned int fd, unsigned int max_fd, unsigned int flags",
+    CONNECT "connect" 3 "int fd, struct sockaddr __user *uservaddr, int addrlen",
+    COPY_FILE_RANGE "copy_file_range" 6 "int fd_in, loff_t __user *off_in, int fd_out, loff_t __user *off_out, size_t len, unsigned int flags",
+    CREAT "creat" 2 "const char __user *pathname, umode_t mode",
+    DELETE_MODULE "delete_module" 2 "const char __user *name_user, unsigned int flags",
+    DUP "dup" 1 "unsigned int fildes",
+    DUP2 "dup2" 2 "unsigned int oldfd, unsigned int newfd",
+    DUP3 "dup3" 3 "unsigned int oldfd, unsigned int newfd, int flags",
+    EPOLL_CREATE "epoll_create" 1 "int size",
+    EPOLL_CREATE1 "epoll_create1" 1 "int flags",
+    EPOLL_CTL "epoll_ctl" 4 "int epfd, int op, int fd, struct epoll_event __user *event",
+    EPOLL_PWAIT "epoll_pwait" 6 "int epfd, struct epoll_event __user *events, int maxevents, int timeout, const sigset_t __user *sigmask, size_t sigsetsize",
+    EPOLL_PWAIT2 "epoll_pwait2" 6 "int epfd, struct epoll_event __user *events, int maxevents, const struct __kernel_timespec __user *timeout, const sigset_t __user *sigmask, size_t sigsetsize",
+    EPOLL_WAIT "epoll_wait" 4 "int epfd, struct epoll_event __user *events, int maxevents, int timeout",
+    EVENTFD "eventfd" 1 "unsigned int count",
+    EVENTFD2 "eventfd2" 2 "unsigned int count, int flags",
+    EXECVE "execve" _,
+    EXECVEAT "execveat" _,
+    EXIT "exit" 1 "int error_code",
+    EXIT_GROUP "exit_group" 1 "int error_code",
+    FACCESSAT "faccessat" 3 "int dfd, const char __user *filename, int mode",
+    FACCESSAT2 "faccessat2" 4 "int dfd, const char __user *filename, int mode, int flags",
+    FADVISE64 "fadvise64" 4 "int fd, loff_t offset, size_t len, int advice",
+    FALLOCATE "fallocate" 4 "int fd, int mode, loff_t offset, loff_t len",
+    FANOTIFY_INIT "fanotify_init" 2 "unsigned int flags, unsigned int event_f_flags",
+    FANOTIFY_MARK "fanotify_mark" 5 "int fanotify_fd, unsigned int flags, __u64 mask, int dfd, const char __user *pathname",
+    FCHDIR "fchdir" 1 "unsigned int fd",
+    FCHMOD "fchmod" 2 "unsigned int fd, umode_t mode",
+    FCHMODAT "fchmodat" 3 "int dfd, const char __user *filename, umode_t mode",
+    FCHOWN "fchown" 3 "unsigned int fd, uid_t user, gid_t group",
+    FCHOWNAT "fchownat" 5 "int dfd, const char __user *filename, uid_t user, gid_t group, int flag",
+    FCNTL "fcntl" 3 "unsigned int fd, unsigned int cmd, unsigned long arg",
+    FDATASYNC "fdatasync" 1 "unsigned int fd",
+    FGETXATTR "fgetxattr" 4 "int fd, const char __user *name, void __user *value, size_t size",
+    FINIT_MODULE "finit_module" 3 "int fd, const char __user *uargs, int flags",
+    FLISTXATTR "flistxattr" 3 "int fd, char __user *list, size_t size",
+    FLOCK "flock" 2 "unsigned int fd, unsigned int cmd",
+    FORK "fork" 0 "",
+    FREMOVEXATTR "fremovexattr" 2 "int fd, const char __user *name",
+    FSCONFIG "fsconfig" 5 "int fd, unsigned int cmd, const char __user *_key, const void __user *_value, int aux",
+    FSETXATTR "fsetxattr" 5 "int fd, const char __user *name, const void __user *value, size_t size, int flags",
+    FSMOUNT "fsmount" 3 "int fs_fd, unsigned int flags, unsigned int attr_flags",
+    FSOPEN "fsopen" 2 "const char __user *_fs_name, unsigned int flags",
+    FSPICK "fspick" 3 "int dfd, const char __user *path, unsigned int flags",
+    FSTAT "fstat" 2 "unsigned int fd, struct stat __user *statbuf",
+    FSTATFS "fstatfs" 2 "unsigned int fd, struct statfs __user *buf",
+    FSYNC "fsync" 1 "unsigned int fd",
+    FTRUNCATE "ftruncate" 2 "unsigned int fd, unsigned long length",
+    FUTEX "futex" 6 "u32 __user *uaddr, int op, u32 val, const struct __kernel_timespec __user *utime, u32 __user *uaddr2, u32 val3",
+    FUTEX_WAITV "futex_waitv" 5 "struct futex_waitv __user *waiters, unsigned int nr_futexes, unsigned int flags, struct __kernel_timespec __user *timeout, clockid_t clockid",
+    FUTIMESAT "futimesat" 3 "int dfd, const char __user *filename, struct __kernel_old_timeval __user *utimes",
+    GET_MEMPOLICY "get_mempolicy" 5 "int __user *policy, unsigned long __user *nmask, unsigned long maxnode, unsigned long addr, unsigned long flags",
+    GET_ROBUST_LIST "get_robust_list" _,
+    GETCPU "getcpu" 3 "unsigned __user *cpup, unsigned __user *nodep, struct getcpu_cache __user *unused",
+    GETCWD "getcwd" 2 "char __user *buf, unsigned long size",
+    GETDENTS "getdents" 3 "unsigned int fd, struct linux_dirent __user *dirent, unsigned int count",
+    GETDENTS64 "getdents64" 3 "unsigned int fd, struct linux_dirent64 __user *dirent, unsigned int count",
+    GETEGID "getegid" 0 "",
+    GETEUID "geteuid" 0 "",
+    GETGID "getgid" 0 "",
+    GETGROUPS "getgroups" 2 "int gidsetsize, gid_t __user *grouplist",
+    GETITIMER "getitimer" 2 "int which, struct __kernel_old_itimerval __user *value",
+    GETPEERNAME "getpeername" 3 "int fd, struct sockaddr __user *usockaddr, int __user *usockaddr_len",
+    GETPGID "getpgid" 1 "pid_t pid",
+    GETPGRP "getpgrp" 0 "",
+    GETPID "getpid" 0 "",
+    GETPMSG "getpmsg" _,
+    GETPPID "getppid" 0 "",
+    GETPRIORITY "getpriority" 2 "int which, int who",
+    GETRANDOM "getrandom" 3 "char __user *ubuf, size_t len, unsigned int flags",
+    GETRESGID "getresgid" 3 "gid_t __user *rgidp, gid_t __user *egidp, gid_t __user *sgidp",
+    GETRESUID "getresuid" 3 "uid_t __user *ruidp, uid_t __user *euidp, uid_t __user *suidp",
+    GETRLIMIT "getrlimit" 2 "unsigned int resource, struct rlimit __user *rlim",
+    GETRUSAGE "getrusage" 2 "int who, struct rusage __user *ru",
+    GETSID "getsid" 1 "pid_t pid",
+    GETSOCKNAME "getsockname" 3 "int fd, struct sockaddr __user *usockaddr, int __user *usockaddr_len",
+    GETSOCKOPT "getsockopt" 5 "int fd, int level, int optname, char __user *optval, int __user *optlen",
+    GETTID "gettid" 0 "",
+    GETTIMEOFDAY "gettimeofday" 2 "struct __kernel_old_timeval __user *tv, struct timezone __user *tz",
+    GETUID "getuid" 0 "",
+    GETXATTR "getxattr" 4 "const char __user *pathname, const char __user *name, void __user *value, size_t size",
+    INIT_MODULE "init_module" 3 "void __user *umod, unsigned long len, const char __user *uargs",
+    INOTIFY_ADD_WATCH "inotify_add_watch" 3 "int fd, const char __user *pathname, u32 mask",
+    INOTIFY_INIT "inotify_init" 0 "",
+    INOTIFY_INIT1 "inotify_init1" 1 "int flags",
+    INOTIFY_RM_WATCH "inotify_rm_watch" 2 "int fd, __s32 wd",
+    IO_CANCEL "io_cancel" 3 "aio_context_t ctx_id, struct iocb __user *iocb, struct io_event __user *result",
+    IO_DESTROY "io_destroy" 1 "aio_context_t ctx",
+    IO_GETEVENTS "io_getevents" 5 "aio_context_t ctx_id, long min_nr, long nr, struct io_event __user *events, struct __kernel_timespec __user *timeout",
+    IO_PGETEVENTS "io_pgetevents" 6 "aio_context_t ctx_id, long min_nr, long nr, struct io_event __user *events, struct __kernel_timespec __user *timeout, const struct __aio_sigset __user *usig",
+    IO_SETUP "io_setup" _,
+    IO_SUBMIT "io_submit" _,
+    IO_URING_ENTER "io_uring_enter" 6 "unsigned int fd, u32 to_submit, u32 min_complete, u32 flags, const void __user *argp, size_t argsz",
+    IO_URING_REGISTER "io_uring_register" 4 "unsigned int fd, unsigned int opcode, void __user *arg, unsigned int nr_args",
+    IO_URING_SETUP "io_uring_setup" 2 "u32 entries, struct io_uring_params __user *params",
+    IOCTL "ioctl" _,
+    IOPERM "ioperm" 3 "unsigned long from, unsigned long num, int turn_on",
+    IOPL "iopl" 1 "unsigned int level",
+    IOPRIO_GET "ioprio_get" 2 "int which, int who",
+    IOPRIO_SET "ioprio_set" 3 "int which, int who, int ioprio",
+    KCMP "kcmp" 5 "pid_t pid1, pid_t pid2, int type, unsigned long idx1, unsigned long idx2",
+    KEXEC_FILE_LOAD "kexec_file_load" 5 "int kernel_fd, int initrd_fd, unsigned long cmdline_len, const char __user *cmdline_ptr, unsigned long flags",
+    KEXEC_LOAD "kexec_load" _,
+    KEYCTL "keyctl" 5 "int option, unsigned long arg2, unsigned long arg3, unsigned long arg4, unsigned long arg5",
+    KILL "kill" 2 "pid_t pid, int sig",
+    LANDLOCK_ADD_RULE "landlock_add_rule" 4 "const int ruleset_fd, const enum landlock_rule_type rule_type, const void __user *const rule_attr, const __u32 flags",
+    LANDLOCK_CREATE_RULESET "landlock_create_ruleset" 3 "const struct landlock_ruleset_attr __user *const attr, const size_t size, const __u32 flags",
+    LANDLOCK_RESTRICT_SELF "landlock_restrict_self" 2 "const int ruleset_fd, const __u32 flags",
+    LCHOWN "lchown" 3 "const char __user *filename, uid_t user, gid_t group",
+    LGETXATTR "lgetxattr" 4 "const char __user *pathname, const char __user *name, void __user *value, size_t size",
+    LINK "link" 2 "const char __user *oldname, const char __user *newname",
+    LINKAT "linkat" 5 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname, int flags",
+    LISTEN "listen" 2 "int fd, int backlog",
+    LISTXATTR "listxattr" 3 "const char __user *pathname, char __user *list, size_t size",
+    LLISTXATTR "llistxattr" 3 "const char __user *pathname, char __user *list, size_t size",
+    LOOKUP_DCOOKIE "lookup_dcookie" 3 "u64 cookie64, char __user *buf, size_t len",
+    LREMOVEXATTR "lremovexattr" 2 "const char __user *pathname, const char __user *name",
+    LSEEK "lseek" 3 "unsigned int fd, off_t offset, unsigned int whence",
+    LSETXATTR "lsetxattr" 5 "const char __user *pathname, const char __user *name, const void __user *value, size_t size, int flags",
+    LSTAT "lstat" 2 "const char __user *filename, struct stat __user *statbuf",
+    MADVISE "madvise" 3 "unsigned long start, size_t len_in, int behavior",
+    MBIND "mbind" 6 "unsigned long start, unsigned long len, unsigned long mode, const unsigned long __user *nmask, unsigned long maxnode, unsigned int flags",
+    MEMBARRIER "membarrier" 3 "int cmd, unsigned int flags, int cpu_id",
+    MEMFD_CREATE "memfd_create" 2 "const char __user *uname, unsigned int flags",
+    MEMFD_SECRET "memfd_secret" 1 "unsigned int flags",
+    MIGRATE_PAGES "migrate_pages" 4 "pid_t pid, unsigned long maxnode, const unsigned long __user *old_nodes, const unsigned long __user *new_nodes",
+    MINCORE "mincore" 3 "unsigned long start, size_t len, unsigned char __user *vec",
+    MKDIR "mkdir" 2 "const char __user *pathname, umode_t mode",
+    MKDIRAT "mkdirat" 3 "int dfd, const char __user *pathname, umode_t mode",
+    MKNOD "mknod" 3 "const char __user *filename, umode_t mode, unsigned dev",
+    MKNODAT "mknodat" 4 "int dfd, const char __user *filename, umode_t mode, unsigned int dev",
+    MLOCK "mlock" 2 "unsigned long start, size_t len",
+    MLOCK2 "mlock2" 3 "unsigned long start, size_t len, int flags",
+    MLOCKALL "mlockall" 1 "int flags",
+    MMAP "mmap" 6 "unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off",
+    MODIFY_LDT "modify_ldt" 3 "int func, void __user *ptr, unsigned long bytecount",
+    MOUNT "mount" 5 "char __user *dev_name, char __user *dir_name, char __user *type, unsigned long flags, void __user *data",
+    MOUNT_SETATTR "mount_setattr" 5 "int dfd, const char __user *path, unsigned int flags, struct mount_attr __user *uattr, size_t usize",
+    MOVE_MOUNT "move_mount" 5 "int from_dfd, const char __user *from_pathname, int to_dfd, const char __user *to_pathname, unsigned int flags",
+    MOVE_PAGES "move_pages" 6 "pid_t pid, unsigned long nr_pages, const void __user * __user *pages, const int __user *nodes, int __user *status, int flags",
+    MPROTECT "mprotect" 3 "unsigned long start, size_t len, unsigned long prot",
+    MQ_GETSETATTR "mq_getsetattr" 3 "mqd_t mqdes, const struct mq_attr __user *u_mqstat, struct mq_attr __user *u_omqstat",
+    MQ_NOTIFY "mq_notify" _,
+    MQ_OPEN "mq_open" 4 "const char __user *u_name, int oflag, umode_t mode, struct mq_attr __user *u_attr",
+    MQ_TIMEDRECEIVE "mq_timedreceive" 5 "mqd_t mqdes, char __user *u_msg_ptr, size_t msg_len, unsigned int __user *u_msg_prio, const struct __kernel_timespec __user *u_abs_timeout",
+    MQ_TIMEDSEND "mq_timedsend" 5 "mqd_t mqdes, const char __user *u_msg_ptr, size_t msg_len, unsigned int msg_prio, const struct __kernel_timespec __user *u_abs_timeout",
+    MQ_UNLINK "mq_unlink" 1 "const char __user *u_name",
+    MREMAP "mremap" 5 "unsigned long addr, unsigned long old_len, unsigned long new_len, unsigned long flags, unsigned long new_addr",
+    MSGCTL "msgctl" 3 "int msqid, int cmd, struct msqid_ds __user *buf",
+    MSGGET "msgget" 2 "key_t key, int msgflg",
+    MSGRCV "msgrcv" 5 "int msqid, struct msgbuf __user *msgp, size_t msgsz, long msgtyp, int msgflg",
+    MSGSND "msgsnd" 4 "int msqid, struct msgbuf __user *msgp, size_t msgsz, int msgflg",
+    MSYNC "msync" 3 "unsigned long start, size_t len, int flags",
+    MUNLOCK "munlock" 2 "unsigned long start, size_t len",
+    MUNLOCKALL "munlockall" 0 "",
+    MUNMAP "munmap" 2 "unsigned long addr, size_t len",
+    NAME_TO_HANDLE_AT "name_to_handle_at" 5 "int dfd, const char __user *name, struct file_handle __user *handle, int __user *mnt_id, int flag",
+    NANOSLEEP "nanosleep" 2 "struct __kernel_timespec __user *rqtp, struct __kernel_timespec __user *rmtp",
+    NEWFSTATAT "newfstatat" 4 "int dfd, const char __user *filename, struct stat __user *statbuf, int flag",
+    OPEN "open" 3 "const char __user *filename, int flags, umode_t mode",
+    OPEN_BY_HANDLE_AT "open_by_handle_at" 3 "int mountdirfd, struct file_handle __user *handle, int flags",
+    OPEN_TREE "open_tree" 3 "int dfd, const char __user *filename, unsigned flags",
+    OPENAT "openat" 4 "int dfd, const char __user *filename, int flags, umode_t mode",
+    OPENAT2 "openat2" 4 "int dfd, const char __user *filename, struct open_how __user *how, size_t usize",
+    PAUSE "pause" 0 "",
+    PERF_EVENT_OPEN "perf_event_open" 5 "struct perf_event_attr __user *attr_uptr, pid_t pid, int cpu, int group_fd, unsigned long flags",
+    PERSONALITY "personality" 1 "unsigned int personality",
+    PIDFD_GETFD "pidfd_getfd" 3 "int pidfd, int fd, unsigned int flags",
+    PIDFD_OPEN "pidfd_open" 2 "pid_t pid, unsigned int flags",
+    PIDFD_SEND_SIGNAL "pidfd_send_signal" 4 "int pidfd, int sig, siginfo_t __user *info, unsigned int flags",
+    PIPE "pipe" 1 "int __user *fildes",
+    PIPE2 "pipe2" 2 "int __user *fildes, int flags",
+    PIVOT_ROOT "pivot_root" 2 "const char __user *new_root, const char __user *put_old",
+    PKEY_ALLOC "pkey_alloc" 2 "unsigned long flags, unsigned long init_val",
+    PKEY_FREE "pkey_free" 1 "int pkey",
+    PKEY_MPROTECT "pkey_mprotect" 4 "unsigned long start, size_t len, unsigned long prot, int pkey",
+    POLL "poll" 3 "struct pollfd __user *ufds, unsigned int nfds, int timeout_msecs",
+    PPOLL "ppoll" 5 "struct pollfd __user *ufds, unsigned int nfds, struct __kernel_timespec __user *tsp, const sigset_t __user *sigmask, size_t sigsetsize",
+    PRCTL "prctl" 5 "int option, unsigned long arg2, unsigned long arg3, unsigned long arg4, unsigned long arg5",
+    PREAD64 "pread64" 4 "unsigned int fd, char __user *buf, size_t count, loff_t pos",
+    PREADV "preadv" _,
+    PREADV2 "preadv2" _,
+    PRLIMIT64 "prlimit64" 4 "pid_t pid, unsigned int resource, const struct rlimit64 __user *new_rlim, struct rlimit64 __user *old_rlim",
+    PROCESS_MADVISE "process_madvise" 5 "int pidfd, const struct iovec __user *vec, size_t vlen, int behavior, unsigned int flags",
+    PROCESS_MRELEASE "process_mrelease" 2 "int pidfd, unsigned int flags",
+    PROCESS_VM_READV "process_vm_readv" 6 "pid_t pid, const struct iovec __user *lvec, unsigned long liovcnt, const struct iovec __user *rvec, unsigned long riovcnt, unsigned long flags",
+    PROCESS_VM_WRITEV "process_vm_writev" 6 "pid_t pid, const struct iovec __user *lvec, unsigned long liovcnt, const struct iovec __user *rvec, unsigned long riovcnt, unsigned long flags",
+    PSELECT6 "pselect6" 6 "int n, fd_set __user *inp, fd_set __user *outp, fd_set __user *exp, struct __kernel_timespec __user *tsp, void __user *sig",
+    PTRACE "ptrace" _,
+    PUTPMSG "putpmsg" _,
+    PWRITE64 "pwrite64" 4 "unsigned int fd, const char __user *buf, size_t count, loff_t pos",
+    PWRITEV "pwritev" _,
+    PWRITEV2 "pwritev2" _,
+    QUOTACTL "quotactl" 4 "unsigned int cmd, const char __user *special, qid_t id, void __user *addr",
+    QUOTACTL_FD "quotactl_fd" 4 "unsigned int fd, unsigned int cmd, qid_t id, void __user *addr",
+    READ "read" 3 "unsigned int fd, char __user *buf, size_t count",
+    READAHEAD "readahead" 3 "int fd, loff_t offset, size_t count",
+    READLINK "readlink" 3 "const char __user *path, char __user *buf, int bufsiz",
+    READLINKAT "readlinkat" 4 "int dfd, const char __user *pathname, char __user *buf, int bufsiz",
+    READV "readv" 3 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen",
+    REBOOT "reboot" 4 "int magic1, int magic2, unsigned int cmd, void __user *arg",
+    RECVFROM "recvfrom" _,
+    RECVMMSG "recvmmsg" _,
+    RECVMSG "recvmsg" _,
+    REMAP_FILE_PAGES "remap_file_pages" 5 "unsigned long start, unsigned long size, unsigned long prot, unsigned long pgoff, unsigned long flags",
+    REMOVEXATTR "removexattr" 2 "const char __user *pathname, const char __user *name",
+    RENAME "rename" 2 "const char __user *oldname, const char __user *newname",
+    RENAMEAT "renameat" 4 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname",
+    RENAMEAT2 "renameat2" 5 "int olddfd, const char __user *oldname, int newdfd, const char __user *newname, unsigned int flags",
+    REQUEST_KEY "request_key" 4 "const char __user *_type, const char __user *_description, const char __user *_callout_info, key_serial_t destringid",
+    RESTART_SYSCALL "restart_syscall" 0 "",
+    RMDIR "rmdir" 1 "const char __user *pathname",
+    RSEQ "rseq" 4 "struct rseq __user *rseq, u32 rseq_len, int flags, u32 sig",
+    RT_SIGACTION "rt_sigaction" _,
+    RT_SIGPENDING "rt_sigpending" _,
+    RT_SIGPROCMASK "rt_sigprocmask" 4 "int how, sigset_t __user *nset, sigset_t __user *oset, size_t sigsetsize",
+    RT_SIGQUEUEINFO "rt_sigqueueinfo" _,
+    RT_SIGRETURN "rt_sigreturn" _,
+    RT_SIGSUSPEND "rt_sigsuspend" 2 "sigset_t __user *unewset, size_t sigsetsize",
+    RT_SIGTIMEDWAIT "rt_sigtimedwait" _,
+    RT_TGSIGQUEUEINFO "rt_tgsigqueueinfo" _,
+    SCHED_GET_PRIORITY_MAX "sched_get_priority_max" 1 "int policy",
+    SCHED_GET_PRIORITY_MIN "sched_get_priority_min" 1 "int policy",
+    SCHED_GETAFFINITY "sched_getaffinity" 3 "pid_t pid, unsigned int len, unsigned long __user *user_mask_ptr",
+    SCHED_GETATTR "sched_getattr" 4 "pid_t pid, struct sched_attr __user *uattr, unsigned int usize, unsigned int flags",
+    SCHED_GETPARAM "sched_getparam" 2 "pid_t pid, struct sched_param __user *param",
+    SCHED_GETSCHEDULER "sched_getscheduler" 1 "pid_t pid",
+    SCHED_RR_GET_INTERVAL "sched_rr_get_interval" 2 "pid_t pid, struct __kernel_timespec __user *interval",
+    SCHED_SETAFFINITY "sched_setaffinity" 3 "pid_t pid, unsigned int len, unsigned long __user *user_mask_ptr",
+    SCHED_SETATTR "sched_setattr" 3 "pid_t pid, struct sched_attr __user *uattr, unsigned int flags",
+    SCHED_SETPARAM "sched_setparam" 2 "pid_t pid, struct sched_param __user *param",
+    SCHED_SETSCHEDULER "sched_setscheduler" 3 "pid_t pid, int policy, struct sched_param __user *param",
+    SCHED_YIELD "sched_yield" 0 "",
+    SECCOMP "seccomp" 3 "unsigned int op, unsigned int flags, void __user *uargs",
+    SECURITY "security" _,
+    SELECT "select" 5 "int n, fd_set __user *inp, fd_set __user *outp, fd_set __user *exp, struct __kernel_old_timeval __user *tvp",
+    SEMCTL "semctl" 4 "int semid, int semnum, int cmd, unsigned long arg",
+    SEMGET "semget" 3 "key_t key, int nsems, int semflg",
+    SEMOP "semop" 3 "int semid, struct sembuf __user *tsops, unsigned nsops",
+    SEMTIMEDOP "semtimedop" 4 "int semid, struct sembuf __user *tsops, unsigned int nsops, const struct __kernel_timespec __user *timeout",
+    SENDFILE "sendfile" 4 "int out_fd, int in_fd, off_t __user *offset, size_t count",
+    SENDMMSG "sendmmsg" _,
+    SENDMSG "sendmsg" _,
+    SENDTO "sendto" 6 "int fd, void __user *buff, size_t len, unsigned int flags, struct sockaddr __user *addr, int addr_len",
+    SET_MEMPOLICY "set_mempolicy" 3 "int mode, const unsigned long __user *nmask, unsigned long maxnode",
+    SET_MEMPOLICY_HOME_NODE "set_mempolicy_home_node" 4 "unsigned long start, unsigned long len, unsigned long home_node, unsigned long flags",
+    SET_ROBUST_LIST "set_robust_list" _,
+    SET_TID_ADDRESS "set_tid_address" 1 "int __user *tidptr",
+    SETDOMAINNAME "setdomainname" 2 "char __user *name, int len",
+    SETFSGID "setfsgid" 1 "gid_t gid",
+    SETFSUID "setfsuid" 1 "uid_t uid",
+    SETGID "setgid" 1 "gid_t gid",
+    SETGROUPS "setgroups" 2 "int gidsetsize, gid_t __user *grouplist",
+    SETHOSTNAME "sethostname" 2 "char __user *name, int len",
+    SETITIMER "setitimer" 3 "int which, struct __kernel_old_itimerval __user *value, struct __kernel_old_itimerval __user *ovalue",
+    SETNS "setns" 2 "int fd, int flags",
+    SETPGID "setpgid" 2 "pid_t pid, pid_t pgid",
+    SETPRIORITY "setpriority" 3 "int which, int who, int niceval",
+    SETREGID "setregid" 2 "gid_t rgid, gid_t egid",
+    SETRESGID "setresgid" 3 "gid_t rgid, gid_t egid, gid_t sgid",
+    SETRESUID "setresuid" 3 "uid_t ruid, uid_t euid, uid_t suid",
+    SETREUID "setreuid" 2 "uid_t ruid, uid_t euid",
+    SETRLIMIT "setrlimit" 2 "unsigned int resource, struct rlimit __user *rlim",
+    SETSID "setsid" 0 "",
+    SETSOCKOPT "setsockopt" 5 "int fd, int level, int optname, char __user *optval, int optlen",
+    SETTIMEOFDAY "settimeofday" 2 "struct __kernel_old_timeval __user *tv, struct timezone __user *tz",
+    SETUID "setuid" 1 "uid_t uid",
+    SETXATTR "setxattr" 5 "const char __user *pathname, const char __user *name, const void __user *value, size_t size, int flags",
+    SHMAT "shmat" 3 "int shmid, char __user *shmaddr, int shmflg",
+    SHMCTL "shmctl" 3 "int shmid, int cmd, struct shmid_ds __user *buf",
+    SHMDT "shmdt" 1 "char __user *shmaddr",
+    SHMGET "shmget" 3 "key_t key, size_t size, int shmflg",
+    SHUTDOWN "shutdown" 2 "int fd, int how",
+    SIGALTSTACK "sigaltstack" _,
+    SIGNALFD "signalfd" 3 "int ufd, sigset_t __user *user_mask, size_t sizemask",
+    SIGNALFD4 "signalfd4" 4 "int ufd, sigset_t __user *user_mask, size_t sizemask, int flags",
+    SOCKET "socket" 3 "int family, int type, int protocol",
+    SOCKETPAIR "socketpair" 4 "int family, int type, int protocol, int __user *usockvec",
+    SPLICE "splice" 6 "int fd_in, loff_t __user *off_in, int fd_out, loff_t __user *off_out, size_t len, unsigned int flags",
+    STAT "stat" 2 "const char __user *filename, struct stat __user *statbuf",
+    STATFS "statfs" 2 "const char __user *pathname, struct statfs __user *buf",
+    STATX "statx" 5 "int dfd, const char __user *filename, unsigned flags, unsigned int mask, struct statx __user *buffer",
+    SWAPOFF "swapoff" 1 "const char __user *specialfile",
+    SWAPON "swapon" 2 "const char __user *specialfile, int swap_flags",
+    SYMLINK "symlink" 2 "const char __user *oldname, const char __user *newname",
+    SYMLINKAT "symlinkat" 3 "const char __user *oldname, int newdfd, const char __user *newname",
+    SYNC "sync" 0 "",
+    SYNC_FILE_RANGE "sync_file_range" 4 "int fd, loff_t offset, loff_t nbytes, unsigned int flags",
+    SYNCFS "syncfs" 1 "int fd",
+    SYSFS "sysfs" 3 "int option, unsigned long arg1, unsigned long arg2",
+    SYSINFO "sysinfo" 1 "struct sysinfo __user *info",
+    SYSLOG "syslog" 3 "int type, char __user *buf, int len",
+    TEE "tee" 4 "int fdin, int fdout, size_t len, unsigned int flags",
+    TGKILL "tgkill" 3 "pid_t tgid, pid_t pid, int sig",
+    TIME "time" 1 "__kernel_old_time_t __user *tloc",
+    TIMER_CREATE "timer_create" _,
+    TIMER_DELETE "timer_delete" 1 "timer_t timer_id",
+    TIMER_GETOVERRUN "timer_getoverrun" 1 "timer_t timer_id",
+    TIMER_GETTIME "timer_gettime" 2 "timer_t timer_id, struct __kernel_itimerspec __user *setting",
+    TIMER_SETTIME "timer_settime" 4 "timer_t timer_id, int flags, const struct __kernel_itimerspec __user *new_setting, struct __kernel_itimerspec __user *old_setting",
+    TIMERFD_CREATE "timerfd_create" 2 "int clockid, int flags",
+    TIMERFD_GETTIME "timerfd_gettime" 2 "int ufd, struct __kernel_itimerspec __user *otmr",
+    TIMERFD_SETTIME "timerfd_settime" 4 "int ufd, int flags, const struct __kernel_itimerspec __user *utmr, struct __kernel_itimerspec __user *otmr",
+    TIMES "times" 1 "struct tms __user *tbuf",
+    TKILL "tkill" 2 "pid_t pid, int sig",
+    TRUNCATE "truncate" 2 "const char __user *path, long length",
+    TUXCALL "tuxcall" _,
+    UMASK "umask" 1 "int mask",
+    UMOUNT2 "umount2" 2 "char __user *name, int flags",
+    UNAME "uname" 1 "struct new_utsname __user *name",
+    UNLINK "unlink" 1 "const char __user *pathname",
+    UNLINKAT "unlinkat" 3 "int dfd, const char __user *pathname, int flag",
+    UNSHARE "unshare" 1 "unsigned long unshare_flags",
+    USERFAULTFD "userfaultfd" 1 "int flags",
+    USTAT "ustat" 2 "unsigned dev, struct ustat __user *ubuf",
+    UTIME "utime" 2 "char __user *filename, struct utimbuf __user *times",
+    UTIMENSAT "utimensat" 4 "int dfd, const char __user *filename, struct __kernel_timespec __user *utimes, int flags",
+    UTIMES "utimes" 2 "char __user *filename, struct __kernel_old_timeval __user *utimes",
+    VFORK "vfork" 0 "",
+    VHANGUP "vhangup" 0 "",
+    VMSPLICE "vmsplice" 4 "int fd, const struct iovec __user *uiov, unsigned long nr_segs, unsigned int flags",
+    WAIT4 "wait4" 4 "pid_t upid, int __user *stat_addr, int options, struct rusage __user *ru",
+    WAITID "waitid" _,
+    WRITE "write" 3 "unsigned int fd, const char __user *buf, size_t count",
+    WRITEV "writev" 3 "unsigned long fd, const struct iovec __user *vec, unsigned long vlen",
+    @alias GETEGID32 = GETEGID,
+    @alias GETEUID32 = GETEUID,
+    @alias GETGID32 = GETGID,
+    @alias GETGROUPS32 = GETGROUPS,
+    @alias GETRESGID32 = GETRESGID,
+    @alias GETRESUID32 = GETRESUID,
+    @alias GETUID32 = GETUID,
+    @alias SETFSGID32 = SETFSGID,
+    @alias SETFSUID32 = SETFSUID,
+    @alias SETGID32 = SETGID,
+    @alias SETGROUPS32 = SETGROUPS,
+    @alias SETREGID32 = SETREGID,
+    @alias SETRESGID32 = SETRESGID,
+    @alias SETRESUID32 = SETRESUID,
+    @alias SETREUID32 = SETREUID,
+    @alias SETUID32 = SETUID,
+}
@@ -0,0 +1,16 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, %rsp points to `argc`. The ABI requires %rbp to be cleared to mark the outermost frame,
+// and %rsp to be 16-byte aligned before the `call`.
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    "xor ebp, ebp",
+    "mov rdi, rsp",
+    "and rsp, -16",
+    "call _scall_internal_start",
+    "ud2",
+    ".size _start, . - _start",
+);
@@ -9,11 +9,21 @@
 
 //! This library was built for x86 Linux.
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
+
+#[cfg(feature = "rt")]
+mod start;
+
+mod restore;
+pub(crate) use restore::*;
 
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> usize {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "int $$0x80",
@@ -23,7 +33,8 @@ pub unsafe fn syscall0(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "int $$0x80",
@@ -34,7 +45,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "int $$0x80",
@@ -46,7 +58,8 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3(n: impl crate::SyscallNumber, a1: usize, a2: usize, a3: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "int $$0x80",
@@ -59,7 +72,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     // esi is LLVM-reserved, so we have to save and restore it
     let ret: usize;
     asm!(
@@ -81,7 +101,15 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 // the stack afterward)
 
 #[inline(always)]
-pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5: usize) -> usize {
+pub unsafe fn syscall5(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     let final_args = [a4, a5];
 
@@ -102,7 +130,7 @@ pub unsafe fn syscall5(n: usize, a1: usize, a2: usize, a3: usize, a4: usize, a5:
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -110,6 +138,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     let final_args = [a4, a5, a6];
 
@@ -131,6 +160,49 @@ pub unsafe fn syscall6(
     ret
 }
 
+// Used by `clone_with_stack()` and `clone3_with_stack()`. In the child, this calls the function
+// whose address is stored at the top of the new stack (passing it the new stack pointer), and then
+// exits with the value that function returns.
+#[inline]
+pub(crate) unsafe fn raw_clone(
+    n: usize,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+    a5: usize,
+) -> usize {
+    let ret: usize;
+    let final_args = [a4, a5];
+
+    asm!(
+        "push esi",
+        "mov esi, [edi + 0]",
+        "mov edi, [edi + 4]",
+        "int $$0x80",
+        "test eax, eax",
+        "jnz 2f",
+        "xor ebp, ebp",
+        "mov eax, esp",
+        "sub esp, 12",
+        "push eax",
+        "call dword ptr [eax]",
+        "mov ebx, eax",
+        "mov eax, {exit}",
+        "int $$0x80",
+        "ud2",
+        "2:",
+        "pop esi",
+        exit = const crate::nr::EXIT,
+        inout("eax") n => ret,
+        in("ebx") a1,
+        in("ecx") a2,
+        in("edx") a3,
+        inout("edi") final_args.as_ptr() => _,
+    );
+    ret
+}
+
 pub use syscall0 as syscall0_nofail;
 pub use syscall1 as syscall1_nofail;
 pub use syscall2 as syscall2_nofail;
@@ -0,0 +1,31 @@
+// The signal return trampolines used as `sa_restorer` by `signal::sigaction()`.
+//
+// Handlers installed without `SA_SIGINFO` get an old-style signal frame, which starts with the
+// signal number (popped before calling `sigreturn()`); handlers installed with `SA_SIGINFO` get an
+// `rt_sigreturn()` frame.
+global_asm!(
+    ".text",
+    ".globl _scall_internal_restore",
+    ".hidden _scall_internal_restore",
+    ".type _scall_internal_restore, @function",
+    "_scall_internal_restore:",
+    "pop eax",
+    "mov eax, {sigreturn}",
+    "int 0x80",
+    ".size _scall_internal_restore, . - _scall_internal_restore",
+    "",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, @function",
+    "_scall_internal_restore_rt:",
+    "mov eax, {rt_sigreturn}",
+    "int 0x80",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    sigreturn = const crate::nr::SIGRETURN,
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+extern "C" {
+    pub(crate) fn _scall_internal_restore();
+    pub(crate) fn _scall_internal_restore_rt();
+}
@@ -0,0 +1,18 @@
+// The `_start` entry point used by the `rt` feature.
+//
+// On entry, %esp points to `argc`. The argument is passed on the stack, which must be 16-byte
+// aligned before the `call`.
+global_asm!(
+    ".text",
+    ".weak _start",
+    ".type _start, @function",
+    "_start:",
+    "xor ebp, ebp",
+    "mov eax, esp",
+    "and esp, -16",
+    "sub esp, 12",
+    "push eax",
+    "call _scall_internal_start",
+    "ud2",
+    ".size _start, . - _start",
+);
@@ -0,0 +1,20 @@
+// The signal return trampoline used as `sa_restorer` by `signal::sigaction()`.
+//
+// The kernel requires `SA_RESTORER` on x86-64. When the handler returns, %rsp points to the signal
+// frame, which `rt_sigreturn()` unwinds. (This is the instruction sequence that debuggers look for
+// to recognize signal frames.)
+global_asm!(
+    ".text",
+    ".globl _scall_internal_restore_rt",
+    ".hidden _scall_internal_restore_rt",
+    ".type _scall_internal_restore_rt, @function",
+    "_scall_internal_restore_rt:",
+    "mov rax, {rt_sigreturn}",
+    "syscall",
+    ".size _scall_internal_restore_rt, . - _scall_internal_restore_rt",
+    rt_sigreturn = const crate::nr::RT_SIGRETURN,
+);
+
+extern "C" {
+    pub(crate) fn _scall_internal_restore_rt();
+}
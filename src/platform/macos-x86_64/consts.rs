@@ -0,0 +1,135 @@
+//! Flag constants for x86-64 macOS.
+
+/* automatically generated by bsd_consts_from_src.py */
+
+pub const AT_EACCESS: i32 = 0x10;
+pub const AT_FDCWD: i32 = -2;
+pub const AT_FDONLY: i32 = 0x400;
+pub const AT_REALDEV: i32 = 0x200;
+pub const AT_REMOVEDIR: i32 = 0x80;
+pub const AT_SYMLINK_FOLLOW: i32 = 0x40;
+pub const AT_SYMLINK_NOFOLLOW: i32 = 0x20;
+pub const AT_SYMLINK_NOFOLLOW_ANY: i32 = 0x800;
+pub const FD_CLOEXEC: i32 = 1;
+pub const F_ALLOCATEALL: i32 = 4;
+pub const F_ALLOCATECONTIG: i32 = 2;
+pub const F_BARRIERFSYNC: i32 = 0x55;
+pub const F_CHKCLEAN: i32 = 0x29;
+pub const F_DUPFD: i32 = 0;
+pub const F_DUPFD_CLOEXEC: i32 = 0x43;
+pub const F_FLUSH_DATA: i32 = 0x28;
+pub const F_FREEZE_FS: i32 = 0x35;
+pub const F_FULLFSYNC: i32 = 0x33;
+pub const F_GETFD: i32 = 1;
+pub const F_GETFL: i32 = 3;
+pub const F_GETLK: i32 = 7;
+pub const F_GETLKPID: i32 = 0x42;
+pub const F_GETNOSIGPIPE: i32 = 0x4a;
+pub const F_GETOWN: i32 = 5;
+pub const F_GETPATH: i32 = 0x32;
+pub const F_GETPATH_MTMINFO: i32 = 0x47;
+pub const F_GETPATH_NOFIRMLINK: i32 = 0x66;
+pub const F_GETPROTECTIONCLASS: i32 = 0x3f;
+pub const F_GETPROTECTIONLEVEL: i32 = 0x4d;
+pub const F_GLOBAL_NOCACHE: i32 = 0x37;
+pub const F_LOG2PHYS: i32 = 0x31;
+pub const F_LOG2PHYS_EXT: i32 = 0x41;
+pub const F_NOCACHE: i32 = 0x30;
+pub const F_NODIRECT: i32 = 0x3e;
+pub const F_PATHPKG_CHECK: i32 = 0x34;
+pub const F_PEOFPOSMODE: i32 = 3;
+pub const F_PREALLOCATE: i32 = 0x2a;
+pub const F_PUNCHHOLE: i32 = 0x63;
+pub const F_RDADVISE: i32 = 0x2c;
+pub const F_RDAHEAD: i32 = 0x2d;
+pub const F_RDLCK: i32 = 1;
+pub const F_SETBACKINGSTORE: i32 = 0x46;
+pub const F_SETFD: i32 = 2;
+pub const F_SETFL: i32 = 4;
+pub const F_SETLK: i32 = 8;
+pub const F_SETLKW: i32 = 9;
+pub const F_SETLKWTIMEOUT: i32 = 10;
+pub const F_SETNOSIGPIPE: i32 = 0x49;
+pub const F_SETOWN: i32 = 6;
+pub const F_SETPROTECTIONCLASS: i32 = 0x40;
+pub const F_SETSIZE: i32 = 0x2b;
+pub const F_SINGLE_WRITER: i32 = 0x4c;
+pub const F_SPECULATIVE_READ: i32 = 0x65;
+pub const F_THAW_FS: i32 = 0x36;
+pub const F_TRIM_ACTIVE_FILE: i32 = 0x64;
+pub const F_UNLCK: i32 = 2;
+pub const F_VOLPOSMODE: i32 = 4;
+pub const F_WRLCK: i32 = 3;
+pub const MADV_CAN_REUSE: i32 = 9;
+pub const MADV_DONTNEED: i32 = 4;
+pub const MADV_FREE: i32 = 5;
+pub const MADV_FREE_REUSABLE: i32 = 7;
+pub const MADV_FREE_REUSE: i32 = 8;
+pub const MADV_NORMAL: i32 = 0;
+pub const MADV_PAGEOUT: i32 = 10;
+pub const MADV_RANDOM: i32 = 1;
+pub const MADV_SEQUENTIAL: i32 = 2;
+pub const MADV_WILLNEED: i32 = 3;
+pub const MADV_ZERO_WIRED_PAGES: i32 = 6;
+pub const MAP_32BIT: i32 = 0x8000;
+pub const MAP_ANON: i32 = 0x1000;
+pub const MAP_ANONYMOUS: i32 = 0x1000;
+pub const MAP_FILE: i32 = 0;
+pub const MAP_FIXED: i32 = 0x10;
+pub const MAP_HASSEMAPHORE: i32 = 0x200;
+pub const MAP_JIT: i32 = 0x800;
+pub const MAP_NOCACHE: i32 = 0x400;
+pub const MAP_NOEXTEND: i32 = 0x100;
+pub const MAP_NORESERVE: i32 = 0x40;
+pub const MAP_PRIVATE: i32 = 2;
+pub const MAP_RENAME: i32 = 0x20;
+pub const MAP_RESILIENT_CODESIGN: i32 = 0x2000;
+pub const MAP_RESILIENT_MEDIA: i32 = 0x4000;
+pub const MAP_SHARED: i32 = 1;
+pub const MAP_TRANSLATED_ALLOW_EXECUTE: i32 = 0x20000;
+pub const MAP_UNIX03: i32 = 0x40000;
+pub const MCL_CURRENT: i32 = 1;
+pub const MCL_FUTURE: i32 = 2;
+pub const MS_ASYNC: i32 = 1;
+pub const MS_DEACTIVATE: i32 = 8;
+pub const MS_INVALIDATE: i32 = 2;
+pub const MS_KILLPAGES: i32 = 4;
+pub const MS_SYNC: i32 = 0x10;
+pub const O_ACCMODE: i32 = 3;
+pub const O_APPEND: i32 = 8;
+pub const O_ASYNC: i32 = 0x40;
+pub const O_CLOEXEC: i32 = 0x1000000;
+pub const O_CREAT: i32 = 0x200;
+pub const O_DIRECTORY: i32 = 0x100000;
+pub const O_DSYNC: i32 = 0x400000;
+pub const O_EVTONLY: i32 = 0x8000;
+pub const O_EXCL: i32 = 0x800;
+pub const O_EXLOCK: i32 = 0x20;
+pub const O_FSYNC: i32 = 0x80;
+pub const O_NDELAY: i32 = 4;
+pub const O_NOCTTY: i32 = 0x20000;
+pub const O_NOFOLLOW: i32 = 0x100;
+pub const O_NOFOLLOW_ANY: i32 = 0x20000000;
+pub const O_NONBLOCK: i32 = 4;
+pub const O_RDONLY: i32 = 0;
+pub const O_RDWR: i32 = 2;
+pub const O_SHLOCK: i32 = 0x10;
+pub const O_SYMLINK: i32 = 0x200000;
+pub const O_SYNC: i32 = 0x80;
+pub const O_TRUNC: i32 = 0x400;
+pub const O_WRONLY: i32 = 1;
+pub const PROT_EXEC: i32 = 4;
+pub const PROT_NONE: i32 = 0;
+pub const PROT_READ: i32 = 1;
+pub const PROT_WRITE: i32 = 2;
+pub const SEEK_CUR: i32 = 1;
+pub const SEEK_DATA: i32 = 4;
+pub const SEEK_END: i32 = 2;
+pub const SEEK_HOLE: i32 = 3;
+pub const SEEK_SET: i32 = 0;
+pub const WCONTINUED: i32 = 0x10;
+pub const WEXITED: i32 = 4;
+pub const WNOHANG: i32 = 1;
+pub const WNOWAIT: i32 = 0x20;
+pub const WSTOPPED: i32 = 8;
+pub const WUNTRACED: i32 = 2;
@@ -9,13 +9,17 @@
 
 //! This library was built for x86-64 MacOS.
 
+pub mod consts;
 pub mod eno;
 pub mod nr;
+pub mod sig;
+pub mod types;
 
 const MACOS_SYSCALL_PREFIX: usize = 33554432;
 
 #[inline(always)]
-pub unsafe fn syscall0(n: usize) -> (usize, bool) {
+pub unsafe fn syscall0(n: impl crate::SyscallNumber) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -30,7 +34,8 @@ pub unsafe fn syscall0(n: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1(n: usize, a1: usize) -> (usize, bool) {
+pub unsafe fn syscall1(n: impl crate::SyscallNumber, a1: usize) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -46,7 +51,8 @@ pub unsafe fn syscall1(n: usize, a1: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> (usize, bool) {
+pub unsafe fn syscall2(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -63,7 +69,13 @@ pub unsafe fn syscall2(n: usize, a1: usize, a2: usize) -> (usize, bool) {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> (usize, bool) {
+pub unsafe fn syscall3(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -81,7 +93,14 @@ pub unsafe fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> (usize, boo
 }
 
 #[inline(always)]
-pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> (usize, bool) {
+pub unsafe fn syscall4(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -101,13 +120,14 @@ pub unsafe fn syscall4(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) ->
 
 #[inline(always)]
 pub unsafe fn syscall5(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -128,7 +148,7 @@ pub unsafe fn syscall5(
 
 #[inline(always)]
 pub unsafe fn syscall6(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -136,6 +156,7 @@ pub unsafe fn syscall6(
     a5: usize,
     a6: usize,
 ) -> (usize, bool) {
+    let n = n.into_nr();
     let ret: usize;
     let is_err: u8;
     asm!(
@@ -156,7 +177,8 @@ pub unsafe fn syscall6(
 }
 
 #[inline(always)]
-pub unsafe fn syscall0_nofail(n: usize) -> usize {
+pub unsafe fn syscall0_nofail(n: impl crate::SyscallNumber) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -168,7 +190,8 @@ pub unsafe fn syscall0_nofail(n: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall1_nofail(n: usize, a1: usize) -> usize {
+pub unsafe fn syscall1_nofail(n: impl crate::SyscallNumber, a1: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -181,7 +204,8 @@ pub unsafe fn syscall1_nofail(n: usize, a1: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall2_nofail(n: usize, a1: usize, a2: usize) -> usize {
+pub unsafe fn syscall2_nofail(n: impl crate::SyscallNumber, a1: usize, a2: usize) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -195,7 +219,13 @@ pub unsafe fn syscall2_nofail(n: usize, a1: usize, a2: usize) -> usize {
 }
 
 #[inline(always)]
-pub unsafe fn syscall3_nofail(n: usize, a1: usize, a2: usize, a3: usize) -> usize {
+pub unsafe fn syscall3_nofail(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -210,7 +240,14 @@ pub unsafe fn syscall3_nofail(n: usize, a1: usize, a2: usize, a3: usize) -> usiz
 }
 
 #[inline(always)]
-pub unsafe fn syscall4_nofail(n: usize, a1: usize, a2: usize, a3: usize, a4: usize) -> usize {
+pub unsafe fn syscall4_nofail(
+    n: impl crate::SyscallNumber,
+    a1: usize,
+    a2: usize,
+    a3: usize,
+    a4: usize,
+) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -227,13 +264,14 @@ pub unsafe fn syscall4_nofail(n: usize, a1: usize, a2: usize, a3: usize, a4: usi
 
 #[inline(always)]
 pub unsafe fn syscall5_nofail(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
     a4: usize,
     a5: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
@@ -251,7 +289,7 @@ pub unsafe fn syscall5_nofail(
 
 #[inline(always)]
 pub unsafe fn syscall6_nofail(
-    n: usize,
+    n: impl crate::SyscallNumber,
     a1: usize,
     a2: usize,
     a3: usize,
@@ -259,6 +297,7 @@ pub unsafe fn syscall6_nofail(
     a5: usize,
     a6: usize,
 ) -> usize {
+    let n = n.into_nr();
     let ret: usize;
     asm!(
         "syscall",
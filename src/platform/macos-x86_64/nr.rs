@@ -401,3 +401,405 @@ pub const MACH_EVENTLINK_SIGNAL_WAIT_UNTIL: usize = 498;
 pub const WORK_INTERVAL_CTL: usize = 499;
 pub const GETENTROPY: usize = 500;
 pub const NECP_OPEN: usize = 501;
+
+sysno_table! {
+    SYSCALL "syscall" _,
+    EXIT "exit" _,
+    FORK "fork" _,
+    READ "read" _,
+    WRITE "write" _,
+    OPEN "open" _,
+    CLOSE "close" _,
+    WAIT4 "wait4" _,
+    LINK "link" _,
+    UNLINK "unlink" _,
+    CHDIR "chdir" _,
+    FCHDIR "fchdir" _,
+    MKNOD "mknod" _,
+    CHMOD "chmod" _,
+    CHOWN "chown" _,
+    GETFSSTAT "getfsstat" _,
+    GETPID "getpid" _,
+    SETUID "setuid" _,
+    GETUID "getuid" _,
+    GETEUID "geteuid" _,
+    PTRACE "ptrace" _,
+    RECVMSG "recvmsg" _,
+    SENDMSG "sendmsg" _,
+    RECVFROM "recvfrom" _,
+    ACCEPT "accept" _,
+    GETPEERNAME "getpeername" _,
+    GETSOCKNAME "getsockname" _,
+    ACCESS "access" _,
+    CHFLAGS "chflags" _,
+    FCHFLAGS "fchflags" _,
+    SYNC "sync" _,
+    KILL "kill" _,
+    GETPPID "getppid" _,
+    DUP "dup" _,
+    PIPE "pipe" _,
+    GETEGID "getegid" _,
+    SIGACTION "sigaction" _,
+    GETGID "getgid" _,
+    SIGPROCMASK "sigprocmask" _,
+    GETLOGIN "getlogin" _,
+    SETLOGIN "setlogin" _,
+    ACCT "acct" _,
+    SIGPENDING "sigpending" _,
+    SIGALTSTACK "sigaltstack" _,
+    IOCTL "ioctl" _,
+    REBOOT "reboot" _,
+    REVOKE "revoke" _,
+    SYMLINK "symlink" _,
+    READLINK "readlink" _,
+    EXECVE "execve" _,
+    UMASK "umask" _,
+    CHROOT "chroot" _,
+    MSYNC "msync" _,
+    VFORK "vfork" _,
+    MUNMAP "munmap" _,
+    MPROTECT "mprotect" _,
+    MADVISE "madvise" _,
+    MINCORE "mincore" _,
+    GETGROUPS "getgroups" _,
+    SETGROUPS "setgroups" _,
+    GETPGRP "getpgrp" _,
+    SETPGID "setpgid" _,
+    SETITIMER "setitimer" _,
+    SWAPON "swapon" _,
+    GETITIMER "getitimer" _,
+    GETDTABLESIZE "getdtablesize" _,
+    DUP2 "dup2" _,
+    FCNTL "fcntl" _,
+    SELECT "select" _,
+    FSYNC "fsync" _,
+    SETPRIORITY "setpriority" _,
+    SOCKET "socket" _,
+    CONNECT "connect" _,
+    GETPRIORITY "getpriority" _,
+    BIND "bind" _,
+    SETSOCKOPT "setsockopt" _,
+    LISTEN "listen" _,
+    SIGSUSPEND "sigsuspend" _,
+    GETTIMEOFDAY "gettimeofday" _,
+    GETRUSAGE "getrusage" _,
+    GETSOCKOPT "getsockopt" _,
+    READV "readv" _,
+    WRITEV "writev" _,
+    SETTIMEOFDAY "settimeofday" _,
+    FCHOWN "fchown" _,
+    FCHMOD "fchmod" _,
+    SETREUID "setreuid" _,
+    SETREGID "setregid" _,
+    RENAME "rename" _,
+    FLOCK "flock" _,
+    MKFIFO "mkfifo" _,
+    SENDTO "sendto" _,
+    SHUTDOWN "shutdown" _,
+    SOCKETPAIR "socketpair" _,
+    MKDIR "mkdir" _,
+    RMDIR "rmdir" _,
+    UTIMES "utimes" _,
+    FUTIMES "futimes" _,
+    ADJTIME "adjtime" _,
+    GETHOSTUUID "gethostuuid" _,
+    SETSID "setsid" _,
+    GETPGID "getpgid" _,
+    SETPRIVEXEC "setprivexec" _,
+    PREAD "pread" _,
+    PWRITE "pwrite" _,
+    NFSSVC "nfssvc" _,
+    STATFS "statfs" _,
+    FSTATFS "fstatfs" _,
+    UNMOUNT "unmount" _,
+    GETFH "getfh" _,
+    QUOTACTL "quotactl" _,
+    MOUNT "mount" _,
+    CSOPS "csops" _,
+    CSOPS_AUDITTOKEN "csops_audittoken" _,
+    WAITID "waitid" _,
+    KDEBUG_TYPEFILTER "kdebug_typefilter" _,
+    KDEBUG_TRACE_STRING "kdebug_trace_string" _,
+    KDEBUG_TRACE64 "kdebug_trace64" _,
+    KDEBUG_TRACE "kdebug_trace" _,
+    SETGID "setgid" _,
+    SETEGID "setegid" _,
+    SETEUID "seteuid" _,
+    SIGRETURN "sigreturn" _,
+    THREAD_SELFCOUNTS "thread_selfcounts" _,
+    FDATASYNC "fdatasync" _,
+    STAT "stat" _,
+    FSTAT "fstat" _,
+    LSTAT "lstat" _,
+    PATHCONF "pathconf" _,
+    FPATHCONF "fpathconf" _,
+    GETRLIMIT "getrlimit" _,
+    SETRLIMIT "setrlimit" _,
+    GETDIRENTRIES "getdirentries" _,
+    MMAP "mmap" _,
+    LSEEK "lseek" _,
+    TRUNCATE "truncate" _,
+    FTRUNCATE "ftruncate" _,
+    __SYSCTL "__sysctl" _,
+    MLOCK "mlock" _,
+    MUNLOCK "munlock" _,
+    UNDELETE "undelete" _,
+    OPEN_DPROTECTED_NP "open_dprotected_np" _,
+    FSGETPATH_EXT "fsgetpath_ext" _,
+    GETATTRLIST "getattrlist" _,
+    SETATTRLIST "setattrlist" _,
+    GETDIRENTRIESATTR "getdirentriesattr" _,
+    EXCHANGEDATA "exchangedata" _,
+    SEARCHFS "searchfs" _,
+    DELETE "delete" _,
+    COPYFILE "copyfile" _,
+    FGETATTRLIST "fgetattrlist" _,
+    FSETATTRLIST "fsetattrlist" _,
+    POLL "poll" _,
+    GETXATTR "getxattr" _,
+    FGETXATTR "fgetxattr" _,
+    SETXATTR "setxattr" _,
+    FSETXATTR "fsetxattr" _,
+    REMOVEXATTR "removexattr" _,
+    FREMOVEXATTR "fremovexattr" _,
+    LISTXATTR "listxattr" _,
+    FLISTXATTR "flistxattr" _,
+    FSCTL "fsctl" _,
+    INITGROUPS "initgroups" _,
+    POSIX_SPAWN "posix_spawn" _,
+    FFSCTL "ffsctl" _,
+    NFSCLNT "nfsclnt" _,
+    FHOPEN "fhopen" _,
+    MINHERIT "minherit" _,
+    SEMSYS "semsys" _,
+    MSGSYS "msgsys" _,
+    SHMSYS "shmsys" _,
+    SEMCTL "semctl" _,
+    SEMGET "semget" _,
+    SEMOP "semop" _,
+    MSGCTL "msgctl" _,
+    MSGGET "msgget" _,
+    MSGSND "msgsnd" _,
+    MSGRCV "msgrcv" _,
+    SHMAT "shmat" _,
+    SHMCTL "shmctl" _,
+    SHMDT "shmdt" _,
+    SHMGET "shmget" _,
+    SHM_OPEN "shm_open" _,
+    SHM_UNLINK "shm_unlink" _,
+    SEM_OPEN "sem_open" _,
+    SEM_CLOSE "sem_close" _,
+    SEM_UNLINK "sem_unlink" _,
+    SEM_WAIT "sem_wait" _,
+    SEM_TRYWAIT "sem_trywait" _,
+    SEM_POST "sem_post" _,
+    SYSCTLBYNAME "sysctlbyname" _,
+    OPEN_EXTENDED "open_extended" _,
+    UMASK_EXTENDED "umask_extended" _,
+    STAT_EXTENDED "stat_extended" _,
+    LSTAT_EXTENDED "lstat_extended" _,
+    FSTAT_EXTENDED "fstat_extended" _,
+    CHMOD_EXTENDED "chmod_extended" _,
+    FCHMOD_EXTENDED "fchmod_extended" _,
+    ACCESS_EXTENDED "access_extended" _,
+    SETTID "settid" _,
+    GETTID "gettid" _,
+    SETSGROUPS "setsgroups" _,
+    GETSGROUPS "getsgroups" _,
+    SETWGROUPS "setwgroups" _,
+    GETWGROUPS "getwgroups" _,
+    MKFIFO_EXTENDED "mkfifo_extended" _,
+    MKDIR_EXTENDED "mkdir_extended" _,
+    IDENTITYSVC "identitysvc" _,
+    SHARED_REGION_CHECK_NP "shared_region_check_np" _,
+    VM_PRESSURE_MONITOR "vm_pressure_monitor" _,
+    PSYNCH_RW_LONGRDLOCK "psynch_rw_longrdlock" _,
+    PSYNCH_RW_YIELDWRLOCK "psynch_rw_yieldwrlock" _,
+    PSYNCH_RW_DOWNGRADE "psynch_rw_downgrade" _,
+    PSYNCH_RW_UPGRADE "psynch_rw_upgrade" _,
+    PSYNCH_MUTEXWAIT "psynch_mutexwait" _,
+    PSYNCH_MUTEXDROP "psynch_mutexdrop" _,
+    PSYNCH_CVBROAD "psynch_cvbroad" _,
+    PSYNCH_CVSIGNAL "psynch_cvsignal" _,
+    PSYNCH_CVWAIT "psynch_cvwait" _,
+    PSYNCH_RW_RDLOCK "psynch_rw_rdlock" _,
+    PSYNCH_RW_WRLOCK "psynch_rw_wrlock" _,
+    PSYNCH_RW_UNLOCK "psynch_rw_unlock" _,
+    PSYNCH_RW_UNLOCK2 "psynch_rw_unlock2" _,
+    GETSID "getsid" _,
+    SETTID_WITH_PID "settid_with_pid" _,
+    PSYNCH_CVCLRPREPOST "psynch_cvclrprepost" _,
+    AIO_FSYNC "aio_fsync" _,
+    AIO_RETURN "aio_return" _,
+    AIO_SUSPEND "aio_suspend" _,
+    AIO_CANCEL "aio_cancel" _,
+    AIO_ERROR "aio_error" _,
+    AIO_READ "aio_read" _,
+    AIO_WRITE "aio_write" _,
+    LIO_LISTIO "lio_listio" _,
+    IOPOLICYSYS "iopolicysys" _,
+    PROCESS_POLICY "process_policy" _,
+    MLOCKALL "mlockall" _,
+    MUNLOCKALL "munlockall" _,
+    ISSETUGID "issetugid" _,
+    __PTHREAD_KILL "__pthread_kill" _,
+    __PTHREAD_SIGMASK "__pthread_sigmask" _,
+    __SIGWAIT "__sigwait" _,
+    __DISABLE_THREADSIGNAL "__disable_threadsignal" _,
+    __PTHREAD_MARKCANCEL "__pthread_markcancel" _,
+    __PTHREAD_CANCELED "__pthread_canceled" _,
+    __SEMWAIT_SIGNAL "__semwait_signal" _,
+    PROC_INFO "proc_info" _,
+    SENDFILE "sendfile" _,
+    STAT64 "stat64" _,
+    FSTAT64 "fstat64" _,
+    LSTAT64 "lstat64" _,
+    STAT64_EXTENDED "stat64_extended" _,
+    LSTAT64_EXTENDED "lstat64_extended" _,
+    FSTAT64_EXTENDED "fstat64_extended" _,
+    GETDIRENTRIES64 "getdirentries64" _,
+    STATFS64 "statfs64" _,
+    FSTATFS64 "fstatfs64" _,
+    GETFSSTAT64 "getfsstat64" _,
+    __PTHREAD_CHDIR "__pthread_chdir" _,
+    __PTHREAD_FCHDIR "__pthread_fchdir" _,
+    AUDIT "audit" _,
+    AUDITON "auditon" _,
+    GETAUID "getauid" _,
+    SETAUID "setauid" _,
+    GETAUDIT_ADDR "getaudit_addr" _,
+    SETAUDIT_ADDR "setaudit_addr" _,
+    AUDITCTL "auditctl" _,
+    BSDTHREAD_CREATE "bsdthread_create" _,
+    BSDTHREAD_TERMINATE "bsdthread_terminate" _,
+    KQUEUE "kqueue" _,
+    KEVENT "kevent" _,
+    LCHOWN "lchown" _,
+    BSDTHREAD_REGISTER "bsdthread_register" _,
+    WORKQ_OPEN "workq_open" _,
+    WORKQ_KERNRETURN "workq_kernreturn" _,
+    KEVENT64 "kevent64" _,
+    __OLD_SEMWAIT_SIGNAL "__old_semwait_signal" _,
+    __OLD_SEMWAIT_SIGNAL_NOCANCEL "__old_semwait_signal_nocancel" _,
+    THREAD_SELFID "thread_selfid" _,
+    LEDGER "ledger" _,
+    KEVENT_QOS "kevent_qos" _,
+    KEVENT_ID "kevent_id" _,
+    __MAC_EXECVE "__mac_execve" _,
+    __MAC_SYSCALL "__mac_syscall" _,
+    __MAC_GET_FILE "__mac_get_file" _,
+    __MAC_SET_FILE "__mac_set_file" _,
+    __MAC_GET_LINK "__mac_get_link" _,
+    __MAC_SET_LINK "__mac_set_link" _,
+    __MAC_GET_PROC "__mac_get_proc" _,
+    __MAC_SET_PROC "__mac_set_proc" _,
+    __MAC_GET_FD "__mac_get_fd" _,
+    __MAC_SET_FD "__mac_set_fd" _,
+    __MAC_GET_PID "__mac_get_pid" _,
+    PSELECT "pselect" _,
+    PSELECT_NOCANCEL "pselect_nocancel" _,
+    READ_NOCANCEL "read_nocancel" _,
+    WRITE_NOCANCEL "write_nocancel" _,
+    OPEN_NOCANCEL "open_nocancel" _,
+    CLOSE_NOCANCEL "close_nocancel" _,
+    WAIT4_NOCANCEL "wait4_nocancel" _,
+    RECVMSG_NOCANCEL "recvmsg_nocancel" _,
+    SENDMSG_NOCANCEL "sendmsg_nocancel" _,
+    RECVFROM_NOCANCEL "recvfrom_nocancel" _,
+    ACCEPT_NOCANCEL "accept_nocancel" _,
+    MSYNC_NOCANCEL "msync_nocancel" _,
+    FCNTL_NOCANCEL "fcntl_nocancel" _,
+    SELECT_NOCANCEL "select_nocancel" _,
+    FSYNC_NOCANCEL "fsync_nocancel" _,
+    CONNECT_NOCANCEL "connect_nocancel" _,
+    SIGSUSPEND_NOCANCEL "sigsuspend_nocancel" _,
+    READV_NOCANCEL "readv_nocancel" _,
+    WRITEV_NOCANCEL "writev_nocancel" _,
+    SENDTO_NOCANCEL "sendto_nocancel" _,
+    PREAD_NOCANCEL "pread_nocancel" _,
+    PWRITE_NOCANCEL "pwrite_nocancel" _,
+    WAITID_NOCANCEL "waitid_nocancel" _,
+    POLL_NOCANCEL "poll_nocancel" _,
+    MSGSND_NOCANCEL "msgsnd_nocancel" _,
+    MSGRCV_NOCANCEL "msgrcv_nocancel" _,
+    SEM_WAIT_NOCANCEL "sem_wait_nocancel" _,
+    AIO_SUSPEND_NOCANCEL "aio_suspend_nocancel" _,
+    __SIGWAIT_NOCANCEL "__sigwait_nocancel" _,
+    __SEMWAIT_SIGNAL_NOCANCEL "__semwait_signal_nocancel" _,
+    __MAC_MOUNT "__mac_mount" _,
+    __MAC_GET_MOUNT "__mac_get_mount" _,
+    __MAC_GETFSSTAT "__mac_getfsstat" _,
+    FSGETPATH "fsgetpath" _,
+    AUDIT_SESSION_SELF "audit_session_self" _,
+    AUDIT_SESSION_JOIN "audit_session_join" _,
+    FILEPORT_MAKEPORT "fileport_makeport" _,
+    FILEPORT_MAKEFD "fileport_makefd" _,
+    AUDIT_SESSION_PORT "audit_session_port" _,
+    PID_SUSPEND "pid_suspend" _,
+    PID_RESUME "pid_resume" _,
+    PID_HIBERNATE "pid_hibernate" _,
+    PID_SHUTDOWN_SOCKETS "pid_shutdown_sockets" _,
+    SHARED_REGION_MAP_AND_SLIDE_NP "shared_region_map_and_slide_np" _,
+    KAS_INFO "kas_info" _,
+    MEMORYSTATUS_CONTROL "memorystatus_control" _,
+    GUARDED_OPEN_NP "guarded_open_np" _,
+    GUARDED_CLOSE_NP "guarded_close_np" _,
+    GUARDED_KQUEUE_NP "guarded_kqueue_np" _,
+    CHANGE_FDGUARD_NP "change_fdguard_np" _,
+    USRCTL "usrctl" _,
+    PROC_RLIMIT_CONTROL "proc_rlimit_control" _,
+    CONNECTX "connectx" _,
+    DISCONNECTX "disconnectx" _,
+    PEELOFF "peeloff" _,
+    SOCKET_DELEGATE "socket_delegate" _,
+    TELEMETRY "telemetry" _,
+    PROC_UUID_POLICY "proc_uuid_policy" _,
+    MEMORYSTATUS_GET_LEVEL "memorystatus_get_level" _,
+    SYSTEM_OVERRIDE "system_override" _,
+    VFS_PURGE "vfs_purge" _,
+    SFI_CTL "sfi_ctl" _,
+    SFI_PIDCTL "sfi_pidctl" _,
+    COALITION "coalition" _,
+    COALITION_INFO "coalition_info" _,
+    NECP_MATCH_POLICY "necp_match_policy" _,
+    GETATTRLISTBULK "getattrlistbulk" _,
+    CLONEFILEAT "clonefileat" _,
+    OPENAT "openat" _,
+    OPENAT_NOCANCEL "openat_nocancel" _,
+    RENAMEAT "renameat" _,
+    FACCESSAT "faccessat" _,
+    FCHMODAT "fchmodat" _,
+    FCHOWNAT "fchownat" _,
+    FSTATAT "fstatat" _,
+    FSTATAT64 "fstatat64" _,
+    LINKAT "linkat" _,
+    UNLINKAT "unlinkat" _,
+    READLINKAT "readlinkat" _,
+    SYMLINKAT "symlinkat" _,
+    MKDIRAT "mkdirat" _,
+    GETATTRLISTAT "getattrlistat" _,
+    PROC_TRACE_LOG "proc_trace_log" _,
+    BSDTHREAD_CTL "bsdthread_ctl" _,
+    OPENBYID_NP "openbyid_np" _,
+    RECVMSG_X "recvmsg_x" _,
+    SENDMSG_X "sendmsg_x" _,
+    THREAD_SELFUSAGE "thread_selfusage" _,
+    CSRCTL "csrctl" _,
+    GUARDED_OPEN_DPROTECTED_NP "guarded_open_dprotected_np" _,
+    GUARDED_WRITE_NP "guarded_write_np" _,
+    GUARDED_PWRITE_NP "guarded_pwrite_np" _,
+    GUARDED_WRITEV_NP "guarded_writev_np" _,
+    RENAMEATX_NP "renameatx_np" _,
+    MREMAP_ENCRYPTED "mremap_encrypted" _,
+    NETAGENT_TRIGGER "netagent_trigger" _,
+    STACK_SNAPSHOT_WITH_CONFIG "stack_snapshot_with_config" _,
+    MICROSTACKSHOT "microstackshot" _,
+    GRAB_PGO_DATA "grab_pgo_data" _,
+    PERSONA "persona" _,
+    MACH_EVENTLINK_SIGNAL "mach_eventlink_signal" _,
+    MACH_EVENTLINK_WAIT_UNTIL "mach_eventlink_wait_until" _,
+    MACH_EVENTLINK_SIGNAL_WAIT_UNTIL "mach_eventlink_signal_wait_until" _,
+    WORK_INTERVAL_CTL "work_interval_ctl" _,
+    GETENTROPY "getentropy" _,
+    NECP_OPEN "necp_open" _,
+}
@@ -0,0 +1,122 @@
+//! Kernel ABI structures for x86-64 macOS.
+
+#![allow(non_camel_case_types)]
+
+use core::ffi::c_void;
+
+/// A time value with nanosecond precision (`struct timespec`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+/// A time value with microsecond precision (`struct timeval`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i32,
+}
+
+/// A buffer for scatter/gather I/O (`struct iovec`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct iovec {
+    pub iov_base: *mut c_void,
+    pub iov_len: usize,
+}
+
+/// A message for `sendmsg()`/`recvmsg()` (`struct msghdr`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct msghdr {
+    pub msg_name: *mut c_void,
+    pub msg_namelen: u32,
+    pub msg_iov: *mut iovec,
+    pub msg_iovlen: i32,
+    pub msg_control: *mut c_void,
+    pub msg_controllen: u32,
+    pub msg_flags: i32,
+}
+
+/// The header of a control message (`struct cmsghdr`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct cmsghdr {
+    pub cmsg_len: u32,
+    pub cmsg_level: i32,
+    pub cmsg_type: i32,
+}
+
+/// A file descriptor to wait on with `poll()` (`struct pollfd`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct pollfd {
+    pub fd: i32,
+    pub events: i16,
+    pub revents: i16,
+}
+
+/// File status information, as returned by `fstat64()`, `stat64()`, `lstat64()`, and `fstatat64()`.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct stat64 {
+    pub st_dev: i32,
+    pub st_mode: u16,
+    pub st_nlink: u16,
+    pub st_ino: u64,
+    pub st_uid: u32,
+    pub st_gid: u32,
+    pub st_rdev: i32,
+    pub st_atimespec: timespec,
+    pub st_mtimespec: timespec,
+    pub st_ctimespec: timespec,
+    pub st_birthtimespec: timespec,
+    pub st_size: i64,
+    pub st_blocks: i64,
+    pub st_blksize: i32,
+    pub st_flags: u32,
+    pub st_gen: u32,
+    pub st_lspare: i32,
+    pub st_qspare: [i64; 2],
+}
+
+/// The signal set used by the kernel (`sigset_t`).
+pub type sigset_t = u32;
+
+/// The structure passed as the new action to `sigaction()` (`struct __sigaction`).
+///
+/// `sa_tramp` is the trampoline that the kernel jumps to in order to call the handler.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct __sigaction {
+    pub sa_handler: usize,
+    pub sa_tramp: usize,
+    pub sa_mask: sigset_t,
+    pub sa_flags: i32,
+}
+
+/// The structure that `sigaction()` stores the old action in (`struct sigaction`).
+///
+/// `sa_handler` can be `SIG_DFL` (0), `SIG_IGN` (1), or the address of a handler function.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct sigaction {
+    pub sa_handler: usize,
+    pub sa_mask: sigset_t,
+    pub sa_flags: i32,
+}
+
+/// An event for `kevent()` (`struct kevent`).
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct kevent {
+    pub ident: usize,
+    pub filter: i16,
+    pub flags: u16,
+    pub fflags: u32,
+    pub data: isize,
+    pub udata: *mut c_void,
+}
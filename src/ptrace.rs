@@ -0,0 +1,729 @@
+//! Tracing other processes with `ptrace()`.
+//!
+//! The C library's `ptrace()` wrapper hides several details of the raw syscall, which the
+//! functions in this module take care of instead:
+//!
+//! - For `PTRACE_PEEK*` requests, the kernel stores the word that was read at the address passed
+//!   as `data` and returns 0. (The C wrapper returns the word itself, so a word of -1 can only be
+//!   told apart from an error by clearing and then checking `errno`.) [`peek_data()`] passes a
+//!   pointer to a local variable, so every value (including negative ones) is returned as `Ok`.
+//! - The registers of a stopped tracee are read with `PTRACE_GETREGSET`, whose layout differs
+//!   between architectures (see [`types::user_regs_struct`]). [`Regs`] knows where each
+//!   architecture keeps the syscall number, the arguments, and the return value; the return value
+//!   is decoded with the same convention as [`decode_raw_result()`] (for example, on MIPS the
+//!   error flag is in `a3`, and on PowerPC and SPARC the kernel sets a condition flag, which is
+//!   converted to a negated error number just like the syscall functions in this crate do).
+//! - On Linux 5.3+, [`get_syscall_info()`] (`PTRACE_GET_SYSCALL_INFO`) reports the same
+//!   information in an architecture-independent format, along with the `AUDIT_ARCH_*` value of
+//!   the ABI the syscall was made with.
+//!
+//! See `examples/strace.rs` for a small `strace`-like program built on this module.
+//!
+//! [`peek_data()`]: ./fn.peek_data.html
+//! [`types::user_regs_struct`]: ../types/struct.user_regs_struct.html
+//! [`Regs`]: ./struct.Regs.html
+//! [`decode_raw_result()`]: ../fn.decode_raw_result.html
+//! [`get_syscall_info()`]: ./fn.get_syscall_info.html
+
+use core::fmt;
+
+use crate::seccomp::AUDIT_ARCH;
+use crate::{types, RawResult, Sysno};
+
+pub const PTRACE_TRACEME: u32 = 0;
+pub const PTRACE_PEEKTEXT: u32 = 1;
+pub const PTRACE_PEEKDATA: u32 = 2;
+pub const PTRACE_PEEKUSR: u32 = 3;
+pub const PTRACE_POKETEXT: u32 = 4;
+pub const PTRACE_POKEDATA: u32 = 5;
+pub const PTRACE_POKEUSR: u32 = 6;
+pub const PTRACE_CONT: u32 = 7;
+pub const PTRACE_KILL: u32 = 8;
+pub const PTRACE_ATTACH: u32 = 16;
+pub const PTRACE_DETACH: u32 = 17;
+pub const PTRACE_SYSCALL: u32 = 24;
+pub const PTRACE_SETOPTIONS: u32 = 0x4200;
+pub const PTRACE_GETEVENTMSG: u32 = 0x4201;
+pub const PTRACE_GETSIGINFO: u32 = 0x4202;
+pub const PTRACE_SETSIGINFO: u32 = 0x4203;
+pub const PTRACE_GETREGSET: u32 = 0x4204;
+pub const PTRACE_SETREGSET: u32 = 0x4205;
+pub const PTRACE_SEIZE: u32 = 0x4206;
+pub const PTRACE_INTERRUPT: u32 = 0x4207;
+pub const PTRACE_LISTEN: u32 = 0x4208;
+pub const PTRACE_GET_SYSCALL_INFO: u32 = 0x420e;
+
+/// Report syscall stops as `SIGTRAP | 0x80`, so they can be told apart from real `SIGTRAP`s.
+pub const PTRACE_O_TRACESYSGOOD: u32 = 0x1;
+pub const PTRACE_O_TRACEFORK: u32 = 0x2;
+pub const PTRACE_O_TRACEVFORK: u32 = 0x4;
+pub const PTRACE_O_TRACECLONE: u32 = 0x8;
+pub const PTRACE_O_TRACEEXEC: u32 = 0x10;
+pub const PTRACE_O_TRACEVFORKDONE: u32 = 0x20;
+pub const PTRACE_O_TRACEEXIT: u32 = 0x40;
+/// Stop the tracee when a seccomp filter returns `SECCOMP_RET_TRACE`.
+pub const PTRACE_O_TRACESECCOMP: u32 = 0x80;
+/// Kill the tracee if the tracer exits.
+pub const PTRACE_O_EXITKILL: u32 = 0x10_0000;
+pub const PTRACE_O_SUSPEND_SECCOMP: u32 = 0x20_0000;
+
+pub const PTRACE_EVENT_FORK: u32 = 1;
+pub const PTRACE_EVENT_VFORK: u32 = 2;
+pub const PTRACE_EVENT_CLONE: u32 = 3;
+pub const PTRACE_EVENT_EXEC: u32 = 4;
+pub const PTRACE_EVENT_VFORK_DONE: u32 = 5;
+pub const PTRACE_EVENT_EXIT: u32 = 6;
+pub const PTRACE_EVENT_SECCOMP: u32 = 7;
+pub const PTRACE_EVENT_STOP: u32 = 128;
+
+pub const PTRACE_SYSCALL_INFO_NONE: u8 = 0;
+pub const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+pub const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+pub const PTRACE_SYSCALL_INFO_SECCOMP: u8 = 3;
+
+/// The register set containing the general-purpose registers, for `PTRACE_GETREGSET`.
+pub const NT_PRSTATUS: u32 = 1;
+
+/// Make a raw `ptrace()` request.
+///
+/// Note that for the `PTRACE_PEEK*` requests, `data` must point to a word where the result will
+/// be stored (see [`peek_data()`]).
+///
+/// [`peek_data()`]: ./fn.peek_data.html
+#[inline]
+pub unsafe fn ptrace(request: u32, pid: i32, addr: usize, data: usize) -> Result<usize, i32> {
+    crate::syscall!(PTRACE, request, pid, addr, data)
+}
+
+/// Make the calling process a tracee of its parent (`PTRACE_TRACEME`).
+#[inline]
+pub fn traceme() -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_TRACEME, 0, 0, 0)?;
+    }
+    Ok(())
+}
+
+/// Attach to the process `pid` and send it a `SIGSTOP` (`PTRACE_ATTACH`).
+#[inline]
+pub fn attach(pid: i32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_ATTACH, pid, 0, 0)?;
+    }
+    Ok(())
+}
+
+/// Attach to the process `pid` without stopping it, and set the `PTRACE_O_*` options in
+/// `options` (`PTRACE_SEIZE`, Linux 3.4+).
+#[inline]
+pub fn seize(pid: i32, options: u32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_SEIZE, pid, 0, options as usize)?;
+    }
+    Ok(())
+}
+
+/// Stop a tracee that was attached with [`seize()`] (`PTRACE_INTERRUPT`).
+///
+/// [`seize()`]: ./fn.seize.html
+#[inline]
+pub fn interrupt(pid: i32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_INTERRUPT, pid, 0, 0)?;
+    }
+    Ok(())
+}
+
+/// Detach from the stopped tracee `pid`, and resume it with the signal `sig` (or 0 for no
+/// signal) (`PTRACE_DETACH`).
+#[inline]
+pub fn detach(pid: i32, sig: i32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_DETACH, pid, 0, sig as usize)?;
+    }
+    Ok(())
+}
+
+/// Set the `PTRACE_O_*` options of the stopped tracee `pid` (`PTRACE_SETOPTIONS`).
+#[inline]
+pub fn set_options(pid: i32, options: u32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_SETOPTIONS, pid, 0, options as usize)?;
+    }
+    Ok(())
+}
+
+/// Resume the stopped tracee `pid` with the signal `sig` (or 0 for no signal) (`PTRACE_CONT`).
+#[inline]
+pub fn cont(pid: i32, sig: i32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_CONT, pid, 0, sig as usize)?;
+    }
+    Ok(())
+}
+
+/// Resume the stopped tracee `pid` with the signal `sig` (or 0 for no signal), and stop it at the
+/// next entry to or exit from a syscall (`PTRACE_SYSCALL`).
+#[inline]
+pub fn cont_syscall(pid: i32, sig: i32) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_SYSCALL, pid, 0, sig as usize)?;
+    }
+    Ok(())
+}
+
+/// Get the message associated with the current `PTRACE_EVENT_*` stop of the tracee `pid`
+/// (`PTRACE_GETEVENTMSG`).
+#[inline]
+pub fn get_event_msg(pid: i32) -> Result<usize, i32> {
+    let mut msg = 0usize;
+    unsafe {
+        ptrace(PTRACE_GETEVENTMSG, pid, 0, &mut msg as *mut usize as usize)?;
+    }
+    Ok(msg)
+}
+
+/// Read a word from the memory of the stopped tracee `pid` at the address `addr`
+/// (`PTRACE_PEEKDATA`).
+///
+/// Unlike with the C library's wrapper, a negative word is not ambiguous: errors are always
+/// reported as `Err`.
+#[inline]
+pub fn peek_data(pid: i32, addr: usize) -> Result<usize, i32> {
+    let mut word = 0usize;
+    unsafe {
+        ptrace(PTRACE_PEEKDATA, pid, addr, &mut word as *mut usize as usize)?;
+    }
+    Ok(word)
+}
+
+/// Write the word `data` to the memory of the stopped tracee `pid` at the address `addr`
+/// (`PTRACE_POKEDATA`).
+#[inline]
+pub fn poke_data(pid: i32, addr: usize, data: usize) -> Result<(), i32> {
+    unsafe {
+        ptrace(PTRACE_POKEDATA, pid, addr, data)?;
+    }
+    Ok(())
+}
+
+/// The general-purpose registers of a stopped tracee.
+///
+/// This wraps [`types::user_regs_struct`], and it provides methods to extract the syscall number,
+/// the arguments, and the return value at a syscall stop regardless of the architecture.
+///
+/// Note that on some architectures, the registers used to pass the first argument (and on MIPS,
+/// the fourth argument) are overwritten with the return value, so [`args()`] is only reliable at
+/// syscall-entry stops. The exceptions are x86, x86-64, ARM, and PowerPC, where the kernel saves
+/// the original value of the first argument. On MIPS with the O32 ABI, the fifth and sixth
+/// arguments are passed on the stack, so [`args()`] returns 0 for them.
+///
+/// [`types::user_regs_struct`]: ../types/struct.user_regs_struct.html
+/// [`args()`]: #method.args
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Regs(types::user_regs_struct);
+
+impl Regs {
+    /// Get the syscall number.
+    ///
+    /// On x86-64, this includes the `X32_SYSCALL_BIT` for syscalls made through the x32 ABI.
+    ///
+    /// On MIPS, the syscall number is passed in `v0`, which the return value overwrites, so this
+    /// (and [`sysno()`]) is only valid at syscall-entry stops. Tracers that need the number at
+    /// the exit stop should remember it from the entry stop. (The exit stop reported by
+    /// [`get_syscall_info()`] doesn't include it either.)
+    ///
+    /// [`sysno()`]: #method.sysno
+    /// [`get_syscall_info()`]: ./fn.get_syscall_info.html
+    #[inline]
+    pub fn nr(&self) -> usize {
+        imp::nr(&self.0)
+    }
+
+    /// Get the syscall, if its number is recognized.
+    ///
+    /// See [`nr()`] for when this is valid.
+    ///
+    /// This assumes that the tracee uses the native ABI of the current target; for other tracees
+    /// (for example, 32-bit x86 processes traced from x86-64), [`get_regs()`] fails.
+    ///
+    /// [`nr()`]: #method.nr
+    /// [`get_regs()`]: ./fn.get_regs.html
+    #[inline]
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+
+    /// Get the syscall's arguments.
+    #[inline]
+    pub fn args(&self) -> [usize; 6] {
+        imp::args(&self.0)
+    }
+
+    /// Get the syscall's return value, in the same format as [`syscall_raw!`] returns it on this
+    /// platform.
+    ///
+    /// This is only meaningful at syscall-exit stops.
+    ///
+    /// [`syscall_raw!`]: ../macro.syscall_raw.html
+    #[inline]
+    pub fn raw_result(&self) -> RawResult {
+        imp::raw_result(&self.0)
+    }
+
+    /// Get the syscall's return value, decoded with [`decode_raw_result()`].
+    ///
+    /// This is only meaningful at syscall-exit stops.
+    ///
+    /// [`decode_raw_result()`]: ../fn.decode_raw_result.html
+    #[inline]
+    pub fn result(&self) -> Result<usize, i32> {
+        crate::decode_raw_result(self.raw_result())
+    }
+
+    /// Get the raw registers.
+    #[inline]
+    pub fn raw(&self) -> &types::user_regs_struct {
+        &self.0
+    }
+
+    /// Get a mutable reference to the raw registers (for example, to modify them before calling
+    /// [`set_regs()`]).
+    ///
+    /// [`set_regs()`]: ./fn.set_regs.html
+    #[inline]
+    pub fn raw_mut(&mut self) -> &mut types::user_regs_struct {
+        &mut self.0
+    }
+}
+
+impl From<types::user_regs_struct> for Regs {
+    #[inline]
+    fn from(regs: types::user_regs_struct) -> Self {
+        Self(regs)
+    }
+}
+
+impl From<Regs> for types::user_regs_struct {
+    #[inline]
+    fn from(regs: Regs) -> Self {
+        regs.0
+    }
+}
+
+impl fmt::Debug for Regs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Regs")
+            .field("nr", &self.nr())
+            .field("args", &self.args())
+            .field("raw_result", &self.raw_result())
+            .finish()
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.orig_rax as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        [regs.rdi, regs.rsi, regs.rdx, regs.r10, regs.r8, regs.r9].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        regs.rax as usize
+    }
+}
+
+#[cfg(target_arch = "x86")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.orig_eax as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        [regs.ebx, regs.ecx, regs.edx, regs.esi, regs.edi, regs.ebp].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        regs.eax as usize
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.regs[8] as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.regs;
+        [r[0], r[1], r[2], r[3], r[4], r[5]].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        regs.regs[0] as usize
+    }
+}
+
+#[cfg(target_arch = "arm")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    // `ORIG_r0`, which holds the first argument even after `r0` is overwritten
+    const ORIG_R0: usize = 17;
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.uregs[7] as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.uregs;
+        [r[ORIG_R0], r[1], r[2], r[3], r[4], r[5]].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        regs.uregs[0] as usize
+    }
+}
+
+#[cfg(target_arch = "riscv64")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.a7 as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        [regs.a0, regs.a1, regs.a2, regs.a3, regs.a4, regs.a5].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        regs.a0 as usize
+    }
+}
+
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    // The syscall number goes in `v0` and the return value comes back in `v0`, with `a3` set if
+    // it is an error number. The original `v0` isn't part of the register set that ptrace reports,
+    // so `nr()` can't be recovered at syscall-exit stops.
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.regs[2] as usize
+    }
+
+    #[cfg(target_arch = "mips")]
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.regs;
+        [r[4], r[5], r[6], r[7], 0, 0].map(|arg| arg as usize)
+    }
+
+    #[cfg(target_arch = "mips64")]
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.regs;
+        [r[4], r[5], r[6], r[7], r[8], r[9]].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        (regs.regs[2] as usize, regs.regs[7] != 0)
+    }
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    // The summary overflow bit of `cr0`, which `sc` sets on error
+    const CR0_SO: usize = 0x1000_0000;
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.gpr[0] as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.gpr;
+        [regs.orig_gpr3, r[4], r[5], r[6], r[7], r[8]].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        // Syscalls made with `scv` (trap 0x3000) return negated error numbers directly
+        let is_scv = regs.trap as usize & 0xfff0 == 0x3000;
+        if !is_scv && regs.ccr as usize & CR0_SO != 0 {
+            (regs.gpr[3] as usize).wrapping_neg()
+        } else {
+            regs.gpr[3] as usize
+        }
+    }
+}
+
+#[cfg(target_arch = "sparc64")]
+mod imp {
+    use crate::{types::user_regs_struct, RawResult};
+
+    // The carry bit of `xcc` in `tstate`, which the kernel sets on error
+    const TSTATE_XCARRY: u64 = 0x10_0000_0000;
+
+    const G1: usize = 1;
+    const O0: usize = 8;
+
+    #[inline]
+    pub fn nr(regs: &user_regs_struct) -> usize {
+        regs.regs[G1] as usize
+    }
+
+    #[inline]
+    pub fn args(regs: &user_regs_struct) -> [usize; 6] {
+        let r = &regs.regs[O0..];
+        [r[0], r[1], r[2], r[3], r[4], r[5]].map(|arg| arg as usize)
+    }
+
+    #[inline]
+    pub fn raw_result(regs: &user_regs_struct) -> RawResult {
+        if regs.tstate & TSTATE_XCARRY != 0 {
+            (regs.regs[O0] as usize).wrapping_neg()
+        } else {
+            regs.regs[O0] as usize
+        }
+    }
+}
+
+/// Get the general-purpose registers of the stopped tracee `pid` (`PTRACE_GETREGSET` with
+/// `NT_PRSTATUS`).
+///
+/// If the tracee is using a different ABI than the current target (for example, a 32-bit x86
+/// process traced from x86-64), the kernel returns a register set with a different layout; in
+/// that case this fails with `EINVAL`. [`get_syscall_info()`] can be used for such tracees.
+///
+/// [`get_syscall_info()`]: ./fn.get_syscall_info.html
+pub fn get_regs(pid: i32) -> Result<Regs, i32> {
+    // All of the fields are integers, so this is a valid value
+    let mut regs: types::user_regs_struct = unsafe { core::mem::zeroed() };
+    let size = core::mem::size_of::<types::user_regs_struct>();
+
+    let mut iov = types::iovec {
+        iov_base: &mut regs as *mut types::user_regs_struct as *mut _,
+        iov_len: size,
+    };
+    unsafe {
+        ptrace(
+            PTRACE_GETREGSET,
+            pid,
+            NT_PRSTATUS as usize,
+            &mut iov as *mut types::iovec as usize,
+        )?;
+    }
+
+    // The kernel updates `iov_len` to the size of the register set it wrote
+    if iov.iov_len != size {
+        return Err(crate::eno::EINVAL);
+    }
+
+    Ok(Regs(regs))
+}
+
+/// Set the general-purpose registers of the stopped tracee `pid` (`PTRACE_SETREGSET` with
+/// `NT_PRSTATUS`).
+///
+/// On most architectures, the syscall number can be changed at a syscall-entry stop by changing
+/// the register that [`Regs::nr()`] reads (on x86 and x86-64, `orig_eax`/`orig_rax`).
+///
+/// [`Regs::nr()`]: ./struct.Regs.html#method.nr
+pub fn set_regs(pid: i32, regs: &Regs) -> Result<(), i32> {
+    let mut iov = types::iovec {
+        iov_base: &regs.0 as *const types::user_regs_struct as *mut _,
+        iov_len: core::mem::size_of::<types::user_regs_struct>(),
+    };
+    unsafe {
+        ptrace(
+            PTRACE_SETREGSET,
+            pid,
+            NT_PRSTATUS as usize,
+            &mut iov as *mut types::iovec as usize,
+        )?;
+    }
+    Ok(())
+}
+
+// `struct ptrace_syscall_info`. The last member is a union of:
+// - `entry`: `nr`, then `args`
+// - `exit`: `rval` (in `nr`), then `is_error` (in the first byte of `args`)
+// - `seccomp`: `nr`, then `args`, then `ret_data`
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+struct RawSyscallInfo {
+    op: u8,
+    pad: [u8; 3],
+    arch: u32,
+    instruction_pointer: u64,
+    stack_pointer: u64,
+    nr: u64,
+    args: [u64; 6],
+    ret_data: u32,
+}
+
+/// The kind of stop reported by [`get_syscall_info()`], along with the information that is
+/// available for it.
+///
+/// [`get_syscall_info()`]: ./fn.get_syscall_info.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SyscallStop {
+    /// The tracee is not stopped at a syscall.
+    None,
+    /// The tracee is stopped at syscall entry.
+    Entry { nr: u64, args: [u64; 6] },
+    /// The tracee is stopped at syscall exit; the return value is decoded like
+    /// [`decode_raw_result()`] does.
+    ///
+    /// [`decode_raw_result()`]: ../fn.decode_raw_result.html
+    Exit(Result<usize, i32>),
+    /// The tracee is stopped because a seccomp filter returned `SECCOMP_RET_TRACE`; `ret_data` is
+    /// the data that the filter returned.
+    Seccomp {
+        nr: u64,
+        args: [u64; 6],
+        ret_data: u32,
+    },
+}
+
+/// Information about the syscall that a tracee is stopped at, as returned by
+/// [`get_syscall_info()`].
+///
+/// [`get_syscall_info()`]: ./fn.get_syscall_info.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SyscallInfo {
+    /// The `AUDIT_ARCH_*` value for the ABI the syscall was made with (see the [`seccomp`]
+    /// module).
+    ///
+    /// [`seccomp`]: ../seccomp/index.html
+    pub arch: u32,
+    pub instruction_pointer: u64,
+    pub stack_pointer: u64,
+    pub stop: SyscallStop,
+}
+
+impl SyscallInfo {
+    /// Get the syscall number, or `None` if the tracee is not stopped at syscall entry (or at a
+    /// seccomp stop).
+    #[inline]
+    pub fn nr(&self) -> Option<u64> {
+        match self.stop {
+            SyscallStop::Entry { nr, .. } | SyscallStop::Seccomp { nr, .. } => Some(nr),
+            _ => None,
+        }
+    }
+
+    /// Get the syscall, if the tracee is stopped at syscall entry (or at a seccomp stop), the
+    /// syscall was made with the native ABI of the current target, and its number is recognized.
+    ///
+    /// Syscalls made with other ABIs can be looked up in the tables in the [`arch`] module (with
+    /// the `arch` feature).
+    ///
+    /// [`arch`]: ../arch/index.html
+    #[inline]
+    pub fn sysno(&self) -> Option<Sysno> {
+        if self.arch == AUDIT_ARCH {
+            self.nr().and_then(|nr| Sysno::new(nr as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Get the syscall's arguments, or `None` if the tracee is not stopped at syscall entry (or
+    /// at a seccomp stop).
+    #[inline]
+    pub fn args(&self) -> Option<[u64; 6]> {
+        match self.stop {
+            SyscallStop::Entry { args, .. } | SyscallStop::Seccomp { args, .. } => Some(args),
+            _ => None,
+        }
+    }
+
+    /// Get the syscall's return value, or `None` if the tracee is not stopped at syscall exit.
+    #[inline]
+    pub fn result(&self) -> Option<Result<usize, i32>> {
+        match self.stop {
+            SyscallStop::Exit(res) => Some(res),
+            _ => None,
+        }
+    }
+}
+
+/// Get information about the syscall that the stopped tracee `pid` is stopped at
+/// (`PTRACE_GET_SYSCALL_INFO`, Linux 5.3+).
+///
+/// Unlike [`get_regs()`], this works regardless of the ABI that the tracee is using. It fails
+/// with `EIO` on older kernels.
+///
+/// [`get_regs()`]: ./fn.get_regs.html
+pub fn get_syscall_info(pid: i32) -> Result<SyscallInfo, i32> {
+    let mut raw = RawSyscallInfo::default();
+    unsafe {
+        ptrace(
+            PTRACE_GET_SYSCALL_INFO,
+            pid,
+            core::mem::size_of::<RawSyscallInfo>(),
+            &mut raw as *mut RawSyscallInfo as usize,
+        )?;
+    }
+
+    let stop = match raw.op {
+        PTRACE_SYSCALL_INFO_ENTRY => SyscallStop::Entry {
+            nr: raw.nr,
+            args: raw.args,
+        },
+        PTRACE_SYSCALL_INFO_EXIT => {
+            let rval = raw.nr as i64;
+            SyscallStop::Exit(if raw.args[0].to_ne_bytes()[0] != 0 {
+                Err(rval.wrapping_neg() as i32)
+            } else {
+                Ok(rval as usize)
+            })
+        }
+        PTRACE_SYSCALL_INFO_SECCOMP => SyscallStop::Seccomp {
+            nr: raw.nr,
+            args: raw.args,
+            ret_data: raw.ret_data,
+        },
+        _ => SyscallStop::None,
+    };
+
+    Ok(SyscallInfo {
+        arch: raw.arch,
+        instruction_pointer: raw.instruction_pointer,
+        stack_pointer: raw.stack_pointer,
+        stop,
+    })
+}
@@ -0,0 +1,190 @@
+/// A version of [`syscall!`] that automatically retries the syscall if it fails with `EINTR`.
+///
+/// Many blocking syscalls (like `read()`, `wait4()`, and `epoll_wait()`) can be interrupted by
+/// signal handlers, in which case they fail with `EINTR` and must be restarted by the caller. This
+/// macro does that automatically; it returns a `Result<usize, i32>` just like [`syscall!`], but
+/// the error will never be `EINTR`.
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall_retry;
+/// let mut fds = [0i32; 2];
+/// unsafe {
+///     assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+///     syscall_retry!(WRITE, fds[1], b"x".as_ptr(), 1).unwrap();
+///
+///     let mut buf = [0u8; 1];
+///     assert_eq!(syscall_retry!(READ, fds[0], buf.as_mut_ptr(), buf.len()), Ok(1));
+///     assert_eq!(&buf, b"x");
+/// #   scall::syscall_nofail!(CLOSE, fds[0]);
+/// #   scall::syscall_nofail!(CLOSE, fds[1]);
+/// }
+/// ```
+///
+/// # Important notes
+///
+/// - The arguments are re-evaluated every time the syscall is retried. Avoid arguments with side
+///   effects.
+///
+/// - Retrying syscalls that accept a *relative* timeout (like `ppoll()` or `clock_nanosleep()`
+///   without `TIMER_ABSTIME`) with the same timeout can lengthen the total time spent waiting
+///   considerably. Use [`syscall_retry_timeout!`] for these syscalls instead.
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`syscall_retry_timeout!`]: ./macro.syscall_retry_timeout.html
+#[macro_export]
+macro_rules! syscall_retry {
+    ($nr:ident$(, $args:expr)* $(,)?) => {
+        loop {
+            match $crate::decode_raw_result($crate::syscall_raw!($nr$(, $args)*)) {
+                Err($crate::eno::EINTR) => (),
+                res => break res,
+            }
+        }
+    };
+
+    ($nr:expr$(, $args:expr)* $(,)?) => {
+        loop {
+            match $crate::decode_raw_result($crate::syscall_raw!($nr$(, $args)*)) {
+                Err($crate::eno::EINTR) => (),
+                res => break res,
+            }
+        }
+    };
+}
+
+/// A version of [`syscall_retry!`] for syscalls that accept a relative timeout.
+///
+/// The first argument must be a mutable place expression (usually a local variable) of a
+/// `timespec`-like type (i.e. one with `tv_sec` and `tv_nsec` fields) that holds the timeout. The
+/// rest of the arguments are passed on to [`syscall!`] as usual; presumably one of them will be a
+/// pointer to the timeout.
+///
+/// Before the syscall is first made, this macro computes the deadline (using `CLOCK_MONOTONIC`).
+/// Each time the syscall fails with `EINTR`, the amount of time remaining until the deadline is
+/// stored back into the timeout before retrying, so the total time spent waiting is not
+/// lengthened by the retries.
+///
+/// # Example
+///
+/// ```
+/// # use scall::syscall_retry_timeout;
+/// let mut timeout = scall::types::timespec {
+///     tv_sec: 0,
+///     tv_nsec: 1_000_000,
+/// };
+///
+/// unsafe {
+///     syscall_retry_timeout!(
+///         timeout,
+///         CLOCK_NANOSLEEP,
+///         scall::consts::CLOCK_MONOTONIC,
+///         0,
+///         &timeout as *const scall::types::timespec,
+///         std::ptr::null_mut::<scall::types::timespec>(),
+///     )
+///     .unwrap();
+/// }
+/// ```
+///
+/// # Important notes
+///
+/// - This macro doesn't know which argument is the timeout; it's up to the caller to make sure
+///   that the syscall actually uses the timeout that was passed as the first argument.
+///
+/// - The timeout expression is evaluated multiple times, so it should be a simple place
+///   expression (like `timeout` or `*timeout_ref`).
+///
+/// - The timeout must be *relative*. If the syscall is given an absolute timeout (for example,
+///   `clock_nanosleep()` with `TIMER_ABSTIME`), use [`syscall_retry!`] instead.
+///
+/// # Safety
+///
+/// See [`syscall!`].
+///
+/// [`syscall!`]: ./macro.syscall.html
+/// [`syscall_retry!`]: ./macro.syscall_retry.html
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[doc(cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd")))]
+#[macro_export]
+macro_rules! syscall_retry_timeout {
+    ($timeout:expr, $nr:ident$(, $args:expr)* $(,)?) => {{
+        $crate::_scall_internal_check_nargs!($nr$(, $args)*);
+        $crate::syscall_retry_timeout!($timeout, $crate::nr::$nr$(, $args)*)
+    }};
+
+    ($timeout:expr, $nr:expr$(, $args:expr)* $(,)?) => {{
+        let deadline = $crate::_scall_internal_deadline(
+            $timeout.tv_sec as i64,
+            $timeout.tv_nsec as i64,
+        );
+
+        loop {
+            match $crate::decode_raw_result($crate::syscall_raw!($nr$(, $args)*)) {
+                Err($crate::eno::EINTR) => {
+                    let (sec, nsec) = $crate::_scall_internal_remaining(deadline);
+                    $timeout.tv_sec = sec as _;
+                    $timeout.tv_nsec = nsec as _;
+                }
+                res => break res,
+            }
+        }
+    }};
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+const NSEC_PER_SEC: i64 = 1_000_000_000;
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn monotonic_now() -> (i64, i64) {
+    let mut ts = crate::types::timespec::default();
+    unsafe {
+        // This can't fail with a valid clock ID and a valid pointer.
+        crate::syscall_nofail!(
+            CLOCK_GETTIME,
+            crate::consts::CLOCK_MONOTONIC,
+            &mut ts as *mut _
+        );
+    }
+    (ts.tv_sec as _, ts.tv_nsec as _)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[doc(hidden)]
+pub fn _scall_internal_deadline(sec: i64, nsec: i64) -> (i64, i64) {
+    let (now_sec, now_nsec) = monotonic_now();
+
+    let mut sec = now_sec.saturating_add(sec);
+    let mut nsec = now_nsec + nsec;
+    if nsec >= NSEC_PER_SEC {
+        sec = sec.saturating_add(nsec / NSEC_PER_SEC);
+        nsec %= NSEC_PER_SEC;
+    }
+
+    (sec, nsec)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[doc(hidden)]
+pub fn _scall_internal_remaining((deadline_sec, deadline_nsec): (i64, i64)) -> (i64, i64) {
+    let (now_sec, now_nsec) = monotonic_now();
+
+    let mut sec = deadline_sec - now_sec;
+    let mut nsec = deadline_nsec - now_nsec;
+    if nsec < 0 {
+        sec -= 1;
+        nsec += NSEC_PER_SEC;
+    }
+
+    if sec < 0 {
+        // The deadline has already passed
+        (0, 0)
+    } else {
+        (sec, nsec)
+    }
+}
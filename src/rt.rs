@@ -0,0 +1,85 @@
+//! A minimal runtime for programs that don't link against a C library.
+//!
+//! When the `rt` feature is enabled, this crate provides a `_start` entry point for every
+//! supported Linux architecture. It:
+//!
+//! 1. Reads `argc`, `argv`, and `envp` from the initial stack.
+//! 2. Seeds the cache used by [`auxv::getauxval()`] from the auxiliary vector that follows `envp`
+//!    (see [`auxv::init()`]), so `/proc` doesn't need to be mounted.
+//! 3. Calls `main()` (which is looked up by its symbol name and has the same signature as in C).
+//! 4. Exits the process (with `exit_group()`) using the value returned by `main()` as the exit
+//!    status.
+//!
+//! `_start` is a weak symbol, so if the C library's startup files are linked in (as they are by
+//! default), they take precedence and this runtime is not used.
+//!
+//! # Example
+//!
+//! ```ignore
+//! #![no_std]
+//! #![no_main]
+//!
+//! use scall::syscall_nofail;
+//!
+//! #[no_mangle]
+//! extern "C" fn main(_argc: i32, _argv: *const *const u8, _envp: *const *const u8) -> i32 {
+//!     let msg = b"Hello, world!\n";
+//!     unsafe {
+//!         syscall_nofail!(WRITE, 1, msg.as_ptr(), msg.len());
+//!     }
+//!     0
+//! }
+//!
+//! #[panic_handler]
+//! fn panic(_info: &core::panic::PanicInfo) -> ! {
+//!     unsafe {
+//!         syscall_nofail!(EXIT_GROUP, 101);
+//!     }
+//!     loop {}
+//! }
+//! ```
+//!
+//! The program must be built with `panic = "abort"`, and the C library's startup files must be
+//! left out when linking. For example:
+//!
+//! ```text
+//! RUSTFLAGS="-C panic=abort -C relocation-model=static -C link-arg=-nostdlib -C link-arg=-static" \
+//!     cargo build --release
+//! ```
+//!
+//! The functions that the compiler expects the C library to provide (`memcpy()`, `memset()`,
+//! etc.) are also missing; they either have to be defined by the program or pulled in from
+//! `compiler_builtins` (for example, with `-Z build-std=core
+//! -Z build-std-features=compiler-builtins-mem`).
+//!
+//! Note that nothing else is set up: there is no thread-local storage, and the signal
+//! disposition and mask are whatever the parent left them as.
+//!
+//! [`auxv::getauxval()`]: ../auxv/fn.getauxval.html
+//! [`auxv::init()`]: ../auxv/fn.init.html
+
+extern "C" {
+    fn main(argc: i32, argv: *const *const u8, envp: *const *const u8) -> i32;
+}
+
+// Called by the `_start` entry points in `platform/*/start.rs` with a pointer to `argc`. The
+// initial stack looks like this:
+//
+//     argc
+//     argv[0], ..., argv[argc - 1], NULL
+//     envp[0], ..., NULL
+//     auxv[0], ..., AT_NULL
+#[no_mangle]
+unsafe extern "C" fn _scall_internal_start(sp: *const usize) -> ! {
+    let argc = *sp;
+    let argv = sp.add(1) as *const *const u8;
+    let envp = argv.add(argc + 1);
+
+    crate::auxv::init(crate::auxv::AuxvIter::from_envp(envp).as_ptr());
+
+    let status = main(argc as i32, argv, envp);
+
+    loop {
+        crate::syscall_nofail!(EXIT_GROUP, status);
+    }
+}
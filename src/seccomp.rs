@@ -0,0 +1,1208 @@
+//! Building and installing seccomp-BPF filters.
+//!
+//! A seccomp filter is a classic BPF program that the kernel runs on every syscall the thread
+//! makes; it inspects a `struct seccomp_data` (the syscall number, the architecture, and the
+//! arguments) and returns an action. Writing these programs by hand is error-prone: the jump
+//! offsets have to be computed manually, the 64-bit arguments have to be compared 32 bits at a
+//! time, and the filter has to check the architecture first (otherwise, for example, a 32-bit x86
+//! syscall made with `int $0x80` from an x86-64 program would be checked against the x86-64
+//! syscall numbers).
+//!
+//! [`Filter`] compiles a list of [`Rule`]s into a program, taking care of all of this. Since this
+//! crate doesn't allocate, the program is written to a buffer provided by the caller;
+//! [`Filter::program_len()`] returns the required size.
+//!
+//! Syscalls that a filter handles with [`Action::UserNotif`] are passed to a supervisor process,
+//! which can inspect them and decide how they should complete; [`Supervisor`] implements that
+//! side of the protocol.
+//!
+//! # Example
+//!
+//! ```
+//! use scall::seccomp::{self, Action, ArgCmp, CmpOp, Filter, Rule, SockFilter};
+//! use scall::{eno, nr, sig, syscall};
+//!
+//! // Make `dup(1000)` fail with `ENOTTY`
+//! let args = [ArgCmp::new32(0, CmpOp::Eq, 1000)];
+//! let rules = [Rule::with_args(nr::DUP, &args, Action::Errno(eno::ENOTTY))];
+//! let filter = Filter::new(Action::Allow, &rules);
+//!
+//! let mut buf = [SockFilter::default(); 16];
+//! let prog = filter.compile(&mut buf).unwrap();
+//! assert_eq!(prog.len(), filter.program_len());
+//!
+//! // Filters are inherited and can't be removed, so install this one in a child process
+//! let mut stack = vec![0u8; 65536];
+//! let pid = unsafe {
+//!     scall::clone_with_stack(
+//!         sig::SIGCHLD as u64,
+//!         stack.as_mut_ptr().add(stack.len()),
+//!         0,
+//!         core::ptr::null_mut(),
+//!         core::ptr::null_mut(),
+//!         || {
+//!             seccomp::set_no_new_privs().unwrap();
+//!             seccomp::install_program(prog, 0).unwrap();
+//!
+//!             match syscall!(DUP, 1000) {
+//!                 Err(eno::ENOTTY) => 0,
+//!                 _ => 1,
+//!             }
+//!         },
+//!     )
+//!     .unwrap()
+//! };
+//!
+//! let mut status = 0;
+//! unsafe {
+//!     syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+//! }
+//! assert_eq!(status, 0);
+//! ```
+//!
+//! [`Filter`]: ./struct.Filter.html
+//! [`Rule`]: ./struct.Rule.html
+//! [`Filter::program_len()`]: ./struct.Filter.html#method.program_len
+//! [`Action::UserNotif`]: ./enum.Action.html#variant.UserNotif
+//! [`Supervisor`]: ./struct.Supervisor.html
+
+use crate::{eno, ioctl};
+
+/// Instruction class: load into the accumulator.
+pub const BPF_LD: u16 = 0x00;
+/// Instruction class: arithmetic/logic on the accumulator.
+pub const BPF_ALU: u16 = 0x04;
+/// Instruction class: jump.
+pub const BPF_JMP: u16 = 0x05;
+/// Instruction class: return.
+pub const BPF_RET: u16 = 0x06;
+
+/// Load size: 32-bit word.
+pub const BPF_W: u16 = 0x00;
+/// Load mode: absolute offset into the `seccomp_data`.
+pub const BPF_ABS: u16 = 0x20;
+
+/// ALU operation: bitwise and.
+pub const BPF_AND: u16 = 0x50;
+
+/// Jump operation: always jump by `k`.
+pub const BPF_JA: u16 = 0x00;
+/// Jump operation: jump if the accumulator equals the operand.
+pub const BPF_JEQ: u16 = 0x10;
+/// Jump operation: jump if the accumulator is greater than the operand.
+pub const BPF_JGT: u16 = 0x20;
+/// Jump operation: jump if the accumulator is greater than or equal to the operand.
+pub const BPF_JGE: u16 = 0x30;
+/// Jump operation: jump if the accumulator and the operand have any bits in common.
+pub const BPF_JSET: u16 = 0x40;
+
+/// Source operand: the constant `k`.
+pub const BPF_K: u16 = 0x00;
+
+/// The maximum number of instructions in a BPF program.
+pub const BPF_MAXINSNS: usize = 4096;
+
+/// Kill the whole process (Linux 4.14+).
+pub const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+/// Kill the calling thread.
+pub const SECCOMP_RET_KILL_THREAD: u32 = 0x0000_0000;
+/// Send a `SIGSYS` signal to the calling thread.
+pub const SECCOMP_RET_TRAP: u32 = 0x0003_0000;
+/// Fail the syscall with the error number in the low 16 bits.
+pub const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+/// Notify the supervisor listening on the filter's notification file descriptor (Linux 5.0+).
+pub const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+/// Notify the `ptrace()` tracer.
+pub const SECCOMP_RET_TRACE: u32 = 0x7ff0_0000;
+/// Log the syscall and allow it (Linux 4.14+).
+pub const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+/// Allow the syscall.
+pub const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+/// Mask for the action part of a filter's return value.
+pub const SECCOMP_RET_ACTION_FULL: u32 = 0xffff_0000;
+/// Mask for the data part of a filter's return value.
+pub const SECCOMP_RET_DATA: u32 = 0x0000_ffff;
+
+/// `seccomp()` operation: only allow `read()`, `write()`, `_exit()` and `sigreturn()`.
+pub const SECCOMP_SET_MODE_STRICT: u32 = 0;
+/// `seccomp()` operation: install a BPF filter.
+pub const SECCOMP_SET_MODE_FILTER: u32 = 1;
+/// `seccomp()` operation: check whether a `SECCOMP_RET_*` action is supported.
+pub const SECCOMP_GET_ACTION_AVAIL: u32 = 2;
+/// `seccomp()` operation: get the sizes of the user notification structures.
+pub const SECCOMP_GET_NOTIF_SIZES: u32 = 3;
+
+/// Install the filter on all the threads in the process.
+pub const SECCOMP_FILTER_FLAG_TSYNC: u32 = 1 << 0;
+/// Log all the actions taken by the filter, except `SECCOMP_RET_ALLOW`.
+pub const SECCOMP_FILTER_FLAG_LOG: u32 = 1 << 1;
+/// Don't enable the speculative store bypass mitigation.
+pub const SECCOMP_FILTER_FLAG_SPEC_ALLOW: u32 = 1 << 2;
+/// Return a file descriptor for receiving `SECCOMP_RET_USER_NOTIF` notifications.
+pub const SECCOMP_FILTER_FLAG_NEW_LISTENER: u32 = 1 << 3;
+/// With `SECCOMP_FILTER_FLAG_TSYNC`, fail with `ESRCH` (instead of returning a thread ID) if
+/// another thread can't be synchronized.
+pub const SECCOMP_FILTER_FLAG_TSYNC_ESRCH: u32 = 1 << 4;
+/// Only let fatal signals interrupt a syscall that is waiting for a user notification reply.
+pub const SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV: u32 = 1 << 5;
+
+/// The `prctl()` option for setting the seccomp mode.
+pub const PR_SET_SECCOMP: i32 = 22;
+/// The `prctl()` option for setting the "no new privileges" flag.
+pub const PR_SET_NO_NEW_PRIVS: i32 = 38;
+/// The seccomp mode for `PR_SET_SECCOMP` that installs a filter.
+pub const SECCOMP_MODE_FILTER: u32 = 2;
+
+/// The `seccomp_data.arch` value for x86-64 (and x32).
+pub const AUDIT_ARCH_X86_64: u32 = 0xc000_003e;
+/// The `seccomp_data.arch` value for 32-bit x86.
+pub const AUDIT_ARCH_I386: u32 = 0x4000_0003;
+/// The `seccomp_data.arch` value for little-endian 32-bit ARM.
+pub const AUDIT_ARCH_ARM: u32 = 0x4000_0028;
+/// The `seccomp_data.arch` value for big-endian 32-bit ARM.
+pub const AUDIT_ARCH_ARMEB: u32 = 0x0000_0028;
+/// The `seccomp_data.arch` value for AArch64.
+pub const AUDIT_ARCH_AARCH64: u32 = 0xc000_00b7;
+/// The `seccomp_data.arch` value for big-endian 32-bit MIPS.
+pub const AUDIT_ARCH_MIPS: u32 = 0x0000_0008;
+/// The `seccomp_data.arch` value for little-endian 32-bit MIPS.
+pub const AUDIT_ARCH_MIPSEL: u32 = 0x4000_0008;
+/// The `seccomp_data.arch` value for big-endian 64-bit MIPS.
+pub const AUDIT_ARCH_MIPS64: u32 = 0x8000_0008;
+/// The `seccomp_data.arch` value for little-endian 64-bit MIPS.
+pub const AUDIT_ARCH_MIPSEL64: u32 = 0xc000_0008;
+/// The `seccomp_data.arch` value for 32-bit PowerPC.
+pub const AUDIT_ARCH_PPC: u32 = 0x0000_0014;
+/// The `seccomp_data.arch` value for big-endian 64-bit PowerPC.
+pub const AUDIT_ARCH_PPC64: u32 = 0x8000_0015;
+/// The `seccomp_data.arch` value for little-endian 64-bit PowerPC.
+pub const AUDIT_ARCH_PPC64LE: u32 = 0xc000_0015;
+/// The `seccomp_data.arch` value for 64-bit RISC-V.
+pub const AUDIT_ARCH_RISCV64: u32 = 0xc000_00f3;
+/// The `seccomp_data.arch` value for 64-bit SPARC.
+pub const AUDIT_ARCH_SPARC64: u32 = 0x8000_002b;
+
+/// The `AUDIT_ARCH_*` value that the kernel reports for syscalls made natively on the current
+/// target.
+#[cfg(target_arch = "x86_64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_X86_64;
+#[cfg(target_arch = "x86")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_I386;
+#[cfg(all(target_arch = "arm", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARM;
+#[cfg(all(target_arch = "arm", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARMEB;
+#[cfg(target_arch = "aarch64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_AARCH64;
+#[cfg(all(target_arch = "mips", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS;
+#[cfg(all(target_arch = "mips", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL;
+#[cfg(all(target_arch = "mips64", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS64;
+#[cfg(all(target_arch = "mips64", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL64;
+#[cfg(target_arch = "powerpc")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC;
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64;
+#[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64LE;
+#[cfg(target_arch = "riscv64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_RISCV64;
+#[cfg(target_arch = "sparc64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_SPARC64;
+
+/// On x86-64, this bit is set in the numbers of the syscalls made through the x32 ABI (which
+/// share `AUDIT_ARCH_X86_64` with the native 64-bit syscalls).
+pub const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+// The offsets of the fields of `struct seccomp_data`
+const DATA_NR: u32 = 0;
+const DATA_ARCH: u32 = 4;
+const DATA_ARGS: u32 = 16;
+
+/// A BPF instruction (`struct sock_filter`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SockFilter {
+    pub code: u16,
+    pub jt: u8,
+    pub jf: u8,
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// Create a non-jump instruction (like the `BPF_STMT()` macro in C).
+    #[inline]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// Create a jump instruction (like the `BPF_JUMP()` macro in C).
+    ///
+    /// `jt` and `jf` are the number of instructions to skip if the condition is true or false,
+    /// respectively.
+    #[inline]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+// `struct sock_fprog`
+#[repr(C)]
+struct SockFprog {
+    len: u16,
+    filter: *const SockFilter,
+}
+
+/// The action a filter takes for a syscall.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Kill the whole process, as if by an unblockable `SIGSYS` (Linux 4.14+).
+    KillProcess,
+    /// Kill the calling thread, as if by an unblockable `SIGSYS`.
+    KillThread,
+    /// Send a `SIGSYS` signal to the calling thread; the value is available in the `si_errno`
+    /// field of the signal information.
+    Trap(u16),
+    /// Fail the syscall with the given error number.
+    Errno(i32),
+    /// Notify the supervisor listening on the filter's notification file descriptor (Linux 5.0+).
+    UserNotif,
+    /// Notify the `ptrace()` tracer; the value is available with `PTRACE_GETEVENTMSG`.
+    Trace(u16),
+    /// Log the syscall and allow it (Linux 4.14+).
+    Log,
+    /// Allow the syscall.
+    Allow,
+}
+
+impl Action {
+    /// Get the `SECCOMP_RET_*` value that a filter returns for this action.
+    #[inline]
+    pub const fn to_raw(self) -> u32 {
+        match self {
+            Self::KillProcess => SECCOMP_RET_KILL_PROCESS,
+            Self::KillThread => SECCOMP_RET_KILL_THREAD,
+            Self::Trap(data) => SECCOMP_RET_TRAP | data as u32,
+            Self::Errno(eno) => SECCOMP_RET_ERRNO | (eno as u32 & SECCOMP_RET_DATA),
+            Self::UserNotif => SECCOMP_RET_USER_NOTIF,
+            Self::Trace(data) => SECCOMP_RET_TRACE | data as u32,
+            Self::Log => SECCOMP_RET_LOG,
+            Self::Allow => SECCOMP_RET_ALLOW,
+        }
+    }
+}
+
+/// A comparison operator for [`ArgCmp`].
+///
+/// All the comparisons are unsigned.
+///
+/// [`ArgCmp`]: ./struct.ArgCmp.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Compare the argument, masked with the given value, to the value in the `ArgCmp` for
+    /// equality.
+    MaskedEq(u64),
+}
+
+/// A comparison of one of the syscall's arguments against a constant.
+///
+/// The arguments are 64-bit values in `struct seccomp_data` on every architecture. On 32-bit
+/// architectures, the upper 32 bits are always 0. On 64-bit architectures, the upper 32 bits of
+/// an argument that the C prototype declares as `int` (or another 32-bit type) are unspecified,
+/// so such arguments should be compared with [`new32()`], which ignores them.
+///
+/// [`new32()`]: #method.new32
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArgCmp {
+    arg: u8,
+    op: CmpOp,
+    value: u64,
+    wide: bool,
+}
+
+impl ArgCmp {
+    /// Compare the full 64-bit value of argument `arg` (0-5) against `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arg` is greater than 5.
+    #[inline]
+    pub const fn new(arg: u8, op: CmpOp, value: u64) -> Self {
+        assert!(arg < 6, "syscalls have at most 6 arguments");
+        Self {
+            arg,
+            op,
+            value,
+            wide: true,
+        }
+    }
+
+    /// Compare the low 32 bits of argument `arg` (0-5) against `value`.
+    ///
+    /// For `CmpOp::MaskedEq`, only the low 32 bits of the mask are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arg` is greater than 5.
+    #[inline]
+    pub const fn new32(arg: u8, op: CmpOp, value: u32) -> Self {
+        assert!(arg < 6, "syscalls have at most 6 arguments");
+        Self {
+            arg,
+            op,
+            value: value as u64,
+            wide: false,
+        }
+    }
+
+    // The number of instructions generated for this comparison
+    fn len(&self) -> usize {
+        let half = match self.op {
+            CmpOp::MaskedEq(_) => 3,
+            _ => 2,
+        };
+
+        if !self.wide {
+            half
+        } else {
+            match self.op {
+                CmpOp::Eq | CmpOp::Ne | CmpOp::MaskedEq(_) => half * 2,
+                // The comparison of the upper halves needs two jumps
+                _ => 5,
+            }
+        }
+    }
+
+    // The offsets of the low and high halves of the argument in `struct seccomp_data`
+    fn offsets(&self) -> (u32, u32) {
+        let off = DATA_ARGS + self.arg as u32 * 8;
+        if cfg!(target_endian = "little") {
+            (off, off + 4)
+        } else {
+            (off + 4, off)
+        }
+    }
+
+    // Emit the comparison; if it fails, jump to the instruction at `fail`
+    fn emit(&self, prog: &mut Emitter, fail: usize) -> Result<(), i32> {
+        let (lo_off, hi_off) = self.offsets();
+        let (lo, hi) = (self.value as u32, (self.value >> 32) as u32);
+        let end = prog.pos + self.len();
+
+        let load = |prog: &mut Emitter, off| prog.stmt(BPF_LD | BPF_W | BPF_ABS, off);
+
+        if self.wide {
+            load(prog, hi_off);
+
+            match self.op {
+                CmpOp::Eq => prog.jump(BPF_JEQ, hi, None, Some(fail))?,
+                CmpOp::Ne => prog.jump(BPF_JEQ, hi, None, Some(end))?,
+                CmpOp::Gt | CmpOp::Ge => {
+                    prog.jump(BPF_JGT, hi, Some(end), None)?;
+                    prog.jump(BPF_JEQ, hi, None, Some(fail))?;
+                }
+                CmpOp::Lt | CmpOp::Le => {
+                    prog.jump(BPF_JGT, hi, Some(fail), None)?;
+                    prog.jump(BPF_JEQ, hi, None, Some(end))?;
+                }
+                CmpOp::MaskedEq(mask) => {
+                    prog.stmt(BPF_ALU | BPF_AND | BPF_K, (mask >> 32) as u32);
+                    prog.jump(BPF_JEQ, hi, None, Some(fail))?;
+                }
+            }
+        }
+
+        load(prog, lo_off);
+
+        match self.op {
+            CmpOp::Eq => prog.jump(BPF_JEQ, lo, None, Some(fail)),
+            CmpOp::Ne => prog.jump(BPF_JEQ, lo, Some(fail), None),
+            CmpOp::Gt => prog.jump(BPF_JGT, lo, None, Some(fail)),
+            CmpOp::Ge => prog.jump(BPF_JGE, lo, None, Some(fail)),
+            CmpOp::Lt => prog.jump(BPF_JGE, lo, Some(fail), None),
+            CmpOp::Le => prog.jump(BPF_JGT, lo, Some(fail), None),
+            CmpOp::MaskedEq(mask) => {
+                prog.stmt(BPF_ALU | BPF_AND | BPF_K, mask as u32);
+                prog.jump(BPF_JEQ, lo, None, Some(fail))
+            }
+        }
+    }
+}
+
+/// A rule in a [`Filter`]: an action to take for a syscall, optionally only if its arguments
+/// match a set of comparisons.
+///
+/// [`Filter`]: ./struct.Filter.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rule<'a> {
+    nr: usize,
+    args: &'a [ArgCmp],
+    action: Action,
+}
+
+impl<'a> Rule<'a> {
+    /// Take `action` for the syscall `nr` (one of the constants from the [`nr`] module).
+    ///
+    /// [`nr`]: ../nr/index.html
+    #[inline]
+    pub const fn new(nr: usize, action: Action) -> Self {
+        Self {
+            nr,
+            args: &[],
+            action,
+        }
+    }
+
+    /// Take `action` for the syscall `nr`, but only if all of the comparisons in `args` are
+    /// true.
+    #[inline]
+    pub const fn with_args(nr: usize, args: &'a [ArgCmp], action: Action) -> Self {
+        Self { nr, args, action }
+    }
+
+    fn len(&self) -> usize {
+        if self.args.is_empty() {
+            2
+        } else {
+            // Check the syscall number, compare the arguments, return the action, and reload the
+            // syscall number if any of the comparisons fails
+            self.args.iter().map(ArgCmp::len).sum::<usize>() + 3
+        }
+    }
+}
+
+// Writes the instructions of a program
+struct Emitter<'b> {
+    buf: &'b mut [SockFilter],
+    pos: usize,
+}
+
+impl Emitter<'_> {
+    fn stmt(&mut self, code: u16, k: u32) {
+        self.buf[self.pos] = SockFilter::stmt(code, k);
+        self.pos += 1;
+    }
+
+    // Emit a conditional jump, where `jt` and `jf` are absolute positions (or `None` to fall
+    // through)
+    fn jump(&mut self, op: u16, k: u32, jt: Option<usize>, jf: Option<usize>) -> Result<(), i32> {
+        let next = self.pos + 1;
+        let offset = |target: Option<usize>| match target {
+            None => Ok(0),
+            Some(target) if target - next <= u8::MAX as usize => Ok((target - next) as u8),
+            // The rule is too long for the jump offsets to fit
+            Some(_) => Err(eno::EINVAL),
+        };
+
+        self.buf[self.pos] = SockFilter::jump(BPF_JMP | op | BPF_K, k, offset(jt)?, offset(jf)?);
+        self.pos += 1;
+        Ok(())
+    }
+}
+
+/// A seccomp filter: a list of [`Rule`]s, and the action to take for the syscalls that don't
+/// match any of them.
+///
+/// The rules are checked in order, and the first one that matches is used. Before checking any
+/// rules, the generated program checks that the syscall was made with the native ABI of the
+/// current target ([`AUDIT_ARCH`], and on x86-64 the presence or absence of the
+/// [`X32_SYSCALL_BIT`]); other syscalls are handled with the "architecture mismatch" action,
+/// which is `Action::KillProcess` by default.
+///
+/// [`Rule`]: ./struct.Rule.html
+/// [`AUDIT_ARCH`]: ./constant.AUDIT_ARCH.html
+/// [`X32_SYSCALL_BIT`]: ./constant.X32_SYSCALL_BIT.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Filter<'a> {
+    rules: &'a [Rule<'a>],
+    default_action: Action,
+    mismatch_action: Action,
+}
+
+impl<'a> Filter<'a> {
+    /// Create a filter that checks `rules`, and takes `default_action` for the syscalls that
+    /// don't match any of them.
+    #[inline]
+    pub const fn new(default_action: Action, rules: &'a [Rule<'a>]) -> Self {
+        Self {
+            rules,
+            default_action,
+            mismatch_action: Action::KillProcess,
+        }
+    }
+
+    /// Set the action to take for syscalls made with a different ABI.
+    #[inline]
+    pub const fn mismatch_action(mut self, action: Action) -> Self {
+        self.mismatch_action = action;
+        self
+    }
+
+    // The length of the architecture check
+    const ARCH_CHECK_LEN: usize = if cfg!(target_arch = "x86_64") { 6 } else { 4 };
+
+    /// Get the number of instructions in the compiled program.
+    pub fn program_len(&self) -> usize {
+        Self::ARCH_CHECK_LEN + self.rules.iter().map(Rule::len).sum::<usize>() + 1
+    }
+
+    /// Compile the filter into a BPF program, stored at the beginning of `buf`.
+    ///
+    /// # Errors
+    ///
+    /// - `ERANGE`: `buf` is too small (see [`program_len()`]).
+    /// - `EINVAL`: The program would be longer than [`BPF_MAXINSNS`], or a rule has so many
+    ///   argument comparisons that the jump offsets don't fit.
+    ///
+    /// [`program_len()`]: #method.program_len
+    /// [`BPF_MAXINSNS`]: ./constant.BPF_MAXINSNS.html
+    pub fn compile<'b>(&self, buf: &'b mut [SockFilter]) -> Result<&'b [SockFilter], i32> {
+        let len = self.program_len();
+        if len > BPF_MAXINSNS {
+            return Err(eno::EINVAL);
+        } else if len > buf.len() {
+            return Err(eno::ERANGE);
+        }
+
+        let buf = &mut buf[..len];
+        let mut prog = Emitter {
+            buf: &mut *buf,
+            pos: 0,
+        };
+        let load = |prog: &mut Emitter, off| prog.stmt(BPF_LD | BPF_W | BPF_ABS, off);
+
+        // Check the architecture
+        load(&mut prog, DATA_ARCH);
+        prog.jump(BPF_JEQ, AUDIT_ARCH, Some(prog.pos + 2), None)?;
+        prog.stmt(BPF_RET | BPF_K, self.mismatch_action.to_raw());
+
+        load(&mut prog, DATA_NR);
+
+        // On x86-64, the x32 ABI shares the same `AUDIT_ARCH` value; it is distinguished by a bit
+        // in the syscall number
+        #[cfg(target_arch = "x86_64")]
+        {
+            let x32 = Some(prog.pos + 2);
+            if cfg!(target_pointer_width = "32") {
+                prog.jump(BPF_JGE, X32_SYSCALL_BIT, x32, None)?;
+            } else {
+                prog.jump(BPF_JGE, X32_SYSCALL_BIT, None, x32)?;
+            }
+            prog.stmt(BPF_RET | BPF_K, self.mismatch_action.to_raw());
+        }
+
+        for rule in self.rules {
+            let next = prog.pos + rule.len();
+            let nr = rule.nr as u32;
+
+            if rule.args.is_empty() {
+                prog.jump(BPF_JEQ, nr, None, Some(next))?;
+                prog.stmt(BPF_RET | BPF_K, rule.action.to_raw());
+            } else {
+                // The argument comparisons jump to the last instruction (which reloads the
+                // syscall number) if they fail
+                let fail = next - 1;
+
+                prog.jump(BPF_JEQ, nr, None, Some(next))?;
+                for arg in rule.args {
+                    arg.emit(&mut prog, fail)?;
+                }
+                prog.stmt(BPF_RET | BPF_K, rule.action.to_raw());
+                load(&mut prog, DATA_NR);
+            }
+
+            debug_assert_eq!(prog.pos, next);
+        }
+
+        prog.stmt(BPF_RET | BPF_K, self.default_action.to_raw());
+        debug_assert_eq!(prog.pos, len);
+
+        Ok(buf)
+    }
+
+    /// Compile the filter into `buf` and install it with [`install_program()`].
+    ///
+    /// [`install_program()`]: ./fn.install_program.html
+    #[inline]
+    pub fn install(&self, buf: &mut [SockFilter], flags: u32) -> Result<usize, i32> {
+        install_program(self.compile(buf)?, flags)
+    }
+}
+
+/// Install a seccomp filter program for the calling thread.
+///
+/// `flags` may contain the `SECCOMP_FILTER_FLAG_*` flags. This uses `seccomp()` if it's available
+/// (Linux 3.17+); otherwise, if `flags` is 0, it falls back on `prctl(PR_SET_SECCOMP)`.
+///
+/// Unless the thread has `CAP_SYS_ADMIN`, [`set_no_new_privs()`] has to be called first.
+///
+/// On success, this returns 0, or the notification file descriptor if `flags` contains
+/// `SECCOMP_FILTER_FLAG_NEW_LISTENER`. Note that if `flags` contains `SECCOMP_FILTER_FLAG_TSYNC`
+/// (but not `SECCOMP_FILTER_FLAG_TSYNC_ESRCH`) and another thread can't be synchronized, the
+/// `seccomp()` syscall "succeeds" and returns the ID of that thread.
+///
+/// [`set_no_new_privs()`]: ./fn.set_no_new_privs.html
+pub fn install_program(prog: &[SockFilter], flags: u32) -> Result<usize, i32> {
+    if prog.len() > u16::MAX as usize {
+        return Err(eno::EINVAL);
+    }
+
+    let fprog = SockFprog {
+        len: prog.len() as u16,
+        filter: prog.as_ptr(),
+    };
+
+    unsafe {
+        match crate::syscall!(SECCOMP, SECCOMP_SET_MODE_FILTER, flags, &fprog) {
+            Err(eno::ENOSYS) if flags == 0 => {
+                crate::syscall!(PRCTL, PR_SET_SECCOMP, SECCOMP_MODE_FILTER, &fprog, 0, 0)
+            }
+            res => res,
+        }
+    }
+}
+
+/// Set the calling thread's "no new privileges" flag, which allows it to install seccomp filters
+/// without `CAP_SYS_ADMIN`.
+///
+/// Once set, the flag is inherited by children and can't be unset, and `execve()` will no longer
+/// grant privileges (for example, through set-user-ID programs).
+#[inline]
+pub fn set_no_new_privs() -> Result<(), i32> {
+    unsafe {
+        crate::syscall!(PRCTL, PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0)?;
+    }
+    Ok(())
+}
+
+/// Check whether the kernel supports the given action (`SECCOMP_GET_ACTION_AVAIL`, Linux 4.14+).
+///
+/// If the action is not supported, this fails with `EOPNOTSUPP`.
+#[inline]
+pub fn get_action_avail(action: Action) -> Result<(), i32> {
+    let action = action.to_raw();
+    unsafe {
+        crate::syscall!(SECCOMP, SECCOMP_GET_ACTION_AVAIL, 0, &action)?;
+    }
+    Ok(())
+}
+
+/// Make the target's syscall proceed as if the filter had allowed it (in
+/// [`SeccompNotifResp::flags`], Linux 5.5+).
+///
+/// [`SeccompNotifResp::flags`]: ./struct.SeccompNotifResp.html#structfield.flags
+pub const SECCOMP_USER_NOTIF_FLAG_CONTINUE: u32 = 1 << 0;
+
+/// Install the file descriptor at `newfd` in the target, as if by `dup2()` (Linux 5.9+).
+pub const SECCOMP_ADDFD_FLAG_SETFD: u32 = 1 << 0;
+/// Install the file descriptor and atomically reply to the notification with its number in the
+/// target (Linux 5.14+).
+pub const SECCOMP_ADDFD_FLAG_SEND: u32 = 1 << 1;
+
+const SECCOMP_IOC_MAGIC: u8 = b'!';
+
+/// Receive a notification (`SECCOMP_IOCTL_NOTIF_RECV`).
+pub const SECCOMP_IOCTL_NOTIF_RECV: u32 =
+    ioctl::iowr(SECCOMP_IOC_MAGIC, 0, core::mem::size_of::<SeccompNotif>());
+/// Reply to a notification (`SECCOMP_IOCTL_NOTIF_SEND`).
+pub const SECCOMP_IOCTL_NOTIF_SEND: u32 = ioctl::iowr(
+    SECCOMP_IOC_MAGIC,
+    1,
+    core::mem::size_of::<SeccompNotifResp>(),
+);
+/// Check whether a notification is still pending (`SECCOMP_IOCTL_NOTIF_ID_VALID`).
+///
+/// This is the number used since Linux 5.7; older kernels used the `_IOR()` encoding, which newer
+/// kernels still accept.
+pub const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 =
+    ioctl::iow(SECCOMP_IOC_MAGIC, 2, core::mem::size_of::<u64>());
+/// Install a file descriptor in the target (`SECCOMP_IOCTL_NOTIF_ADDFD`, Linux 5.9+).
+pub const SECCOMP_IOCTL_NOTIF_ADDFD: u32 = ioctl::iow(
+    SECCOMP_IOC_MAGIC,
+    3,
+    core::mem::size_of::<SeccompNotifAddfd>(),
+);
+
+// Only needed by `Supervisor::run()`; these have the same values on every architecture
+const POLLIN: i16 = 0x1;
+const POLLHUP: i16 = 0x10;
+
+/// The information about a syscall that a filter sees (`struct seccomp_data`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SeccompData {
+    /// The syscall number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value for the ABI the syscall was made with.
+    pub arch: u32,
+    pub instruction_pointer: u64,
+    /// The arguments, zero-extended to 64 bits on 32-bit architectures.
+    pub args: [u64; 6],
+}
+
+/// A notification received from the kernel (`struct seccomp_notif`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SeccompNotif {
+    /// The cookie that identifies this notification.
+    pub id: u64,
+    /// The thread ID of the target, in the supervisor's PID namespace (or 0 if it isn't visible
+    /// there).
+    pub pid: u32,
+    pub flags: u32,
+    pub data: SeccompData,
+}
+
+/// A reply to a notification (`struct seccomp_notif_resp`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SeccompNotifResp {
+    pub id: u64,
+    /// The value the syscall returns if `error` is 0.
+    pub val: i64,
+    /// 0, or a *negated* error number to fail the syscall with.
+    pub error: i32,
+    /// Either 0 or [`SECCOMP_USER_NOTIF_FLAG_CONTINUE`].
+    ///
+    /// [`SECCOMP_USER_NOTIF_FLAG_CONTINUE`]: ./constant.SECCOMP_USER_NOTIF_FLAG_CONTINUE.html
+    pub flags: u32,
+}
+
+/// A request to install a file descriptor in the target (`struct seccomp_notif_addfd`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SeccompNotifAddfd {
+    pub id: u64,
+    /// The `SECCOMP_ADDFD_FLAG_*` flags.
+    pub flags: u32,
+    /// The supervisor's file descriptor to install.
+    pub srcfd: u32,
+    /// The file descriptor number to use in the target, with `SECCOMP_ADDFD_FLAG_SETFD`.
+    pub newfd: u32,
+    /// The flags for the new file descriptor (only `O_CLOEXEC` is accepted).
+    pub newfd_flags: u32,
+}
+
+/// The sizes of the notification structures used by the running kernel (`struct
+/// seccomp_notif_sizes`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct SeccompNotifSizes {
+    pub seccomp_notif: u16,
+    pub seccomp_notif_resp: u16,
+    pub seccomp_data: u16,
+}
+
+/// Get the sizes of the notification structures used by the running kernel
+/// (`SECCOMP_GET_NOTIF_SIZES`, Linux 5.0+).
+///
+/// The request numbers in this module encode the sizes of this crate's definitions, so the
+/// ioctls will fail with `EINVAL` (or `ENOTTY`) if a future kernel changes them. This can be used
+/// to detect that situation up front.
+#[inline]
+pub fn get_notif_sizes() -> Result<SeccompNotifSizes, i32> {
+    let mut sizes = SeccompNotifSizes::default();
+    unsafe {
+        crate::syscall!(SECCOMP, SECCOMP_GET_NOTIF_SIZES, 0, &mut sizes)?;
+    }
+    Ok(sizes)
+}
+
+/// A syscall that a [`Supervisor`] has been asked to handle.
+///
+/// [`Supervisor`]: ./struct.Supervisor.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Notification(SeccompNotif);
+
+impl Notification {
+    /// Get the cookie that identifies this notification.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.0.id
+    }
+
+    /// Get the thread ID of the target, in the supervisor's PID namespace (or 0 if it isn't
+    /// visible there).
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        self.0.pid
+    }
+
+    /// Get the raw syscall number.
+    ///
+    /// On x86-64, this includes the [`X32_SYSCALL_BIT`] for syscalls made through the x32 ABI.
+    ///
+    /// [`X32_SYSCALL_BIT`]: ./constant.X32_SYSCALL_BIT.html
+    #[inline]
+    pub fn nr(&self) -> usize {
+        self.0.data.nr as u32 as usize
+    }
+
+    /// Get the syscall, if it was made with the native ABI of the current target (see
+    /// [`AUDIT_ARCH`]) and its number is recognized.
+    ///
+    /// A filter compiled with [`Filter`] only lets through syscalls made with the native ABI, so
+    /// this will only return `None` for other filters (or for syscalls that are newer than this
+    /// crate). For other ABIs, [`nr()`] can be looked up in the tables in the [`arch`] module
+    /// (with the `arch` feature).
+    ///
+    /// [`AUDIT_ARCH`]: ./constant.AUDIT_ARCH.html
+    /// [`Filter`]: ./struct.Filter.html
+    /// [`nr()`]: #method.nr
+    /// [`arch`]: ../arch/index.html
+    #[inline]
+    pub fn sysno(&self) -> Option<crate::Sysno> {
+        if self.0.data.arch == AUDIT_ARCH {
+            crate::Sysno::new(self.nr())
+        } else {
+            None
+        }
+    }
+
+    /// Get the syscall's arguments.
+    #[inline]
+    pub fn args(&self) -> [u64; 6] {
+        self.0.data.args
+    }
+
+    /// Get the raw `struct seccomp_notif` received from the kernel.
+    #[inline]
+    pub fn raw(&self) -> &SeccompNotif {
+        &self.0
+    }
+}
+
+/// A [`Supervisor`]'s reply to a [`Notification`].
+///
+/// [`Supervisor`]: ./struct.Supervisor.html
+/// [`Notification`]: ./struct.Notification.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Reply {
+    /// Fail the syscall with the given error number (one of the constants in [`eno`]).
+    ///
+    /// [`eno`]: ../eno/index.html
+    Errno(i32),
+    /// Make the syscall return the given value.
+    Return(i64),
+    /// Let the syscall proceed normally (Linux 5.5+).
+    ///
+    /// Note that the target's memory may have changed since the supervisor inspected it, so this
+    /// must not be used to allow a syscall based on the contents of memory that it reads (such as
+    /// a path).
+    Continue,
+}
+
+impl Reply {
+    /// Get the `struct seccomp_notif_resp` for replying to the notification `id`.
+    #[inline]
+    pub const fn to_raw(self, id: u64) -> SeccompNotifResp {
+        let (val, error, flags) = match self {
+            Self::Errno(eno) => (0, -eno, 0),
+            Self::Return(val) => (val, 0, 0),
+            Self::Continue => (0, 0, SECCOMP_USER_NOTIF_FLAG_CONTINUE),
+        };
+
+        SeccompNotifResp {
+            id,
+            val,
+            error,
+            flags,
+        }
+    }
+}
+
+/// The supervisor side of a `SECCOMP_RET_USER_NOTIF` filter.
+///
+/// This wraps the notification file descriptor returned by [`install_program()`] (with
+/// `SECCOMP_FILTER_FLAG_NEW_LISTENER`), which the child usually has to pass to the supervisor
+/// (for example, over a Unix socket). Like [`FdWriter`], it does not take ownership of the file
+/// descriptor.
+///
+/// Notifications can be handled one at a time with [`recv()`] and [`send()`], or in a loop with
+/// [`run()`].
+///
+/// # Example
+///
+/// ```
+/// use scall::seccomp::{self, Action, Filter, Reply, Rule, SockFilter, Supervisor};
+/// use scall::{consts, nr, sig, syscall, syscall_nofail, Sysno};
+///
+/// # if seccomp::get_notif_sizes().is_err() {
+/// #     return;
+/// # }
+/// let rules = [Rule::new(nr::GETPPID, Action::UserNotif)];
+/// let filter = Filter::new(Action::Allow, &rules);
+///
+/// let mut fds = [0i32; 2];
+/// unsafe {
+///     syscall!(PIPE2, fds.as_mut_ptr(), consts::O_CLOEXEC).unwrap();
+/// }
+///
+/// // The child shares the file descriptor table, so it only has to send the number of the
+/// // notification file descriptor
+/// let mut stack = vec![0u8; 65536];
+/// let pid = unsafe {
+///     scall::clone_with_stack(
+///         consts::CLONE_FILES | sig::SIGCHLD as u64,
+///         stack.as_mut_ptr().add(stack.len()),
+///         0,
+///         core::ptr::null_mut(),
+///         core::ptr::null_mut(),
+///         || {
+///             let mut buf = [SockFilter::default(); 16];
+///             seccomp::set_no_new_privs().unwrap();
+///             let fd = filter
+///                 .install(&mut buf, seccomp::SECCOMP_FILTER_FLAG_NEW_LISTENER)
+///                 .unwrap() as i32;
+///             syscall!(WRITE, fds[1], &fd, 4).unwrap();
+///
+///             match syscall!(GETPPID) {
+///                 Ok(1234) => 0,
+///                 _ => 1,
+///             }
+///         },
+///     )
+///     .unwrap()
+/// };
+///
+/// let mut fd = -1i32;
+/// unsafe {
+///     syscall!(READ, fds[0], &mut fd, 4).unwrap();
+/// }
+///
+/// let supervisor = Supervisor::new(fd);
+/// let notif = supervisor.recv().unwrap();
+/// assert_eq!(notif.sysno(), Some(Sysno::GETPPID));
+/// supervisor.send(notif.id(), Reply::Return(1234)).unwrap();
+///
+/// let mut status = 0;
+/// unsafe {
+///     syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+///     syscall_nofail!(CLOSE, fd);
+///     syscall_nofail!(CLOSE, fds[0]);
+///     syscall_nofail!(CLOSE, fds[1]);
+/// }
+/// assert_eq!(status, 0);
+/// ```
+///
+/// [`install_program()`]: ./fn.install_program.html
+/// [`FdWriter`]: ../io/struct.FdWriter.html
+/// [`recv()`]: #method.recv
+/// [`send()`]: #method.send
+/// [`run()`]: #method.run
+#[derive(Debug)]
+pub struct Supervisor {
+    fd: i32,
+}
+
+impl Supervisor {
+    /// Create a `Supervisor` that receives notifications from the given file descriptor.
+    #[inline]
+    pub const fn new(fd: i32) -> Self {
+        Self { fd }
+    }
+
+    /// Get the notification file descriptor.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.fd
+    }
+
+    /// Wait for a notification (`SECCOMP_IOCTL_NOTIF_RECV`).
+    ///
+    /// This fails with `ENOENT` if the target was killed (or its syscall was interrupted by a
+    /// signal) after the notification was queued, in which case it should simply be called
+    /// again.
+    pub fn recv(&self) -> Result<Notification, i32> {
+        // The kernel requires the buffer to be zeroed
+        let mut notif = SeccompNotif::default();
+        unsafe {
+            crate::syscall_retry!(IOCTL, self.fd, SECCOMP_IOCTL_NOTIF_RECV, &mut notif)?;
+        }
+        Ok(Notification(notif))
+    }
+
+    /// Reply to the notification `id` (`SECCOMP_IOCTL_NOTIF_SEND`).
+    ///
+    /// This fails with `ENOENT` if the notification is no longer pending (for example, because
+    /// the target was killed).
+    pub fn send(&self, id: u64, reply: Reply) -> Result<(), i32> {
+        let resp = reply.to_raw(id);
+        unsafe {
+            crate::syscall_retry!(IOCTL, self.fd, SECCOMP_IOCTL_NOTIF_SEND, &resp)?;
+        }
+        Ok(())
+    }
+
+    /// Check whether the notification `id` is still pending (`SECCOMP_IOCTL_NOTIF_ID_VALID`).
+    ///
+    /// If it isn't, this fails with `ENOENT`.
+    #[inline]
+    pub fn id_valid(&self, id: u64) -> Result<(), i32> {
+        unsafe {
+            crate::syscall!(IOCTL, self.fd, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)?;
+        }
+        Ok(())
+    }
+
+    /// Install the file descriptor `srcfd` in the target of the notification `id`
+    /// (`SECCOMP_IOCTL_NOTIF_ADDFD`, Linux 5.9+), and return its number in the target.
+    ///
+    /// If `newfd` is `Some`, the file descriptor is installed at that number (replacing any file
+    /// descriptor already there); otherwise the lowest available number is used. `flags` may
+    /// contain [`SECCOMP_ADDFD_FLAG_SEND`], and `newfd_flags` may contain `O_CLOEXEC`.
+    ///
+    /// [`SECCOMP_ADDFD_FLAG_SEND`]: ./constant.SECCOMP_ADDFD_FLAG_SEND.html
+    pub fn add_fd(
+        &self,
+        id: u64,
+        srcfd: i32,
+        newfd: Option<i32>,
+        flags: u32,
+        newfd_flags: u32,
+    ) -> Result<i32, i32> {
+        let setfd = if newfd.is_some() {
+            SECCOMP_ADDFD_FLAG_SETFD
+        } else {
+            0
+        };
+
+        let addfd = SeccompNotifAddfd {
+            id,
+            flags: flags | setfd,
+            srcfd: srcfd as u32,
+            newfd: newfd.unwrap_or(0) as u32,
+            newfd_flags,
+        };
+
+        unsafe {
+            crate::syscall_retry!(IOCTL, self.fd, SECCOMP_IOCTL_NOTIF_ADDFD, &addfd)
+                .map(|fd| fd as i32)
+        }
+    }
+
+    /// Read memory from the target of the notification `notif`, starting at the address `addr`,
+    /// into `buf`. Returns the number of bytes read.
+    ///
+    /// This reads from `/proc/<pid>/mem`, and it checks with [`id_valid()`] that the notification
+    /// is still pending after opening the file. Otherwise, the target could have been killed and
+    /// its thread ID reused by an unrelated process, which would then be read instead.
+    ///
+    /// Note that other threads in the target can still modify the memory after it is read, so
+    /// decisions should be based only on the copy in `buf` (and [`Reply::Continue`] must not be
+    /// used to allow a syscall based on it).
+    ///
+    /// [`id_valid()`]: #method.id_valid
+    /// [`Reply::Continue`]: ./enum.Reply.html#variant.Continue
+    pub fn read_mem(&self, notif: &Notification, addr: u64, buf: &mut [u8]) -> Result<usize, i32> {
+        let mut path = [0; 32];
+        let fd = unsafe {
+            crate::syscall!(
+                OPENAT,
+                crate::consts::AT_FDCWD,
+                proc_mem_path(notif.pid(), &mut path).as_ptr(),
+                crate::consts::O_RDONLY | crate::consts::O_CLOEXEC,
+                0
+            )?
+        };
+
+        let res = self.id_valid(notif.id()).and_then(|()| unsafe {
+            crate::syscall_args64!(PREAD64, fd, buf.as_mut_ptr(), buf.len(), @u64 addr)
+        });
+
+        unsafe {
+            crate::syscall_nofail!(CLOSE, fd);
+        }
+        res
+    }
+
+    /// Handle notifications in a loop, replying to each one with the value returned by
+    /// `handler`.
+    ///
+    /// Notifications that are no longer pending (because the target was killed) are skipped.
+    /// This returns `Ok(())` once there are no more tasks using the filter; note that a task that
+    /// has exited still uses it until it is reaped (so the reaping has to happen on another
+    /// thread). This can only be detected on Linux 5.8+; on older kernels this never returns
+    /// unless an error occurs.
+    pub fn run<F: FnMut(&Self, &Notification) -> Reply>(&self, mut handler: F) -> Result<(), i32> {
+        loop {
+            let mut pfd = crate::types::pollfd {
+                fd: self.fd,
+                events: POLLIN,
+                revents: 0,
+            };
+            unsafe {
+                crate::syscall_retry!(
+                    PPOLL,
+                    &mut pfd,
+                    1,
+                    core::ptr::null::<u8>(),
+                    core::ptr::null::<u8>(),
+                    0
+                )?;
+            }
+
+            if pfd.revents & POLLIN == 0 {
+                if pfd.revents & POLLHUP != 0 {
+                    return Ok(());
+                }
+                continue;
+            }
+
+            let notif = match self.recv() {
+                Ok(notif) => notif,
+                Err(eno::ENOENT) => continue,
+                Err(e) => return Err(e),
+            };
+
+            match self.send(notif.id(), handler(self, &notif)) {
+                Ok(()) | Err(eno::ENOENT) => (),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+// Write the NUL-terminated path "/proc/<pid>/mem" into `buf`
+fn proc_mem_path(pid: u32, buf: &mut [u8; 32]) -> &[u8] {
+    const PREFIX: &[u8] = b"/proc/";
+    const SUFFIX: &[u8] = b"/mem\0";
+
+    let mut digits = [0; 10];
+    let mut ndigits = 0;
+    let mut pid = pid;
+    loop {
+        digits[ndigits] = b'0' + (pid % 10) as u8;
+        ndigits += 1;
+        pid /= 10;
+        if pid == 0 {
+            break;
+        }
+    }
+
+    let mut len = PREFIX.len();
+    buf[..len].copy_from_slice(PREFIX);
+    for &digit in digits[..ndigits].iter().rev() {
+        buf[len] = digit;
+        len += 1;
+    }
+    buf[len..len + SUFFIX.len()].copy_from_slice(SUFFIX);
+    len += SUFFIX.len();
+
+    &buf[..len]
+}
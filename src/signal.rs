@@ -0,0 +1,461 @@
+//! Installing signal handlers, and wrappers for the other signal-related syscalls.
+//!
+//! Calling `rt_sigaction()` directly is harder than it looks: on x86-64, the kernel requires
+//! `SA_RESTORER` and a trampoline that calls `rt_sigreturn()` when the handler returns; `struct
+//! sigaction` has a different layout on MIPS (and on RISC-V); and on SPARC, the trampoline is
+//! passed as an extra argument. [`sigaction()`] takes care of all of this, using trampolines
+//! written in assembly on the architectures that need them (x86, x86-64, ARM, AArch64, and SPARC;
+//! on the others, the kernel provides one).
+//!
+//! # Example
+//!
+//! ```
+//! use core::sync::atomic::{AtomicI32, Ordering};
+//! use scall::signal::{self, SigAction, SigHandler};
+//! use scall::{sig, syscall_nofail, SigSet};
+//!
+//! static CAUGHT: AtomicI32 = AtomicI32::new(0);
+//!
+//! extern "C" fn handler(sig: i32) {
+//!     CAUGHT.store(sig, Ordering::SeqCst);
+//! }
+//!
+//! unsafe {
+//!     let action = SigAction::new(SigHandler::Handler(handler), 0, SigSet::empty());
+//!     let old = signal::sigaction(sig::SIGUSR1, Some(&action)).unwrap();
+//!
+//!     let (pid, tid) = (syscall_nofail!(GETPID), syscall_nofail!(GETTID));
+//!     signal::tgkill(pid as i32, tid as i32, sig::SIGUSR1).unwrap();
+//!     assert_eq!(CAUGHT.load(Ordering::SeqCst), sig::SIGUSR1);
+//!
+//!     signal::sigaction(sig::SIGUSR1, Some(&old)).unwrap();
+//! }
+//! ```
+//!
+//! [`sigaction()`]: ./fn.sigaction.html
+
+use core::ffi::c_void;
+use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+
+use crate::{sig, types, SigSet};
+
+/// `si_code` value for signals sent by `kill()`.
+pub const SI_USER: i32 = 0;
+/// `si_code` value for signals sent by the kernel.
+pub const SI_KERNEL: i32 = 0x80;
+/// `si_code` value for signals sent by `sigqueue()` (and [`SigInfo::queue()`]).
+///
+/// [`SigInfo::queue()`]: ./struct.SigInfo.html#method.queue
+pub const SI_QUEUE: i32 = -1;
+/// `si_code` value for signals sent by `tkill()` or `tgkill()`.
+pub const SI_TKILL: i32 = -6;
+
+const SIG_DFL: usize = 0;
+const SIG_IGN: usize = 1;
+
+/// What to do when a signal is delivered.
+#[derive(Clone, Copy, Debug)]
+pub enum SigHandler {
+    /// Perform the default action (`SIG_DFL`).
+    Default,
+    /// Ignore the signal (`SIG_IGN`).
+    Ignore,
+    /// Call a function with the signal number.
+    Handler(extern "C" fn(i32)),
+    /// Call a function with the signal number, information about the signal, and the context
+    /// (a `ucontext_t`) that was interrupted (`SA_SIGINFO`).
+    SigAction(extern "C" fn(i32, *mut SigInfo, *mut c_void)),
+}
+
+impl SigHandler {
+    fn to_raw(self) -> usize {
+        match self {
+            Self::Default => SIG_DFL,
+            Self::Ignore => SIG_IGN,
+            Self::Handler(f) => f as usize,
+            Self::SigAction(f) => f as usize,
+        }
+    }
+
+    unsafe fn from_raw(handler: usize, flags: u32) -> Self {
+        match handler {
+            SIG_DFL => Self::Default,
+            SIG_IGN => Self::Ignore,
+            _ if flags & sig::SA_SIGINFO != 0 => {
+                let f: extern "C" fn(i32, *mut SigInfo, *mut c_void) =
+                    core::mem::transmute(handler);
+                Self::SigAction(f)
+            }
+            _ => {
+                let f: extern "C" fn(i32) = core::mem::transmute(handler);
+                Self::Handler(f)
+            }
+        }
+    }
+}
+
+/// The action to take when a signal is delivered, as passed to and returned by [`sigaction()`].
+///
+/// [`sigaction()`]: ./fn.sigaction.html
+#[derive(Clone, Copy, Debug)]
+pub struct SigAction {
+    handler: SigHandler,
+    flags: u32,
+    mask: SigSet,
+}
+
+impl SigAction {
+    /// Create a new `SigAction`.
+    ///
+    /// `flags` is a combination of the `SA_*` flags. `SA_SIGINFO` is added or removed
+    /// automatically depending on the type of `handler`, and `SA_RESTORER` is managed by
+    /// [`sigaction()`]. `mask` is the set of signals to block while the handler runs (in
+    /// addition to the signal itself, unless `SA_NODEFER` is specified).
+    ///
+    /// [`sigaction()`]: ./fn.sigaction.html
+    #[inline]
+    pub fn new(handler: SigHandler, flags: u32, mask: SigSet) -> Self {
+        let flags = match handler {
+            SigHandler::SigAction(_) => flags | sig::SA_SIGINFO,
+            _ => flags & !sig::SA_SIGINFO,
+        };
+
+        Self {
+            handler,
+            flags,
+            mask,
+        }
+    }
+
+    /// Get the handler.
+    #[inline]
+    pub fn handler(&self) -> SigHandler {
+        self.handler
+    }
+
+    /// Get the flags.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Get the set of signals that are blocked while the handler runs.
+    #[inline]
+    pub fn mask(&self) -> SigSet {
+        self.mask
+    }
+}
+
+// Get the signal return trampoline for a handler with the given flags
+#[cfg(any(target_arch = "x86", target_arch = "arm"))]
+fn restorer(flags: u32) -> usize {
+    if flags & sig::SA_SIGINFO != 0 {
+        crate::platform::_scall_internal_restore_rt as unsafe extern "C" fn() as usize
+    } else {
+        crate::platform::_scall_internal_restore as unsafe extern "C" fn() as usize
+    }
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "sparc64",
+))]
+fn restorer(_flags: u32) -> usize {
+    crate::platform::_scall_internal_restore_rt as unsafe extern "C" fn() as usize
+}
+
+fn to_raw(act: &SigAction) -> types::sigaction {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    return types::sigaction {
+        sa_flags: act.flags,
+        sa_handler: act.handler.to_raw(),
+        sa_mask: act.mask.into(),
+    };
+
+    #[cfg(target_arch = "riscv64")]
+    return types::sigaction {
+        sa_handler: act.handler.to_raw(),
+        sa_flags: act.flags as usize,
+        sa_mask: act.mask.into(),
+    };
+
+    #[cfg(any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64",
+    ))]
+    return types::sigaction {
+        sa_handler: act.handler.to_raw(),
+        sa_flags: (act.flags | sig::SA_RESTORER) as usize,
+        sa_restorer: restorer(act.flags),
+        sa_mask: act.mask.into(),
+    };
+
+    // The kernel always provides the trampoline on PowerPC, and SPARC takes it as an argument to
+    // `rt_sigaction()`
+    #[cfg(any(
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc64",
+    ))]
+    return types::sigaction {
+        sa_handler: act.handler.to_raw(),
+        sa_flags: act.flags as usize,
+        sa_restorer: 0,
+        sa_mask: act.mask.into(),
+    };
+}
+
+// `SA_RESTORER` is set by `to_raw()` (or by the kernel on PowerPC), so it is hidden from callers
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
+const RESTORER_FLAG: u32 = sig::SA_RESTORER;
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+const RESTORER_FLAG: u32 = 0;
+
+fn from_raw(raw: &types::sigaction) -> SigAction {
+    // `sa_flags` is already a `u32` on MIPS
+    #[allow(clippy::unnecessary_cast)]
+    let flags = raw.sa_flags as u32 & !RESTORER_FLAG;
+
+    SigAction {
+        handler: unsafe { SigHandler::from_raw(raw.sa_handler, flags) },
+        flags,
+        mask: raw.sa_mask.into(),
+    }
+}
+
+/// Change the action taken when the given signal is delivered (if `act` is not `None`), and
+/// return the previous action.
+///
+/// # Safety
+///
+/// Signal handlers can run at any point in the program (in any thread that doesn't have the
+/// signal blocked), so they must only do things that are async-signal-safe. Changing the action
+/// for a signal may also break other code that relies on it (such as the C library's use of
+/// signals for its own purposes).
+pub unsafe fn sigaction(signum: i32, act: Option<&SigAction>) -> Result<SigAction, i32> {
+    let new = act.map(to_raw);
+    let mut old = MaybeUninit::<types::sigaction>::uninit();
+
+    #[cfg(not(target_arch = "sparc64"))]
+    crate::syscall!(
+        RT_SIGACTION,
+        signum,
+        new.as_ref(),
+        old.as_mut_ptr(),
+        SigSet::SIZE
+    )?;
+
+    #[cfg(target_arch = "sparc64")]
+    crate::syscall!(
+        RT_SIGACTION,
+        signum,
+        new.as_ref(),
+        old.as_mut_ptr(),
+        if act.is_some() { restorer(0) - 8 } else { 0 },
+        SigSet::SIZE
+    )?;
+
+    Ok(from_raw(&old.assume_init()))
+}
+
+/// Change the current thread's signal mask (if `set` is not `None`), and return the previous
+/// mask.
+///
+/// `how` is `SIG_BLOCK`, `SIG_UNBLOCK`, or `SIG_SETMASK`. `SIGKILL` and `SIGSTOP` cannot be
+/// blocked; they are silently removed from `set`.
+pub fn sigprocmask(how: i32, set: Option<&SigSet>) -> Result<SigSet, i32> {
+    let mut old = SigSet::empty();
+    unsafe {
+        crate::syscall!(RT_SIGPROCMASK, how, set, &mut old, SigSet::SIZE)?;
+    }
+    Ok(old)
+}
+
+/// Set the current thread's alternate signal stack (if `ss` is not `None`), and return the
+/// previous one.
+///
+/// Handlers installed with `SA_ONSTACK` run on the alternate signal stack. To disable it, pass a
+/// `stack_t` with `ss_flags` set to `SS_DISABLE`.
+///
+/// # Safety
+///
+/// The memory described by `ss` must remain valid (and must not be used for anything else) until
+/// the alternate signal stack is changed again, or the thread exits.
+pub unsafe fn sigaltstack(ss: Option<&types::stack_t>) -> Result<types::stack_t, i32> {
+    let mut old = MaybeUninit::<types::stack_t>::uninit();
+    crate::syscall!(SIGALTSTACK, ss, old.as_mut_ptr())?;
+    Ok(old.assume_init())
+}
+
+/// Send a signal to a specific thread (`tid`) in a specific thread group (`tgid`).
+pub fn tgkill(tgid: i32, tid: i32, sig: i32) -> Result<(), i32> {
+    unsafe {
+        crate::syscall!(TGKILL, tgid, tid, sig)?;
+    }
+    Ok(())
+}
+
+/// Send a signal, along with the given information, to the thread group `tgid`.
+///
+/// Unless the signal is being sent to the current process, `info.code()` must be negative (for
+/// example, [`SI_QUEUE`]); see [`SigInfo::queue()`].
+///
+/// [`SI_QUEUE`]: ./constant.SI_QUEUE.html
+/// [`SigInfo::queue()`]: ./struct.SigInfo.html#method.queue
+pub fn rt_sigqueueinfo(tgid: i32, sig: i32, info: &SigInfo) -> Result<(), i32> {
+    unsafe {
+        crate::syscall!(RT_SIGQUEUEINFO, tgid, sig, info)?;
+    }
+    Ok(())
+}
+
+// The size of the three `int`s that come before the union in `siginfo_t` (which is aligned like a
+// pointer)
+const SIGINFO_HEADER_SIZE: usize = if size_of::<usize>() == 8 { 16 } else { 12 };
+
+/// Information about a signal (`siginfo_t`).
+///
+/// This is passed to handlers installed with [`SigHandler::SigAction`], and to
+/// [`rt_sigqueueinfo()`]. Only the fields that are common to most signals have accessors; the
+/// others can be read through [`as_bytes()`].
+///
+/// [`SigHandler::SigAction`]: ./enum.SigHandler.html#variant.SigAction
+/// [`rt_sigqueueinfo()`]: ./fn.rt_sigqueueinfo.html
+/// [`as_bytes()`]: #method.as_bytes
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SigInfo {
+    signo: i32,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    errno: i32,
+    code: i32,
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    errno: i32,
+    fields: [usize; (128 - SIGINFO_HEADER_SIZE) / size_of::<usize>()],
+}
+
+impl SigInfo {
+    /// Create a new `SigInfo` with the given signal number and `si_code`, and all other fields
+    /// set to 0.
+    #[inline]
+    pub const fn new(signo: i32, code: i32) -> Self {
+        Self {
+            signo,
+            errno: 0,
+            code,
+            fields: [0; (128 - SIGINFO_HEADER_SIZE) / size_of::<usize>()],
+        }
+    }
+
+    /// Create a `SigInfo` for sending a signal with [`rt_sigqueueinfo()`], like `sigqueue()`
+    /// does.
+    ///
+    /// `si_code` is set to [`SI_QUEUE`], the PID and UID are set to those of the current process,
+    /// and `value` is stored in `si_value`.
+    ///
+    /// [`rt_sigqueueinfo()`]: ./fn.rt_sigqueueinfo.html
+    /// [`SI_QUEUE`]: ./constant.SI_QUEUE.html
+    pub fn queue(signo: i32, value: usize) -> Self {
+        let mut info = Self::new(signo, SI_QUEUE);
+
+        #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+        let uid = unsafe { crate::syscall_nofail!(GETUID32) };
+        #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+        let uid = unsafe { crate::syscall_nofail!(GETUID) };
+
+        let fields = info.fields.as_mut_ptr() as *mut u8;
+        unsafe {
+            *(fields as *mut i32) = crate::syscall_nofail!(GETPID) as i32;
+            *(fields.add(4) as *mut u32) = uid as u32;
+            *(fields.add(8) as *mut usize) = value;
+        }
+        info
+    }
+
+    /// Get the signal number (`si_signo`).
+    #[inline]
+    pub fn signo(&self) -> i32 {
+        self.signo
+    }
+
+    /// Get the error number associated with the signal (`si_errno`), which is usually 0.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        self.errno
+    }
+
+    /// Get the code indicating why the signal was sent (`si_code`).
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// Get the PID of the sender (`si_pid`).
+    ///
+    /// This is valid for signals sent by `kill()`, `tgkill()`, and `sigqueue()`, and for
+    /// `SIGCHLD`.
+    #[inline]
+    pub fn pid(&self) -> i32 {
+        unsafe { *(self.fields.as_ptr() as *const i32) }
+    }
+
+    /// Get the real UID of the sender (`si_uid`).
+    ///
+    /// This is valid for the same signals as [`pid()`].
+    ///
+    /// [`pid()`]: #method.pid
+    #[inline]
+    pub fn uid(&self) -> u32 {
+        unsafe { *(self.fields.as_ptr() as *const u32).add(1) }
+    }
+
+    /// Get the value sent with the signal (`si_value`, as a pointer-sized integer).
+    ///
+    /// This is valid for signals sent by `sigqueue()` (and POSIX timers).
+    #[inline]
+    pub fn value(&self) -> usize {
+        unsafe { *((self.fields.as_ptr() as *const u8).add(8) as *const usize) }
+    }
+
+    /// Get the address that caused the fault (`si_addr`).
+    ///
+    /// This is valid for `SIGSEGV`, `SIGBUS`, `SIGILL`, `SIGFPE`, and `SIGTRAP`.
+    #[inline]
+    pub fn addr(&self) -> usize {
+        self.fields[0]
+    }
+
+    /// Get the raw bytes of the structure.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 128] {
+        unsafe { &*(self as *const Self as *const [u8; 128]) }
+    }
+}
+
+impl fmt::Debug for SigInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SigInfo")
+            .field("signo", &self.signo)
+            .field("errno", &self.errno)
+            .field("code", &self.code)
+            .finish()
+    }
+}
@@ -0,0 +1,139 @@
+use core::mem::size_of;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+type Word = usize;
+#[cfg(any(target_os = "freebsd", target_os = "macos"))]
+type Word = u32;
+
+const WORD_BITS: usize = Word::BITS as usize;
+const WORDS: usize = size_of::<crate::types::sigset_t>() / size_of::<Word>();
+
+/// A set of signals, laid out the way the kernel expects.
+///
+/// This is the same size as the kernel's `sigset_t` on the current platform ([`SigSet::SIZE`]
+/// bytes), *not* the C library's `sigset_t` (which is usually much larger on Linux). A reference
+/// to a `SigSet` can be passed directly to `rt_sigprocmask()`, `rt_sigsuspend()`, `ppoll()`,
+/// etc., with [`SigSet::SIZE`] as the `sigsetsize` argument.
+///
+/// # Example
+///
+/// ```
+/// # use scall::{sig, SigSet};
+/// let mut set = SigSet::empty();
+/// set.insert(sig::SIGUSR1);
+/// assert!(set.contains(sig::SIGUSR1));
+/// assert!(!set.contains(sig::SIGUSR2));
+///
+/// # #[cfg(any(target_os = "linux", target_os = "android"))]
+/// unsafe {
+///     let mut old = SigSet::empty();
+///     scall::syscall!(RT_SIGPROCMASK, sig::SIG_BLOCK, &set, &mut old, SigSet::SIZE).unwrap();
+///     scall::syscall!(RT_SIGPROCMASK, sig::SIG_SETMASK, &old, 0, SigSet::SIZE).unwrap();
+/// }
+/// ```
+///
+/// [`SigSet::SIZE`]: #associatedconstant.SIZE
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct SigSet([Word; WORDS]);
+
+impl SigSet {
+    /// The size of a `SigSet` in bytes (i.e. the `sigsetsize` argument to the `rt_sig*()`
+    /// syscalls).
+    pub const SIZE: usize = size_of::<Self>();
+
+    /// The largest signal number that can be stored in a `SigSet`.
+    pub const MAX_SIGNAL: i32 = (WORDS * WORD_BITS) as i32;
+
+    /// Create an empty signal set.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self([0; WORDS])
+    }
+
+    /// Create a signal set containing every signal.
+    #[inline]
+    pub const fn full() -> Self {
+        Self([!0; WORDS])
+    }
+
+    #[inline]
+    fn index(sig: i32) -> Option<(usize, Word)> {
+        if (1..=Self::MAX_SIGNAL).contains(&sig) {
+            let bit = (sig - 1) as usize;
+            Some((bit / WORD_BITS, 1 << (bit % WORD_BITS)))
+        } else {
+            None
+        }
+    }
+
+    /// Add the given signal to this set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig` is not between 1 and [`SigSet::MAX_SIGNAL`] (inclusive).
+    ///
+    /// [`SigSet::MAX_SIGNAL`]: #associatedconstant.MAX_SIGNAL
+    #[inline]
+    pub fn insert(&mut self, sig: i32) {
+        let (i, mask) = Self::index(sig).expect("invalid signal number");
+        self.0[i] |= mask;
+    }
+
+    /// Remove the given signal from this set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig` is not between 1 and [`SigSet::MAX_SIGNAL`] (inclusive).
+    ///
+    /// [`SigSet::MAX_SIGNAL`]: #associatedconstant.MAX_SIGNAL
+    #[inline]
+    pub fn remove(&mut self, sig: i32) {
+        let (i, mask) = Self::index(sig).expect("invalid signal number");
+        self.0[i] &= !mask;
+    }
+
+    /// Check whether the given signal is in this set.
+    ///
+    /// Invalid signal numbers are never in the set.
+    #[inline]
+    pub fn contains(&self, sig: i32) -> bool {
+        match Self::index(sig) {
+            Some((i, mask)) => self.0[i] & mask != 0,
+            None => false,
+        }
+    }
+
+    /// Check whether this set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    /// Iterate over the signals in this set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = i32> + '_ {
+        (1..=Self::MAX_SIGNAL).filter(move |&sig| self.contains(sig))
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl From<crate::types::sigset_t> for SigSet {
+    #[inline]
+    fn from(set: crate::types::sigset_t) -> Self {
+        Self(set.sig)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl From<SigSet> for crate::types::sigset_t {
+    #[inline]
+    fn from(set: SigSet) -> Self {
+        Self { sig: set.0 }
+    }
+}
+
+impl core::fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
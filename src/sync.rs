@@ -0,0 +1,501 @@
+//! Synchronization primitives built directly on futexes.
+//!
+//! These are small, `no_std`, allocation-free versions of the primitives in `std::sync` (and of
+//! `std::thread::park()`), for use in programs that can't rely on the C library's pthreads
+//! implementation (for example, with the `rt` feature). They all use private futexes (see
+//! [`futex`]), so they only work between threads of the same process.
+//!
+//! Unlike their `std` counterparts, none of these types implement poisoning.
+//!
+//! [`futex`]: ../futex/index.html
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+
+use crate::futex::{futex_wait, futex_wake, FUTEX_PRIVATE_FLAG};
+
+// Wait on `futex` (a private futex) as long as it contains `expected`, ignoring errors. Returns
+// `false` if the timeout elapsed.
+fn wait(futex: &AtomicU32, expected: u32, timeout: Option<Duration>) -> bool {
+    futex_wait(futex, expected, timeout, FUTEX_PRIVATE_FLAG) != Err(crate::eno::ETIMEDOUT)
+}
+
+fn wake_one(futex: &AtomicU32) {
+    let _ = futex_wake(futex, 1, FUTEX_PRIVATE_FLAG);
+}
+
+fn wake_all(futex: &AtomicU32) {
+    let _ = futex_wake(futex, u32::MAX, FUTEX_PRIVATE_FLAG);
+}
+
+const UNLOCKED: u32 = 0;
+const LOCKED: u32 = 1;
+// Locked, and there may be other threads waiting on the futex
+const CONTENDED: u32 = 2;
+
+/// A mutual exclusion lock protecting a value of type `T`.
+///
+/// # Example
+///
+/// ```
+/// use scall::sync::Mutex;
+///
+/// static COUNTER: Mutex<u32> = Mutex::new(0);
+///
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| *COUNTER.lock() += 1))
+///     .collect();
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+/// assert_eq!(*COUNTER.lock(), 4);
+/// ```
+pub struct Mutex<T: ?Sized> {
+    futex: AtomicU32,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Create a new unlocked mutex containing the given value.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            futex: AtomicU32::new(UNLOCKED),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consume the mutex and return the value it contains.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquire the lock, blocking until it is available.
+    ///
+    /// This mutex is not reentrant; calling `lock()` while the current thread already holds the
+    /// lock will deadlock.
+    #[inline]
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        if self
+            .futex
+            .compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            self.lock_contended();
+        }
+
+        MutexGuard { mutex: self }
+    }
+
+    #[cold]
+    fn lock_contended(&self) {
+        // Spin for a little while in case the lock is released soon
+        for _ in 0..100 {
+            if self.futex.load(Ordering::Relaxed) == UNLOCKED
+                && self
+                    .futex
+                    .compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return;
+            }
+            core::hint::spin_loop();
+        }
+
+        // Since we can't tell whether there are other waiters, we have to mark the lock as
+        // contended when we acquire it here
+        while self.futex.swap(CONTENDED, Ordering::Acquire) != UNLOCKED {
+            wait(&self.futex, CONTENDED, None);
+        }
+    }
+
+    /// Try to acquire the lock without blocking.
+    ///
+    /// Returns `None` if the lock is currently held by another thread.
+    #[inline]
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.futex
+            .compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| MutexGuard { mutex: self })
+    }
+
+    /// Get a mutable reference to the contained value.
+    ///
+    /// No locking is necessary, since this requires a mutable reference to the mutex.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    #[inline]
+    fn unlock(&self) {
+        if self.futex.swap(UNLOCKED, Ordering::Release) == CONTENDED {
+            wake_one(&self.futex);
+        }
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Some(guard) => d.field("data", &&*guard),
+            None => d.field("data", &format_args!("<locked>")),
+        };
+        d.finish()
+    }
+}
+
+/// A guard that releases the lock on a [`Mutex`] when it is dropped.
+///
+/// [`Mutex`]: ./struct.Mutex.html
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.mutex.unlock();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// A condition variable, for use with a [`Mutex`].
+///
+/// # Example
+///
+/// ```
+/// use scall::sync::{Condvar, Mutex};
+///
+/// static READY: Mutex<bool> = Mutex::new(false);
+/// static CONDVAR: Condvar = Condvar::new();
+///
+/// let thread = std::thread::spawn(|| {
+///     *READY.lock() = true;
+///     CONDVAR.notify_one();
+/// });
+///
+/// let mut ready = READY.lock();
+/// while !*ready {
+///     ready = CONDVAR.wait(ready);
+/// }
+/// # drop(ready);
+/// # thread.join().unwrap();
+/// ```
+///
+/// [`Mutex`]: ./struct.Mutex.html
+#[derive(Debug, Default)]
+pub struct Condvar {
+    // Incremented on every notification
+    futex: AtomicU32,
+}
+
+impl Condvar {
+    /// Create a new condition variable.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            futex: AtomicU32::new(0),
+        }
+    }
+
+    /// Release the lock held by `guard`, wait for a notification, and then reacquire the lock.
+    ///
+    /// Like with every condition variable, spurious wakeups are possible, so this should be
+    /// called in a loop that checks the condition being waited for.
+    #[inline]
+    pub fn wait<'a, T: ?Sized>(&self, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+        self.wait_inner(guard, None).0
+    }
+
+    /// Like [`wait()`], but give up after the given amount of time.
+    ///
+    /// Returns the reacquired guard, and `true` if the timeout elapsed (although the condition
+    /// may still have become true in the meantime).
+    ///
+    /// [`wait()`]: #method.wait
+    #[inline]
+    pub fn wait_timeout<'a, T: ?Sized>(
+        &self,
+        guard: MutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> (MutexGuard<'a, T>, bool) {
+        self.wait_inner(guard, Some(timeout))
+    }
+
+    fn wait_inner<'a, T: ?Sized>(
+        &self,
+        guard: MutexGuard<'a, T>,
+        timeout: Option<Duration>,
+    ) -> (MutexGuard<'a, T>, bool) {
+        // Read the counter before unlocking, so a notification sent after that can't be missed
+        let seq = self.futex.load(Ordering::Relaxed);
+        let mutex = guard.mutex;
+        drop(guard);
+
+        let timed_out = !wait(&self.futex, seq, timeout);
+        (mutex.lock(), timed_out)
+    }
+
+    /// Wake up one thread that is waiting on this condition variable (if there are any).
+    #[inline]
+    pub fn notify_one(&self) {
+        self.futex.fetch_add(1, Ordering::Relaxed);
+        wake_one(&self.futex);
+    }
+
+    /// Wake up all threads that are waiting on this condition variable.
+    #[inline]
+    pub fn notify_all(&self) {
+        self.futex.fetch_add(1, Ordering::Relaxed);
+        wake_all(&self.futex);
+    }
+}
+
+const INCOMPLETE: u32 = 0;
+const RUNNING: u32 = 1;
+// Running, and there may be other threads waiting for it to complete
+const QUEUED: u32 = 2;
+const COMPLETE: u32 = 3;
+
+/// A primitive for running one-time initialization.
+///
+/// # Example
+///
+/// ```
+/// use scall::sync::Once;
+///
+/// static INIT: Once = Once::new();
+///
+/// let mut count = 0;
+/// INIT.call_once(|| count += 1);
+/// INIT.call_once(|| count += 1);
+/// assert_eq!(count, 1);
+/// assert!(INIT.is_completed());
+/// ```
+#[derive(Debug)]
+pub struct Once {
+    futex: AtomicU32,
+}
+
+impl Once {
+    /// Create a new `Once`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            futex: AtomicU32::new(INCOMPLETE),
+        }
+    }
+
+    /// Check whether initialization has completed.
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        self.futex.load(Ordering::Acquire) == COMPLETE
+    }
+
+    /// Run `f` if this is the first time `call_once()` has been called on this `Once`.
+    ///
+    /// If another thread is currently running its closure, this blocks until it finishes. When
+    /// this returns, it is guaranteed that some closure has run to completion (and its effects
+    /// are visible to the current thread).
+    ///
+    /// If `f` panics, the `Once` is reset, and the next call to `call_once()` will run its
+    /// closure.
+    #[inline]
+    pub fn call_once<F: FnOnce()>(&self, f: F) {
+        if !self.is_completed() {
+            self.call_once_slow(f);
+        }
+    }
+
+    #[cold]
+    fn call_once_slow<F: FnOnce()>(&self, f: F) {
+        let mut state = self.futex.load(Ordering::Acquire);
+        loop {
+            match state {
+                INCOMPLETE => {
+                    if let Err(new) = self.futex.compare_exchange_weak(
+                        INCOMPLETE,
+                        RUNNING,
+                        Ordering::Acquire,
+                        Ordering::Acquire,
+                    ) {
+                        state = new;
+                        continue;
+                    }
+
+                    // Resets the state (and wakes up waiters) if `f` panics
+                    let mut guard = OnceGuard {
+                        futex: &self.futex,
+                        state: INCOMPLETE,
+                    };
+                    f();
+                    guard.state = COMPLETE;
+                    return;
+                }
+
+                RUNNING => {
+                    if let Err(new) = self.futex.compare_exchange_weak(
+                        RUNNING,
+                        QUEUED,
+                        Ordering::Acquire,
+                        Ordering::Acquire,
+                    ) {
+                        state = new;
+                        continue;
+                    }
+
+                    wait(&self.futex, QUEUED, None);
+                    state = self.futex.load(Ordering::Acquire);
+                }
+
+                QUEUED => {
+                    wait(&self.futex, QUEUED, None);
+                    state = self.futex.load(Ordering::Acquire);
+                }
+
+                _ => return,
+            }
+        }
+    }
+}
+
+impl Default for Once {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct OnceGuard<'a> {
+    futex: &'a AtomicU32,
+    state: u32,
+}
+
+impl Drop for OnceGuard<'_> {
+    fn drop(&mut self) {
+        if self.futex.swap(self.state, Ordering::Release) == QUEUED {
+            wake_all(self.futex);
+        }
+    }
+}
+
+const EMPTY: u32 = 0;
+const NOTIFIED: u32 = 1;
+const PARKED: u32 = u32::MAX;
+
+/// A thread parker, like `std::thread::park()` and `Thread::unpark()`.
+///
+/// A `Parker` holds a single "token". [`park()`] blocks until the token is available and then
+/// consumes it, and [`unpark()`] makes the token available (waking up the parked thread, if
+/// there is one). Calling `unpark()` several times before `park()` is called makes only a single
+/// token available.
+///
+/// A `Parker` should only be parked on by one thread at a time (typically, it is owned by the
+/// thread that parks on it), but it can be unparked from any thread.
+///
+/// # Example
+///
+/// ```
+/// use scall::sync::Parker;
+///
+/// static PARKER: Parker = Parker::new();
+///
+/// let thread = std::thread::spawn(|| PARKER.unpark());
+/// PARKER.park();
+/// # thread.join().unwrap();
+/// ```
+///
+/// [`park()`]: #method.park
+/// [`unpark()`]: #method.unpark
+#[derive(Debug, Default)]
+pub struct Parker {
+    futex: AtomicU32,
+}
+
+impl Parker {
+    /// Create a new `Parker` (without a token available).
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            futex: AtomicU32::new(EMPTY),
+        }
+    }
+
+    /// Block until the token is available, then consume it.
+    pub fn park(&self) {
+        while !self.park_inner(None) {}
+    }
+
+    /// Like [`park()`], but give up after the given amount of time.
+    ///
+    /// Returns `true` if the token was consumed. This may also return `false` spuriously, before
+    /// the timeout has elapsed.
+    ///
+    /// [`park()`]: #method.park
+    pub fn park_timeout(&self, timeout: Duration) -> bool {
+        self.park_inner(Some(timeout))
+    }
+
+    fn park_inner(&self, timeout: Option<Duration>) -> bool {
+        // EMPTY -> PARKED, or NOTIFIED -> EMPTY
+        if self.futex.fetch_sub(1, Ordering::Acquire) == NOTIFIED {
+            return true;
+        }
+
+        wait(&self.futex, PARKED, timeout);
+
+        // Whether or not we were woken up, consume the token if it's there
+        self.futex.swap(EMPTY, Ordering::Acquire) == NOTIFIED
+    }
+
+    /// Make the token available, waking up the parked thread if there is one.
+    #[inline]
+    pub fn unpark(&self) {
+        if self.futex.swap(NOTIFIED, Ordering::Release) == PARKED {
+            wake_one(&self.futex);
+        }
+    }
+}
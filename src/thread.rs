@@ -0,0 +1,570 @@
+//! Minimal threads for programs that don't use the C library.
+//!
+//! [`spawn()`] (and [`Builder::spawn()`]) create a thread with [`clone_with_stack()`], on a stack
+//! allocated with `mmap()` (with a guard page below it to catch overflows). [`JoinHandle::join()`]
+//! waits for the thread to exit using `CLONE_CHILD_CLEARTID`: the kernel clears the thread ID
+//! stored next to the stack and wakes up the futex at that address when the thread exits, after
+//! which its stack can be unmapped.
+//!
+//! The C library knows nothing about these threads, so they must not use it (or anything in `std`
+//! that relies on it, such as thread-local storage or `std::thread::current()`). They are meant
+//! for programs built with the `rt` feature, or ones that are otherwise careful to only make raw
+//! syscalls. The [`sync`] module provides synchronization primitives that work in such threads.
+//!
+//! Each new thread gets a TLS block of its own, placed in the same mapping as its stack and set
+//! with `CLONE_SETTLS` (unless [`Builder::tls()`] is used to provide one instead). It holds a
+//! fresh copy of the executable's thread-local variables (its `PT_TLS` segment), laid out the way
+//! the architecture's TLS ABI expects, and a zeroed thread control block (with the self-pointer
+//! at `tp[0]` on x86, x86-64, and SPARC64). The thread-local variables of shared libraries
+//! (including the C library's) are *not* set up.
+//!
+//! # Example
+//!
+//! ```
+//! use core::sync::atomic::{AtomicU32, Ordering};
+//! use scall::thread;
+//!
+//! static COUNTER: AtomicU32 = AtomicU32::new(0);
+//!
+//! let handles: Vec<_> = (0..4)
+//!     .map(|i| unsafe {
+//!         thread::spawn(move || {
+//!             COUNTER.fetch_add(1, Ordering::SeqCst);
+//!             i * 2
+//!         })
+//!         .unwrap()
+//!     })
+//!     .collect();
+//!
+//! let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+//! assert_eq!(results, [0, 2, 4, 6]);
+//! assert_eq!(COUNTER.load(Ordering::SeqCst), 4);
+//! ```
+//!
+//! [`spawn()`]: ./fn.spawn.html
+//! [`Builder::spawn()`]: ./struct.Builder.html#method.spawn
+//! [`Builder::tls()`]: ./struct.Builder.html#method.tls
+//! [`clone_with_stack()`]: ../fn.clone_with_stack.html
+//! [`JoinHandle::join()`]: ./struct.JoinHandle.html#method.join
+//! [`sync`]: ../sync/index.html
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::{align_of, size_of, MaybeUninit};
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use crate::consts;
+
+/// The stack size used for new threads if [`Builder::stack_size()`] is not called.
+///
+/// [`Builder::stack_size()`]: ./struct.Builder.html#method.stack_size
+pub const DEFAULT_STACK_SIZE: usize = 128 * 1024;
+
+// `clone()` flags for creating a thread that shares everything with the current one (like
+// `pthread_create()` does). `exit_signal` is 0, since nothing waits for threads with `wait()`.
+const THREAD_FLAGS: u64 = consts::CLONE_VM
+    | consts::CLONE_FS
+    | consts::CLONE_FILES
+    | consts::CLONE_SIGHAND
+    | consts::CLONE_THREAD
+    | consts::CLONE_SYSVSEM
+    | consts::CLONE_PARENT_SETTID
+    | consts::CLONE_CHILD_CLEARTID;
+
+#[cfg(target_arch = "x86_64")]
+const ARCH_SET_FS: usize = 0x1002;
+
+// `flags` for a `struct user_desc` describing a TLS segment: `seg_32bit`, `limit_in_pages`, and
+// `useable`
+#[cfg(target_arch = "x86")]
+const USER_DESC_TLS_FLAGS: u32 = 0x51;
+
+// Get the GDT entry currently loaded in %gs (or 0 if there isn't one)
+#[cfg(target_arch = "x86")]
+fn gs_entry() -> u32 {
+    let gs: u32;
+    unsafe {
+        asm!("mov {0:e}, gs", out(reg) gs, options(nomem, nostack, preserves_flags));
+    }
+    gs >> 3
+}
+
+// The size of the zeroed thread control block that is reserved next to each TLS block
+const TCB_SIZE: usize = 256;
+
+// On these architectures, the TLS block is below the thread pointer ("variant II"), which points
+// to the TCB. On the others, it is above the thread pointer.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "sparc64"))]
+const TLS_BELOW_TP: bool = true;
+#[cfg(not(any(target_arch = "x86_64", target_arch = "x86", target_arch = "sparc64")))]
+const TLS_BELOW_TP: bool = false;
+
+// For TLS blocks above the thread pointer: the space reserved for the TCB between the thread
+// pointer and the TLS block, and the bias that is added to the thread pointer
+#[cfg(target_arch = "aarch64")]
+const TLS_GAP: (usize, usize) = (16, 0);
+#[cfg(target_arch = "arm")]
+const TLS_GAP: (usize, usize) = (8, 0);
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
+const TLS_GAP: (usize, usize) = (0, 0x7000);
+#[cfg(not(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+const TLS_GAP: (usize, usize) = (0, 0);
+
+// The executable's TLS segment (`PT_TLS`), which is copied into the TLS block of every new thread
+struct TlsImage {
+    addr: usize,
+    filesz: usize,
+    memsz: usize,
+    align: usize,
+}
+
+impl TlsImage {
+    // Find the TLS segment through the program headers in the auxiliary vector
+    fn get() -> Self {
+        use crate::auxv::{getauxval, AT_PHDR, AT_PHENT, AT_PHNUM};
+        use crate::vdso::elf;
+
+        let mut image = Self {
+            addr: 0,
+            filesz: 0,
+            memsz: 0,
+            align: 1,
+        };
+
+        let (phdr, phnum) = match (getauxval(AT_PHDR), getauxval(AT_PHNUM)) {
+            (Some(phdr), Some(phnum)) if phdr != 0 => (phdr, phnum),
+            _ => return image,
+        };
+        if let Some(phent) = getauxval(AT_PHENT) {
+            if phent != size_of::<elf::Phdr>() {
+                return image;
+            }
+        }
+        let phdrs = unsafe { core::slice::from_raw_parts(phdr as *const elf::Phdr, phnum) };
+
+        // The executable's load bias (for position-independent executables)
+        let bias = phdrs
+            .iter()
+            .find(|ph| ph.p_type == elf::PT_PHDR)
+            .map_or(0, |ph| phdr.wrapping_sub(ph.p_vaddr));
+
+        if let Some(ph) = phdrs.iter().find(|ph| ph.p_type == elf::PT_TLS) {
+            image.addr = bias.wrapping_add(ph.p_vaddr);
+            image.filesz = ph.p_filesz;
+            image.memsz = ph.p_memsz;
+            image.align = ph.p_align.max(1);
+        }
+        image
+    }
+
+    // The amount of space that `install()` may use
+    fn reserve(&self) -> usize {
+        self.memsz + 2 * self.align.max(16) + TCB_SIZE + TLS_GAP.0
+    }
+
+    // Copy the image into a TLS block placed just below `top` (in zeroed memory), and return the
+    // lowest address used along with the new thread pointer.
+    //
+    // The block's offset from the thread pointer is computed the same way that the linker (and
+    // the C library) compute it for the executable's thread-local variables, and its address is
+    // congruent to the image's address modulo the alignment.
+    unsafe fn install(&self, top: usize) -> (usize, usize) {
+        // The thread pointer is kept 16-byte aligned, but the offset only depends on the image's
+        // own alignment
+        let (align, tp_align) = (self.align, self.align.max(16));
+
+        let (block, bottom, tp) = if TLS_BELOW_TP {
+            let tp = (top - TCB_SIZE) & !(tp_align - 1);
+            let offset = self.memsz + (0usize.wrapping_sub(self.memsz + self.addr) & (align - 1));
+            // The TCB starts with a pointer to itself
+            (tp as *mut usize).write(tp);
+            (tp - offset, tp - offset, tp)
+        } else {
+            let (gap, bias) = TLS_GAP;
+            let offset = gap + (self.addr.wrapping_sub(gap) & (align - 1));
+            let tp = (top - offset - self.memsz) & !(tp_align - 1);
+            (tp + offset, tp - TCB_SIZE, tp + bias)
+        };
+
+        core::ptr::copy_nonoverlapping(self.addr as *const u8, block as *mut u8, self.filesz);
+        (bottom, tp)
+    }
+}
+
+// Stored at the top of the thread's stack mapping. `tid` is set by the kernel when the thread is
+// created (`CLONE_PARENT_SETTID`) and cleared when it exits (`CLONE_CHILD_CLEARTID`).
+struct Packet<T> {
+    tid: AtomicU32,
+    done: AtomicBool,
+    result: UnsafeCell<MaybeUninit<T>>,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+))]
+unsafe fn mmap_anonymous(len: usize, prot: i32, flags: i32) -> Result<usize, i32> {
+    crate::syscall!(MMAP2, 0, len, prot, flags | consts::MAP_ANONYMOUS, -1, 0)
+}
+
+#[cfg(not(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "x86",
+)))]
+unsafe fn mmap_anonymous(len: usize, prot: i32, flags: i32) -> Result<usize, i32> {
+    crate::syscall!(MMAP, 0, len, prot, flags | consts::MAP_ANONYMOUS, -1, 0)
+}
+
+/// Configuration for a new thread.
+///
+/// # Example
+///
+/// ```
+/// use scall::thread::Builder;
+///
+/// let handle = unsafe { Builder::new().stack_size(16384).spawn(|| 42).unwrap() };
+/// assert_eq!(handle.join(), Some(42));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder {
+    stack_size: usize,
+    tls: Option<usize>,
+}
+
+impl Builder {
+    /// Create a new `Builder` with the default settings.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            stack_size: DEFAULT_STACK_SIZE,
+            tls: None,
+        }
+    }
+
+    /// Set the size of the new thread's stack.
+    ///
+    /// This is rounded up to a multiple of the page size. The guard page is not included.
+    #[inline]
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = size;
+        self
+    }
+
+    /// Set the thread pointer of the new thread (with `CLONE_SETTLS`).
+    ///
+    /// `tp` is the value that the architecture's thread pointer register should have (`%fs` base
+    /// on x86-64, `TPIDR_EL0` on AArch64, `tp` on RISC-V, etc.); see [`set_thread_pointer()`].
+    /// On 32-bit x86, the new thread reuses the TLS segment (GDT entry) that is loaded in the
+    /// current thread's `%gs`, with `tp` as its base address, so spawning fails with `EINVAL` if
+    /// the current thread doesn't have one.
+    ///
+    /// If this is not called, a TLS block for the new thread is allocated along with its stack
+    /// (see the [module documentation](./index.html)).
+    ///
+    /// [`set_thread_pointer()`]: ./fn.set_thread_pointer.html
+    #[inline]
+    pub fn tls(mut self, tp: usize) -> Self {
+        self.tls = Some(tp);
+        self
+    }
+
+    /// Spawn a new thread that runs `f`.
+    ///
+    /// # Safety
+    ///
+    /// `f` must not use the C library, or anything else that needs to know about the threads in
+    /// the process (see the [module documentation](./index.html)). If [`tls()`] was called, the
+    /// thread pointer must be valid for whatever `f` does with it. Otherwise, only the executable's
+    /// thread-local variables are available to `f` (see the [module documentation](./index.html)).
+    ///
+    /// On 32-bit x86, a TLS segment can only be set up for the new thread if the current thread
+    /// has one loaded in `%gs`. If it doesn't, the new thread is created without one, just like
+    /// the current thread.
+    ///
+    /// [`tls()`]: #method.tls
+    pub unsafe fn spawn<F, T>(self, f: F) -> Result<JoinHandle<T>, i32>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let page_size = crate::auxv::page_size();
+        let stack_size = (self.stack_size.max(1) + page_size - 1) & !(page_size - 1);
+
+        let image = if self.tls.is_none() {
+            Some(TlsImage::get())
+        } else {
+            None
+        };
+        let tls_size = image.as_ref().map_or(0, TlsImage::reserve);
+        let map_len = stack_size + page_size + ((tls_size + page_size - 1) & !(page_size - 1));
+
+        let map = mmap_anonymous(
+            map_len,
+            consts::PROT_READ | consts::PROT_WRITE,
+            consts::MAP_PRIVATE | consts::MAP_STACK,
+        )?;
+
+        let handle = JoinHandle {
+            packet: Self::packet_addr::<T>(map + map_len) as *mut Packet<T>,
+            map,
+            map_len,
+        };
+
+        if let Err(eno) = self.spawn_on(&handle, image, f) {
+            crate::syscall_nofail!(MUNMAP, map, map_len);
+            return Err(eno);
+        }
+
+        Ok(handle)
+    }
+
+    // Where to put the `Packet` at the top of the stack mapping
+    fn packet_addr<T>(top: usize) -> usize {
+        let align = core::cmp::max(16, align_of::<Packet<T>>());
+        (top - size_of::<Packet<T>>()) & !(align - 1)
+    }
+
+    unsafe fn spawn_on<F, T>(
+        &self,
+        handle: &JoinHandle<T>,
+        image: Option<TlsImage>,
+        f: F,
+    ) -> Result<(), i32>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        // The guard page
+        crate::syscall!(
+            MPROTECT,
+            handle.map,
+            crate::auxv::page_size(),
+            consts::PROT_NONE
+        )?;
+
+        let packet = handle.packet;
+        packet.write(Packet {
+            tid: AtomicU32::new(0),
+            done: AtomicBool::new(false),
+            result: UnsafeCell::new(MaybeUninit::uninit()),
+        });
+
+        // The new thread's TLS block goes between its stack and the `Packet`
+        let (stack, tp) = match image {
+            Some(image) => {
+                let (bottom, tp) = image.install(packet as usize);
+                (bottom & !15, tp)
+            }
+            None => (packet as usize, self.tls.unwrap_or(0)),
+        };
+
+        #[cfg(target_arch = "x86")]
+        let desc;
+        #[cfg(target_arch = "x86")]
+        let (flags, tls) = match gs_entry() {
+            0 if self.tls.is_none() => (THREAD_FLAGS, 0),
+            0 => return Err(crate::eno::EINVAL),
+            entry => {
+                desc = crate::types::user_desc {
+                    entry_number: entry,
+                    base_addr: tp as u32,
+                    limit: 0xfffff,
+                    flags: USER_DESC_TLS_FLAGS,
+                };
+                (
+                    THREAD_FLAGS | consts::CLONE_SETTLS,
+                    &desc as *const _ as usize,
+                )
+            }
+        };
+        #[cfg(not(target_arch = "x86"))]
+        let (flags, tls) = (THREAD_FLAGS | consts::CLONE_SETTLS, tp);
+
+        let tid = &(*packet).tid as *const AtomicU32 as *mut i32;
+        let packet = packet as usize;
+
+        crate::clone_with_stack(flags, stack as *mut u8, tls, tid, tid, move || {
+            let packet = &*(packet as *const Packet<T>);
+            (*packet.result.get()).as_mut_ptr().write(f());
+            packet.done.store(true, Ordering::Release);
+            0
+        })?;
+
+        Ok(())
+    }
+}
+
+impl Default for Builder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Spawn a new thread that runs `f`, with the default settings.
+///
+/// This is equivalent to `Builder::new().spawn(f)`; see [`Builder::spawn()`].
+///
+/// # Safety
+///
+/// See [`Builder::spawn()`].
+///
+/// [`Builder::spawn()`]: ./struct.Builder.html#method.spawn
+#[inline]
+pub unsafe fn spawn<F, T>(f: F) -> Result<JoinHandle<T>, i32>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn(f)
+}
+
+/// A handle to a thread created with [`spawn()`] or [`Builder::spawn()`].
+///
+/// Dropping a `JoinHandle` without calling [`join()`] detaches the thread; its stack (and the
+/// value returned by the thread's function) will never be freed.
+///
+/// [`spawn()`]: ./fn.spawn.html
+/// [`Builder::spawn()`]: ./struct.Builder.html#method.spawn
+/// [`join()`]: #method.join
+pub struct JoinHandle<T> {
+    packet: *mut Packet<T>,
+    map: usize,
+    map_len: usize,
+}
+
+unsafe impl<T: Send> Send for JoinHandle<T> {}
+unsafe impl<T: Send> Sync for JoinHandle<T> {}
+
+impl<T> JoinHandle<T> {
+    #[inline]
+    fn packet(&self) -> &Packet<T> {
+        unsafe { &*self.packet }
+    }
+
+    /// Get the thread ID of the thread, or 0 if it has exited.
+    #[inline]
+    pub fn tid(&self) -> i32 {
+        self.packet().tid.load(Ordering::Acquire) as i32
+    }
+
+    /// Check whether the thread has exited.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.tid() == 0
+    }
+
+    /// Wait for the thread to exit, and return the value returned by its function.
+    ///
+    /// This returns `None` if the thread exited without returning a value (for example, because
+    /// it panicked or called `exit()` itself).
+    pub fn join(self) -> Option<T> {
+        let packet = self.packet();
+
+        loop {
+            let tid = packet.tid.load(Ordering::Acquire);
+            if tid == 0 {
+                break;
+            }
+            // The kernel wakes up this futex as a shared futex, so the private flag can't be used
+            let _ = crate::futex::futex_wait(&packet.tid, tid, None, 0);
+        }
+
+        let result = if packet.done.load(Ordering::Acquire) {
+            Some(unsafe { (*packet.result.get()).as_ptr().read() })
+        } else {
+            None
+        };
+
+        unsafe {
+            crate::syscall_nofail!(MUNMAP, self.map, self.map_len);
+        }
+
+        result
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("tid", &self.tid())
+            .finish()
+    }
+}
+
+/// Set the thread pointer of the current thread.
+///
+/// On these architectures, the thread pointer can only be changed with the kernel's help:
+///
+/// - On x86-64, this sets the `%fs` base with `arch_prctl(ARCH_SET_FS)`.
+/// - On 32-bit x86, this sets up a TLS segment with base address `tp` using `set_thread_area()`
+///   (reusing the GDT entry loaded in `%gs`, or allocating a new one), and loads it into `%gs`.
+/// - On ARM, this sets `TPIDRURO` with the ARM-specific `set_tls()` syscall.
+/// - On MIPS, this sets the value returned by `rdhwr $29` with `set_thread_area()`.
+///
+/// (On the other architectures, the thread pointer is an ordinary register.)
+///
+/// # Safety
+///
+/// This changes where thread-local variables are found, so anything that uses them (including
+/// the C library) will break unless `tp` points to a correctly laid out TLS block.
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64",
+))]
+#[doc(cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64",
+)))]
+pub unsafe fn set_thread_pointer(tp: usize) -> Result<(), i32> {
+    #[cfg(target_arch = "x86_64")]
+    crate::syscall!(ARCH_PRCTL, ARCH_SET_FS, tp)?;
+
+    #[cfg(target_arch = "x86")]
+    {
+        let entry = gs_entry();
+        let mut desc = crate::types::user_desc {
+            entry_number: if entry == 0 { u32::MAX } else { entry },
+            base_addr: tp as u32,
+            limit: 0xfffff,
+            flags: USER_DESC_TLS_FLAGS,
+        };
+        crate::syscall!(SET_THREAD_AREA, &mut desc)?;
+
+        asm!(
+            "mov gs, {0:x}",
+            in(reg) (desc.entry_number << 3 | 3) as u16,
+            options(nostack, preserves_flags),
+        );
+    }
+
+    #[cfg(target_arch = "arm")]
+    crate::syscall!(ARM_SET_TLS, tp)?;
+
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    crate::syscall!(SET_THREAD_AREA, tp)?;
+
+    Ok(())
+}
@@ -0,0 +1,597 @@
+//! Fast paths for time-related syscalls using the vDSO.
+//!
+//! Linux maps a small shared library (the "vDSO") into every process, which implements some
+//! syscalls (most notably `clock_gettime()`) entirely in userspace. This module locates the vDSO
+//! through the `AT_SYSINFO_EHDR` auxiliary vector entry (see [`auxv`]), looks up symbols in its
+//! dynamic symbol table (without allocating), and calls them.
+//!
+//! The wrappers in this module ([`clock_gettime()`], [`gettimeofday()`], [`time()`], and
+//! [`getcpu()`]) resolve the vDSO function the first time they are called, and transparently
+//! fall back to the real syscall if the vDSO (or the particular function) is not available on the
+//! current architecture or kernel. If a vDSO function fails, the syscall is also retried to
+//! obtain the error number (some architectures report errors from the vDSO in ways that cannot be
+//! observed from Rust).
+//!
+//! # Example
+//!
+//! ```
+//! use scall::{consts, vdso};
+//!
+//! let ts = vdso::clock_gettime(consts::CLOCK_MONOTONIC).unwrap();
+//! assert!(ts.tv_nsec < 1_000_000_000);
+//!
+//! assert_eq!(vdso::clock_gettime(-1).unwrap_err(), scall::eno::EINVAL);
+//! ```
+//!
+//! [`clock_gettime()`]: ./fn.clock_gettime.html
+//! [`gettimeofday()`]: ./fn.gettimeofday.html
+//! [`time()`]: ./fn.time.html
+//! [`getcpu()`]: ./fn.getcpu.html
+//! [`auxv`]: ../auxv/index.html
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::types::{timespec, timeval};
+
+// The names and version of the vDSO symbols on each architecture
+#[cfg(target_arch = "x86_64")]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = Some("__vdso_time");
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+#[cfg(target_arch = "x86")]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = Some("__vdso_time");
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+#[cfg(target_arch = "aarch64")]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6.39";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__kernel_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__kernel_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = None;
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc64",
+))]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = None;
+}
+
+#[cfg(target_arch = "riscv64")]
+mod syms {
+    pub const VERSION: &str = "LINUX_4.15";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+// On 64-bit big-endian PowerPC (ELFv1), function pointers point to function descriptors, and the
+// vDSO doesn't provide any. Just use the syscalls there.
+#[cfg(any(
+    target_arch = "powerpc",
+    all(target_arch = "powerpc64", target_endian = "little"),
+))]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6.15";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__kernel_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__kernel_gettimeofday");
+    pub const TIME: Option<&str> = Some("__kernel_time");
+    pub const GETCPU: Option<&str> = Some("__kernel_getcpu");
+}
+
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+mod syms {
+    pub const VERSION: &str = "LINUX_2.6.15";
+    pub const CLOCK_GETTIME: Option<&str> = None;
+    pub const GETTIMEOFDAY: Option<&str> = None;
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = None;
+}
+
+// The ELF structures; the native word size determines the ELF class. Addresses and offsets are
+// `usize` in both classes, but some fields are in different orders.
+#[allow(non_camel_case_types)]
+pub(crate) mod elf {
+    #[repr(C)]
+    pub struct Ehdr {
+        pub e_ident: [u8; 16],
+        pub e_type: u16,
+        pub e_machine: u16,
+        pub e_version: u32,
+        pub e_entry: usize,
+        pub e_phoff: usize,
+        pub e_shoff: usize,
+        pub e_flags: u32,
+        pub e_ehsize: u16,
+        pub e_phentsize: u16,
+        pub e_phnum: u16,
+        pub e_shentsize: u16,
+        pub e_shnum: u16,
+        pub e_shstrndx: u16,
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[repr(C)]
+    pub struct Phdr {
+        pub p_type: u32,
+        pub p_flags: u32,
+        pub p_offset: usize,
+        pub p_vaddr: usize,
+        pub p_paddr: usize,
+        pub p_filesz: usize,
+        pub p_memsz: usize,
+        pub p_align: usize,
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[repr(C)]
+    pub struct Phdr {
+        pub p_type: u32,
+        pub p_offset: usize,
+        pub p_vaddr: usize,
+        pub p_paddr: usize,
+        pub p_filesz: usize,
+        pub p_memsz: usize,
+        pub p_flags: u32,
+        pub p_align: usize,
+    }
+
+    #[repr(C)]
+    pub struct Dyn {
+        pub d_tag: isize,
+        pub d_val: usize,
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[repr(C)]
+    pub struct Sym {
+        pub st_name: u32,
+        pub st_info: u8,
+        pub st_other: u8,
+        pub st_shndx: u16,
+        pub st_value: usize,
+        pub st_size: usize,
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[repr(C)]
+    pub struct Sym {
+        pub st_name: u32,
+        pub st_value: usize,
+        pub st_size: usize,
+        pub st_info: u8,
+        pub st_other: u8,
+        pub st_shndx: u16,
+    }
+
+    #[repr(C)]
+    pub struct Verdef {
+        pub vd_version: u16,
+        pub vd_flags: u16,
+        pub vd_ndx: u16,
+        pub vd_cnt: u16,
+        pub vd_hash: u32,
+        pub vd_aux: u32,
+        pub vd_next: u32,
+    }
+
+    #[repr(C)]
+    pub struct Verdaux {
+        pub vda_name: u32,
+        pub vda_next: u32,
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    pub const ELFCLASS: u8 = 2;
+    #[cfg(target_pointer_width = "32")]
+    pub const ELFCLASS: u8 = 1;
+
+    pub const PT_LOAD: u32 = 1;
+    pub const PT_DYNAMIC: u32 = 2;
+    pub const PT_PHDR: u32 = 6;
+    pub const PT_TLS: u32 = 7;
+
+    pub const DT_NULL: isize = 0;
+    pub const DT_HASH: isize = 4;
+    pub const DT_STRTAB: isize = 5;
+    pub const DT_SYMTAB: isize = 6;
+    pub const DT_GNU_HASH: isize = 0x6fff_fef5;
+    pub const DT_VERSYM: isize = 0x6fff_fff0;
+    pub const DT_VERDEF: isize = 0x6fff_fffc;
+
+    pub const STT_NOTYPE: u8 = 0;
+    pub const STT_FUNC: u8 = 2;
+    pub const STB_GLOBAL: u8 = 1;
+    pub const STB_WEAK: u8 = 2;
+    pub const SHN_UNDEF: u16 = 0;
+
+    pub const VER_FLG_BASE: u16 = 1;
+
+    pub fn elf_hash(name: &[u8]) -> u32 {
+        let mut h = 0u32;
+        for &c in name {
+            h = (h << 4).wrapping_add(c as u32);
+            let g = h & 0xf000_0000;
+            if g != 0 {
+                h ^= g >> 24;
+            }
+            h &= !g;
+        }
+        h
+    }
+
+    pub fn gnu_hash(name: &[u8]) -> u32 {
+        name.iter()
+            .fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum HashTable {
+    Sysv {
+        nbucket: u32,
+        bucket: *const u32,
+        chain: *const u32,
+    },
+    Gnu {
+        nbucket: u32,
+        symoffset: u32,
+        bucket: *const u32,
+        chain: *const u32,
+    },
+}
+
+/// A parsed view of the vDSO's dynamic symbol table.
+///
+/// Most users will want to use the wrapper functions in this module instead; this is exposed for
+/// looking up other symbols (for example, `__vdso_clock_getres` or `__vdso_getrandom`).
+#[derive(Copy, Clone, Debug)]
+pub struct Vdso {
+    load_offset: usize,
+    symtab: *const elf::Sym,
+    strtab: *const u8,
+    hash: HashTable,
+    versym: *const u16,
+    verdef: *const elf::Verdef,
+}
+
+// The vDSO is mapped for the lifetime of the process and never modified.
+unsafe impl Send for Vdso {}
+unsafe impl Sync for Vdso {}
+
+impl Vdso {
+    /// Locate the vDSO of the current process and parse its symbol table.
+    ///
+    /// Returns `None` if the vDSO could not be found (for example, if the auxiliary vector could
+    /// not be read or the kernel was booted with `vdso=0`) or could not be parsed.
+    pub fn get() -> Option<Self> {
+        let ehdr =
+            crate::auxv::getauxval(crate::auxv::AT_SYSINFO_EHDR).filter(|&addr| addr != 0)?;
+        unsafe { Self::from_ehdr(ehdr as *const u8) }
+    }
+
+    /// Parse the symbol table of the vDSO (or another ELF shared object that has been mapped into
+    /// memory in the same way) whose ELF header is at `base`.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to a valid, fully mapped ELF image that remains mapped for as long as
+    /// the returned `Vdso` (or any symbols looked up from it) are used.
+    pub unsafe fn from_ehdr(base: *const u8) -> Option<Self> {
+        let ehdr = &*(base as *const elf::Ehdr);
+        if ehdr.e_ident[..4] != *b"\x7fELF" || ehdr.e_ident[4] != elf::ELFCLASS {
+            return None;
+        }
+
+        let phdrs = core::slice::from_raw_parts(
+            base.add(ehdr.e_phoff) as *const elf::Phdr,
+            ehdr.e_phnum as usize,
+        );
+
+        let mut load_offset = None;
+        let mut dynamic = None;
+        for phdr in phdrs {
+            match phdr.p_type {
+                elf::PT_LOAD if load_offset.is_none() => {
+                    load_offset = Some((base as usize + phdr.p_offset).wrapping_sub(phdr.p_vaddr));
+                }
+                elf::PT_DYNAMIC => dynamic = Some(base.add(phdr.p_offset) as *const elf::Dyn),
+                _ => (),
+            }
+        }
+        let load_offset = load_offset?;
+        let mut dynp = dynamic?;
+
+        let mut symtab = None;
+        let mut strtab = None;
+        let mut sysv_hash: Option<*const u32> = None;
+        let mut gnu_hash: Option<*const u32> = None;
+        let mut versym = core::ptr::null();
+        let mut verdef = core::ptr::null();
+
+        while (*dynp).d_tag != elf::DT_NULL {
+            let addr = (*dynp).d_val.wrapping_add(load_offset);
+            match (*dynp).d_tag {
+                elf::DT_STRTAB => strtab = Some(addr as *const u8),
+                elf::DT_SYMTAB => symtab = Some(addr as *const elf::Sym),
+                elf::DT_HASH => sysv_hash = Some(addr as *const u32),
+                elf::DT_GNU_HASH => gnu_hash = Some(addr as *const u32),
+                elf::DT_VERSYM => versym = addr as *const u16,
+                elf::DT_VERDEF => verdef = addr as *const elf::Verdef,
+                _ => (),
+            }
+            dynp = dynp.add(1);
+        }
+
+        let hash = if let Some(table) = gnu_hash {
+            let nbucket = *table;
+            let symoffset = *table.add(1);
+            let bloom_size = *table.add(2) as usize;
+            let bucket = table.add(4).cast::<usize>().add(bloom_size).cast::<u32>();
+            HashTable::Gnu {
+                nbucket,
+                symoffset,
+                bucket,
+                chain: bucket.add(nbucket as usize),
+            }
+        } else {
+            let table = sysv_hash?;
+            let nbucket = *table;
+            HashTable::Sysv {
+                nbucket,
+                bucket: table.add(2),
+                chain: table.add(2 + nbucket as usize),
+            }
+        };
+
+        if verdef.is_null() {
+            versym = core::ptr::null();
+        }
+
+        Some(Self {
+            load_offset,
+            symtab: symtab?,
+            strtab: strtab?,
+            hash,
+            versym,
+            verdef,
+        })
+    }
+
+    unsafe fn str_eq(&self, offset: u32, s: &[u8]) -> bool {
+        let cstr = self.strtab.add(offset as usize);
+        s.iter().enumerate().all(|(i, &c)| *cstr.add(i) == c) && *cstr.add(s.len()) == 0
+    }
+
+    unsafe fn version_matches(&self, sym_index: usize, version: &[u8], ver_hash: u32) -> bool {
+        if self.versym.is_null() {
+            return true;
+        }
+
+        let ver = *self.versym.add(sym_index) & 0x7fff;
+
+        let mut def = self.verdef;
+        loop {
+            if (*def).vd_flags & elf::VER_FLG_BASE == 0 && (*def).vd_ndx & 0x7fff == ver {
+                break;
+            } else if (*def).vd_next == 0 {
+                return false;
+            }
+            def = (def as *const u8).add((*def).vd_next as usize) as *const elf::Verdef;
+        }
+
+        let aux = &*((def as *const u8).add((*def).vd_aux as usize) as *const elf::Verdaux);
+        (*def).vd_hash == ver_hash && self.str_eq(aux.vda_name, version)
+    }
+
+    unsafe fn check_sym(&self, index: usize, name: &[u8], version: &[u8], ver_hash: u32) -> bool {
+        let sym = &*self.symtab.add(index);
+        let ty = sym.st_info & 0xf;
+        let bind = sym.st_info >> 4;
+
+        (ty == elf::STT_FUNC || ty == elf::STT_NOTYPE)
+            && (bind == elf::STB_GLOBAL || bind == elf::STB_WEAK)
+            && sym.st_shndx != elf::SHN_UNDEF
+            && self.str_eq(sym.st_name, name)
+            && self.version_matches(index, version, ver_hash)
+    }
+
+    /// Look up the address of the symbol with the given name and version (for example,
+    /// `"__vdso_clock_gettime"` and `"LINUX_2.6"`).
+    ///
+    /// The caller is responsible for transmuting the address into a function pointer with the
+    /// correct signature.
+    pub fn lookup(&self, name: &str, version: &str) -> Option<usize> {
+        let (name, version) = (name.as_bytes(), version.as_bytes());
+        let ver_hash = elf::elf_hash(version);
+
+        unsafe {
+            let index = match self.hash {
+                HashTable::Sysv {
+                    nbucket,
+                    bucket,
+                    chain,
+                } => {
+                    if nbucket == 0 {
+                        return None;
+                    }
+
+                    let mut i = *bucket.add((elf::elf_hash(name) % nbucket) as usize) as usize;
+                    loop {
+                        if i == 0 {
+                            return None;
+                        } else if self.check_sym(i, name, version, ver_hash) {
+                            break i;
+                        }
+                        i = *chain.add(i) as usize;
+                    }
+                }
+
+                HashTable::Gnu {
+                    nbucket,
+                    symoffset,
+                    bucket,
+                    chain,
+                } => {
+                    if nbucket == 0 {
+                        return None;
+                    }
+
+                    let h = elf::gnu_hash(name);
+                    let mut i = *bucket.add((h % nbucket) as usize) as usize;
+                    if i < symoffset as usize {
+                        return None;
+                    }
+                    loop {
+                        let h2 = *chain.add(i - symoffset as usize);
+                        if h | 1 == h2 | 1 && self.check_sym(i, name, version, ver_hash) {
+                            break i;
+                        } else if h2 & 1 != 0 {
+                            return None;
+                        }
+                        i += 1;
+                    }
+                }
+            };
+
+            Some(
+                self.load_offset
+                    .wrapping_add((*self.symtab.add(index)).st_value),
+            )
+        }
+    }
+}
+
+// Each cached entry is 0 if the symbol has not been looked up yet, 1 if it is not available, or
+// the address of the function.
+static CLOCK_GETTIME: AtomicUsize = AtomicUsize::new(0);
+static GETTIMEOFDAY: AtomicUsize = AtomicUsize::new(0);
+static TIME: AtomicUsize = AtomicUsize::new(0);
+static GETCPU: AtomicUsize = AtomicUsize::new(0);
+
+fn resolve(cache: &AtomicUsize, name: Option<&str>) -> Option<usize> {
+    let addr = match cache.load(Ordering::Relaxed) {
+        0 => {
+            // Several threads may race to look up the symbol, but they will all get the same result.
+            // If the vDSO can't be found (possibly because the auxiliary vector couldn't be read
+            // yet), nothing is cached, so it is looked up again next time.
+            let addr = match name {
+                Some(name) => Vdso::get()?.lookup(name, syms::VERSION).unwrap_or(1),
+                None => 1,
+            };
+            cache.store(addr, Ordering::Relaxed);
+            addr
+        }
+        addr => addr,
+    };
+
+    if addr == 1 {
+        None
+    } else {
+        Some(addr)
+    }
+}
+
+/// Get the current time of the specified clock (for example, `CLOCK_MONOTONIC`).
+///
+/// This uses the vDSO if possible, and falls back on the `clock_gettime()` syscall otherwise.
+pub fn clock_gettime(clock: i32) -> Result<timespec, i32> {
+    let mut ts = timespec::default();
+
+    if let Some(addr) = resolve(&CLOCK_GETTIME, syms::CLOCK_GETTIME) {
+        let func: extern "C" fn(i32, *mut timespec) -> i32 = unsafe { core::mem::transmute(addr) };
+        if func(clock, &mut ts) == 0 {
+            return Ok(ts);
+        }
+    }
+
+    unsafe {
+        crate::syscall!(CLOCK_GETTIME, clock, &mut ts)?;
+    }
+    Ok(ts)
+}
+
+/// Get the current wall-clock time with microsecond precision.
+///
+/// This uses the vDSO if possible, and falls back on the `gettimeofday()` syscall otherwise.
+pub fn gettimeofday() -> Result<timeval, i32> {
+    let mut tv = timeval::default();
+
+    if let Some(addr) = resolve(&GETTIMEOFDAY, syms::GETTIMEOFDAY) {
+        let func: extern "C" fn(*mut timeval, *mut u8) -> i32 =
+            unsafe { core::mem::transmute(addr) };
+        if func(&mut tv, core::ptr::null_mut()) == 0 {
+            return Ok(tv);
+        }
+    }
+
+    unsafe {
+        crate::syscall!(GETTIMEOFDAY, &mut tv, 0)?;
+    }
+    Ok(tv)
+}
+
+/// Get the current wall-clock time in seconds since the Unix epoch.
+///
+/// This uses the vDSO's `time()` if the architecture provides one, and [`clock_gettime()`] with
+/// `CLOCK_REALTIME` otherwise.
+///
+/// [`clock_gettime()`]: ./fn.clock_gettime.html
+pub fn time() -> i64 {
+    // `__kernel_old_time_t`, which is a `long` (except on x32, where it is 64 bits)
+    #[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+    type OldTime = isize;
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    type OldTime = i64;
+
+    if let Some(addr) = resolve(&TIME, syms::TIME) {
+        let func: extern "C" fn(*mut OldTime) -> OldTime = unsafe { core::mem::transmute(addr) };
+        return func(core::ptr::null_mut()) as i64;
+    }
+
+    // This can't fail with a valid clock ID
+    clock_gettime(crate::consts::CLOCK_REALTIME)
+        .map(|ts| ts.tv_sec as _)
+        .unwrap_or(0)
+}
+
+/// Get the CPU and NUMA node that the calling thread is currently running on, as a `(cpu, node)`
+/// tuple.
+///
+/// This uses the vDSO if possible, and falls back on the `getcpu()` syscall otherwise. Note that
+/// the thread may be migrated to another CPU at any time, so the result may already be stale.
+pub fn getcpu() -> Result<(u32, u32), i32> {
+    let (mut cpu, mut node) = (0u32, 0u32);
+
+    if let Some(addr) = resolve(&GETCPU, syms::GETCPU) {
+        let func: extern "C" fn(*mut u32, *mut u32, *mut u8) -> isize =
+            unsafe { core::mem::transmute(addr) };
+        if func(&mut cpu, &mut node, core::ptr::null_mut()) == 0 {
+            return Ok((cpu, node));
+        }
+    }
+
+    unsafe {
+        crate::syscall!(GETCPU, &mut cpu, &mut node, 0)?;
+    }
+    Ok((cpu, node))
+}
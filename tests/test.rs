@@ -7,7 +7,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use scall::{eno, syscall, syscall_nofail, syscall_raw};
+use scall::{
+    consts, eno, sig, syscall, syscall_errno, syscall_into, syscall_nofail, syscall_raw, types,
+    Errno, SigSet, SyscallArg, Sysno,
+};
 
 #[test]
 fn test_ebadf() {
@@ -51,6 +54,239 @@ fn test_ebadf() {
     }
 }
 
+#[test]
+fn test_errno() {
+    assert_eq!(Errno::new(eno::ENOENT), Errno::ENOENT);
+    assert_eq!(Errno::ENOENT.into_raw(), eno::ENOENT);
+    assert_eq!(Errno::ENOENT, eno::ENOENT);
+    assert_eq!(Errno::ENOENT.name(), Some("ENOENT"));
+    assert_eq!(
+        Errno::ENOENT.description(),
+        Some("No such file or directory")
+    );
+    assert_eq!(Errno::from_name("ENOENT"), Some(Errno::ENOENT));
+    assert_eq!(Errno::from_name("enoent"), Some(Errno::ENOENT));
+    assert_eq!(Errno::from_name("ENOTANERROR"), None);
+
+    // Aliases
+    assert_eq!(Errno::EWOULDBLOCK, Errno::EAGAIN);
+    assert_eq!(Errno::EWOULDBLOCK.name(), Some("EAGAIN"));
+    assert_eq!(Errno::from_name("EWOULDBLOCK"), Some(Errno::EAGAIN));
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        assert_eq!(Errno::from_name("EOPNOTSUPP"), Some(Errno::new(95)));
+        assert_eq!(Errno::new(95).name(), Some("EOPNOTSUPP"));
+
+        assert_eq!(Errno::EDEADLOCK.into_raw(), eno::EDEADLOCK);
+        assert_eq!(Errno::from_name("EDEADLOCK"), Some(Errno::EDEADLOCK));
+
+        // Only the comment on the same line as the #define is used
+        assert_eq!(
+            Errno::ENOLCK.description(),
+            Some("No record locks available")
+        );
+        assert_eq!(
+            Errno::EKEYREJECTED.description(),
+            Some("Key was rejected by service")
+        );
+    }
+
+    assert_eq!(format!("{:?}", Errno::EBADF), "EBADF");
+    assert_eq!(format!("{:?}", Errno::new(-1)), "Errno(-1)");
+    assert_eq!(format!("{}", Errno::new(-1)), "Unknown error -1");
+    assert!(format!("{}", Errno::EBADF).starts_with("EBADF: Bad file"));
+
+    unsafe {
+        assert_eq!(syscall_errno!(CLOSE, -1i32), Err(Errno::EBADF));
+        assert_eq!(syscall_errno!(GETPID), Ok(std::process::id() as usize));
+    }
+}
+
+#[test]
+fn test_sysno() {
+    assert_eq!(Sysno::GETPID as usize, scall::nr::GETPID);
+    assert_eq!(Sysno::GETPID.into_raw(), scall::nr::GETPID);
+    assert_eq!(usize::from(Sysno::GETPID), scall::nr::GETPID);
+    assert_eq!(Sysno::new(scall::nr::GETPID), Some(Sysno::GETPID));
+    assert_eq!(Sysno::new(usize::MAX), None);
+
+    assert_eq!(Sysno::GETPID.name(), "getpid");
+    assert_eq!(Sysno::GETPID.to_string(), "getpid");
+    assert_eq!(Sysno::from_name("getpid"), Some(Sysno::GETPID));
+    assert_eq!(Sysno::from_name("GETPID"), Some(Sysno::GETPID));
+    assert_eq!(Sysno::from_name("notasyscall"), None);
+
+    #[cfg(target_os = "linux")]
+    {
+        assert_eq!(Sysno::new(scall::nr::OPENAT), Some(Sysno::OPENAT));
+        assert_eq!(Sysno::OPENAT.name(), "openat");
+        assert_eq!(Sysno::OPENAT.arg_count(), Some(4));
+        assert_eq!(Sysno::GETPID.arg_count(), Some(0));
+    }
+    #[cfg(all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "64"
+    ))]
+    {
+        assert_eq!(Sysno::new(0x101), Some(Sysno::OPENAT));
+        assert_eq!(Sysno::SETUID32, Sysno::SETUID);
+        assert_eq!(Sysno::from_name("setuid32"), Some(Sysno::SETUID));
+    }
+    #[cfg(all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "32"
+    ))]
+    {
+        assert_eq!(Sysno::new(0x4000_0101), Some(Sysno::OPENAT));
+        assert_eq!(Sysno::new(0x101), None);
+        assert_eq!(Sysno::IOCTL.into_raw(), 0x4000_0202);
+    }
+
+    let mut count = 0;
+    for sysno in Sysno::iter() {
+        assert_eq!(Sysno::new(sysno.into_raw()), Some(sysno));
+        assert_eq!(Sysno::from_name(sysno.name()), Some(sysno));
+        count += 1;
+    }
+    assert!(count > 100);
+
+    let pid = std::process::id() as usize;
+    unsafe {
+        assert_eq!(syscall!(Sysno::GETPID), Ok(pid));
+        let sysno = Sysno::GETPID;
+        assert_eq!(syscall!((sysno)), Ok(pid));
+        assert_eq!(syscall_nofail!((sysno)), pid);
+        assert_eq!(scall::decode_raw_result(scall::syscall0(sysno)), Ok(pid));
+        assert_eq!(syscall!(Sysno::KILL, pid, 0,), syscall!(KILL, pid, 0));
+        assert_eq!(scall::syscall_unchecked!(GETPID, 0), Ok(pid));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_error() {
+    use std::io::ErrorKind;
+
+    for &(eno, kind) in [
+        (eno::ENOENT, ErrorKind::NotFound),
+        (eno::EACCES, ErrorKind::PermissionDenied),
+        (eno::EPERM, ErrorKind::PermissionDenied),
+        (eno::EAGAIN, ErrorKind::WouldBlock),
+        (eno::EWOULDBLOCK, ErrorKind::WouldBlock),
+        (eno::EINTR, ErrorKind::Interrupted),
+        (eno::EPIPE, ErrorKind::BrokenPipe),
+        (eno::EEXIST, ErrorKind::AlreadyExists),
+        (eno::EINVAL, ErrorKind::InvalidInput),
+        (eno::ETIMEDOUT, ErrorKind::TimedOut),
+        (eno::ECONNREFUSED, ErrorKind::ConnectionRefused),
+        (eno::ECONNRESET, ErrorKind::ConnectionReset),
+        (eno::EADDRINUSE, ErrorKind::AddrInUse),
+        (eno::ENOMEM, ErrorKind::OutOfMemory),
+        (eno::ENOSYS, ErrorKind::Unsupported),
+        (eno::ENOTDIR, ErrorKind::NotADirectory),
+        (eno::EISDIR, ErrorKind::IsADirectory),
+        (eno::ENOTEMPTY, ErrorKind::DirectoryNotEmpty),
+        (eno::EROFS, ErrorKind::ReadOnlyFilesystem),
+        (eno::ENOSPC, ErrorKind::StorageFull),
+        (eno::EXDEV, ErrorKind::CrossesDevices),
+    ]
+    .iter()
+    {
+        assert_eq!(scall::error_kind(eno), kind);
+        assert_eq!(Errno::new(eno).kind(), kind);
+        assert_eq!(
+            std::io::Error::from(Errno::new(eno)).raw_os_error(),
+            Some(eno)
+        );
+    }
+
+    unsafe {
+        let err = scall::syscall_io!(CLOSE, -1i32).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(eno::EBADF));
+
+        assert_eq!(
+            scall::syscall_io!(GETPID).unwrap(),
+            std::process::id() as usize
+        );
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[test]
+fn test_retry() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    extern "C" fn handler(_sig: libc::c_int) {}
+
+    unsafe {
+        // Note: no SA_RESTART
+        let mut sa: libc::sigaction = std::mem::zeroed();
+        sa.sa_sigaction = handler as extern "C" fn(libc::c_int) as usize;
+        assert_eq!(libc::sigaction(libc::SIGUSR2, &sa, std::ptr::null_mut()), 0);
+    }
+
+    // Keep interrupting this thread with SIGUSR2 until we're done
+    let done = Arc::new(AtomicBool::new(false));
+    let target = unsafe { libc::pthread_self() } as usize;
+    let interrupter = {
+        let done = done.clone();
+        std::thread::spawn(move || {
+            while !done.load(Ordering::SeqCst) {
+                unsafe {
+                    libc::pthread_kill(target as libc::pthread_t, libc::SIGUSR2);
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        })
+    };
+
+    unsafe {
+        let mut timeout = types::timespec {
+            tv_sec: 0,
+            tv_nsec: 100_000_000,
+        };
+        let start = Instant::now();
+        assert_eq!(
+            scall::syscall_retry_timeout!(
+                timeout,
+                NANOSLEEP,
+                &timeout as *const types::timespec,
+                std::ptr::null_mut::<types::timespec>(),
+            ),
+            Ok(0)
+        );
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(1000), "{:?}", elapsed);
+
+        let mut fds = [0i32; 2];
+        assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+        let wfd = fds[1];
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            syscall!(WRITE, wfd, b"x".as_ptr(), 1).unwrap();
+        });
+
+        let mut buf = [0u8; 1];
+        assert_eq!(
+            scall::syscall_retry!(READ, fds[0], buf.as_mut_ptr(), buf.len()),
+            Ok(1)
+        );
+        assert_eq!(&buf, b"x");
+
+        writer.join().unwrap();
+        syscall_nofail!(CLOSE, fds[0]);
+        syscall_nofail!(CLOSE, fds[1]);
+    }
+
+    done.store(true, Ordering::SeqCst);
+    interrupter.join().unwrap();
+}
+
 #[test]
 fn test_fsync() {
     unsafe {
@@ -109,26 +345,1789 @@ fn test_getpid() {
 
 #[test]
 fn test_faccessat() {
+    unsafe {
+        // On Linux, the faccessat() syscall doesn't have a `flags` argument (that was added in
+        // faccessat2())
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        {
+            assert_eq!(
+                syscall!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK),
+                Ok(0)
+            );
+
+            assert_eq!(
+                syscall_nofail!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK),
+                0
+            );
+
+            let res = syscall!(FACCESSAT2, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0);
+
+            assert!(res == Ok(0) || res == Err(eno::ENOSYS), "{:?}", res);
+        }
+
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        {
+            assert_eq!(
+                syscall!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0),
+                Ok(0)
+            );
+
+            assert_eq!(
+                syscall_nofail!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0),
+                0
+            );
+        }
+    }
+}
+
+#[test]
+fn test_syscall_arg() {
+    assert_eq!((-1i32).into_arg(), usize::MAX);
+    assert_eq!((-1i8).into_arg(), usize::MAX);
+    assert_eq!(u32::MAX.into_arg(), u32::MAX as usize);
+    assert_eq!(true.into_arg(), 1);
+    assert_eq!(None::<&i32>.into_arg(), 0);
+
+    let x = 0i32;
+    assert_eq!(Some(&x).into_arg(), &x as *const i32 as usize);
+
+    let buf = [1u8, 2];
+    assert_eq!((&buf[..]).into_arg(), buf.as_ptr() as usize);
+
+    unsafe {
+        let mut fds = [0i32; 2];
+        assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+
+        assert_eq!(syscall_into!(WRITE, fds[1], b"abc", 3), Ok(3i32));
+
+        let mut buf = [0u8; 3];
+        assert_eq!(syscall!(READ, fds[0], &mut buf, buf.len()), Ok(3));
+        assert_eq!(&buf, b"abc");
+
+        let () = syscall_into!(CLOSE, fds[0]).unwrap();
+        let () = syscall_into!(CLOSE, fds[1]).unwrap();
+        assert_eq!(syscall_into!(CLOSE, fds[0]), Err::<(), _>(eno::EBADF));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_syscall_arg_fd() {
+    use std::os::unix::io::{AsFd, AsRawFd};
+
+    let file = std::fs::File::open("/").unwrap();
+    assert_eq!(file.as_fd().into_arg(), file.as_raw_fd() as usize);
+
     unsafe {
         assert_eq!(
-            syscall!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0),
-            Ok(0)
+            syscall!(FCNTL, file.as_fd(), libc::F_GETFD, 0),
+            Ok(libc::FD_CLOEXEC as usize)
         );
+    }
+}
 
+#[test]
+fn test_consts() {
+    assert_eq!(consts::O_DIRECTORY, libc::O_DIRECTORY);
+    assert_eq!(consts::O_NOFOLLOW, libc::O_NOFOLLOW);
+    assert_eq!(consts::O_CLOEXEC, libc::O_CLOEXEC);
+    assert_eq!(consts::O_NONBLOCK, libc::O_NONBLOCK);
+    assert_eq!(consts::MAP_ANONYMOUS, libc::MAP_ANONYMOUS);
+    assert_eq!(consts::MAP_PRIVATE, libc::MAP_PRIVATE);
+    assert_eq!(consts::PROT_WRITE, libc::PROT_WRITE);
+    assert_eq!(consts::AT_FDCWD, libc::AT_FDCWD);
+    assert_eq!(consts::AT_SYMLINK_NOFOLLOW, libc::AT_SYMLINK_NOFOLLOW);
+    assert_eq!(consts::F_GETFD, libc::F_GETFD);
+    assert_eq!(consts::FD_CLOEXEC, libc::FD_CLOEXEC);
+    assert_eq!(consts::SEEK_END, libc::SEEK_END);
+    assert_eq!(consts::WNOHANG, libc::WNOHANG);
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        assert_eq!(consts::CLONE_VM, libc::CLONE_VM as u64);
+        assert_eq!(consts::CLONE_NEWTIME, libc::CLONE_NEWTIME as u64);
+        assert_eq!(consts::CLOCK_BOOTTIME, libc::CLOCK_BOOTTIME);
+    }
+
+    unsafe {
+        let fd = syscall!(
+            OPENAT,
+            consts::AT_FDCWD,
+            b"/\0".as_ptr(),
+            consts::O_RDONLY | consts::O_DIRECTORY | consts::O_CLOEXEC,
+            0
+        )
+        .unwrap();
         assert_eq!(
-            syscall_nofail!(FACCESSAT, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0),
+            syscall!(FCNTL, fd, consts::F_GETFD, 0),
+            Ok(consts::FD_CLOEXEC as usize)
+        );
+        syscall_nofail!(CLOSE, fd);
+
+        // These 32-bit Linux platforms only have `mmap2()` (or an `mmap()` that takes a struct)
+        #[cfg(all(
+            any(target_os = "linux", target_os = "android"),
+            any(
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "powerpc",
+                target_arch = "x86",
+            ),
+        ))]
+        let addr = syscall!(
+            MMAP2,
+            0,
+            4096,
+            consts::PROT_READ | consts::PROT_WRITE,
+            consts::MAP_PRIVATE | consts::MAP_ANONYMOUS,
+            -1,
+            0
+        )
+        .unwrap();
+        #[cfg(not(all(
+            any(target_os = "linux", target_os = "android"),
+            any(
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "powerpc",
+                target_arch = "x86",
+            ),
+        )))]
+        let addr = syscall!(
+            MMAP,
+            0,
+            4096,
+            consts::PROT_READ | consts::PROT_WRITE,
+            consts::MAP_PRIVATE | consts::MAP_ANONYMOUS,
+            -1,
             0
+        )
+        .unwrap();
+        *(addr as *mut u8) = 1;
+        syscall!(MUNMAP, addr, 4096).unwrap();
+    }
+}
+
+macro_rules! offset_of {
+    ($ty:ty, $field:ident) => {{
+        let val = std::mem::MaybeUninit::<$ty>::uninit();
+        let base = val.as_ptr();
+        unsafe { std::ptr::addr_of!((*base).$field) as usize - base as usize }
+    }};
+}
+
+macro_rules! assert_same_layout {
+    ($ours:ty, $theirs:ty, [$($field:ident),* $(,)?] $(,)?) => {{
+        assert_eq!(std::mem::size_of::<$ours>(), std::mem::size_of::<$theirs>());
+        assert_eq!(std::mem::align_of::<$ours>(), std::mem::align_of::<$theirs>());
+        $(
+            assert_eq!(
+                offset_of!($ours, $field),
+                offset_of!($theirs, $field),
+                stringify!($field)
+            );
+        )*
+    }};
+}
+
+#[test]
+fn test_types_layout() {
+    use std::mem::size_of;
+
+    // The C library's versions of these structures match the kernel's
+    assert_same_layout!(types::timespec, libc::timespec, [tv_sec, tv_nsec]);
+    assert_same_layout!(types::timeval, libc::timeval, [tv_sec, tv_usec]);
+    assert_same_layout!(types::iovec, libc::iovec, [iov_base, iov_len]);
+    assert_same_layout!(types::pollfd, libc::pollfd, [fd, events, revents]);
+    assert_same_layout!(
+        types::cmsghdr,
+        libc::cmsghdr,
+        [cmsg_len, cmsg_level, cmsg_type]
+    );
+    assert_same_layout!(
+        types::msghdr,
+        libc::msghdr,
+        [
+            msg_name,
+            msg_namelen,
+            msg_iov,
+            msg_iovlen,
+            msg_control,
+            msg_controllen,
+            msg_flags
+        ],
+    );
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        assert_same_layout!(types::epoll_event, libc::epoll_event, [events]);
+
+        // The kernel's sigset_t is much smaller than the C library's
+        #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+        assert_eq!(size_of::<types::sigset_t>(), 8);
+        #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+        assert_eq!(size_of::<types::sigset_t>(), 16);
+    }
+
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(target_arch = "x86_64", target_arch = "aarch64"),
+    ))]
+    assert_same_layout!(
+        types::stat,
+        libc::stat,
+        [
+            st_dev, st_ino, st_nlink, st_mode, st_uid, st_gid, st_rdev, st_size, st_blksize,
+            st_blocks, st_atime, st_mtime, st_ctime
+        ],
+    );
+
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "x86_64"
+    ))]
+    {
+        assert_eq!(size_of::<types::stat>(), 144);
+        assert_eq!(size_of::<types::epoll_event>(), 12);
+        assert_eq!(offset_of!(types::epoll_event, data), 4);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(size_of::<types::sigaction>(), 32);
+        // x32 uses the 32-bit compat layout
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(size_of::<types::sigaction>(), 20);
+    }
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(target_arch = "aarch64", target_arch = "riscv64"),
+    ))]
+    assert_eq!(size_of::<types::stat>(), 128);
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(target_arch = "x86", target_arch = "arm"),
+    ))]
+    {
+        assert_eq!(size_of::<types::stat>(), 64);
+        assert_eq!(
+            offset_of!(types::stat64, st_size),
+            48 - 4 * cfg!(target_arch = "x86") as usize
+        );
+        assert_eq!(
+            size_of::<types::stat64>(),
+            104 - 8 * cfg!(target_arch = "x86") as usize
+        );
+    }
+    #[cfg(all(any(target_os = "linux", target_os = "android"), target_arch = "mips"))]
+    {
+        assert_eq!(size_of::<types::stat>(), 144);
+        assert_eq!(size_of::<types::stat64>(), 104);
+        assert_eq!(size_of::<types::sigaction>(), 24);
+    }
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "mips64"
+    ))]
+    {
+        assert_eq!(size_of::<types::stat>(), 104);
+        assert_eq!(size_of::<types::sigaction>(), 32);
+    }
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "sparc64"
+    ))]
+    {
+        assert_eq!(size_of::<types::stat>(), 104);
+        assert_eq!(size_of::<types::stat64>(), 144);
+    }
+
+    #[cfg(target_os = "freebsd")]
+    {
+        assert_same_layout!(
+            types::stat,
+            libc::stat,
+            [st_mode, st_size, st_blocks, st_flags]
+        );
+        assert_same_layout!(
+            types::kevent,
+            libc::kevent,
+            [ident, filter, flags, fflags, data, udata]
+        );
+        assert_eq!(size_of::<types::sigaction>(), 32);
+    }
+    #[cfg(target_os = "macos")]
+    {
+        assert_same_layout!(
+            types::stat64,
+            libc::stat,
+            [st_mode, st_ino, st_size, st_blocks, st_flags]
+        );
+        assert_same_layout!(
+            types::kevent,
+            libc::kevent,
+            [ident, filter, flags, fflags, data, udata]
+        );
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_auxv() {
+    use scall::auxv;
+
+    unsafe {
+        for &key in &[
+            auxv::AT_PAGESZ,
+            auxv::AT_HWCAP2,
+            auxv::AT_RANDOM,
+            auxv::AT_SECURE,
+            auxv::AT_SYSINFO_EHDR,
+            auxv::AT_MINSIGSTKSZ,
+        ] {
+            assert_eq!(
+                auxv::getauxval(key).unwrap_or(0),
+                libc::getauxval(key as _) as usize,
+                "{}",
+                key
+            );
+        }
+        assert_eq!(
+            auxv::page_size(),
+            libc::sysconf(libc::_SC_PAGESIZE) as usize
+        );
+        assert!(!auxv::secure());
+        assert_eq!(
+            auxv::random_bytes().unwrap().as_ptr() as usize,
+            libc::getauxval(libc::AT_RANDOM) as usize
+        );
+    }
+    assert_eq!(auxv::getauxval(12345), None);
+
+    let entries: Vec<_> = auxv::ProcAuxvIter::open().unwrap().collect();
+    assert!(entries.contains(&(auxv::AT_PAGESZ, auxv::page_size())));
+    // glibc replaces AT_HWCAP with its own value on some architectures
+    assert!(entries.contains(&(auxv::AT_HWCAP, auxv::getauxval(auxv::AT_HWCAP).unwrap())));
+    assert!(!entries.iter().any(|&(key, _)| key == auxv::AT_NULL));
+
+    // A fake initial stack: envp, then the auxiliary vector
+    let var = b"A=B\0";
+    let stack: [usize; 8] = [
+        var.as_ptr() as usize,
+        0,
+        auxv::AT_PAGESZ,
+        4096,
+        auxv::AT_SECURE,
+        0,
+        auxv::AT_NULL,
+        0,
+    ];
+    let fake: Vec<_> =
+        unsafe { auxv::AuxvIter::from_envp(stack.as_ptr() as *const *const u8) }.collect();
+    assert_eq!(fake, [(auxv::AT_PAGESZ, 4096), (auxv::AT_SECURE, 0)]);
+    let fake2: Vec<_> = unsafe { auxv::AuxvIter::from_ptr(stack[2..].as_ptr()) }.collect();
+    assert_eq!(fake, fake2);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_vdso() {
+    use scall::vdso;
+
+    let ehdr = unsafe { libc::getauxval(libc::AT_SYSINFO_EHDR) };
+    if ehdr != 0 {
+        let vd = vdso::Vdso::get().unwrap();
+        assert_eq!(vd.lookup("no_such_symbol", "LINUX_2.6"), None);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            let vd2 = unsafe { vdso::Vdso::from_ehdr(ehdr as *const u8) }.unwrap();
+            let addr = vd.lookup("__vdso_clock_gettime", "LINUX_2.6").unwrap();
+            assert_eq!(vd2.lookup("__vdso_clock_gettime", "LINUX_2.6"), Some(addr));
+            assert!(addr > ehdr as usize);
+            assert_eq!(vd.lookup("__vdso_clock_gettime", "LINUX_1.0"), None);
+        }
+        #[cfg(target_arch = "aarch64")]
+        assert!(vd
+            .lookup("__kernel_clock_gettime", "LINUX_2.6.39")
+            .is_some());
+    }
+
+    unsafe {
+        let mut ts = std::mem::zeroed::<libc::timespec>();
+        assert_eq!(libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts), 0);
+        let ts2 = vdso::clock_gettime(consts::CLOCK_MONOTONIC).unwrap();
+        assert!((ts2.tv_sec as i64, ts2.tv_nsec as i64) >= (ts.tv_sec as i64, ts.tv_nsec as i64));
+    }
+    assert_eq!(vdso::clock_gettime(-1), Err(eno::EINVAL));
+
+    let tv = vdso::gettimeofday().unwrap();
+    let now = vdso::time();
+    assert!(tv.tv_usec < 1_000_000);
+    assert!(now >= tv.tv_sec as i64 && now - tv.tv_sec as i64 <= 1);
+    assert!((now - unsafe { libc::time(std::ptr::null_mut()) } as i64).abs() <= 1);
+
+    let (cpu, _node) = vdso::getcpu().unwrap();
+    assert!((cpu as i64) < unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) } as i64);
+}
+
+#[test]
+fn test_io() {
+    use core::fmt::Write;
+    use scall::io::{self, FdWriter};
+    use scall::{fd_eprintln, fd_print, fd_println};
+
+    fn read_all(fd: i32, buf: &mut [u8]) -> usize {
+        let mut n = 0;
+        loop {
+            match unsafe { syscall!(READ, fd, buf[n..].as_mut_ptr(), buf.len() - n) } {
+                Ok(0) | Err(eno::EAGAIN) => return n,
+                Ok(i) => n += i,
+                Err(eno) => panic!("{}", eno),
+            }
+        }
+    }
+
+    let mut fds = [0i32; 2];
+    unsafe {
+        assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+        assert_eq!(libc::fcntl(fds[0], libc::F_SETFL, libc::O_NONBLOCK), 0);
+    }
+    let [r, w] = fds;
+    let mut buf = [0u8; 4096];
+
+    fd_print!(w, "{}-{:x}", 1, 255).unwrap();
+    fd_println!(w).unwrap();
+    fd_println!(w, "abc").unwrap();
+    fd_println!(w, "{:?}", Some(1)).unwrap();
+    let n = read_all(r, &mut buf);
+    assert_eq!(&buf[..n], b"1-ff\nabc\nSome(1)\n");
+
+    fd_eprintln!();
+    fd_eprintln!("{}", "test_io");
+
+    // Buffering
+    let mut writer = FdWriter::new(w);
+    assert_eq!(writer.fd(), w);
+    write!(writer, "{}", 123).unwrap();
+    assert_eq!(writer.buffer(), b"123");
+    assert_eq!(read_all(r, &mut buf), 0);
+    writer.flush().unwrap();
+    assert_eq!(writer.buffer(), b"");
+    let n = read_all(r, &mut buf);
+    assert_eq!(&buf[..n], b"123");
+
+    // Data that doesn't fit in the buffer
+    let long = [b'x'; FdWriter::CAPACITY * 2 + 1];
+    writer.write_bytes(b"a").unwrap();
+    writer.write_bytes(&long).unwrap();
+    writer.write_bytes(b"b").unwrap();
+    assert_eq!(writer.buffer(), b"b");
+    drop(writer);
+    let n = read_all(r, &mut buf);
+    assert_eq!(n, long.len() + 2);
+    assert_eq!(buf[0], b'a');
+    assert!(buf[1..n - 1].iter().all(|&c| c == b'x'));
+    assert_eq!(buf[n - 1], b'b');
+
+    io::write_all(w, b"abc").unwrap();
+    let n = read_all(r, &mut buf);
+    assert_eq!(&buf[..n], b"abc");
+
+    // Nothing is written if formatting fails partway through
+    struct Fail;
+    impl core::fmt::Display for Fail {
+        fn fmt(&self, _f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+    assert_eq!(fd_println!(w, "abc{}", Fail), Err(eno::EINVAL));
+    assert_eq!(read_all(r, &mut buf), 0);
+
+    unsafe {
+        syscall_nofail!(CLOSE, r);
+        syscall_nofail!(CLOSE, w);
+    }
+
+    // Errors
+    assert_eq!(fd_println!(w, "abc"), Err(eno::EBADF));
+    let mut writer = FdWriter::new(w);
+    writer.write_bytes(b"abc").unwrap();
+    assert_eq!(writer.flush(), Err(eno::EBADF));
+    assert_eq!(writer.buffer(), b"");
+    assert_eq!(writer.write_args(format_args!("{}", long.len())), Ok(()));
+    assert_eq!(writer.write_bytes(&long), Err(eno::EBADF));
+    assert_eq!(io::write_all(w, b"abc"), Err(eno::EBADF));
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_futex() {
+    use scall::futex::{self, FutexWaitv, FUTEX2_PRIVATE, FUTEX_PRIVATE_FLAG};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    let word = AtomicU32::new(0);
+    assert_eq!(
+        futex::futex_wait(&word, 1, None, FUTEX_PRIVATE_FLAG),
+        Err(eno::EAGAIN)
+    );
+    let start = Instant::now();
+    assert_eq!(
+        futex::futex_wait(
+            &word,
+            0,
+            Some(Duration::from_millis(10)),
+            FUTEX_PRIVATE_FLAG
+        ),
+        Err(eno::ETIMEDOUT)
+    );
+    assert!(start.elapsed() >= Duration::from_millis(10));
+    assert_eq!(
+        futex::futex_wake(&word, u32::MAX, FUTEX_PRIVATE_FLAG),
+        Ok(0)
+    );
+
+    // Wake up waiting threads
+    let words = Arc::new([AtomicU32::new(0), AtomicU32::new(0)]);
+    let waiter = |i: usize| {
+        let words = words.clone();
+        std::thread::spawn(move || {
+            while words[i].load(Ordering::Acquire) == 0 {
+                match futex::futex_wait(&words[i], 0, None, FUTEX_PRIVATE_FLAG) {
+                    Ok(()) | Err(eno::EAGAIN) | Err(eno::EINTR) => (),
+                    Err(eno) => panic!("{}", eno),
+                }
+            }
+        })
+    };
+
+    let threads: Vec<_> = (0..3).map(|_| waiter(0)).collect();
+    std::thread::sleep(Duration::from_millis(50));
+
+    // Wake one and requeue the rest to the second futex
+    assert_eq!(
+        futex::futex_requeue(&words[0], 1, 1, &words[1], u32::MAX, FUTEX_PRIVATE_FLAG),
+        Err(eno::EAGAIN)
+    );
+    words[0].store(1, Ordering::Release);
+    assert_eq!(
+        futex::futex_requeue(&words[0], 1, 1, &words[1], u32::MAX, FUTEX_PRIVATE_FLAG),
+        Ok(3)
+    );
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(
+        futex::futex_wake(&words[1], u32::MAX, FUTEX_PRIVATE_FLAG),
+        Ok(2)
+    );
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    // futex_waitv() (Linux 5.16+)
+    let a = AtomicU32::new(0);
+    let b = AtomicU32::new(0);
+    let waiters = [
+        FutexWaitv::new(&a, 0, FUTEX2_PRIVATE),
+        FutexWaitv::new(&b, 0, FUTEX2_PRIVATE),
+    ];
+    match futex::futex_waitv(
+        &waiters,
+        Some(Duration::from_secs(0)),
+        consts::CLOCK_MONOTONIC,
+    ) {
+        Err(eno::ENOSYS) => (),
+        res => {
+            assert_eq!(res, Err(eno::ETIMEDOUT));
+            assert_eq!(
+                futex::futex_waitv(&[], None, consts::CLOCK_MONOTONIC),
+                Err(eno::EINVAL)
+            );
+
+            b.store(1, Ordering::Relaxed);
+            assert_eq!(
+                futex::futex_waitv(&waiters, None, consts::CLOCK_MONOTONIC),
+                Err(eno::EAGAIN)
+            );
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_sync() {
+    use scall::sync::{Condvar, Mutex, Once, Parker};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    // Mutex
+    let mutex = Arc::new(Mutex::new(0u64));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let mutex = mutex.clone();
+            std::thread::spawn(move || {
+                for _ in 0..10000 {
+                    *mutex.lock() += 1;
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(*mutex.lock(), 40000);
+
+    let guard = mutex.lock();
+    assert!(mutex.try_lock().is_none());
+    assert_eq!(format!("{:?}", mutex), "Mutex { data: <locked> }");
+    drop(guard);
+    assert_eq!(format!("{:?}", mutex), "Mutex { data: 40000 }");
+    assert_eq!(*mutex.try_lock().unwrap(), 40000);
+
+    let mut mutex = Mutex::new(vec![1]);
+    mutex.get_mut().push(2);
+    assert_eq!(mutex.into_inner(), [1, 2]);
+
+    // Condvar
+    let pair = Arc::new((Mutex::new(0), Condvar::new()));
+    let (lock, cvar) = &*pair;
+
+    let (guard, timed_out) = cvar.wait_timeout(lock.lock(), Duration::from_millis(10));
+    assert!(timed_out);
+    assert_eq!(*guard, 0);
+    drop(guard);
+
+    let threads: Vec<_> = (0..3)
+        .map(|_| {
+            let pair = pair.clone();
+            std::thread::spawn(move || {
+                let (lock, cvar) = &*pair;
+                let mut guard = lock.lock();
+                while *guard == 0 {
+                    guard = cvar.wait(guard);
+                }
+                *guard += 1;
+            })
+        })
+        .collect();
+    std::thread::sleep(Duration::from_millis(50));
+    *lock.lock() = 1;
+    cvar.notify_all();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(*lock.lock(), 4);
+
+    // Once
+    let once = Arc::new((Once::new(), Mutex::new(0)));
+    assert!(!once.0.is_completed());
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let once = once.clone();
+            std::thread::spawn(move || {
+                once.0.call_once(|| {
+                    std::thread::sleep(Duration::from_millis(20));
+                    *once.1.lock() += 1;
+                });
+                assert_eq!(*once.1.lock(), 1);
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert!(once.0.is_completed());
+
+    // A panicking closure resets the Once
+    let once = Once::new();
+    assert!(std::panic::catch_unwind(|| once.call_once(|| panic!())).is_err());
+    assert!(!once.is_completed());
+    let mut called = false;
+    once.call_once(|| called = true);
+    assert!(called && once.is_completed());
+
+    // Parker
+    let parker = Arc::new(Parker::new());
+    assert!(!parker.park_timeout(Duration::from_millis(10)));
+    parker.unpark();
+    parker.unpark();
+    assert!(parker.park_timeout(Duration::from_secs(10)));
+    assert!(!parker.park_timeout(Duration::from_millis(10)));
+
+    let thread = {
+        let parker = parker.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            parker.unpark();
+        })
+    };
+    parker.park();
+    thread.join().unwrap();
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_clone_with_stack() {
+    use scall::{consts, sig};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    unsafe fn wait(pid: usize) -> i32 {
+        let mut status = 0;
+        assert_eq!(scall::syscall!(WAIT4, pid, &mut status, 0, 0), Ok(pid));
+        status
+    }
+
+    let value = AtomicU32::new(0);
+    let mut stack = vec![0u8; 65536];
+    let stack_top = unsafe { stack.as_mut_ptr().add(stack.len()) };
+
+    unsafe {
+        // Shared memory; the parent is suspended until the child exits
+        let mut ptid = 0;
+        let pid = scall::clone_with_stack(
+            consts::CLONE_VM
+                | consts::CLONE_VFORK
+                | consts::CLONE_PARENT_SETTID
+                | sig::SIGCHLD as u64,
+            stack_top,
+            0,
+            &mut ptid,
+            std::ptr::null_mut(),
+            || {
+                value.store(scall::syscall_nofail!(GETTID) as u32, Ordering::SeqCst);
+                42
+            },
+        )
+        .unwrap();
+        assert_eq!(ptid as usize, pid);
+        assert_eq!(value.load(Ordering::SeqCst) as usize, pid);
+        assert_eq!(wait(pid), 42 << 8);
+
+        // Separate memory; the child's changes are not visible to the parent
+        let data = Box::new(7);
+        let pid = scall::clone_with_stack(
+            sig::SIGCHLD as u64,
+            stack_top,
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            || {
+                value.store(1, Ordering::SeqCst);
+                *data
+            },
+        )
+        .unwrap();
+        assert_eq!(wait(pid), 7 << 8);
+        assert_ne!(value.load(Ordering::SeqCst), 1);
+
+        // CLONE_SIGHAND requires CLONE_VM
+        assert_eq!(
+            scall::clone_with_stack(
+                consts::CLONE_SIGHAND | sig::SIGCHLD as u64,
+                stack_top,
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                || 0,
+            ),
+            Err(scall::eno::EINVAL)
         );
+    }
+
+    #[cfg(not(target_arch = "sparc64"))]
+    unsafe {
+        let args = scall::types::clone_args {
+            flags: consts::CLONE_VM | consts::CLONE_VFORK,
+            exit_signal: sig::SIGCHLD as u64,
+            stack: stack.as_mut_ptr() as u64,
+            stack_size: stack.len() as u64,
+            ..Default::default()
+        };
+
+        match scall::clone3_with_stack(&args, || {
+            value.store(2, Ordering::SeqCst);
+            3
+        }) {
+            Ok(pid) => {
+                assert_eq!(value.load(Ordering::SeqCst), 2);
+                assert_eq!(wait(pid), 3 << 8);
+            }
+            Err(eno) => assert_eq!(eno, scall::eno::ENOSYS),
+        }
+
+        let args = scall::types::clone_args {
+            stack_size: 0,
+            ..args
+        };
+        assert_eq!(
+            scall::clone3_with_stack(&args, || 0),
+            Err(scall::eno::EINVAL)
+        );
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_thread() {
+    use scall::sync::Mutex;
+    use scall::thread::{self, Builder};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    unsafe {
+        // The threads must not allocate, since the C library's thread-local storage isn't set up
+        // for them
+        static MUTEX: Mutex<u32> = Mutex::new(0);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        *MUTEX.lock() += 1;
+                    }
+                    i
+                })
+                .unwrap()
+            })
+            .collect();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join(), Some(i));
+        }
+        assert_eq!(*MUTEX.lock(), 4000);
+
+        // The thread runs on its own stack, and shares the process ID
+        static GO: AtomicBool = AtomicBool::new(false);
+        let handle = Builder::new()
+            .stack_size(10000)
+            .spawn(|| {
+                while !GO.load(Ordering::SeqCst) {
+                    scall::syscall_nofail!(SCHED_YIELD);
+                }
+                let local = 0u8;
+                (
+                    &local as *const u8 as usize,
+                    scall::syscall_nofail!(GETPID),
+                    scall::syscall_nofail!(GETTID),
+                )
+            })
+            .unwrap();
+        let tid = handle.tid() as usize;
+        assert_ne!(tid, 0);
+        assert_ne!(tid, scall::syscall_nofail!(GETTID));
+        assert!(!handle.is_finished());
+        GO.store(true, Ordering::SeqCst);
+        while !handle.is_finished() {
+            scall::syscall_nofail!(SCHED_YIELD);
+        }
+        assert_eq!(handle.tid(), 0);
+        let (addr, pid, thread_tid) = handle.join().unwrap();
+        let local = 0u8;
+        assert!((addr as isize - &local as *const u8 as isize).abs() > 10000);
+        assert_eq!(pid, scall::syscall_nofail!(GETPID));
+        assert_eq!(thread_tid, tid);
+
+        // Exiting without returning
+        let handle = thread::spawn(|| -> u32 {
+            loop {
+                scall::syscall_nofail!(EXIT, 0);
+            }
+        })
+        .unwrap();
+        assert_eq!(handle.join(), None);
+
+        // Each thread gets its own copy of the executable's thread-local variables
+        // (Clippy wrongly asks for a `const` initializer with `-Zbuild-std`)
+        std::thread_local! {
+            #[allow(clippy::missing_const_for_thread_local)]
+            static LOCAL: std::cell::Cell<usize> = const { std::cell::Cell::new(1) };
+        }
+        LOCAL.with(|local| local.set(2));
+        let handle = thread::spawn(|| LOCAL.with(|local| local.replace(3))).unwrap();
+        assert_eq!(handle.join(), Some(1));
+        assert_eq!(LOCAL.with(|local| local.get()), 2);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            fn get_fs() -> usize {
+                let mut fs = 0u64;
+                unsafe { scall::syscall!(ARCH_PRCTL, 0x1003, &mut fs).unwrap_or(0) };
+                fs as usize
+            }
+
+            // The TCB starts with a pointer to itself
+            let handle = thread::spawn(|| {
+                let tp = get_fs();
+                (tp, *(tp as *const usize))
+            })
+            .unwrap();
+            let (tp, tcb_self) = handle.join().unwrap();
+            assert_ne!(tp, get_fs());
+            assert_eq!(tcb_self, tp);
+
+            // A TCB that is large enough to hold the stack protector canary at %fs:0x28
+            static mut TCB: [usize; 8] = [0; 8];
+            let tp = core::ptr::addr_of_mut!(TCB) as usize;
+            *(tp as *mut usize) = tp;
+            let handle = Builder::new().tls(tp).spawn(get_fs).unwrap();
+            assert_eq!(handle.join(), Some(tp));
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_signal() {
+    use scall::signal::{self, SigAction, SigHandler, SigInfo};
+    use scall::{sig, SigSet};
+    use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+    static CAUGHT: AtomicI32 = AtomicI32::new(0);
+    static CODE: AtomicI32 = AtomicI32::new(0);
+    static VALUE: AtomicUsize = AtomicUsize::new(0);
+    static STACK_ADDR: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn handler(sig: i32) {
+        let local = 0u8;
+        STACK_ADDR.store(&local as *const u8 as usize, Ordering::SeqCst);
+        CAUGHT.store(sig, Ordering::SeqCst);
+    }
+
+    extern "C" fn info_handler(sig: i32, info: *mut SigInfo, _ctx: *mut std::ffi::c_void) {
+        let info = unsafe { &*info };
+        assert_eq!(info.signo(), sig);
+        CODE.store(info.code(), Ordering::SeqCst);
+        VALUE.store(info.value(), Ordering::SeqCst);
+        CAUGHT.store(sig, Ordering::SeqCst);
+    }
+
+    let (pid, tid) = unsafe {
+        (
+            scall::syscall_nofail!(GETPID) as i32,
+            scall::syscall_nofail!(GETTID) as i32,
+        )
+    };
+
+    unsafe {
+        // A plain handler, with the signal blocked for a while
+        let mut set = SigSet::empty();
+        set.insert(sig::SIGUSR1);
+        let action = SigAction::new(SigHandler::Handler(handler), sig::SA_RESTART, set);
+        assert!(action.flags() & sig::SA_SIGINFO == 0);
+        let old_usr1 = signal::sigaction(sig::SIGUSR1, Some(&action)).unwrap();
+
+        let cur = signal::sigaction(sig::SIGUSR1, None).unwrap();
+        assert!(
+            matches!(cur.handler(), SigHandler::Handler(f) if f as usize == handler as extern "C" fn(i32) as usize)
+        );
+        assert_eq!(cur.flags(), sig::SA_RESTART);
+        assert!(cur.mask().contains(sig::SIGUSR1));
+
+        let old_mask = signal::sigprocmask(sig::SIG_BLOCK, Some(&set)).unwrap();
+        signal::tgkill(pid, tid, sig::SIGUSR1).unwrap();
+        assert_eq!(CAUGHT.load(Ordering::SeqCst), 0);
+        let mask = signal::sigprocmask(sig::SIG_SETMASK, Some(&old_mask)).unwrap();
+        assert!(mask.contains(sig::SIGUSR1));
+        assert_eq!(CAUGHT.load(Ordering::SeqCst), sig::SIGUSR1);
+        assert_eq!(signal::sigprocmask(sig::SIG_BLOCK, None).unwrap(), old_mask);
+
+        // On an alternate signal stack
+        let mut stack = vec![0u8; 65536];
+        let ss = scall::types::stack_t {
+            ss_sp: stack.as_mut_ptr() as *mut _,
+            ss_flags: 0,
+            ss_size: stack.len(),
+        };
+        let old_ss = signal::sigaltstack(Some(&ss)).unwrap();
+        let action = SigAction::new(
+            SigHandler::Handler(handler),
+            sig::SA_ONSTACK,
+            SigSet::empty(),
+        );
+        signal::sigaction(sig::SIGUSR1, Some(&action)).unwrap();
+        CAUGHT.store(0, Ordering::SeqCst);
+        signal::tgkill(pid, tid, sig::SIGUSR1).unwrap();
+        assert_eq!(CAUGHT.load(Ordering::SeqCst), sig::SIGUSR1);
+        let addr = STACK_ADDR.load(Ordering::SeqCst);
+        assert!(addr >= stack.as_ptr() as usize && addr < stack.as_ptr() as usize + stack.len());
+
+        let cur_ss = signal::sigaltstack(Some(&scall::types::stack_t {
+            ss_sp: std::ptr::null_mut(),
+            ss_flags: sig::SS_DISABLE as i32,
+            ss_size: 0,
+        }))
+        .unwrap();
+        assert_eq!(cur_ss.ss_sp, ss.ss_sp);
+        assert_eq!(cur_ss.ss_size, ss.ss_size);
+        if old_ss.ss_flags & sig::SS_DISABLE as i32 == 0 {
+            signal::sigaltstack(Some(&old_ss)).unwrap();
+        }
+
+        // SA_SIGINFO, with a value sent by rt_sigqueueinfo()
+        let action = SigAction::new(SigHandler::SigAction(info_handler), 0, SigSet::empty());
+        assert!(action.flags() & sig::SA_SIGINFO != 0);
+        signal::sigaction(sig::SIGUSR1, Some(&action)).unwrap();
+        let info = SigInfo::queue(sig::SIGUSR1, 0x1234);
+        assert_eq!(std::mem::size_of::<SigInfo>(), 128);
+        assert_eq!(info.pid(), pid);
+        assert_eq!(info.value(), 0x1234);
+
+        CAUGHT.store(0, Ordering::SeqCst);
+        signal::rt_sigqueueinfo(pid, sig::SIGUSR1, &info).unwrap();
+        // The signal may be delivered to any thread that doesn't have it blocked
+        while CAUGHT.load(Ordering::SeqCst) == 0 {
+            scall::syscall_nofail!(SCHED_YIELD);
+        }
+        assert_eq!(CODE.load(Ordering::SeqCst), signal::SI_QUEUE);
+        assert_eq!(VALUE.load(Ordering::SeqCst), 0x1234);
+
+        CAUGHT.store(0, Ordering::SeqCst);
+        signal::tgkill(pid, tid, sig::SIGUSR1).unwrap();
+        assert_eq!(CAUGHT.load(Ordering::SeqCst), sig::SIGUSR1);
+        assert_eq!(CODE.load(Ordering::SeqCst), signal::SI_TKILL);
+
+        // Ignored
+        let action = SigAction::new(SigHandler::Ignore, 0, SigSet::empty());
+        signal::sigaction(sig::SIGUSR1, Some(&action)).unwrap();
+        signal::tgkill(pid, tid, sig::SIGUSR1).unwrap();
+        assert!(matches!(
+            signal::sigaction(sig::SIGUSR1, Some(&old_usr1))
+                .unwrap()
+                .handler(),
+            SigHandler::Ignore
+        ));
+
+        assert_eq!(
+            signal::sigaction(sig::SIGKILL, Some(&action)).unwrap_err(),
+            scall::eno::EINVAL
+        );
+        assert_eq!(signal::tgkill(pid, -1, 0), Err(scall::eno::EINVAL));
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_io_uring() {
+    use scall::io_uring::*;
+    use std::time::Duration;
+
+    let mut ring = match IoUring::new(4) {
+        Ok(ring) => ring,
+        // io_uring is disabled or unavailable
+        Err(eno::ENOSYS) | Err(eno::EPERM) => return,
+        Err(e) => panic!("io_uring_setup() failed: {}", e),
+    };
+    assert_eq!(ring.sq_entries(), 4);
+    assert_eq!(ring.cq_entries(), 8);
+
+    assert_eq!(
+        IoUring::with_params(
+            4,
+            Params {
+                flags: IORING_SETUP_SQE128,
+                ..Params::default()
+            }
+        )
+        .unwrap_err(),
+        eno::EINVAL
+    );
+
+    unsafe {
+        // Fill the submission queue with NOPs
+        for i in 0..4 {
+            ring.push(&Sqe::nop().user_data(i)).unwrap();
+        }
+        assert!(ring.is_sq_full());
+        assert_eq!(ring.push(&Sqe::nop()), Err(eno::EBUSY));
+        assert_eq!(ring.submit_and_wait(4), Ok(4));
+        assert_eq!(ring.sq_len(), 0);
+        assert_eq!(ring.cq_len(), 4);
+
+        for i in 0..4 {
+            let cqe = ring.pop().unwrap();
+            assert_eq!(cqe.user_data, i);
+            assert_eq!(cqe.result(), Ok(0));
+        }
+        assert_eq!(ring.pop(), None);
+
+        let mut fds = [0i32; 2];
+        assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+
+        // Wait for the pipe to become readable while writing to it
+        ring.push(&Sqe::poll_add(fds[0], libc::POLLIN as u32).user_data(1))
+            .unwrap();
+        ring.submit().unwrap();
+
+        let msg = b"hello";
+        ring.push(&Sqe::write(fds[1], msg.as_ptr(), msg.len() as u32, u64::MAX).user_data(2))
+            .unwrap();
+        ring.submit_and_wait(2).unwrap();
+
+        let mut cqes = [ring.wait().unwrap(), ring.wait().unwrap()];
+        cqes.sort_by_key(|cqe| cqe.user_data);
+        assert_eq!(cqes[0].result(), Ok(libc::POLLIN as usize));
+        assert_eq!(cqes[1].result(), Ok(msg.len()));
+
+        // Read it back with readv()
+        let mut buf = [0u8; 8];
+        let iov = types::iovec {
+            iov_base: buf.as_mut_ptr() as *mut _,
+            iov_len: buf.len(),
+        };
+        ring.push(&Sqe::readv(fds[0], &iov, 1, u64::MAX)).unwrap();
+        let cqe = ring.wait().unwrap();
+        assert_eq!(cqe.result(), Ok(msg.len()));
+        assert_eq!(&buf[..msg.len()], msg);
+
+        // A timeout that expires
+        let ts = Timespec::from(Duration::from_millis(10));
+        ring.push(&Sqe::timeout(&ts, 0, 0)).unwrap();
+        assert_eq!(ring.wait().unwrap().result(), Err(eno::ETIME));
+
+        // Operations on a closed file descriptor fail
+        ring.push(&Sqe::close(fds[0])).unwrap();
+        assert_eq!(ring.wait().unwrap().result(), Ok(0));
+        ring.push(&Sqe::read(fds[0], buf.as_mut_ptr(), 1, u64::MAX))
+            .unwrap();
+        assert_eq!(ring.wait().unwrap().result(), Err(eno::EBADF));
+
+        libc::close(fds[1]);
+    }
+
+    // SQPOLL requires privileges on older kernels
+    let mut ring = match IoUring::with_params(
+        2,
+        Params {
+            flags: IORING_SETUP_SQPOLL,
+            sq_thread_idle: 1,
+            ..Params::default()
+        },
+    ) {
+        Ok(ring) => ring,
+        Err(eno::EPERM) => return,
+        Err(e) => panic!("io_uring_setup() failed: {}", e),
+    };
+
+    for i in 0..3 {
+        // Give the kernel thread time to go to sleep
+        std::thread::sleep(Duration::from_millis(10));
+
+        unsafe {
+            ring.push(&Sqe::nop().user_data(i)).unwrap();
+        }
+        assert_eq!(ring.submit(), Ok(1));
+
+        let cqe = ring.wait().unwrap();
+        assert_eq!(cqe.user_data, i);
+        assert_eq!(cqe.result(), Ok(0));
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_seccomp() {
+    use scall::nr;
+    use scall::seccomp::{self, Action, ArgCmp, CmpOp, Filter, Rule, SockFilter};
+
+    let dup_eq = [ArgCmp::new32(0, CmpOp::Eq, 1000)];
+    let dup_masked = [ArgCmp::new(0, CmpOp::MaskedEq(0xff00), 0x3000)];
+    let close_range = [
+        ArgCmp::new(0, CmpOp::Ge, 2000),
+        ArgCmp::new(0, CmpOp::Lt, 2010),
+    ];
+    let close_wide = [
+        ArgCmp::new(0, CmpOp::Gt, 3000),
+        ArgCmp::new(0, CmpOp::Le, 3002),
+        ArgCmp::new(0, CmpOp::Ne, 3001),
+    ];
+    let rules = [
+        Rule::new(nr::GETPPID, Action::Errno(eno::EPERM)),
+        Rule::with_args(nr::DUP, &dup_eq, Action::Errno(eno::ENOTTY)),
+        Rule::with_args(nr::DUP, &dup_masked, Action::Errno(eno::EXDEV)),
+        Rule::with_args(nr::CLOSE, &close_range, Action::Errno(eno::EROFS)),
+        Rule::with_args(nr::CLOSE, &close_wide, Action::Errno(eno::ESPIPE)),
+    ];
+    let filter = Filter::new(Action::Allow, &rules).mismatch_action(Action::KillThread);
+
+    let mut buf = [SockFilter::default(); 64];
+    assert_eq!(
+        filter.compile(&mut buf[..filter.program_len() - 1]),
+        Err(eno::ERANGE)
+    );
+    let prog = filter.compile(&mut buf).unwrap();
+    assert_eq!(prog.len(), filter.program_len());
+    assert_eq!(
+        prog[prog.len() - 1],
+        SockFilter::stmt(
+            seccomp::BPF_RET | seccomp::BPF_K,
+            seccomp::SECCOMP_RET_ALLOW
+        )
+    );
+
+    match seccomp::get_action_avail(Action::Log) {
+        Ok(()) | Err(eno::EOPNOTSUPP) | Err(eno::ENOSYS) => (),
+        Err(e) => panic!("{}", e),
+    }
+
+    // Filters can't be removed, so install this one in a child process
+    let mut stack = vec![0u8; 65536];
+    let pid = unsafe {
+        scall::clone_with_stack(
+            sig::SIGCHLD as u64,
+            stack.as_mut_ptr().add(stack.len()),
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            || {
+                // Exit with the number of the check that failed
+                let mut n = 0;
+                macro_rules! check {
+                    ($cond:expr) => {
+                        n += 1;
+                        if !$cond {
+                            return n;
+                        }
+                    };
+                }
+
+                check!(seccomp::set_no_new_privs().is_ok());
+                check!(filter.install(&mut [SockFilter::default(); 64], 0) == Ok(0));
+
+                check!(syscall!(GETPPID) == Err(eno::EPERM));
+
+                check!(syscall!(DUP, 1000) == Err(eno::ENOTTY));
+                check!(syscall!(DUP, 1001) == Err(eno::EBADF));
+                check!(syscall!(DUP, 0x3055) == Err(eno::EXDEV));
+                check!(syscall!(DUP, 0x3155) == Err(eno::EBADF));
+
+                check!(syscall!(CLOSE, 1999) == Err(eno::EBADF));
+                check!(syscall!(CLOSE, 2000) == Err(eno::EROFS));
+                check!(syscall!(CLOSE, 2009) == Err(eno::EROFS));
+                check!(syscall!(CLOSE, 2010) == Err(eno::EBADF));
+
+                check!(syscall!(CLOSE, 3000) == Err(eno::EBADF));
+                check!(syscall!(CLOSE, 3001) == Err(eno::EBADF));
+                check!(syscall!(CLOSE, 3002) == Err(eno::ESPIPE));
+                check!(syscall!(CLOSE, 3003) == Err(eno::EBADF));
+
+                // Only the low 32 bits are checked by new32()
+                #[cfg(target_pointer_width = "64")]
+                check!(syscall!(DUP, 0x1_0000_03e8usize) == Err(eno::ENOTTY));
+
+                0
+            },
+        )
+        .unwrap()
+    };
+
+    let mut status = 0;
+    unsafe {
+        syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+    }
+    assert_eq!(status, 0, "check {} failed", status >> 8);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_seccomp_notify() {
+    use scall::nr;
+    use scall::seccomp::{self, Action, Filter, Reply, Rule, SockFilter, Supervisor};
+
+    assert_eq!(Reply::Errno(eno::EPERM).to_raw(7).error, -eno::EPERM);
+    assert_eq!(Reply::Return(-3).to_raw(7).val, -3);
+    assert_eq!(
+        Reply::Continue.to_raw(7).flags,
+        seccomp::SECCOMP_USER_NOTIF_FLAG_CONTINUE
+    );
+
+    let sizes = match seccomp::get_notif_sizes() {
+        Ok(sizes) => sizes,
+        // Not supported (or blocked by a container runtime's own filter)
+        Err(eno::ENOSYS) | Err(eno::EINVAL) | Err(eno::EPERM) => return,
+        Err(e) => panic!("{}", e),
+    };
+    assert_eq!(
+        sizes.seccomp_notif as usize,
+        std::mem::size_of::<seccomp::SeccompNotif>()
+    );
+    assert_eq!(
+        sizes.seccomp_notif_resp as usize,
+        std::mem::size_of::<seccomp::SeccompNotifResp>()
+    );
+    assert_eq!(
+        sizes.seccomp_data as usize,
+        std::mem::size_of::<seccomp::SeccompData>()
+    );
+
+    let rules = [
+        Rule::new(nr::GETPPID, Action::UserNotif),
+        Rule::new(nr::DUP, Action::UserNotif),
+        Rule::new(nr::CHDIR, Action::UserNotif),
+    ];
+    let filter = Filter::new(Action::Allow, &rules);
+
+    let mut fds = [0i32; 2];
+    unsafe {
+        syscall!(PIPE2, fds.as_mut_ptr(), consts::O_CLOEXEC).unwrap();
+    }
+
+    let mut stack = vec![0u8; 65536];
+    let pid = unsafe {
+        scall::clone_with_stack(
+            consts::CLONE_FILES | sig::SIGCHLD as u64,
+            stack.as_mut_ptr().add(stack.len()),
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            || {
+                let mut n = 0;
+                macro_rules! check {
+                    ($cond:expr) => {
+                        n += 1;
+                        if !$cond {
+                            return n;
+                        }
+                    };
+                }
+
+                let mut buf = [SockFilter::default(); 16];
+                check!(seccomp::set_no_new_privs().is_ok());
+                // Send -1 on failure so the parent doesn't block forever
+                let fd = filter
+                    .install(&mut buf, seccomp::SECCOMP_FILTER_FLAG_NEW_LISTENER)
+                    .map_or(-1, |fd| fd as i32);
+                check!(syscall!(WRITE, fds[1], &fd, 4) == Ok(4));
+                check!(fd >= 0);
+
+                check!(syscall!(GETPPID) == Ok(1234));
+                check!(syscall!(DUP, 1000) == Err(eno::ENOTTY));
+                check!(syscall!(CHDIR, b"/nonexistent\0") == Err(eno::EROFS));
+                // Allowed by the supervisor, so the kernel actually runs it
+                check!(syscall!(CHDIR, b"/\0") == Ok(0));
+
+                0
+            },
+        )
+        .unwrap()
+    };
+
+    let mut fd = -1i32;
+    unsafe {
+        assert_eq!(syscall!(READ, fds[0], &mut fd, 4), Ok(4));
+    }
+    assert!(fd >= 0);
 
+    // The filter is in use until the child is reaped, so wait for it in another thread
+    let waiter = std::thread::spawn(move || {
+        let mut status = 0;
+        unsafe {
+            syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+        }
+        status
+    });
+
+    let supervisor = Supervisor::new(fd);
+    let mut seen = Vec::new();
+    supervisor
+        .run(|supervisor, notif| {
+            assert_eq!(notif.pid() as usize, pid);
+            seen.push(notif.sysno().unwrap());
+
+            match notif.sysno().unwrap() {
+                Sysno::GETPPID => Reply::Return(1234),
+                Sysno::DUP => {
+                    assert_eq!(notif.args()[0], 1000);
+                    Reply::Errno(eno::ENOTTY)
+                }
+                Sysno::CHDIR => {
+                    let mut path = [0u8; 16];
+                    let n = supervisor
+                        .read_mem(notif, notif.args()[0], &mut path)
+                        .unwrap();
+                    if path[..n].starts_with(b"/\0") {
+                        Reply::Continue
+                    } else {
+                        assert!(path[..n].starts_with(b"/nonexistent\0"));
+                        Reply::Errno(eno::EROFS)
+                    }
+                }
+                sysno => panic!("unexpected syscall {}", sysno),
+            }
+        })
+        .unwrap();
+
+    let status = waiter.join().unwrap();
+    assert_eq!(
+        seen,
+        [Sysno::GETPPID, Sysno::DUP, Sysno::CHDIR, Sysno::CHDIR]
+    );
+
+    unsafe {
+        syscall_nofail!(CLOSE, fd);
+        syscall_nofail!(CLOSE, fds[0]);
+        syscall_nofail!(CLOSE, fds[1]);
+    }
+    assert_eq!(status, 0, "check {} failed", status >> 8);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn test_ptrace() {
+    use scall::ptrace::{self, SyscallStop};
+
+    static NEG: usize = !0;
+
+    let mut stack = vec![0u8; 65536];
+    let pid = unsafe {
+        scall::clone_with_stack(
+            sig::SIGCHLD as u64,
+            stack.as_mut_ptr().add(stack.len()),
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            || {
+                if ptrace::traceme().is_err() {
+                    return 1;
+                }
+                let pid = syscall_nofail!(GETPID);
+                syscall_nofail!(KILL, pid, sig::SIGSTOP);
+                syscall_nofail!(DUP, 1000);
+                0
+            },
+        )
+        .unwrap() as i32
+    };
+
+    let wait = || {
+        let mut status = 0;
+        unsafe {
+            syscall!(WAIT4, pid, &mut status, 0, 0).unwrap();
+        }
+        status
+    };
+    // WIFSTOPPED() and WSTOPSIG()
+    let stop_sig = |status: i32| {
+        if status & 0xff == 0x7f {
+            Some((status >> 8) & 0xff)
+        } else {
+            None
+        }
+    };
+
+    assert_eq!(stop_sig(wait()), Some(sig::SIGSTOP));
+    ptrace::set_options(
+        pid,
+        ptrace::PTRACE_O_TRACESYSGOOD | ptrace::PTRACE_O_EXITKILL,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ptrace::peek_data(pid, &NEG as *const usize as usize),
+        Ok(!0)
+    );
+    assert_eq!(ptrace::peek_data(pid, 0), Err(eno::EIO));
+
+    // Skip the exit from kill()
+    let mut entered = false;
+    let mut status;
+    loop {
+        ptrace::cont_syscall(pid, 0).unwrap();
+        status = wait();
+        if stop_sig(status) != Some(sig::SIGTRAP | 0x80) {
+            break;
+        }
+
+        let regs = ptrace::get_regs(pid).unwrap();
+        let info = ptrace::get_syscall_info(pid);
+
+        if entered {
+            assert_eq!(regs.result(), Err(eno::EBADF));
+            if let Ok(info) = info {
+                assert_eq!(info.stop, SyscallStop::Exit(Err(eno::EBADF)));
+            }
+            entered = false;
+        } else if regs.sysno() == Some(Sysno::DUP) {
+            assert_eq!(regs.args()[0], 1000);
+            if let Ok(info) = info {
+                assert_eq!(info.sysno(), Some(Sysno::DUP));
+                assert_eq!(info.args().unwrap()[0], 1000);
+            }
+            entered = true;
+        }
+    }
+
+    assert_eq!(status, 0);
+}
+
+#[cfg(feature = "arch")]
+#[test]
+fn test_arch() {
+    use scall::arch;
+
+    assert_eq!(
+        arch::x86::nr::Sysno::from_name("open").unwrap().into_raw(),
+        5
+    );
+    assert_eq!(
+        arch::x86_64::nr::Sysno::from_name("open")
+            .unwrap()
+            .into_raw(),
+        2
+    );
+    assert_eq!(arch::aarch64::nr::Sysno::from_name("open"), None);
+    assert_eq!(
+        arch::mips::nr::Sysno::new(arch::mips::nr::GETPID).map(|sysno| sysno.name()),
+        Some("getpid")
+    );
+    assert_eq!(arch::armeabi::nr::Sysno::SETUID32.name(), "setuid32");
+    // x32 numbers have __X32_SYSCALL_BIT set
+    assert_eq!(arch::x32::nr::OPEN, 0x4000_0002);
+    assert_eq!(arch::x32::nr::IOCTL, 0x4000_0202);
+    assert_eq!(
+        arch::x32::nr::Sysno::new(0x4000_0202).map(|sysno| sysno.name()),
+        Some("ioctl")
+    );
+
+    assert_eq!(
+        arch::mips::eno::name(arch::mips::eno::EDQUOT),
+        Some("EDQUOT")
+    );
+    assert_eq!(
+        arch::mips::eno::from_name("EWOULDBLOCK"),
+        Some(arch::mips::eno::EAGAIN)
+    );
+    assert_eq!(
+        arch::x86::eno::description(arch::x86::eno::ENOENT),
+        Some("No such file or directory")
+    );
+    assert_eq!(arch::sparc64::eno::name(-1), None);
+
+    // The native tables are still selected by default
+    #[cfg(all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "64"
+    ))]
+    {
+        assert_eq!(Sysno::GETPID.into_raw(), arch::x86_64::nr::GETPID);
+        assert_eq!(Errno::ENOSYS.into_raw(), arch::x86_64::eno::ENOSYS);
+    }
+    #[cfg(all(
+        target_os = "linux",
+        target_arch = "x86_64",
+        target_pointer_width = "32"
+    ))]
+    assert_eq!(Sysno::GETPID.into_raw(), arch::x32::nr::GETPID);
+    #[cfg(all(target_os = "linux", target_arch = "x86"))]
+    assert_eq!(Sysno::GETPID.into_raw(), arch::x86::nr::GETPID);
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    assert_eq!(Sysno::GETPID.into_raw(), arch::aarch64::nr::GETPID);
+}
+
+#[test]
+fn test_ioctl() {
+    use scall::ioctl;
+    use std::mem::size_of;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        assert_eq!(ioctl::io(b'f', 1), libc::_IO(b'f' as _, 1) as u32);
+        assert_eq!(
+            ioctl::ior(b'f', 1, size_of::<isize>()),
+            libc::_IOR::<isize>(b'f' as _, 1) as u32
+        );
+        assert_eq!(
+            ioctl::iow(b'T', 202, size_of::<i32>()),
+            libc::_IOW::<i32>(b'T' as _, 202) as u32
+        );
+        assert_eq!(
+            ioctl::iowr(b'T', 202, size_of::<[u8; 40]>()),
+            libc::_IOWR::<[u8; 40]>(b'T' as _, 202) as u32
+        );
+        assert_eq!(
+            ioctl::iow(b'T', 202, size_of::<i32>()),
+            libc::TUNSETIFF as _
+        );
+        assert_eq!(
+            ioctl::ior(b'f', 1, size_of::<isize>()),
+            libc::FS_IOC_GETFLAGS as _
+        );
+    }
+
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        target_arch = "x86_64"
+    ))]
+    assert_eq!(ioctl::ior(b'f', 1, 8), 0x80086601);
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        any(
+            target_arch = "powerpc64",
+            target_arch = "mips64",
+            target_arch = "sparc64"
+        ),
+    ))]
+    assert_eq!(ioctl::ior(b'f', 1, 8), 0x40086601);
+    #[cfg(any(target_os = "freebsd", target_os = "macos"))]
+    assert_eq!(
+        ioctl::ior(b'f', 127, size_of::<i32>()),
+        libc::FIONREAD as u32
+    );
+
+    let req = ioctl::iowr(b'x', 0x42, 123);
+    assert_eq!(ioctl::ioc_dir(req), ioctl::IOC_READ | ioctl::IOC_WRITE);
+    assert_eq!(ioctl::ioc_type(req), b'x');
+    assert_eq!(ioctl::ioc_nr(req), 0x42);
+    assert_eq!(ioctl::ioc_size(req), 123);
+    assert_eq!(
+        ioctl::ioc_size(ioctl::ior(b'x', 0, ioctl::IOC_SIZE_MAX)),
+        ioctl::IOC_SIZE_MAX
+    );
+
+    unsafe {
         #[cfg(any(target_os = "linux", target_os = "android"))]
         {
-            let res = syscall!(FACCESSAT2, libc::AT_FDCWD, b"/\0".as_ptr(), libc::F_OK, 0);
+            let fd = syscall!(
+                OPENAT,
+                consts::AT_FDCWD,
+                b"/dev/urandom\0",
+                consts::O_RDONLY | consts::O_CLOEXEC,
+                0
+            )
+            .unwrap();
+            let mut count = -1i32;
+            let rndgetentcnt = ioctl::ior(b'R', 0x00, size_of::<i32>());
+            assert_eq!(syscall!(IOCTL, fd, rndgetentcnt, &mut count), Ok(0));
+            assert!(count >= 0);
+            syscall_nofail!(CLOSE, fd);
+        }
 
-            assert!(res == Ok(0) || res == Err(eno::ENOSYS), "{:?}", res);
+        #[cfg(any(target_os = "freebsd", target_os = "macos"))]
+        {
+            let mut fds = [0i32; 2];
+            assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+            assert_eq!(syscall!(WRITE, fds[1], b"abc", 3), Ok(3));
+            let mut nread = 0i32;
+            let fionread = ioctl::ior(b'f', 127, size_of::<i32>());
+            assert_eq!(syscall!(IOCTL, fds[0], fionread, &mut nread), Ok(0));
+            assert_eq!(nread, 3);
+            syscall_nofail!(CLOSE, fds[0]);
+            syscall_nofail!(CLOSE, fds[1]);
         }
     }
 }
 
+#[test]
+fn test_sig() {
+    assert_eq!(sig::SIGUSR1, libc::SIGUSR1);
+    assert_eq!(sig::SIGUSR2, libc::SIGUSR2);
+    assert_eq!(sig::SIGCHLD, libc::SIGCHLD);
+    assert_eq!(sig::SIGBUS, libc::SIGBUS);
+    assert_eq!(sig::SIGSYS, libc::SIGSYS);
+    assert_eq!(sig::SIG_BLOCK, libc::SIG_BLOCK);
+    assert_eq!(sig::SIG_SETMASK, libc::SIG_SETMASK);
+    assert_eq!(sig::SA_SIGINFO, libc::SA_SIGINFO as u32);
+    assert_eq!(sig::SA_RESTART, libc::SA_RESTART as u32);
+    assert_eq!(sig::SS_DISABLE, libc::SS_DISABLE as u32);
+
+    assert_eq!(SigSet::SIZE, std::mem::size_of::<types::sigset_t>());
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    assert_eq!(SigSet::MAX_SIGNAL, sig::SIGRTMAX);
+
+    let mut set = SigSet::empty();
+    assert!(set.is_empty());
+    set.insert(sig::SIGUSR1);
+    set.insert(sig::SIGUSR2);
+    set.insert(SigSet::MAX_SIGNAL);
+    assert!(set.contains(sig::SIGUSR1));
+    assert!(!set.contains(sig::SIGCHLD));
+    assert!(!set.contains(0));
+    assert!(!set.contains(SigSet::MAX_SIGNAL + 1));
+    let mut sigs = vec![sig::SIGUSR1, sig::SIGUSR2, SigSet::MAX_SIGNAL];
+    sigs.sort_unstable();
+    assert_eq!(set.iter().collect::<Vec<_>>(), sigs);
+    set.remove(sig::SIGUSR2);
+    assert!(!set.contains(sig::SIGUSR2));
+    assert_eq!(SigSet::full().iter().count(), SigSet::MAX_SIGNAL as usize);
+
+    unsafe fn sigprocmask(how: i32, set: Option<&SigSet>, old: &mut SigSet) {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        syscall!(RT_SIGPROCMASK, how, set, old, SigSet::SIZE).unwrap();
+        #[cfg(any(target_os = "freebsd", target_os = "macos"))]
+        syscall!(SIGPROCMASK, how, set, old).unwrap();
+    }
+
+    unsafe {
+        // Block SIGUSR1 in this thread, and check that the C library sees it as blocked
+        let mut set = SigSet::empty();
+        set.insert(sig::SIGUSR1);
+        let mut old = SigSet::empty();
+        sigprocmask(sig::SIG_BLOCK, Some(&set), &mut old);
+        assert!(!old.contains(sig::SIGUSR1));
+
+        let mut cur = std::mem::zeroed::<libc::sigset_t>();
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, std::ptr::null(), &mut cur),
+            0
+        );
+        assert_eq!(libc::sigismember(&cur, libc::SIGUSR1), 1);
+
+        let mut blocked = SigSet::empty();
+        sigprocmask(sig::SIG_SETMASK, Some(&old), &mut blocked);
+        assert!(blocked.contains(sig::SIGUSR1));
+
+        sigprocmask(sig::SIG_BLOCK, None, &mut blocked);
+        assert_eq!(blocked, old);
+    }
+}
+
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    target_pointer_width = "64",
+))]
+#[test]
+fn test_fstat() {
+    unsafe {
+        let fd = syscall!(
+            OPENAT,
+            consts::AT_FDCWD,
+            b"/\0".as_ptr(),
+            consts::O_RDONLY,
+            0
+        )
+        .unwrap();
+
+        let mut st = std::mem::zeroed::<types::stat>();
+        syscall!(FSTAT, fd, &mut st as *mut _).unwrap();
+        assert_eq!(st.st_mode & 0o170000, 0o040000);
+
+        // sparc64 has `fstatat64()` instead of `newfstatat()`
+        #[cfg(not(target_arch = "sparc64"))]
+        let mut st2 = std::mem::zeroed::<types::stat>();
+        #[cfg(not(target_arch = "sparc64"))]
+        syscall!(
+            NEWFSTATAT,
+            consts::AT_FDCWD,
+            b"/\0".as_ptr(),
+            &mut st2 as *mut _,
+            0
+        )
+        .unwrap();
+        #[cfg(target_arch = "sparc64")]
+        let mut st2 = std::mem::zeroed::<types::stat64>();
+        #[cfg(target_arch = "sparc64")]
+        syscall!(
+            FSTATAT64,
+            consts::AT_FDCWD,
+            b"/\0".as_ptr(),
+            &mut st2 as *mut _,
+            0
+        )
+        .unwrap();
+        assert_eq!(
+            (st.st_dev as u64, st.st_ino as u64),
+            (st2.st_dev as u64, st2.st_ino as u64)
+        );
+
+        syscall_nofail!(CLOSE, fd);
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn test_pread64() {
@@ -287,62 +2286,76 @@ fn test_prctl() {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 #[test]
 fn test_epoll() {
-    unsafe fn epoll_wait(
-        epfd: libc::c_int,
-        events: &mut [libc::epoll_event],
-        timeout: libc::c_int,
-    ) -> Result<usize, libc::c_int> {
-        syscall!(
-            EPOLL_PWAIT,
-            epfd,
-            events.as_mut_ptr(),
-            events.len(),
-            timeout,
-            std::ptr::null::<libc::sigset_t>(),
-            std::mem::size_of::<libc::sigset_t>(),
-        )
-    }
+    use scall::epoll::{self, EpollEvent};
+
+    assert_eq!(
+        std::mem::size_of::<EpollEvent>(),
+        std::mem::size_of::<libc::epoll_event>()
+    );
+
+    let epfd = epoll::epoll_create1(epoll::EPOLL_CLOEXEC).unwrap();
+
+    let mut events = [EpollEvent::default(); 2];
+
+    assert_eq!(epoll::epoll_wait(epfd, &mut events, 0), Ok(0));
+
+    // We have to call into libc to set up the pipe so it can handle the architecture
+    // differences for us
+    let mut pipefds = [0i32; 2];
+    assert_eq!(
+        unsafe { libc::pipe2(pipefds.as_mut_ptr(), libc::O_CLOEXEC) },
+        0
+    );
+
+    // Watch for events on the read end of the pipe
+    let event = EpollEvent::new(epoll::EPOLLIN, pipefds[0] as u64);
+    epoll::epoll_ctl(epfd, epoll::EPOLL_CTL_ADD, pipefds[0], Some(&event)).unwrap();
+    assert_eq!(
+        epoll::epoll_ctl(epfd, epoll::EPOLL_CTL_ADD, pipefds[0], Some(&event)),
+        Err(eno::EEXIST)
+    );
+
+    // No events yet
+    assert_eq!(epoll::epoll_wait(epfd, &mut events, 0), Ok(0));
+    assert_eq!(
+        epoll::epoll_pwait(epfd, &mut events, 0, Some(&SigSet::empty())),
+        Ok(0)
+    );
 
     unsafe {
-        let epfd = syscall!(EPOLL_CREATE1, libc::EPOLL_CLOEXEC).unwrap() as libc::c_int;
-
-        let mut events = [std::mem::zeroed::<libc::epoll_event>(); 2];
-
-        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(0));
-
-        // We have to call into libc to set up the pipe so it can handle the architecture
-        // differences for us
-        let mut pipefds = [0i32; 2];
-        assert_eq!(libc::pipe2(pipefds.as_mut_ptr(), libc::O_CLOEXEC), 0);
-
-        // Watch for events on the read end of the pipe
-        events[0].u64 = pipefds[0] as u64;
-        events[0].events = libc::EPOLLIN as u32;
-        syscall!(
-            EPOLL_CTL,
-            epfd,
-            libc::EPOLL_CTL_ADD,
-            pipefds[0],
-            &mut events[0] as *mut _,
-        )
-        .unwrap();
-
-        // No events yet
-        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(0));
-
         // Now write() some data in, and it should poll as ready
         assert_eq!(syscall!(WRITE, pipefds[1], &b'0' as *const _, 1), Ok(1));
-        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(1));
-        assert_eq!({ events[0].u64 }, pipefds[0] as u64);
-        assert_eq!({ events[0].events }, libc::EPOLLIN as u32);
+    }
+    assert_eq!(epoll::epoll_wait(epfd, &mut events, 0), Ok(1));
+    assert_eq!(events[0].data(), pipefds[0] as u64);
+    assert_eq!(events[0].events(), epoll::EPOLLIN);
+
+    events[0] = EpollEvent::default();
+    match epoll::epoll_pwait2(
+        epfd,
+        &mut events,
+        Some(std::time::Duration::from_millis(10)),
+        None,
+    ) {
+        Ok(n) => {
+            assert_eq!(n, 1);
+            assert_eq!(events[0].data(), pipefds[0] as u64);
+            assert_eq!(events[0].events(), epoll::EPOLLIN);
+        }
+        // epoll_pwait2() was added in Linux 5.11
+        Err(eno::ENOSYS) => (),
+        Err(e) => panic!("epoll_pwait2() failed: {}", e),
+    }
+
+    // Stop watching for input
+    epoll::epoll_ctl(epfd, epoll::EPOLL_CTL_DEL, pipefds[0], None).unwrap();
+    assert_eq!(epoll::epoll_wait(epfd, &mut events, 0), Ok(0));
 
+    unsafe {
         // Close both ends of the pipe
         syscall_nofail!(CLOSE, pipefds[0]);
         syscall_nofail!(CLOSE, pipefds[1]);
 
-        // Now no events
-        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(0));
-
         syscall_nofail!(CLOSE, epfd);
     }
 }
@@ -352,9 +2365,9 @@ fn test_epoll() {
 fn test_kqueue() {
     unsafe fn kevent(
         kq: libc::c_int,
-        changes: &[libc::kevent],
-        events: &mut [libc::kevent],
-        timeout: Option<libc::timespec>,
+        changes: &[types::kevent],
+        events: &mut [types::kevent],
+        timeout: Option<types::timespec>,
     ) -> Result<usize, libc::c_int> {
         let raw_timeout = if let Some(ref timeout) = timeout {
             timeout
@@ -374,14 +2387,14 @@ fn test_kqueue() {
     }
 
     unsafe {
-        let timeout_0 = libc::timespec {
+        let timeout_0 = types::timespec {
             tv_sec: 0,
             tv_nsec: 0,
         };
 
         let kq = syscall!(KQUEUE).unwrap() as libc::c_int;
 
-        let mut events = [std::mem::zeroed::<libc::kevent>(); 2];
+        let mut events = [std::mem::zeroed::<types::kevent>(); 2];
 
         assert_eq!(kevent(kq, &[], &mut events, Some(timeout_0)), Ok(0));
 
@@ -0,0 +1,144 @@
+#!/usr/bin/env python3
+import os
+import re
+import sys
+from typing import Dict, List, Optional
+
+CONST_DEFINE_RE = re.compile(
+    r"^\s*#\s*define\s+(?P<name>[A-Z][A-Z0-9_]*)\s+(?P<value>[^/]+?)\s*(/[*/].*)?$"
+)
+
+# The headers that constants are read from, and a regex matching the names of the constants that
+# should be taken from each one.
+CONST_HEADERS = {
+    "freebsd": [
+        ("sys/fcntl.h", r"O_[A-Z0-9_]+|F_[A-Z0-9_]+|FD_CLOEXEC|AT_[A-Z0-9_]+"),
+        ("sys/mman.h", r"PROT_[A-Z]+|MAP_[A-Z0-9_]+|MS_[A-Z]+|MCL_[A-Z]+|MADV_[A-Z]+"),
+        ("sys/wait.h", r"W[A-Z]+"),
+        ("sys/unistd.h", r"SEEK_[A-Z]+"),
+        ("sys/_clock_id.h", r"CLOCK_[A-Z_]+|TIMER_ABSTIME"),
+    ],
+    "macos": [
+        ("sys/fcntl.h", r"O_[A-Z0-9_]+|F_[A-Z0-9_]+|FD_CLOEXEC|AT_[A-Z0-9_]+"),
+        ("sys/mman.h", r"PROT_[A-Z]+|MAP_[A-Z0-9_]+|MS_[A-Z]+|MCL_[A-Z]+|MADV_[A-Z_]+"),
+        ("sys/wait.h", r"W[A-Z]+"),
+        ("sys/unistd.h", r"SEEK_[A-Z]+"),
+    ],
+}
+
+# Kernel-internal or obsolete constants
+EXCLUDED_CONSTS = {
+    "freebsd": re.compile(
+        r"^(F_OGETLK|F_OSETLK|F_OSETLKW|F_WAIT|F_FLOCK|F_POSIX|F_REMOTE|F_NOINTR|F_FIRSTOPEN|F_UNLCKSYS|F_CANCEL|MAP_COPY|MAP_RESERVED[0-9]+|MAP_ALIGNMENT_[A-Z]+|WCOREFLAG|WLINUXCLONE)$"
+    ),
+    "macos": re.compile(
+        r"^(O_POPUP|O_ALERT|O_DP_[A-Z]+|MAP_COPY|MAP_RESERVED[0-9]+|F_[A-Z_]*PRIVATE[A-Z_]*|WCOREFLAG)$"
+    ),
+}
+
+C_INT_SUFFIX = re.compile(r"\b(0[xX][0-9a-fA-F]+|[0-9]+)[uUlL]+\b")
+C_OCTAL = re.compile(r"\b0([0-7]+)\b")
+C_EXPR = re.compile(r"^[()0-9a-fA-FxXo\s|&<>~+\-*]*$")
+C_NAME = re.compile(r"\b[A-Z_][A-Z0-9_]*\b")
+
+NICE_OS_NAMES = {
+    "freebsd": "FreeBSD",
+    "macos": "macOS",
+}
+
+NICE_ARCH_NAMES = {
+    "x86_64": "x86-64",
+}
+
+
+def eval_c_expr(expr: str, known: Dict[str, int]) -> Optional[int]:
+    def lookup(match):
+        name = match.group(0)
+        return "({})".format(known[name]) if name in known else name
+
+    expr = C_NAME.sub(lookup, expr)
+    expr = C_INT_SUFFIX.sub(r"\1", expr)
+    expr = C_OCTAL.sub(r"0o\1", expr)
+    if not C_EXPR.match(expr):
+        return None
+
+    try:
+        return int(eval(expr, {"__builtins__": {}}))
+    except (SyntaxError, TypeError):
+        return None
+
+
+def read_const_header_file(
+    fpath: str, name_re: str, excluded: "re.Pattern[str]"
+) -> Dict[str, int]:
+    name_pattern = re.compile(r"^({})$".format(name_re))
+    consts: Dict[str, int] = {}
+
+    with open(fpath) as file:
+        for line in file:
+            match = CONST_DEFINE_RE.match(line)
+            if match is None:
+                continue
+
+            name = match.group("name")
+            if not name_pattern.match(name) or excluded.match(name):
+                continue
+
+            value = eval_c_expr(match.group("value"), consts)
+            if value is not None:
+                # Later definitions (e.g. inside #else blocks) don't override earlier ones
+                consts.setdefault(name, value)
+
+    return consts
+
+
+def format_value(value: int) -> str:
+    # Flags are easier to read in hex, but small numbers (which are usually enum-like values such
+    # as F_GETFD or SEEK_END) are clearer in decimal.
+    return str(value) if value < 16 else "{:#x}".format(value)
+
+
+def main(args: List[str]) -> None:
+    if len(args) != 2:
+        print(
+            "Usage: {} <kernel include directory> <OS name>".format(sys.argv[0]),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    repo_path = os.path.dirname(os.path.dirname(os.path.realpath(__file__)))
+
+    include_path = args[0]
+    os_name = args[1]
+
+    arches = [
+        name[len(os_name) + 1 :]
+        for name in os.listdir(os.path.join(repo_path, "src/platform"))
+        if name.startswith(os_name + "-")
+    ]
+
+    consts: Dict[str, int] = {}
+    for header, name_re in CONST_HEADERS[os_name]:
+        consts.update(
+            read_const_header_file(
+                os.path.join(include_path, header), name_re, EXCLUDED_CONSTS[os_name]
+            )
+        )
+
+    for arch in arches:
+        fpath = os.path.join(repo_path, "src/platform", os_name + "-" + arch, "consts.rs")
+
+        with open(fpath, "w") as file:
+            file.write(
+                "//! Flag constants for {} {}.\n\n".format(
+                    NICE_ARCH_NAMES.get(arch, arch), NICE_OS_NAMES[os_name]
+                )
+            )
+            file.write("/* automatically generated by bsd_consts_from_src.py */\n\n")
+
+            for name, value in sorted(consts.items()):
+                file.write("pub const {}: i32 = {};\n".format(name, format_value(value)))
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])
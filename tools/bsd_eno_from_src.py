@@ -1,11 +1,12 @@
 #!/usr/bin/env python3
+import json
 import os
 import re
 import sys
-from typing import Dict, List, Optional
+from typing import Dict, List, NamedTuple, Optional
 
 ERRNO_DEFINE_RE = re.compile(
-    r"(\s*\/\*([^*]|\*[^/])*\*\/)*\s*(#define\s+(?P<name>[A-Z0-9_]+)(\s+((?P<num>\d+)|(?P<other>\w+)|(?P<extra>.*)))?|#undef\s+(?P<undef_name>[A-Z0-9_]+))(\s*/\*([^*]|\*[^/])*\*/)*\s+"
+    r"(\s*\/\*([^*]|\*[^/])*\*\/)*\s*(#define[ \t]+(?P<name>[A-Z0-9_]+)([ \t]+((?P<num>\d+)|(?P<other>\w+)|(?P<extra>[^\n]*)))?|#undef[ \t]+(?P<undef_name>[A-Z0-9_]+))([ \t]*/\*(?P<desc>[^\n]*?)\*/)?[ \t]*(\n|$)"
 )
 
 REQUIRED_ERRNOS = {
@@ -32,9 +33,17 @@ NICE_ARCH_NAMES = {
 }
 
 
+class ErrnoInfo(NamedTuple):
+    num: int
+    desc: str
+    # If this name was defined in terms of another (e.g. EWOULDBLOCK -> EAGAIN), the name of the
+    # "real" error number.
+    alias_of: Optional[str] = None
+
+
 def read_errno_header_file(
-    fpath: str, base: Optional[Dict[str, int]] = None
-) -> Dict[str, int]:
+    fpath: str, base: Optional[Dict[str, ErrnoInfo]] = None
+) -> Dict[str, ErrnoInfo]:
     errnos = dict({} if base is None else base)
 
     text = ""
@@ -75,16 +84,32 @@ def read_errno_header_file(
         if not name.startswith("E"):
             continue
 
+        desc = " ".join((match.group("desc") or "").split())
+
         other_name = match.group("other")
         if other_name:
-            errnos[name] = errnos[other_name]
+            other = errnos[other_name]
+            errnos[name] = ErrnoInfo(
+                other.num, other.desc, other.alias_of or other_name
+            )
         else:
-            errnos[name] = int(match.group("num"))
+            errnos[name] = ErrnoInfo(int(match.group("num")), desc)
 
     errnos.pop("ELAST", None)
     return errnos
 
 
+def write_errno_table(f, errnos: Dict[str, ErrnoInfo]) -> None:
+    f.write("\nerrno_table! {\n")
+    for name, info in sorted(errnos.items()):
+        if info.alias_of is None:
+            f.write("    {} {},\n".format(name, json.dumps(info.desc)))
+    for name, info in sorted(errnos.items()):
+        if info.alias_of is not None:
+            f.write("    @alias {} = {},\n".format(name, info.alias_of))
+    f.write("}\n")
+
+
 def main(args: List[str]) -> None:
     if len(args) != 2:
         print(
@@ -125,8 +150,10 @@ def main(args: List[str]) -> None:
             )
             file.write("/* Automatically generated by bsd_eno_nr_from_src.py */\n\n")
 
-            for name, eno in sorted(errnos.items()):
-                file.write("pub const {}: i32 = {};\n".format(name, eno))
+            for name, info in sorted(errnos.items()):
+                file.write("pub const {}: i32 = {};\n".format(name, info.num))
+
+            write_errno_table(file, errnos)
 
 
 if __name__ == "__main__":
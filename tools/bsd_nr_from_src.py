@@ -3,7 +3,7 @@ import re
 import os
 import sys
 
-from typing import List
+from typing import Dict, List, Tuple
 
 ITEM_RE = re.compile(
     r"[ \t]*(?P<nr_range>\d+(-\d+)?)[ \t]+(?P<aue>AUE_[A-Z0-9a-z_]+)[ \t]+(?P<type>[A-Z0-9]+([ \t]*\|[ \t]*[A-Z0-9]+)*)[ \t]*"
@@ -25,6 +25,26 @@ NICE_ARCH_NAMES = {
 }
 
 
+def write_sysno_table(f, syscalls: List[Tuple[str, int]]) -> None:
+    # If multiple names map to the same number, only the first one gets a variant in the `Sysno`
+    # enum; the rest are added as aliases.
+    primaries: Dict[int, str] = {}
+    aliases = []
+    for name, nr in syscalls:
+        if nr in primaries:
+            aliases.append((name, primaries[nr]))
+        else:
+            primaries[nr] = name
+
+    f.write("\nsysno_table! {\n")
+    for nr, name in primaries.items():
+        # The number of arguments isn't known, so calls to these syscalls aren't checked.
+        f.write('    {} "{}" _,\n'.format(name, name.lower()))
+    for name, target in aliases:
+        f.write("    @alias {} = {},\n".format(name, target))
+    f.write("}\n")
+
+
 def main(args: List[str]) -> None:
     if len(args) != 2:
         print("Usage: {} <syscalls.master file> <OS name>".format(sys.argv[0]))
@@ -121,6 +141,8 @@ def main(args: List[str]) -> None:
             for (name, nr) in syscalls:
                 file.write("pub const {}: usize = {};\n".format(name, nr))
 
+            write_sysno_table(file, syscalls)
+
 
 if __name__ == "__main__":
     main(sys.argv[1:])
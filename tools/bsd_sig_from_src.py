@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+import os
+import re
+import sys
+from typing import Dict, List
+
+from bsd_consts_from_src import NICE_ARCH_NAMES, NICE_OS_NAMES, read_const_header_file
+from sig_from_src import REQUIRED_SIGNALS, write_sig_consts
+
+SIG_NAME_RE = r"SIG[A-Z0-9]+|SIG_BLOCK|SIG_UNBLOCK|SIG_SETMASK|SA_[A-Z0-9_]+|SS_[A-Z]+|MINSIGSTKSZ"
+
+# Obsolete aliases, and constants that are only defined for strict POSIX compatibility
+EXCLUDED_SIG_CONSTS = {
+    "freebsd": re.compile(r"^(SIGLWP|SIG_HOLD)$"),
+    "macos": re.compile(r"^(SIGPOLL|SA_USERSPACE_MASK)$"),
+}
+
+# Linux-specific signals
+NON_BSD_SIGNALS = {"SIGPWR", "SIGRTMIN", "SIGRTMAX"}
+
+
+def main(args: List[str]) -> None:
+    if len(args) != 2:
+        print(
+            "Usage: {} <kernel include directory> <OS name>".format(sys.argv[0]),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    repo_path = os.path.dirname(os.path.dirname(os.path.realpath(__file__)))
+
+    include_path = args[0]
+    os_name = args[1]
+
+    arches = [
+        name[len(os_name) + 1 :]
+        for name in os.listdir(os.path.join(repo_path, "src/platform"))
+        if name.startswith(os_name + "-")
+    ]
+
+    consts: Dict[str, int] = read_const_header_file(
+        os.path.join(include_path, "sys/signal.h"), SIG_NAME_RE, EXCLUDED_SIG_CONSTS[os_name]
+    )
+
+    required = REQUIRED_SIGNALS - NON_BSD_SIGNALS
+    assert set(consts.keys()) >= required, "{}: {}".format(
+        os_name, required - set(consts.keys())
+    )
+
+    for arch in arches:
+        fpath = os.path.join(repo_path, "src/platform", os_name + "-" + arch, "sig.rs")
+
+        with open(fpath, "w") as file:
+            file.write(
+                "//! Signal numbers and flags for {} {}.\n\n".format(
+                    NICE_ARCH_NAMES.get(arch, arch), NICE_OS_NAMES[os_name]
+                )
+            )
+            file.write("/* automatically generated by bsd_sig_from_src.py */\n\n")
+            write_sig_consts(file, consts)
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])
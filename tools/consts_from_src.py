@@ -0,0 +1,247 @@
+#!/usr/bin/env python3
+import os
+import re
+import subprocess
+import sys
+import tempfile
+from typing import Dict, Iterator, List, Optional, Tuple
+
+# The headers that constants are read from, and a regex matching the names of the constants that
+# should be taken from each one. Each header is processed separately, so (for example) the mount
+# flags pulled in by <linux/fs.h> don't leak into the `MS_*` msync() flags.
+CONST_HEADERS = [
+    ("linux/fcntl.h", r"O_[A-Z0-9_]+|F_[A-Z0-9_]+|FD_CLOEXEC|AT_[A-Z0-9_]+"),
+    ("asm/mman.h", r"PROT_[A-Z0-9_]+|MAP_[A-Z0-9_]+|MS_[A-Z]+|MCL_[A-Z]+|MADV_[A-Z0-9_]+"),
+    ("linux/mman.h", r"MAP_[A-Z0-9_]+|MREMAP_[A-Z]+|MLOCK_[A-Z]+"),
+    ("linux/sched.h", r"CLONE_[A-Z0-9_]+|CSIGNAL"),
+    ("linux/wait.h", r"W[A-Z]+|P_[A-Z]+"),
+    ("linux/time.h", r"CLOCK_[A-Z0-9_]+|TIMER_ABSTIME"),
+    ("linux/fs.h", r"SEEK_[A-Z]+"),
+]
+
+# Constants that are not useful to userspace (or are not actually flags)
+EXCLUDED_CONSTS = re.compile(
+    r"^(O_TMPFILE_MASK|F_LINUX_SPECIFIC_BASE|MAP_HUGE_[0-9]+[KMG]B|CLOCK_SGI_CYCLE|CLONE_ARGS_SIZE_VER[0-9]+|SEEK_MAX)$"
+)
+
+# Constants that need a type other than i32
+CONST_TYPES = {
+    "CSIGNAL": "u64",
+    "CLONE_": "u64",
+}
+
+TYPE_RANGES = {
+    "i32": (-(1 << 31), (1 << 31) - 1),
+    "u64": (0, (1 << 64) - 1),
+}
+
+C_INT_SUFFIX = re.compile(r"\b(0[xX][0-9a-fA-F]+|[0-9]+)[uUlL]+\b")
+C_OCTAL = re.compile(r"\b0([0-7]+)\b")
+C_EXPR = re.compile(r"^[()0-9a-fA-FxXo\s|&<>~+\-*]*$")
+
+NICE_ARCH_NAMES = {
+    "x86_64": "x86-64",
+    "powerpc": "PowerPC",
+    "powerpc64": "PowerPC64",
+    "mips": "MIPS",
+    "mips64": "MIPS64",
+    "sparc64": "SPARC64",
+    "armeabi": "arm",
+    "riscv64": "RISCV-64",
+}
+
+
+# Since -undef is passed to the preprocessor, everything the headers use to detect the
+# architecture/ABI has to be defined by hand.
+ARCH_INFO = {
+    "aarch64": {"name": "arm64", "defines": {"__aarch64__": 1}},
+    "armeabi": {"name": "arm", "defines": {"__arm__": 1, "__ARM_EABI__": 1}},
+    "mips": {
+        "name": "mips",
+        "defines": {
+            "__mips__": 1,
+            "_MIPS_SIM_ABI32": 1,
+            "_MIPS_SIM_NABI32": 2,
+            "_MIPS_SIM_ABI64": 3,
+            "_MIPS_SIM": "_MIPS_SIM_ABI32",
+            "_MIPS_SZLONG": 32,
+        },
+    },
+    "mips64": {
+        "name": "mips",
+        "defines": {
+            "__mips__": 1,
+            "_MIPS_SIM_ABI32": 1,
+            "_MIPS_SIM_NABI32": 2,
+            "_MIPS_SIM_ABI64": 3,
+            "_MIPS_SIM": "_MIPS_SIM_ABI64",
+            "_MIPS_SZLONG": 64,
+        },
+    },
+    "powerpc": {"name": "powerpc", "defines": {"__powerpc__": 1}},
+    "powerpc64": {
+        "name": "powerpc",
+        "defines": {"__powerpc__": 1, "__powerpc64__": 1},
+    },
+    "sparc64": {
+        "name": "sparc",
+        "defines": {"__sparc__": 1, "__arch64__": 1},
+    },
+    "riscv64": {
+        "name": "riscv",
+        "defines": {"__riscv": 1, "__riscv_xlen": 64, "__SIZEOF_POINTER__": 8},
+    },
+    "x86": {"name": "x86", "defines": {"__i386__": 1}},
+    "x86_64": {"name": "x86", "defines": {"__x86_64__": 1}},
+    "x32": {"name": "x86", "defines": {"__x86_64__": 1, "__ILP32__": 1}},
+}
+
+
+def arch_defines(info) -> str:
+    return "\n".join(
+        "#define {} {}".format(name, value) for name, value in info["defines"].items()
+    )
+
+
+def eval_c_expr(expr: str) -> Optional[int]:
+    expr = C_INT_SUFFIX.sub(r"\1", expr)
+    expr = C_OCTAL.sub(r"0o\1", expr)
+    if not C_EXPR.match(expr):
+        return None
+
+    try:
+        return int(eval(expr, {"__builtins__": {}}))
+    except (SyntaxError, TypeError):
+        return None
+
+
+def const_type(name: str) -> str:
+    for prefix, ty in CONST_TYPES.items():
+        if name.startswith(prefix):
+            return ty
+    return "i32"
+
+
+def run_preprocessor(linux_path: str, arch: str, text: str, extra_args: List[str]) -> str:
+    with tempfile.NamedTemporaryFile(mode="w+", suffix=".h") as f:
+        f.write(text)
+        f.flush()
+
+        return subprocess.run(
+            [
+                "gcc",
+                "-nostdinc",
+                "-undef",  # don't let the host's predefined macros leak into the results
+                "-I",
+                "{}/arch/{}/include/uapi".format(linux_path, arch),
+                "-I",
+                "{}/arch/{}/include/generated/uapi".format(linux_path, arch),
+                "-I",
+                "{}/include/uapi".format(linux_path),
+                "-P",
+                "-E",
+                *extra_args,
+                f.name,
+            ],
+            check=True,
+            encoding="utf-8",
+            stdout=subprocess.PIPE,
+        ).stdout
+
+
+def load_header_consts(
+    linux_path: str,
+    arch: str,
+    header: str,
+    name_re: str,
+    extra: str = "",
+    excluded: "re.Pattern[str]" = EXCLUDED_CONSTS,
+) -> Iterator[Tuple[str, int]]:
+    prelude = "{}\n#include <{}>\n".format(extra, header)
+
+    # First, get a list of all the (object-like) macros the header defines
+    name_pattern = re.compile(r"^#define ({}) ".format(name_re))
+    names = sorted(
+        match.group(1)
+        for match in map(
+            name_pattern.match,
+            run_preprocessor(linux_path, arch, prelude, ["-dM"]).split("\n"),
+        )
+        if match is not None and not excluded.match(match.group(1))
+    )
+
+    # Then expand them all
+    text = prelude + "".join("gen_const {0} {0}\n".format(name) for name in names)
+
+    for line in run_preprocessor(linux_path, arch, text, []).split("\n"):
+        if line.startswith("gen_const "):
+            _, name, expr = line.split(" ", 2)
+            value = eval_c_expr(expr)
+            if value is None:
+                # Not an integer constant (e.g. a struct initializer)
+                continue
+            yield (name, value)
+
+
+def load_arch_consts(linux_path: str, arch: str, extra: str) -> Dict[str, int]:
+    consts = {}
+    for header, name_re in CONST_HEADERS:
+        consts.update(load_header_consts(linux_path, arch, header, name_re, extra))
+
+    for name, value in list(consts.items()):
+        low, high = TYPE_RANGES[const_type(name)]
+        if not low <= value <= high:
+            print(
+                "{} doesn't fit in {}; skipping".format(name, const_type(name)),
+                file=sys.stderr,
+            )
+            del consts[name]
+
+    return consts
+
+
+def format_value(value: int) -> str:
+    # Flags are easier to read in hex, but small numbers (which are usually enum-like values such
+    # as F_GETFD or SEEK_END) are clearer in decimal.
+    return str(value) if value < 16 else "{:#x}".format(value)
+
+
+def write_consts(f, consts: Dict[str, int]) -> None:
+    for name, value in sorted(consts.items()):
+        f.write(
+            "pub const {}: {} = {};\n".format(name, const_type(name), format_value(value))
+        )
+
+
+def main(args: List[str]) -> None:
+    if len(args) != 1:
+        print(
+            "Usage: {} <path to Linux kernel source>".format(sys.argv[0]),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    repo_path = os.path.dirname(os.path.dirname(os.path.realpath(__file__)))
+    linux_path = args[0]
+
+    for arch, info in ARCH_INFO.items():
+        extra = arch_defines(info)
+
+        consts = load_arch_consts(linux_path, info["name"], extra)
+        if not consts:
+            raise RuntimeError("No constants found for {}!".format(arch))
+
+        with open(
+            "{}/src/platform/linux-{}/consts.rs".format(repo_path, arch), "w"
+        ) as f:
+            f.write(
+                "//! Flag constants for {} Linux.\n\n".format(
+                    NICE_ARCH_NAMES.get(arch, arch)
+                )
+            )
+            f.write("/* automatically generated by consts_from_src.py */\n\n")
+            write_consts(f, consts)
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])
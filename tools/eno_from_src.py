@@ -1,11 +1,12 @@
 #!/usr/bin/env python3
+import json
 import os
 import re
 import sys
-from typing import Dict, List, Optional
+from typing import Dict, List, NamedTuple, Optional
 
 ERRNO_DEFINE_RE = re.compile(
-    r"(\s*/\*([^*]|\*[^/])*\*/)*\s*(#define\s+(?P<name>[A-Z0-9_]+)(\s+((?P<num>\d+)|(?P<other>\w+)))?|#undef\s+(?P<undef_name>[A-Z0-9_]+))(\s*/\*([^*]|\*[^/])*\*/)*\s+"
+    r"(\s*/\*([^*]|\*[^/])*\*/)*\s*(#define[ \t]+(?P<name>[A-Z0-9_]+)([ \t]+((?P<num>\d+)|(?P<other>\w+)))?|#undef[ \t]+(?P<undef_name>[A-Z0-9_]+))([ \t]*/\*(?P<desc>[^\n]*?)\*/)?[ \t]*(\n|$)"
 )
 
 NICE_ARCH_NAMES = {
@@ -24,9 +25,17 @@ REQUIRED_ERRNOS = set(
 )
 
 
+class ErrnoInfo(NamedTuple):
+    num: int
+    desc: str
+    # If this name was defined in terms of another (e.g. EWOULDBLOCK -> EAGAIN), the name of the
+    # "real" error number.
+    alias_of: Optional[str] = None
+
+
 def read_errno_header_file(
-    fpath: str, base: Optional[Dict[str, int]] = None
-) -> Dict[str, int]:
+    fpath: str, base: Optional[Dict[str, ErrnoInfo]] = None
+) -> Dict[str, ErrnoInfo]:
     errnos = dict({} if base is None else base)
 
     text = ""
@@ -51,22 +60,38 @@ def read_errno_header_file(
         if not name.startswith("E"):
             continue
 
+        desc = " ".join((match.group("desc") or "").split())
+
         other_name = match.group("other")
         if other_name:
-            errnos[name] = errnos[other_name]
+            other = errnos[other_name]
+            errnos[name] = ErrnoInfo(
+                other.num, other.desc, other.alias_of or other_name
+            )
         else:
-            errnos[name] = int(match.group("num"))
+            errnos[name] = ErrnoInfo(int(match.group("num")), desc)
 
     errnos.pop("EMAXERRNO", None)
     return errnos
 
 
+def write_errno_table(f, errnos: Dict[str, ErrnoInfo]) -> None:
+    f.write("\nerrno_table! {\n")
+    for name, info in sorted(errnos.items()):
+        if info.alias_of is None:
+            f.write("    {} {},\n".format(name, json.dumps(info.desc)))
+    for name, info in sorted(errnos.items()):
+        if info.alias_of is not None:
+            f.write("    @alias {} = {},\n".format(name, info.alias_of))
+    f.write("}\n")
+
+
 def read_arch_errnos(
     linux_path: str,
     arch: str,
-    base: Dict[str, int],
+    base: Dict[str, ErrnoInfo],
     header_path: Optional[str] = None,
-) -> Dict[str, int]:
+) -> Dict[str, ErrnoInfo]:
     if header_path is None:
         header_path = (
             os.path.join(linux_path, "arch", arch, "include/uapi/asm/errno.h")
@@ -103,6 +128,7 @@ def main(args: List[str]) -> None:
         "riscv64": None,
         "x86": None,
         "x86_64": None,
+        "x32": None,
     }
 
     karch_header_paths = {
@@ -137,8 +163,10 @@ def main(args: List[str]) -> None:
                 )
             )
             f.write("/* automatically generated by eno_from_src.py */\n\n")
-            for name, eno in sorted(errnos.items()):
-                f.write("pub const {}: i32 = {};\n".format(name, eno))
+            for name, info in sorted(errnos.items()):
+                f.write("pub const {}: i32 = {};\n".format(name, info.num))
+
+            write_errno_table(f, errnos)
 
 
 if __name__ == "__main__":
@@ -6,10 +6,40 @@ import tempfile
 import subprocess
 import sys
 
-from typing import Iterator, Iterable, List, Set, Tuple
+from typing import Dict, Iterator, Iterable, List, Optional, Set, Tuple
 
 SIMPLE_MATH = re.compile(r"^[()+0-9a-fx\s]*$")
 NUMBER = re.compile(r"[0-9a-fx]+")
+SYSCALL_DEFINE = re.compile(r"\bSYSCALL_DEFINE([0-6])\(([^)]*)\)")
+
+X32_SYSCALL_BIT = 0x40000000
+
+# The oldest kernel the tables may be generated from. Older kernels are missing syscalls that the
+# crate relies on (such as `futex_waitv()`, added in 5.16).
+MIN_LINUX_VERSION = (5, 17)
+
+# Types that take up two registers on 32-bit architectures
+WIDE_TYPES = {"loff_t", "u64", "__u64", "s64", "__s64", "long long", "unsigned long long"}
+
+# Syscalls whose implementations (`sys_*()` functions) have different names. This is only used
+# for architectures where the syscall table isn't available.
+ENTRY_ALIASES = {
+    "_llseek": "llseek",
+    "_newselect": "select",
+    "_sysctl": "sysctl",
+    "stat": "newstat",
+    "lstat": "newlstat",
+    "fstat": "newfstat",
+    "oldstat": "stat",
+    "oldlstat": "lstat",
+    "oldfstat": "fstat",
+    "uname": "newuname",
+    "readdir": "old_readdir",
+    "umount": "oldumount",
+    "umount2": "umount",
+    "ugetrlimit": "getrlimit",
+    "mmap2": "mmap_pgoff",
+}
 
 NICE_ARCH_NAMES = {
     "x86_64": "x86-64",
@@ -23,8 +53,19 @@ NICE_ARCH_NAMES = {
 }
 
 
+def read_linux_version(linux_path: str) -> Tuple[int, int]:
+    version = {}
+    with open(os.path.join(linux_path, "Makefile")) as f:
+        for line in f:
+            m = re.match(r"^(VERSION|PATCHLEVEL)\s*=\s*(\d+)\s*$", line)
+            if m:
+                version[m.group(1)] = int(m.group(2))
+
+    return (version["VERSION"], version["PATCHLEVEL"])
+
+
 def load_table(
-    linux_path: str, path: str, arches: Set[str]
+    linux_path: str, path: str, arches: Set[str], offset: int = 0
 ) -> Iterator[Tuple[str, int]]:
     with open("{}/{}".format(linux_path, path)) as f:
         for line in f:
@@ -34,7 +75,7 @@ def load_table(
 
             nr, arch, name = line.split("\t", 4)[0:3]
             if arch in arches:
-                yield (name, int(nr))
+                yield (name, int(nr) + offset)
 
 
 def eval_expr(expr: str) -> int:
@@ -120,6 +161,157 @@ def load_headers(
             yield (name, eval_expr(nr))
 
 
+def load_table_entries(
+    linux_path: str, path: str, arches: Set[str]
+) -> Iterator[Tuple[str, str]]:
+    with open("{}/{}".format(linux_path, path)) as f:
+        for line in f:
+            line = line.strip()
+            if line.startswith("#") or not line:
+                continue
+
+            fields = line.split()
+            if len(fields) >= 4 and fields[1] in arches:
+                entry = fields[3]
+                if entry.startswith("sys_"):
+                    entry = entry[4:]
+                yield (fields[2], entry)
+
+
+Signature = List[Tuple[str, str]]
+
+# The signature of clone() on architectures that select CONFIG_CLONE_BACKWARDS, which pass `tls`
+# before `child_tidptr`. kernel/fork.c defines clone() differently depending on the kernel
+# configuration, so the right definition can't be picked from the source alone.
+CLONE_BACKWARDS_SIGNATURE: Signature = [
+    ("unsigned long", "clone_flags"),
+    ("unsigned long", "newsp"),
+    ("int __user *", "parent_tidptr"),
+    ("unsigned long", "tls"),
+    ("int __user *", "child_tidptr"),
+]
+
+
+def load_signatures(linux_path: str) -> Dict[str, Dict[str, Signature]]:
+    # Returns {arch directory name (or "" for generic code): {syscall name: [(type, name)]}}
+    fnames = subprocess.run(
+        ["git", "--no-pager", "grep", "-l", "-E", r"SYSCALL_DEFINE[0-6]\("],
+        cwd=linux_path,
+        check=True,
+        encoding="utf-8",
+        stdout=subprocess.PIPE,
+    ).stdout.split()
+
+    candidates: Dict[str, Dict[str, List[Signature]]] = {}
+    for fname in fnames:
+        if not fname.endswith(".c"):
+            continue
+
+        parts = fname.split("/")
+        arch = parts[1] if parts[0] == "arch" else ""
+
+        with open(os.path.join(linux_path, fname)) as f:
+            for m in SYSCALL_DEFINE.finditer(f.read()):
+                items = [" ".join(item.split()) for item in m.group(2).split(",")]
+                if len(items) != 1 + 2 * int(m.group(1)):
+                    continue
+                sig = list(zip(items[1::2], items[2::2]))
+                candidates.setdefault(arch, {}).setdefault(items[0], []).append(sig)
+
+    # Some syscalls are defined differently depending on the kernel configuration (for example,
+    # CONFIG_CLONE_BACKWARDS). Pick the most common number of arguments.
+    signatures: Dict[str, Dict[str, Signature]] = {}
+    for arch, sigs in candidates.items():
+        for name, options in sigs.items():
+            counts = [len(sig) for sig in options]
+            best = max(counts, key=counts.count)
+            signatures.setdefault(arch, {})[name] = options[counts.index(best)]
+
+    return signatures
+
+
+def lookup_signature(
+    signatures: Dict[str, Dict[str, Signature]], arch_dir: str, entry: str
+) -> Optional[Signature]:
+    for arch in [arch_dir, ""]:
+        sig = signatures.get(arch, {}).get(entry)
+        if sig is not None:
+            return sig
+    return None
+
+
+def format_signature(sig: Signature) -> str:
+    return ", ".join(
+        ty + name if ty.endswith("*") else "{} {}".format(ty, name) for ty, name in sig
+    )
+
+
+def count_args(sig: Signature, pointer_width: int) -> Optional[int]:
+    if pointer_width == 32 and any(ty.replace("const ", "") in WIDE_TYPES for ty, _ in sig):
+        # 64-bit arguments are split (and sometimes aligned) differently on every 32-bit
+        # architecture; don't try to check these.
+        return None
+    return len(sig)
+
+
+def resolve_signatures(
+    signatures: Dict[str, Dict[str, Signature]],
+    arch_dir: str,
+    names: Iterable[str],
+    entries: Dict[str, str],
+    pointer_width: int,
+) -> Dict[str, Tuple[Optional[int], Optional[str]]]:
+    # Returns {syscall name: (number of arguments, signature)}
+    result = {}
+    for name in names:
+        if name in entries:
+            sig = lookup_signature(signatures, arch_dir, entries[name])
+        else:
+            sig = lookup_signature(signatures, arch_dir, ENTRY_ALIASES.get(name, name))
+            if sig is None and name.endswith("32"):
+                # 32-bit UID/GID syscalls (like setuid32)
+                sig = lookup_signature(signatures, arch_dir, name[:-2])
+
+        if sig is not None:
+            result[name] = (count_args(sig, pointer_width), format_signature(sig))
+
+    return result
+
+
+def write_sysno_table(
+    f,
+    nums: Dict[str, int],
+    aliases: Dict[str, str],
+    sigs: Dict[str, Tuple[Optional[int], Optional[str]]],
+) -> None:
+    # Some system calls have multiple names for the same number. Only one name gets a variant in
+    # the `Sysno` enum; the rest are added as aliases.
+    aliases = dict(aliases)
+    primaries = {}
+    for name, nr in sorted(nums.items()):
+        if name in aliases:
+            continue
+        elif nr in primaries:
+            aliases[name] = primaries[nr]
+        else:
+            primaries[nr] = name
+
+    f.write("\nsysno_table! {\n")
+    for name in sorted(primaries.values()):
+        nargs, sig = sigs.get(name, (None, None))
+        f.write(
+            '    {} "{}" {}{},\n'.format(
+                name.upper(),
+                name,
+                "_" if nargs is None else nargs,
+                "" if sig is None else ' "{}"'.format(sig),
+            )
+        )
+    for name, target in sorted(aliases.items()):
+        f.write("    @alias {} = {},\n".format(name.upper(), target.upper()))
+    f.write("}\n")
+
+
 def main(args: List[str]) -> None:
     if len(args) != 1:
         print("Usage: {} <path to Linux kernel source>".format(sys.argv[0]))
@@ -129,6 +321,15 @@ def main(args: List[str]) -> None:
 
     linux_path = args[0]
 
+    linux_version = read_linux_version(linux_path)
+    if linux_version < MIN_LINUX_VERSION:
+        print(
+            "Linux {}.{} is too old; at least {}.{} is required".format(
+                *linux_version, *MIN_LINUX_VERSION
+            )
+        )
+        sys.exit(1)
+
     RE_SYSCALL_NR = re.compile(r"\b__([A-Z]+_)?NR_([a-z0-9_]+)\b")
     names = set(
         x.groups()
@@ -158,39 +359,63 @@ def main(args: List[str]) -> None:
         "aarch64": {
             "name": "arm64",
             "headers": {"defines": {}},
+            "pointer_width": 64,
+            "clone_backwards": True,
         },
         "armeabi": {
             "name": "arm",
             "table": {"fname": "tools/syscall.tbl", "abis": {"common", "eabi"}},
             "headers": {"defines": {"__ARM_EABI__": ""}},
+            "pointer_width": 32,
+            "clone_backwards": True,
         },
         "mips": {
             "name": "mips",
             "headers": {"defines": {"_MIPS_SIM": "_MIPS_SIM_ABI32"}},
+            "entries": {"fname": "kernel/syscalls/syscall_o32.tbl", "abis": {"o32"}},
+            "pointer_width": 32,
         },
         "mips64": {
             "name": "mips",
             "headers": {"defines": {"_MIPS_SIM": "_MIPS_SIM_ABI64"}},
+            "entries": {"fname": "kernel/syscalls/syscall_n64.tbl", "abis": {"n64"}},
+            "pointer_width": 64,
         },
         "powerpc": {
             "name": "powerpc",
             "headers": {"defines": {"__arch64__": None}},
+            "entries": {
+                "fname": "kernel/syscalls/syscall.tbl",
+                "abis": {"common", "32", "nospu"},
+            },
+            "pointer_width": 32,
         },
         "powerpc64": {
             "name": "powerpc",
             "headers": {"defines": {"__arch64__": 1, "__powerpc64__": ""}},
+            "entries": {
+                "fname": "kernel/syscalls/syscall.tbl",
+                "abis": {"common", "64", "nospu"},
+            },
+            "pointer_width": 64,
         },
         "sparc64": {
             "name": "sparc",
             "headers": {"defines": {}},
+            "entries": {"fname": "kernel/syscalls/syscall.tbl", "abis": {"common", "64"}},
+            "pointer_width": 64,
         },
         "riscv64": {
             "name": "riscv",
             "headers": {"defines": {}},
+            "pointer_width": 64,
+            "clone_backwards": True,
         },
         "x86": {
             "name": "x86",
             "table": {"fname": "entry/syscalls/syscall_32.tbl", "abis": {"i386"}},
+            "pointer_width": 32,
+            "clone_backwards": True,
         },
         "x86_64": {
             "name": "x86",
@@ -198,12 +423,41 @@ def main(args: List[str]) -> None:
                 "fname": "entry/syscalls/syscall_64.tbl",
                 "abis": {"common", "64"},
             },
+            "pointer_width": 64,
+        },
+        "x32": {
+            "name": "x86",
+            # x32 shares the x86-64 table. The "x32" entries replace the "64" entries for syscalls
+            # that take pointers to structures whose layout differs, and every syscall number has
+            # __X32_SYSCALL_BIT set. (The entry points of most of the "x32" entries are
+            # compat_sys_*(), so their signatures aren't found.)
+            "table": {
+                "fname": "entry/syscalls/syscall_64.tbl",
+                "abis": {"common", "x32"},
+                "offset": X32_SYSCALL_BIT,
+            },
+            # Pointers are 32 bits, but the registers are 64 bits, so 64-bit arguments aren't split
+            "pointer_width": 64,
         },
     }
 
+    signatures = load_signatures(linux_path)
+
     numbers = {}
+    entries = {}
     for arch, info in arch_info.items():
         numbers[arch] = {}
+        entries[arch] = {}
+
+        for key in ["table", "entries"]:
+            if key in info:
+                entries[arch].update(
+                    load_table_entries(
+                        linux_path,
+                        os.path.join("arch", info["name"], info[key]["fname"]),
+                        info[key]["abis"],
+                    )
+                )
 
         if "table" in info:
             numbers[arch].update(
@@ -211,6 +465,7 @@ def main(args: List[str]) -> None:
                     linux_path,
                     os.path.join("arch", info["name"], info["table"]["fname"]),
                     info["table"]["abis"],
+                    info["table"].get("offset", 0),
                 )
             )
 
@@ -230,6 +485,7 @@ def main(args: List[str]) -> None:
         if not nums:
             raise RuntimeError("No system call numbers found for {}!".format(arch))
 
+        aliases = {}
         for name in [
             "setuid",
             "setreuid",
@@ -250,6 +506,9 @@ def main(args: List[str]) -> None:
         ]:
             if name + "32" not in nums:
                 nums[name + "32"] = nums[name]
+                aliases[name + "32"] = name
+
+        nums.pop("_exit", None)
 
         with open("{}/src/platform/linux-{}/nr.rs".format(repo_path, arch), "w") as f:
             f.write(
@@ -257,13 +516,28 @@ def main(args: List[str]) -> None:
                     NICE_ARCH_NAMES.get(arch, arch)
                 )
             )
-            f.write("/* automatically generated by nr_from_src.py */\n\n")
+            f.write(
+                "/* automatically generated by nr_from_src.py from Linux {}.{} */\n\n".format(
+                    *linux_version
+                )
+            )
             for name, nr in sorted(nums.items()):
-                if name == "_exit":
-                    continue
-
                 f.write("pub const {}: usize = {};\n".format(name.upper(), nr))
 
+            sigs = resolve_signatures(
+                signatures,
+                arch_info[arch]["name"],
+                nums.keys(),
+                entries[arch],
+                arch_info[arch]["pointer_width"],
+            )
+            if arch_info[arch].get("clone_backwards") and "clone" in sigs:
+                sigs["clone"] = (
+                    len(CLONE_BACKWARDS_SIGNATURE),
+                    format_signature(CLONE_BACKWARDS_SIGNATURE),
+                )
+            write_sysno_table(f, nums, aliases, sigs)
+
 
 if "__main__" == __name__:
     main(sys.argv[1:])
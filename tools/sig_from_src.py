@@ -0,0 +1,89 @@
+#!/usr/bin/env python3
+import os
+import re
+import sys
+from typing import Dict, List
+
+from consts_from_src import (
+    ARCH_INFO,
+    NICE_ARCH_NAMES,
+    arch_defines,
+    format_value,
+    load_header_consts,
+)
+
+# The headers that signal-related constants are read from, and a regex matching the names of the
+# constants that should be taken from each one.
+SIG_HEADERS = [
+    (
+        "asm/signal.h",
+        r"SIG[A-Z0-9]+|SIG_BLOCK|SIG_UNBLOCK|SIG_SETMASK|SA_[A-Z0-9_]+|MINSIGSTKSZ",
+    ),
+    ("linux/signal.h", r"SS_[A-Z]+"),
+]
+
+# Obsolete aliases, and constants that are not useful to userspace
+EXCLUDED_SIG_CONSTS = re.compile(r"^(SIGUNUSED|SA_NOMASK|SA_ONESHOT|SS_FLAG_BITS)$")
+
+REQUIRED_SIGNALS = set(
+    "SIGHUP SIGINT SIGQUIT SIGILL SIGTRAP SIGABRT SIGBUS SIGFPE SIGKILL SIGUSR1 SIGSEGV SIGUSR2 SIGPIPE SIGALRM SIGTERM SIGCHLD SIGCONT SIGSTOP SIGTSTP SIGTTIN SIGTTOU SIGURG SIGXCPU SIGXFSZ SIGVTALRM SIGPROF SIGWINCH SIGIO SIGPWR SIGSYS SIGRTMIN SIGRTMAX".split()
+)
+
+
+def sig_const_type(name: str) -> str:
+    if name in ("SIGSTKSZ", "MINSIGSTKSZ"):
+        return "usize"
+    elif name.startswith(("SA_", "SS_")):
+        return "u32"
+    else:
+        return "i32"
+
+
+def write_sig_consts(f, consts: Dict[str, int]) -> None:
+    for name, value in sorted(consts.items()):
+        # Signal numbers and stack sizes are always written in decimal
+        if name.startswith("SIG") or name.endswith("STKSZ"):
+            value_str = str(value)
+        else:
+            value_str = format_value(value)
+        f.write("pub const {}: {} = {};\n".format(name, sig_const_type(name), value_str))
+
+
+def main(args: List[str]) -> None:
+    if len(args) != 1:
+        print(
+            "Usage: {} <path to Linux kernel source>".format(sys.argv[0]),
+            file=sys.stderr,
+        )
+        sys.exit(1)
+
+    repo_path = os.path.dirname(os.path.dirname(os.path.realpath(__file__)))
+    linux_path = args[0]
+
+    for arch, info in ARCH_INFO.items():
+        extra = arch_defines(info)
+
+        consts: Dict[str, int] = {}
+        for header, name_re in SIG_HEADERS:
+            consts.update(
+                load_header_consts(
+                    linux_path, info["name"], header, name_re, extra, EXCLUDED_SIG_CONSTS
+                )
+            )
+
+        assert set(consts.keys()) >= REQUIRED_SIGNALS, "{}: {}".format(
+            arch, REQUIRED_SIGNALS - set(consts.keys())
+        )
+
+        with open("{}/src/platform/linux-{}/sig.rs".format(repo_path, arch), "w") as f:
+            f.write(
+                "//! Signal numbers and flags for {} Linux.\n\n".format(
+                    NICE_ARCH_NAMES.get(arch, arch)
+                )
+            )
+            f.write("/* automatically generated by sig_from_src.py */\n\n")
+            write_sig_consts(f, consts)
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])